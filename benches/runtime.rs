@@ -0,0 +1,131 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use futures_executor::block_on;
+use kes::bench::NoopBuiltin;
+use kes::context::Context;
+use kes::interner::Interner;
+use kes::parser::{count_tokens, parse};
+use kes::program::Program;
+
+/// Counter loop with arithmetic and comparison
+fn loop_heavy() -> String {
+    "$합 = 0; $1 = 0; 반복 $1 < 2000 { $합 = $합 + $1 * 2 % 7; $1 = $1 + 1; }".into()
+}
+
+/// Many string literals printed and passed to builtins
+fn string_heavy() -> String {
+    "@'안녕하세요' '반갑습니다' $이름 = '철수'; @!'{$이름}님' 이름표('철수', '영희');\n".repeat(200)
+}
+
+/// Strings built, joined and compared in loop, values are moved on stack every iteration
+fn string_loop() -> String {
+    "$1 = 0; $2 = 0; 반복 $1 < 2000 { $문장 = '번호' + $1 + '번'; 만약 $문장 == '번호7번' { $2 = $2 + 1; } $1 = $1 + 1; }".into()
+}
+
+/// Nested conditions
+fn branch_heavy() -> String {
+    "$1 = 3; 만약 1 + 2 == $1 { 만약 $1 > 2 { $2 = 1; } 혹은 $1 < 0 { $2 = 2; } 그외 { $2 = 3; } } 그외 { 만약 $1 { } }\n"
+        .repeat(200)
+}
+
+/// Same builtins called again in loop, handles are resolved once
+fn call_heavy() -> String {
+    "$1 = 0; 반복 $1 < 2000 { 함수($1); 이름표('철수', $1); $1 = $1 + 1; }".into()
+}
+
+fn corpora() -> [(&'static str, String); 5] {
+    [
+        ("loop", loop_heavy()),
+        ("string", string_heavy()),
+        ("string-loop", string_loop()),
+        ("branch", branch_heavy()),
+        ("call", call_heavy()),
+    ]
+}
+
+fn lex(c: &mut Criterion) {
+    let mut group = c.benchmark_group("lex");
+    for (name, source) in corpora().iter() {
+        group.throughput(Throughput::Bytes(source.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), source, |b, source| {
+            let mut interner = Interner::new();
+            b.iter(|| count_tokens(source, &mut interner).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn parse_source(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+    for (name, source) in corpora().iter() {
+        group.throughput(Throughput::Bytes(source.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), source, |b, source| {
+            let mut interner = Interner::new();
+            b.iter(|| parse(source, &mut interner).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn compile(c: &mut Criterion) {
+    let mut group = c.benchmark_group("compile");
+    for (name, source) in corpora().iter() {
+        let mut interner = Interner::new();
+        let ast = parse(source, &mut interner).unwrap();
+        group.bench_function(*name, |b| {
+            b.iter(|| Program::from_ast(&ast, interner.clone()))
+        });
+    }
+    group.finish();
+}
+
+fn serialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialize");
+    for (name, source) in corpora().iter() {
+        let program = Program::from_source(source).unwrap();
+        let bytes = bincode::serialize(&program).unwrap();
+        group.bench_function(BenchmarkId::new("encode", name), |b| {
+            b.iter(|| bincode::serialize(&program).unwrap())
+        });
+        group.bench_function(BenchmarkId::new("decode", name), |b| {
+            b.iter(|| bincode::deserialize::<Program>(&bytes).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn execute(c: &mut Criterion) {
+    let mut group = c.benchmark_group("execute");
+    for (name, source) in corpora().iter() {
+        let program = Program::from_source(source).unwrap();
+        group.bench_function(*name, |b| {
+            b.iter(|| block_on(Context::new(&program).run(NoopBuiltin)).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn format(c: &mut Criterion) {
+    let mut group = c.benchmark_group("format");
+    for (name, source) in corpora().iter() {
+        group.throughput(Throughput::Bytes(source.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(name), source, |b, source| {
+            let mut out = Vec::new();
+            b.iter(|| {
+                kes::formatter::format_code(source, &mut out).unwrap();
+                out.clear();
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    lex,
+    parse_source,
+    compile,
+    serialize,
+    execute,
+    format
+);
+criterion_main!(benches);
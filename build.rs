@@ -1,8 +1,22 @@
 fn main() {
-    // lalrpop::process_root().unwrap();
-    lalrpop::Configuration::new()
-        .always_use_colors()
-        .emit_rerun_directives(true)
-        .process_current_dir()
-        .unwrap();
+    #[cfg(feature = "grammar-codegen")]
+    {
+        // lalrpop::process_root().unwrap();
+        lalrpop::Configuration::new()
+            .always_use_colors()
+            .emit_rerun_directives(true)
+            .process_current_dir()
+            .unwrap();
+
+        // `KES_UPDATE_GRAMMAR=1 cargo build` refreshes parser for `pregenerated-grammar`
+        println!("cargo:rerun-if-env-changed=KES_UPDATE_GRAMMAR");
+        if std::env::var_os("KES_UPDATE_GRAMMAR").is_some() {
+            let out_dir = std::env::var_os("OUT_DIR").unwrap();
+            std::fs::copy(
+                std::path::Path::new(&out_dir).join("grammar.rs"),
+                "src/grammar_generated.rs",
+            )
+            .unwrap();
+        }
+    }
 }
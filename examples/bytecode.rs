@@ -0,0 +1,46 @@
+//! Compile script into bytecode and run it without parsing source again
+
+use kes::prelude::{Context, Program, RecordBuiltin, Source};
+use std::env;
+
+fn compile(source: &str) -> Vec<u8> {
+    let program = Program::from_source(source).unwrap();
+    bincode::serialize(&program).unwrap()
+}
+
+fn run(bytecode: &[u8]) -> String {
+    let program = bincode::deserialize::<Program>(bytecode).unwrap();
+    let mut builtin = RecordBuiltin::new();
+
+    futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+
+    builtin.text().into()
+}
+
+fn main() {
+    if let Some(arg) = env::args().nth(1) {
+        let source = Source::read(arg).unwrap();
+        let bytecode = compile(source.as_str());
+        println!("bytecode: {} bytes", bytecode.len());
+        println!("{}", run(&bytecode));
+    } else {
+        println!("Usage: <program> <path>");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compile, run};
+
+    #[test]
+    fn round_trip() {
+        let source = include_str!("fib.kes");
+        let bytecode = compile(source);
+
+        assert_eq!(compile(source), bytecode);
+        assert_eq!(
+            run(&bytecode),
+            "$0: 0@$0: 1@$0: 2@$0: 3@$0: 5@$0: 8@$0: 13@$0: 21@$0: 34@$0: 55@"
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use kes::program::Program;
+use kes::prelude::Program;
 use std::env;
 
 fn main() {
@@ -10,3 +10,16 @@ fn main() {
         println!("Usage: <program> <path>");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use kes::prelude::Program;
+
+    #[test]
+    fn dump_json() {
+        let program = Program::from_source(include_str!("fib.kes")).unwrap();
+        let json = serde_json::to_string(&program).unwrap();
+
+        assert_eq!(serde_json::from_str::<Program>(&json).unwrap(), program);
+    }
+}
@@ -1,8 +1,4 @@
-use kes::async_trait;
-use kes::builtin::Builtin;
-use kes::context::Context;
-use kes::program::Program;
-use kes::value::Value;
+use kes::prelude::{async_trait, compile_source, Builtin, Context, Value};
 
 pub struct StdioBuiltin;
 
@@ -28,9 +24,27 @@ impl Builtin for StdioBuiltin {
 }
 
 fn main() {
-    let program = Program::from_source(include_str!("fib.kes")).unwrap();
+    let program = compile_source(include_str!("fib.kes")).unwrap();
 
     let ctx = Context::new(&program);
 
     futures_executor::block_on(ctx.run(StdioBuiltin)).unwrap();
 }
+
+#[cfg(test)]
+mod tests {
+    use kes::prelude::{Context, Program, RecordBuiltin};
+
+    #[test]
+    fn fib() {
+        let program = Program::from_source(include_str!("fib.kes")).unwrap();
+        let mut builtin = RecordBuiltin::new();
+
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+
+        assert_eq!(
+            builtin.text(),
+            "$0: 0@$0: 1@$0: 2@$0: 3@$0: 5@$0: 8@$0: 13@$0: 21@$0: 34@$0: 55@"
+        );
+    }
+}
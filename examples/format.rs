@@ -12,3 +12,15 @@ fn main() {
         println!("Usage: <program> <path>");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use kes::formatter::format_code_to_string;
+
+    #[test]
+    fn format_fib() {
+        let code = include_str!("fib.kes");
+
+        assert_eq!(format_code_to_string(code).unwrap(), code);
+    }
+}
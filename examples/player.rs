@@ -0,0 +1,290 @@
+//! Minimal GUI script player
+//!
+//! Run with `cargo run --example player --features player -- script.kes`
+//!
+//! Script runs on its own thread and is driven by `Context::step`, click text box to advance.
+//! Set `KES_PLAYER_FONT` to font file path which has hangul glyphs.
+
+use eframe::egui;
+use kes::prelude::{
+    async_trait, Builtin, Context, Program, RuntimeResult, Snapshot, Source, StdBuiltin, Value,
+    WaitControl, WaitPolicy,
+};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+const DEMO: &str = "
+@!'안녕하세요, kes 플레이어입니다.';
+$횟수 = 0;
+반복 $횟수 < 3 {
+    $횟수 = $횟수 + 1;
+    @!$횟수 '번째 대사입니다.';
+}
+@@'저장 버튼을 누르고 진행한 뒤';
+@!'불러오기로 돌아가보세요.';
+@!'끝';
+";
+
+enum Command {
+    Advance,
+    Save,
+    Load(Snapshot),
+}
+
+enum Event {
+    Print(String),
+    NewLine,
+    Wait,
+    Clear,
+    Saved(Snapshot),
+    Finished(Result<(), String>),
+}
+
+/// State shared between script thread and builtin
+#[derive(Default)]
+struct Shared {
+    /// Snapshot taken at start of current line
+    checkpoint: Snapshot,
+    load: Option<Snapshot>,
+    closed: bool,
+}
+
+struct PlayerBuiltin {
+    events: Sender<Event>,
+    commands: Receiver<Command>,
+    shared: Arc<Mutex<Shared>>,
+}
+
+#[async_trait]
+impl Builtin for PlayerBuiltin {
+    async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> Value {
+        Value::Int(0)
+    }
+
+    fn print(&mut self, v: Value) {
+        self.events.send(Event::Print(v.to_string())).ok();
+    }
+
+    fn new_line(&mut self) {
+        self.events.send(Event::NewLine).ok();
+    }
+
+    async fn wait(&mut self) {
+        self.events.send(Event::Wait).ok();
+
+        loop {
+            match self.commands.recv() {
+                Ok(Command::Advance) => break,
+                Ok(Command::Save) => {
+                    let snapshot = self.shared.lock().unwrap().checkpoint.clone();
+                    self.events.send(Event::Saved(snapshot)).ok();
+                }
+                Ok(Command::Load(snapshot)) => {
+                    self.shared.lock().unwrap().load = Some(snapshot);
+                    break;
+                }
+                Err(_) => {
+                    self.shared.lock().unwrap().closed = true;
+                    break;
+                }
+            }
+        }
+
+        self.events.send(Event::Clear).ok();
+    }
+}
+
+async fn play(
+    mut ctx: Context<'_>,
+    builtin: &mut impl Builtin,
+    shared: &Mutex<Shared>,
+) -> RuntimeResult<()> {
+    let mut line = None;
+
+    loop {
+        let location = ctx.location();
+
+        if line != Some(location) {
+            line = Some(location);
+            shared.lock().unwrap().checkpoint = ctx.snapshot();
+        }
+
+        if !ctx.step(builtin).await? {
+            return Ok(());
+        }
+
+        let mut shared = shared.lock().unwrap();
+
+        if shared.closed {
+            return Ok(());
+        }
+
+        if let Some(snapshot) = shared.load.take() {
+            ctx.restore(snapshot);
+            line = None;
+        }
+    }
+}
+
+struct Player {
+    events: Receiver<Event>,
+    commands: Sender<Command>,
+    wait_control: WaitControl,
+    text: String,
+    waiting: bool,
+    skip: bool,
+    save: Option<Snapshot>,
+    finished: Option<Result<(), String>>,
+}
+
+impl Player {
+    fn handle_events(&mut self) {
+        while let Ok(event) = self.events.try_recv() {
+            match event {
+                Event::Print(text) => self.text.push_str(&text),
+                Event::NewLine => self.text.push('\n'),
+                Event::Wait => self.waiting = true,
+                Event::Clear => self.text.clear(),
+                Event::Saved(snapshot) => self.save = Some(snapshot),
+                Event::Finished(result) => self.finished = Some(result),
+            }
+        }
+    }
+}
+
+impl eframe::App for Player {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        self.handle_events();
+
+        egui::TopBottomPanel::bottom("controls").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(self.waiting, egui::Button::new("저장"))
+                    .clicked()
+                {
+                    self.commands.send(Command::Save).ok();
+                }
+
+                if ui
+                    .add_enabled(
+                        self.waiting && self.save.is_some(),
+                        egui::Button::new("불러오기"),
+                    )
+                    .clicked()
+                {
+                    if let Some(save) = self.save.clone() {
+                        self.waiting = false;
+                        self.commands.send(Command::Load(save)).ok();
+                    }
+                }
+
+                if ui.checkbox(&mut self.skip, "스킵").changed() {
+                    self.wait_control.set_policy(if self.skip {
+                        WaitPolicy::Skip
+                    } else {
+                        WaitPolicy::Normal
+                    });
+                }
+            });
+        });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            let response =
+                ui.interact(ui.max_rect(), ui.id().with("advance"), egui::Sense::click());
+
+            ui.label(egui::RichText::new(&self.text).size(20.0));
+
+            match &self.finished {
+                Some(Ok(())) => {
+                    ui.label("(끝)");
+                }
+                Some(Err(err)) => {
+                    ui.colored_label(egui::Color32::RED, err);
+                }
+                None if self.waiting => {
+                    ui.label("▼");
+                }
+                None => {}
+            }
+
+            if response.clicked() && self.waiting {
+                self.waiting = false;
+                self.commands.send(Command::Advance).ok();
+            }
+        });
+
+        ctx.request_repaint_after(Duration::from_millis(16));
+    }
+}
+
+fn setup_font(ctx: &egui::Context) {
+    let font = match std::env::var_os("KES_PLAYER_FONT").map(std::fs::read) {
+        Some(Ok(font)) => font,
+        _ => return,
+    };
+
+    let mut fonts = egui::FontDefinitions::default();
+    fonts
+        .font_data
+        .insert("hangul".into(), egui::FontData::from_owned(font));
+    for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+        fonts
+            .families
+            .entry(family)
+            .or_default()
+            .insert(0, "hangul".into());
+    }
+    ctx.set_fonts(fonts);
+}
+
+fn main() -> eframe::Result<()> {
+    let source = match std::env::args().nth(1) {
+        Some(path) => Source::read(path).expect("read script").into_string(),
+        None => DEMO.into(),
+    };
+
+    // script thread borrows program until process ends
+    let program: &'static Program = Box::leak(Box::new(
+        Program::from_source(&source).expect("parse script"),
+    ));
+
+    let ctx = Context::new(program);
+    let wait_control = ctx.wait_control();
+    let (command_tx, command_rx) = channel();
+    let (event_tx, event_rx) = channel();
+
+    std::thread::spawn(move || {
+        let shared = Arc::new(Mutex::new(Shared::default()));
+        let mut builtin = StdBuiltin::new(PlayerBuiltin {
+            events: event_tx.clone(),
+            commands: command_rx,
+            shared: shared.clone(),
+        });
+
+        let result = futures_executor::block_on(play(ctx, &mut builtin, &shared));
+        event_tx
+            .send(Event::Finished(result.map_err(|err| err.to_string())))
+            .ok();
+    });
+
+    let player = Player {
+        events: event_rx,
+        commands: command_tx,
+        wait_control,
+        text: String::new(),
+        waiting: false,
+        skip: false,
+        save: None,
+        finished: None,
+    };
+
+    eframe::run_native(
+        "kes player",
+        eframe::NativeOptions::default(),
+        Box::new(|cc| {
+            setup_font(&cc.egui_ctx);
+            Ok(Box::new(player))
+        }),
+    )
+}
@@ -0,0 +1,43 @@
+//! Smallest embedding of `tiny-runtime`, CI builds it for wasm32 to check code size
+//!
+//! `cargo build --release --example tiny_wasm --no-default-features --features tiny-runtime --target wasm32-unknown-unknown`
+
+use kes::tiny::{Host, Image, Vm};
+use kes::value::Value;
+use std::fmt::Write;
+
+struct Output(String);
+
+impl Host for Output {
+    fn call(&mut self, _name: &str, _vm: &mut Vm<'_>) -> Value {
+        Value::Null
+    }
+    fn print(&mut self, value: Value) {
+        write!(self.0, "{}", value).unwrap();
+    }
+    fn new_line(&mut self) {
+        self.0.push('\n');
+    }
+    fn wait(&mut self) {}
+}
+
+/// Run image of `len` bytes at `ptr`, returns length of printed text or `-1` on error
+///
+/// # Safety
+///
+/// `ptr` must point to `len` readable bytes
+#[no_mangle]
+pub unsafe extern "C" fn run(ptr: *const u8, len: usize) -> isize {
+    // SAFETY: caller guarantees `ptr..ptr + len` is readable
+    let bytes = std::slice::from_raw_parts(ptr, len);
+    let image = match Image::decode(bytes) {
+        Ok(image) => image,
+        Err(_) => return -1,
+    };
+    let mut output = Output(String::new());
+
+    match Vm::new(&image).run(&mut output) {
+        Ok(()) => output.0.len() as isize,
+        Err(_) => -1,
+    }
+}
@@ -0,0 +1,250 @@
+//! `kes debug` terminal UI
+//!
+//! Keys: `s` step instruction, `n` next line, `c` continue to breakpoint,
+//! `b` toggle breakpoint on selected line, `↑`/`↓` move selection, `q` quit
+
+use kes::async_trait;
+use kes::builtin::Builtin;
+use kes::context::Context;
+use kes::error::{Language, ParseErrorMessage};
+use kes::program::Program;
+use kes::stdlib::StdBuiltin;
+use kes::trace::literal;
+use kes::value::Value;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{DefaultTerminal, Frame};
+use std::fmt::Write;
+use std::io;
+
+/// Collects script output for output pane
+#[derive(Default)]
+struct OutputBuiltin {
+    text: String,
+}
+
+#[async_trait]
+impl Builtin for OutputBuiltin {
+    async fn run(&mut self, name: &str, _ctx: &mut Context<'_>) -> Value {
+        write!(self.text, "<{}>", name).unwrap();
+        Value::Int(0)
+    }
+
+    fn print(&mut self, v: Value) {
+        write!(self.text, "{}", v).unwrap();
+    }
+
+    fn new_line(&mut self) {
+        self.text.push('\n');
+    }
+
+    async fn wait(&mut self) {}
+}
+
+enum Command {
+    Step,
+    Next,
+    Continue,
+}
+
+struct Debugger<'p> {
+    program: &'p Program,
+    lines: Vec<&'p str>,
+    ctx: Context<'p>,
+    builtin: StdBuiltin<OutputBuiltin>,
+    /// Line selected in source view, starts from 1
+    selected: usize,
+    status: String,
+    finished: bool,
+}
+
+impl<'p> Debugger<'p> {
+    fn new(program: &'p Program, source: &'p str) -> Self {
+        let ctx = Context::new(program);
+        let selected = ctx.location().line.max(1);
+
+        Self {
+            program,
+            lines: source.lines().collect(),
+            ctx,
+            builtin: StdBuiltin::new(OutputBuiltin::default()),
+            selected,
+            status: "s: 스텝, n: 다음 줄, c: 계속, b: 중단점, q: 종료".into(),
+            finished: false,
+        }
+    }
+
+    fn execute(&mut self, command: Command) {
+        if self.finished {
+            return;
+        }
+
+        let builtin = &mut self.builtin;
+        let ctx = &mut self.ctx;
+        let result = futures_executor::block_on(async {
+            match command {
+                Command::Step => ctx.step(builtin).await,
+                Command::Next => ctx.next_line(builtin).await,
+                Command::Continue => ctx.run_to_breakpoint(builtin).await,
+            }
+        });
+
+        match result {
+            Ok(true) => {
+                self.selected = self.ctx.location().line;
+                self.status = match self.ctx.watch_hit() {
+                    Some(name) => {
+                        format!(
+                            "{}에서 `${}` 감시점에 걸렸습니다",
+                            self.ctx.location(),
+                            name
+                        )
+                    }
+                    None => format!("{}에서 멈췄습니다", self.ctx.location()),
+                };
+            }
+            Ok(false) => {
+                self.finished = true;
+                self.status = "스크립트가 끝났습니다".into();
+            }
+            Err(err) => {
+                self.finished = true;
+                self.status = err.to_string();
+            }
+        }
+    }
+
+    fn toggle_breakpoint(&mut self) {
+        if self.ctx.breakpoints().any(|line| line == self.selected) {
+            self.ctx.remove_breakpoint(self.selected);
+        } else {
+            self.ctx.set_breakpoint(self.selected);
+        }
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [main, output, status] = Layout::vertical([
+            Constraint::Min(5),
+            Constraint::Length(8),
+            Constraint::Length(1),
+        ])
+        .areas(frame.area());
+        let [source, side] =
+            Layout::horizontal([Constraint::Percentage(65), Constraint::Percentage(35)])
+                .areas(main);
+        let [variables, stack] =
+            Layout::vertical([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(side);
+
+        let current = if self.finished {
+            None
+        } else {
+            Some(self.ctx.location().line)
+        };
+        let breakpoints = self.ctx.breakpoints().collect::<Vec<_>>();
+        let items = self.lines.iter().enumerate().map(|(idx, text)| {
+            let line = idx + 1;
+            let mark = if breakpoints.contains(&line) {
+                '●'
+            } else {
+                ' '
+            };
+            let arrow = if current == Some(line) { '>' } else { ' ' };
+            let item = ListItem::new(format!("{}{}{:4} {}", mark, arrow, line, text));
+
+            if current == Some(line) {
+                item.style(Style::default().fg(Color::Yellow))
+            } else {
+                item
+            }
+        });
+        let mut state = ListState::default().with_selected(self.selected.checked_sub(1));
+        frame.render_stateful_widget(
+            List::new(items)
+                .block(Block::bordered().title("소스"))
+                .highlight_style(Style::default().add_modifier(Modifier::REVERSED)),
+            source,
+            &mut state,
+        );
+
+        let mut vars = self
+            .ctx
+            .variables
+            .iter()
+            .map(|(name, value)| {
+                format!(
+                    "${} = {}",
+                    self.program.resolve(*name).unwrap_or_default(),
+                    literal(value)
+                )
+            })
+            .collect::<Vec<_>>();
+        vars.sort();
+        frame.render_widget(
+            List::new(vars).block(Block::bordered().title("변수")),
+            variables,
+        );
+
+        let values = self
+            .ctx
+            .stack()
+            .iter()
+            .rev()
+            .map(literal)
+            .collect::<Vec<_>>();
+        frame.render_widget(
+            List::new(values).block(Block::bordered().title("스택")),
+            stack,
+        );
+
+        let text = &self.builtin.inner().text;
+        let shown = text.lines().rev().take(6).collect::<Vec<_>>();
+        frame.render_widget(
+            Paragraph::new(shown.into_iter().rev().map(Line::from).collect::<Vec<_>>())
+                .wrap(Wrap { trim: false })
+                .block(Block::bordered().title("출력")),
+            output,
+        );
+
+        frame.render_widget(Paragraph::new(self.status.as_str()), status);
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> io::Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            let key = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => key,
+                _ => continue,
+            };
+
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Char('s') => self.execute(Command::Step),
+                KeyCode::Char('n') => self.execute(Command::Next),
+                KeyCode::Char('c') => self.execute(Command::Continue),
+                KeyCode::Char('b') => self.toggle_breakpoint(),
+                KeyCode::Up => self.selected = self.selected.saturating_sub(1).max(1),
+                KeyCode::Down => self.selected = (self.selected + 1).min(self.lines.len()).max(1),
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn debug(source: &str) -> io::Result<()> {
+    let program = match Program::from_source(source) {
+        Ok(program) => program,
+        Err(err) => {
+            eprintln!("{}", ParseErrorMessage::new(&err, Language::Korean));
+            return Ok(());
+        }
+    };
+
+    let mut terminal = ratatui::init();
+    let result = Debugger::new(&program, source).run(&mut terminal);
+    ratatui::restore();
+    result
+}
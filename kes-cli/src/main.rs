@@ -0,0 +1,66 @@
+#[cfg(feature = "debugger")]
+mod debugger;
+
+use kes::error::{Language, ParseErrorMessage};
+use kes::program::{Program, StringEntry};
+use kes::source::Source;
+use serde::Serialize;
+use std::env;
+use std::process;
+
+#[derive(Serialize)]
+struct FileStrings {
+    file: String,
+    strings: Vec<StringEntry>,
+}
+
+fn read(path: &str) -> Result<Source<'static>, String> {
+    Source::read(path).map_err(|err| format!("{}: {}", path, err))
+}
+
+/// Nothing is printed to stdout when any file can't be read or parsed
+fn extract(paths: &[String]) -> Result<(), String> {
+    let mut files = Vec::with_capacity(paths.len());
+
+    for path in paths {
+        let source = read(path)?;
+        let program = Program::from_source(source.as_str()).map_err(|err| {
+            format!(
+                "{}: {}",
+                path,
+                ParseErrorMessage::new(&err, Language::Korean)
+            )
+        })?;
+
+        files.push(FileStrings {
+            file: path.clone(),
+            strings: program.extract_strings(),
+        });
+    }
+
+    println!("{}", serde_json::to_string_pretty(&files).unwrap());
+    Ok(())
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+
+    let result = match args.split_first() {
+        Some((cmd, paths)) if cmd == "extract" && !paths.is_empty() => extract(paths),
+        #[cfg(feature = "debugger")]
+        Some((cmd, [path])) if cmd == "debug" => read(path).and_then(|source| {
+            debugger::debug(source.as_str()).map_err(|err| format!("IO 에러: {}", err))
+        }),
+        _ => {
+            eprintln!("Usage: kes extract <path>...");
+            #[cfg(feature = "debugger")]
+            eprintln!("       kes debug <path>");
+            process::exit(2);
+        }
+    };
+
+    if let Err(message) = result {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
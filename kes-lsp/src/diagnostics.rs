@@ -0,0 +1,230 @@
+use crate::document::Document;
+use kes::error::{
+    CompileWarning, Language, LexicalError, ParseError, ParseErrorInfo, ParseErrorMessage,
+};
+use kes::program::{CompileOptions, Diagnostics};
+use kes::signature::{ParamType, Signature, Signatures};
+use lsp_types::{Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString, Range};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Information,
+    Hint,
+    /// Don't report at all
+    Off,
+}
+
+impl Severity {
+    fn to_lsp(self) -> Option<DiagnosticSeverity> {
+        match self {
+            Severity::Error => Some(DiagnosticSeverity::ERROR),
+            Severity::Warning => Some(DiagnosticSeverity::WARNING),
+            Severity::Information => Some(DiagnosticSeverity::INFORMATION),
+            Severity::Hint => Some(DiagnosticSeverity::HINT),
+            Severity::Off => None,
+        }
+    }
+}
+
+/// Type of builtin parameter or return value in settings
+///
+/// Names of `ParamType` like `int` or keywords of script like `수`
+fn param_type(name: &str) -> Option<ParamType> {
+    ParamType::from_keyword(name).or_else(|| {
+        [
+            ParamType::Any,
+            ParamType::Int,
+            ParamType::Str,
+            ParamType::Bool,
+            ParamType::List,
+        ]
+        .iter()
+        .copied()
+        .find(|ty| ty.name() == name)
+    })
+}
+
+/// Builtin declared by host, same declaration is used for checking calls, editor and preview run
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct BuiltinSetting {
+    pub params: Vec<String>,
+    pub returns: Option<String>,
+}
+
+impl BuiltinSetting {
+    /// `None` when any type is unknown
+    fn signature(&self) -> Option<Signature> {
+        let params = self
+            .params
+            .iter()
+            .map(|name| param_type(name))
+            .collect::<Option<Vec<_>>>()?;
+        let ret = match &self.returns {
+            Some(name) => param_type(name)?,
+            None => ParamType::Any,
+        };
+        Some(Signature::new(params).returns(ret))
+    }
+}
+
+/// Settings from `initializationOptions` or `kes` section of configuration
+///
+/// ```json
+/// {
+///     "strict": true,
+///     "severity": { "unreachable-code": "hint", "use-before-assign": "off" },
+///     "builtins": { "이름표": { "params": ["str", "수"], "returns": "str" } }
+/// }
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Report undeclared variables like `CompileOptions::strict`
+    pub strict: bool,
+    /// Severity by code, codes of warnings come from `CompileWarning::code`
+    pub severity: HashMap<String, Severity>,
+    /// Builtins of host, calls are checked only when it isn't empty
+    pub builtins: HashMap<String, BuiltinSetting>,
+    #[serde(skip)]
+    signatures: Signatures,
+}
+
+impl Settings {
+    /// Invalid settings are ignored instead of failing whole server,
+    /// builtins with unknown types are left out
+    pub fn from_value(value: serde_json::Value) -> Self {
+        let mut settings: Self = serde_json::from_value(value).unwrap_or_default();
+        for (name, builtin) in &settings.builtins {
+            if let Some(signature) = builtin.signature() {
+                settings.signatures.insert(name.clone(), signature);
+            }
+        }
+        settings
+    }
+
+    /// Signatures of `builtins`, `None` when host declared nothing
+    pub fn signatures(&self) -> Option<&Signatures> {
+        if self.builtins.is_empty() {
+            None
+        } else {
+            Some(&self.signatures)
+        }
+    }
+
+    pub fn compile_options(&self) -> CompileOptions<'_> {
+        CompileOptions {
+            strict: self.strict,
+            signatures: self.signatures(),
+            ..CompileOptions::default()
+        }
+    }
+
+    fn severity(&self, code: &str, default: Severity) -> Option<DiagnosticSeverity> {
+        self.severity.get(code).copied().unwrap_or(default).to_lsp()
+    }
+}
+
+const PARSE_ERROR: &str = "parse-error";
+const UNDECLARED_VARIABLE: &str = "undeclared-variable";
+
+fn make(
+    range: Range,
+    severity: DiagnosticSeverity,
+    code: &str,
+    message: String,
+    tags: Option<Vec<DiagnosticTag>>,
+) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(severity),
+        code: Some(NumberOrString::String(code.into())),
+        source: Some("kes".into()),
+        message,
+        tags,
+        ..Diagnostic::default()
+    }
+}
+
+fn error_diagnostic(
+    document: &Document,
+    settings: &Settings,
+    error: &ParseError,
+) -> Option<Diagnostic> {
+    let code = match error {
+        ParseError::User {
+            error: LexicalError::UndeclaredVariable(..),
+        } => UNDECLARED_VARIABLE,
+        _ => PARSE_ERROR,
+    };
+    let severity = settings.severity(code, Severity::Error)?;
+    let location = ParseErrorInfo::new(error)
+        .location()
+        .unwrap_or_else(|| document.end_location());
+
+    Some(make(
+        document.line_range(location),
+        severity,
+        code,
+        ParseErrorMessage::new(error, Language::Korean).to_string(),
+        None,
+    ))
+}
+
+fn warning_diagnostic(
+    document: &Document,
+    settings: &Settings,
+    warning: &CompileWarning,
+) -> Option<Diagnostic> {
+    let severity = settings.severity(warning.code(), Severity::Warning)?;
+    let range = match warning.span() {
+        Some(span) => document.span_range(span),
+        None => document.line_range(warning.location()),
+    };
+    let tags = match warning {
+        CompileWarning::UnreachableCode(..) => Some(vec![DiagnosticTag::UNNECESSARY]),
+        _ => None,
+    };
+
+    Some(make(
+        range,
+        severity,
+        warning.code(),
+        warning.to_string(),
+        tags,
+    ))
+}
+
+/// Parse errors and compile warnings of document, sorted by position
+///
+/// `found` comes from `Document::compile` with `Settings::compile_options`
+pub fn diagnostics(
+    document: &Document,
+    settings: &Settings,
+    found: &Diagnostics,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = found
+        .errors
+        .iter()
+        .filter_map(|error| error_diagnostic(document, settings, error))
+        .chain(
+            found
+                .warnings
+                .iter()
+                .filter_map(|warning| warning_diagnostic(document, settings, warning)),
+        )
+        .collect::<Vec<_>>();
+
+    diagnostics.sort_by_key(|diagnostic| {
+        (
+            diagnostic.range.start.line,
+            diagnostic.range.start.character,
+        )
+    });
+    diagnostics
+}
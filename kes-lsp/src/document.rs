@@ -0,0 +1,149 @@
+use crate::reparse::ParsedDocument;
+use kes::location::{Location, Span};
+use kes::program::{CompileOptions, Diagnostics, Program};
+use kes::source::Source;
+use lsp_types::{Position, Range};
+
+fn line_starts(text: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(idx, _)| idx + 1))
+        .collect()
+}
+
+/// Opened text document
+pub struct Document {
+    text: String,
+    version: i32,
+    /// Byte offset of start of each line
+    line_starts: Vec<usize>,
+    parsed: ParsedDocument,
+}
+
+impl Document {
+    pub fn new(text: &str, version: i32) -> Self {
+        // offsets of spans are based on normalized source,
+        // front matter is kept so program compiled from text still has metadata
+        let source = Source::new(text);
+        let header = source.header();
+        let text = format!("{}{}", header, &source.as_str()[header.len()..]);
+        let line_starts = line_starts(&text);
+        let parsed = ParsedDocument::new(&text);
+
+        Self {
+            text,
+            version,
+            line_starts,
+            parsed,
+        }
+    }
+
+    /// Apply one change of `textDocument/didChange`, change without range replaces whole text
+    pub fn apply_change(&mut self, range: Option<Range>, text: &str) {
+        let range = match range {
+            Some(range) => range,
+            None => {
+                *self = Self::new(text, self.version);
+                return;
+            }
+        };
+
+        let start = self.offset(range.start);
+        let end = self.offset(range.end).max(start);
+        let text = text.replace("\r\n", "\n");
+        let lines = self.line_starts.len();
+
+        self.text.replace_range(start..end, &text);
+        self.line_starts = line_starts(&self.text);
+        let lines = self.line_starts.len() as isize - lines as isize;
+        self.parsed
+            .edit(&self.text, start, end, start + text.len(), lines);
+    }
+
+    pub fn set_version(&mut self, version: i32) {
+        self.version = version;
+    }
+
+    pub fn parsed(&self) -> &ParsedDocument {
+        &self.parsed
+    }
+
+    /// Compile kept statements, done once per change and shared by diagnostics and index
+    pub fn compile(&self, options: &CompileOptions) -> (Program, Diagnostics) {
+        Program::try_from_parsed(
+            self.parsed.stmts(),
+            self.parsed.errors(),
+            self.parsed.interner().clone(),
+            options,
+        )
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+
+    fn line_text(&self, line: usize) -> &str {
+        let start = self.line_starts[line];
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.text.len(), |end| end - 1);
+        &self.text[start..end]
+    }
+
+    /// LSP position of byte offset, character is counted in UTF-16
+    pub fn position(&self, offset: usize) -> Position {
+        let offset = offset.min(self.text.len());
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        };
+        let character = self.text[self.line_starts[line]..offset]
+            .encode_utf16()
+            .count();
+
+        Position::new(line as u32, character as u32)
+    }
+
+    /// Byte offset of LSP position, clamped into text
+    pub fn offset(&self, position: Position) -> usize {
+        let line = (position.line as usize).min(self.line_starts.len() - 1);
+        let start = self.line_starts[line];
+        let mut units = 0;
+
+        for (idx, c) in self.line_text(line).char_indices() {
+            if units >= position.character as usize {
+                return start + idx;
+            }
+            units += c.len_utf16();
+        }
+
+        start + self.line_text(line).len()
+    }
+
+    pub fn span_range(&self, span: Span) -> Range {
+        Range::new(self.position(span.start), self.position(span.end))
+    }
+
+    /// Whole line of `location`, lines of `Location` start from 1
+    pub fn line_range(&self, location: Location) -> Range {
+        let line = location
+            .line
+            .saturating_sub(1)
+            .min(self.line_starts.len() - 1);
+        let len = self.line_text(line).encode_utf16().count();
+
+        Range::new(
+            Position::new(line as u32, 0),
+            Position::new(line as u32, len as u32),
+        )
+    }
+
+    /// Last line, for errors without location
+    pub fn end_location(&self) -> Location {
+        Location::new(self.line_starts.len())
+    }
+}
@@ -0,0 +1,80 @@
+use crate::document::Document;
+use crate::walk::visit_exprs;
+use kes::analysis::{constant_variables, fold_constant_with};
+use kes::ast::{Expr, ExprKind};
+use kes::interner::Interner;
+use kes::location::Span;
+use kes::parser::parse_recovering;
+use kes::trace::literal;
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind, Position, Range};
+
+/// Command which evaluates constant expression in selection, arguments are uri and range
+pub const EVALUATE: &str = "kes.evaluate";
+
+fn contains(outer: Span, inner: Span) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+fn len(span: Span) -> usize {
+    span.end - span.start
+}
+
+/// Value of smallest expression containing `range`, literals alone are skipped
+///
+/// Variables assigned with constant at top level before statement of expression are known
+pub fn evaluate(document: &Document, range: Range) -> Option<(Range, String)> {
+    let span = Span::new(document.offset(range.start), document.offset(range.end));
+    let mut interner = Interner::new();
+    let (ast, errors) = parse_recovering(document.text(), &mut interner);
+    // statements may be missing, known values could be wrong
+    if !errors.is_empty() {
+        return None;
+    }
+
+    for (idx, stmt) in ast.iter().enumerate() {
+        let mut found: Option<&Expr> = None;
+        visit_exprs(std::slice::from_ref(stmt), &mut |expr| {
+            let is_literal = matches!(
+                expr.kind,
+                ExprKind::Number(_)
+                    | ExprKind::String(_)
+                    | ExprKind::Bool(_)
+                    | ExprKind::Null
+                    | ExprKind::Asset(_)
+            );
+            if !is_literal
+                && contains(expr.span, span)
+                && found.map_or(true, |found| len(expr.span) < len(found.span))
+            {
+                found = Some(expr);
+            }
+        });
+
+        let expr = match found {
+            Some(expr) => expr,
+            None => continue,
+        };
+
+        // variables assigned in block are unknown in it
+        let before = if stmt.is_block() { idx + 1 } else { idx };
+        let variables = constant_variables(&ast[..before], &interner);
+        let value = fold_constant_with(expr, &interner, &variables)?;
+
+        return Some((document.span_range(expr.span), literal(&value)));
+    }
+
+    None
+}
+
+/// Value of expression under cursor
+pub fn hover(document: &Document, position: Position) -> Option<Hover> {
+    let (range, value) = evaluate(document, Range::new(position, position))?;
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("```kes\n= {}\n```", value),
+        }),
+        range: Some(range),
+    })
+}
@@ -0,0 +1,24 @@
+use crate::document::Document;
+use kes::formatter::{format_code_lossy, FormatOptions};
+use lsp_types::{Position, Range, TextEdit};
+
+/// Edit replacing whole document with formatted text
+///
+/// Broken statements are kept as they are, their errors are already published as diagnostics
+pub fn formatting(document: &Document) -> Vec<TextEdit> {
+    let mut out = Vec::new();
+    if format_code_lossy(document.text(), &mut out, FormatOptions::default()).is_err() {
+        return Vec::new();
+    }
+
+    let formatted = String::from_utf8(out).unwrap();
+    if formatted == document.text() {
+        return Vec::new();
+    }
+
+    let range = Range::new(
+        Position::new(0, 0),
+        document.position(document.text().len()),
+    );
+    vec![TextEdit::new(range, formatted)]
+}
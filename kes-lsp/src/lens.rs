@@ -0,0 +1,97 @@
+use crate::document::Document;
+use crate::run::RUN_FILE;
+use crate::workspace::WorkspaceIndex;
+use kes::location::Location;
+use lsp_types::{CodeLens, Command, Position, Range, Url};
+use serde_json::to_value;
+
+/// Client command showing locations, arguments are uri, position and locations
+const SHOW_REFERENCES: &str = "editor.action.showReferences";
+
+/// Lens on `range` showing builtin calls of call graph
+fn calls_lens(
+    uri: &Url,
+    document: &Document,
+    range: Range,
+    calls: &[(String, Location)],
+) -> serde_json::Result<CodeLens> {
+    let locations = calls
+        .iter()
+        .map(|(_, location)| lsp_types::Location::new(uri.clone(), document.line_range(*location)))
+        .collect::<Vec<_>>();
+
+    Ok(CodeLens {
+        range,
+        command: Some(Command::new(
+            format!("호출 {}개", locations.len()),
+            SHOW_REFERENCES.into(),
+            Some(vec![
+                to_value(uri)?,
+                to_value(range.start)?,
+                to_value(locations)?,
+            ]),
+        )),
+        data: None,
+    })
+}
+
+/// Run lens on first line, builtin calls of each `장면` and `선택` arm,
+/// and reference count of each variable definition
+pub fn code_lenses(
+    uri: &Url,
+    document: &Document,
+    index: &WorkspaceIndex,
+) -> serde_json::Result<Vec<CodeLens>> {
+    let mut lenses = vec![CodeLens {
+        range: Range::new(Position::new(0, 0), Position::new(0, 0)),
+        command: Some(Command::new(
+            "▶ 실행".into(),
+            RUN_FILE.into(),
+            Some(vec![to_value(uri)?]),
+        )),
+        data: None,
+    }];
+
+    let file = match index.file(uri) {
+        Some(file) => file,
+        None => return Ok(lenses),
+    };
+
+    for (idx, scene) in file.scenes.iter().enumerate() {
+        lenses.push(calls_lens(
+            uri,
+            document,
+            scene.range,
+            file.scene_calls(idx),
+        )?);
+    }
+
+    for &(start, end) in &file.arms {
+        let calls = file.calls_between(start, end);
+        lenses.push(calls_lens(
+            uri,
+            document,
+            document.line_range(start),
+            calls,
+        )?);
+    }
+
+    for definition in &file.definitions {
+        let references = index.references(&definition.name);
+        lenses.push(CodeLens {
+            range: definition.range,
+            command: Some(Command::new(
+                format!("참조 {}개", references.len()),
+                SHOW_REFERENCES.into(),
+                Some(vec![
+                    to_value(uri)?,
+                    to_value(definition.range.start)?,
+                    to_value(references)?,
+                ]),
+            )),
+            data: None,
+        });
+    }
+
+    Ok(lenses)
+}
@@ -0,0 +1,597 @@
+mod diagnostics;
+mod document;
+mod eval;
+mod format;
+mod lens;
+mod reparse;
+mod run;
+mod selection;
+mod signature;
+mod walk;
+mod workspace;
+
+use diagnostics::Settings;
+use document::Document;
+use kes::error::{Language, ParseErrorMessage};
+use kes::program::Diagnostics;
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, RequestId, Response};
+use lsp_types::notification::{
+    DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles, DidCloseTextDocument,
+    DidOpenTextDocument, Notification as _, Progress, PublishDiagnostics, ShowMessage,
+};
+use lsp_types::request::{
+    CodeLensRequest, ExecuteCommand, Formatting, HoverRequest, RegisterCapability, Request as _,
+    SelectionRangeRequest, SignatureHelpRequest, WorkDoneProgressCreate, WorkspaceSymbolRequest,
+};
+use lsp_types::{
+    CodeLensOptions, CodeLensParams, Diagnostic, DidChangeConfigurationParams,
+    DidChangeTextDocumentParams, DidChangeWatchedFilesParams, DidCloseTextDocumentParams,
+    DidOpenTextDocumentParams, DocumentFormattingParams, ExecuteCommandOptions,
+    ExecuteCommandParams, FileChangeType, HoverParams, HoverProviderCapability, InitializeParams,
+    MessageType, NumberOrString, OneOf, ProgressParams, ProgressParamsValue,
+    PublishDiagnosticsParams, Range, Registration, RegistrationParams, SelectionRangeParams,
+    SelectionRangeProviderCapability, ServerCapabilities, ShowMessageParams, SignatureHelpOptions,
+    SignatureHelpParams, TextDocumentSyncCapability, TextDocumentSyncKind, Url, WorkDoneProgress,
+    WorkDoneProgressBegin, WorkDoneProgressCreateParams, WorkDoneProgressEnd,
+    WorkDoneProgressReport, WorkspaceSymbolParams, WorkspaceSymbolResponse,
+};
+use std::collections::{BTreeSet, HashMap};
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::Duration;
+use workspace::WorkspaceIndex;
+
+type ServerResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+/// Changed files are indexed after no file event came for this duration
+///
+/// Switching branch sends many events at once
+const REINDEX_DELAY: Duration = Duration::from_millis(300);
+
+/// Indexing which waits for response of `window/workDoneProgress/create`
+struct PendingIndex {
+    /// Id of create request, also used as progress token
+    id: String,
+    paths: Vec<PathBuf>,
+}
+
+struct Server {
+    connection: Connection,
+    documents: HashMap<Url, Document>,
+    index: WorkspaceIndex,
+    /// Errors and warnings from compiling each open document
+    compiled: HashMap<Url, Diagnostics>,
+    /// Error of last `kes.runFile`, cleared when document is changed
+    runtime_errors: HashMap<Url, Diagnostic>,
+    settings: Settings,
+    /// Client can show `$/progress`
+    work_done_progress: bool,
+    /// Files changed on disk, waiting for `REINDEX_DELAY`
+    pending_files: BTreeSet<PathBuf>,
+    /// Number of indexing runs, each run has own progress token
+    index_runs: u32,
+    pending_index: Option<PendingIndex>,
+}
+
+impl Server {
+    fn send_notification(&self, method: &str, params: impl serde::Serialize) -> ServerResult<()> {
+        self.connection
+            .sender
+            .send(Message::Notification(Notification::new(
+                method.into(),
+                params,
+            )))?;
+        Ok(())
+    }
+
+    fn send_request(
+        &self,
+        id: &str,
+        method: &str,
+        params: impl serde::Serialize,
+    ) -> ServerResult<()> {
+        self.connection.sender.send(Message::Request(Request::new(
+            RequestId::from(id.to_string()),
+            method.into(),
+            params,
+        )))?;
+        Ok(())
+    }
+
+    fn progress(&self, token: &str, progress: WorkDoneProgress) -> ServerResult<()> {
+        self.send_notification(
+            Progress::METHOD,
+            ProgressParams {
+                token: NumberOrString::String(token.into()),
+                value: ProgressParamsValue::WorkDone(progress),
+            },
+        )
+    }
+
+    /// Index files, progress is reported after client creates token for this run
+    fn index_files(&mut self, paths: Vec<PathBuf>) -> ServerResult<()> {
+        if !self.work_done_progress {
+            self.index_paths(&paths, None)?;
+            return Ok(());
+        }
+
+        if let Some(pending) = self.pending_index.as_mut() {
+            pending.paths.extend(paths);
+            return Ok(());
+        }
+
+        self.index_runs += 1;
+        let id = format!("kes/indexing/{}", self.index_runs);
+        self.send_request(
+            &id,
+            WorkDoneProgressCreate::METHOD,
+            WorkDoneProgressCreateParams {
+                token: NumberOrString::String(id.clone()),
+            },
+        )?;
+        self.pending_index = Some(PendingIndex { id, paths });
+        Ok(())
+    }
+
+    /// Start indexing which waited for `response`, without progress when client refused token
+    fn handle_response(&mut self, response: Response) -> ServerResult<()> {
+        let pending = match self.pending_index.take() {
+            Some(pending) if response.id == RequestId::from(pending.id.clone()) => pending,
+            other => {
+                self.pending_index = other;
+                return Ok(());
+            }
+        };
+        let token = response.error.is_none().then_some(pending.id.as_str());
+        self.index_paths(&pending.paths, token)
+    }
+
+    /// Index files reporting progress with `token`, open documents keep their text
+    fn index_paths(&mut self, paths: &[PathBuf], token: Option<&str>) -> ServerResult<()> {
+        if let Some(token) = token {
+            self.progress(
+                token,
+                WorkDoneProgress::Begin(WorkDoneProgressBegin {
+                    title: "kes 파일 색인".into(),
+                    percentage: Some(0),
+                    ..WorkDoneProgressBegin::default()
+                }),
+            )?;
+        }
+
+        for (idx, path) in paths.iter().enumerate() {
+            let is_open =
+                Url::from_file_path(path).map_or(false, |uri| self.documents.contains_key(&uri));
+            if !is_open {
+                self.index.update_file(path);
+            }
+
+            if let (Some(token), 0) = (token, idx % 32) {
+                self.progress(
+                    token,
+                    WorkDoneProgress::Report(WorkDoneProgressReport {
+                        message: Some(path.display().to_string()),
+                        percentage: Some((idx * 100 / paths.len()) as u32),
+                        ..WorkDoneProgressReport::default()
+                    }),
+                )?;
+            }
+        }
+
+        if let Some(token) = token {
+            self.progress(
+                token,
+                WorkDoneProgress::End(WorkDoneProgressEnd {
+                    message: Some(format!("{}개 파일", paths.len())),
+                }),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn show_message(&self, typ: MessageType, message: String) -> ServerResult<()> {
+        self.send_notification(ShowMessage::METHOD, ShowMessageParams { typ, message })
+    }
+
+    /// Publish every diagnostic of current version at once
+    fn publish_diagnostics(&self, uri: &Url) -> ServerResult<()> {
+        let (document, found) = match self.documents.get(uri).zip(self.compiled.get(uri)) {
+            Some(compiled) => compiled,
+            None => return Ok(()),
+        };
+
+        let mut diagnostics = diagnostics::diagnostics(document, &self.settings, found);
+        diagnostics.extend(self.runtime_errors.get(uri).cloned());
+
+        self.send_notification(
+            PublishDiagnostics::METHOD,
+            PublishDiagnosticsParams::new(uri.clone(), diagnostics, Some(document.version())),
+        )
+    }
+
+    /// Index follows text of open document instead of file on disk
+    fn open_document(&mut self, uri: Url, document: Document) -> ServerResult<()> {
+        let (program, found) = document.compile(&self.settings.compile_options());
+        self.index.update(uri.clone(), &document, &program);
+        self.compiled.insert(uri.clone(), found);
+        self.documents.insert(uri.clone(), document);
+        self.runtime_errors.remove(&uri);
+        self.publish_diagnostics(&uri)
+    }
+
+    /// Run document and show printed text, runtime error is published as diagnostic
+    fn run_file(&mut self, uri: Url) -> ServerResult<Option<String>> {
+        let document = match self.documents.get(&uri) {
+            Some(document) => document,
+            None => return Ok(None),
+        };
+
+        let output = match run::run(document, self.settings.signatures()) {
+            Ok(output) => output,
+            Err(err) => {
+                let message = ParseErrorMessage::new(&err, Language::Korean).to_string();
+                self.show_message(MessageType::ERROR, message)?;
+                return Ok(None);
+            }
+        };
+
+        match output.error_diagnostic(document) {
+            Some(diagnostic) => {
+                self.show_message(MessageType::ERROR, diagnostic.message.clone())?;
+                self.runtime_errors.insert(uri.clone(), diagnostic);
+            }
+            None => {
+                self.runtime_errors.remove(&uri);
+            }
+        }
+        self.show_message(MessageType::INFO, output.text.clone())?;
+        self.send_notification(
+            run::ExecutedLines::METHOD,
+            run::ExecutedLinesParams {
+                uri: uri.clone(),
+                lines: output
+                    .statements
+                    .iter()
+                    .map(|location| location.line.saturating_sub(1) as u32)
+                    .collect(),
+            },
+        )?;
+        self.publish_diagnostics(&uri)?;
+
+        Ok(Some(output.text))
+    }
+
+    /// Show value of constant expression in `range`
+    fn evaluate(&self, uri: &Url, range: Range) -> ServerResult<Option<String>> {
+        let value = self
+            .documents
+            .get(uri)
+            .and_then(|document| eval::evaluate(document, range))
+            .map(|(_, value)| value);
+
+        match &value {
+            Some(value) => self.show_message(MessageType::INFO, format!("= {}", value))?,
+            None => self.show_message(
+                MessageType::WARNING,
+                "선택한 식의 값을 계산할수 없습니다".into(),
+            )?,
+        }
+
+        Ok(value)
+    }
+
+    fn handle_request(&mut self, req: Request) -> ServerResult<()> {
+        let id = req.id.clone();
+        let response = match self.respond(req) {
+            Ok(response) => response,
+            // malformed params fail only that request
+            Err(err) if err.is::<serde_json::Error>() => {
+                Response::new_err(id, ErrorCode::InvalidParams as i32, err.to_string())
+            }
+            Err(err) => return Err(err),
+        };
+        self.connection.sender.send(Message::Response(response))?;
+        Ok(())
+    }
+
+    fn respond(&mut self, req: Request) -> ServerResult<Response> {
+        let response = match req.method.as_str() {
+            WorkspaceSymbolRequest::METHOD => {
+                let params: WorkspaceSymbolParams = serde_json::from_value(req.params)?;
+                Response::new_ok(
+                    req.id,
+                    WorkspaceSymbolResponse::Nested(self.index.symbols(&params.query)),
+                )
+            }
+            SelectionRangeRequest::METHOD => {
+                let params: SelectionRangeParams = serde_json::from_value(req.params)?;
+                let ranges = self
+                    .documents
+                    .get(&params.text_document.uri)
+                    .map(|document| {
+                        params
+                            .positions
+                            .iter()
+                            .map(|position| selection::selection_range(document, *position))
+                            .collect::<Vec<_>>()
+                    });
+                Response::new_ok(req.id, ranges)
+            }
+            HoverRequest::METHOD => {
+                let params: HoverParams = serde_json::from_value(req.params)?;
+                let position = params.text_document_position_params;
+                let hover = self
+                    .documents
+                    .get(&position.text_document.uri)
+                    .and_then(|document| {
+                        self.settings
+                            .signatures()
+                            .and_then(|signatures| {
+                                signature::hover(document, position.position, signatures)
+                            })
+                            .or_else(|| eval::hover(document, position.position))
+                    });
+                Response::new_ok(req.id, hover)
+            }
+            SignatureHelpRequest::METHOD => {
+                let params: SignatureHelpParams = serde_json::from_value(req.params)?;
+                let position = params.text_document_position_params;
+                let help = self
+                    .documents
+                    .get(&position.text_document.uri)
+                    .zip(self.settings.signatures())
+                    .and_then(|(document, signatures)| {
+                        signature::signature_help(document, position.position, signatures)
+                    });
+                Response::new_ok(req.id, help)
+            }
+            Formatting::METHOD => {
+                let params: DocumentFormattingParams = serde_json::from_value(req.params)?;
+                let edits = self
+                    .documents
+                    .get(&params.text_document.uri)
+                    .map(format::formatting);
+                Response::new_ok(req.id, edits)
+            }
+            CodeLensRequest::METHOD => {
+                let params: CodeLensParams = serde_json::from_value(req.params)?;
+                let uri = &params.text_document.uri;
+                let lenses = match self.documents.get(uri) {
+                    Some(document) => lens::code_lenses(uri, document, &self.index)?,
+                    None => Vec::new(),
+                };
+                Response::new_ok(req.id, lenses)
+            }
+            ExecuteCommand::METHOD => {
+                let params: ExecuteCommandParams = serde_json::from_value(req.params)?;
+                let mut arguments = params.arguments.into_iter();
+                let uri = arguments
+                    .next()
+                    .map(serde_json::from_value::<Url>)
+                    .transpose()?;
+                let range = arguments
+                    .next()
+                    .map(serde_json::from_value::<Range>)
+                    .transpose()?;
+
+                match (params.command.as_str(), uri, range) {
+                    (run::RUN_FILE, Some(uri), _) => Response::new_ok(req.id, self.run_file(uri)?),
+                    (eval::EVALUATE, Some(uri), Some(range)) => {
+                        Response::new_ok(req.id, self.evaluate(&uri, range)?)
+                    }
+                    _ => Response::new_err(
+                        req.id,
+                        ErrorCode::InvalidParams as i32,
+                        format!("잘못된 명령입니다: {}", params.command),
+                    ),
+                }
+            }
+            _ => Response::new_err(
+                req.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("지원하지 않는 요청입니다: {}", req.method),
+            ),
+        };
+        Ok(response)
+    }
+
+    fn handle_notification(&mut self, not: Notification) -> ServerResult<()> {
+        match not.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: DidOpenTextDocumentParams = serde_json::from_value(not.params)?;
+                let document = params.text_document;
+                self.open_document(
+                    document.uri,
+                    Document::new(&document.text, document.version),
+                )?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: DidChangeTextDocumentParams = serde_json::from_value(not.params)?;
+                let uri = params.text_document.uri;
+                let mut document = match self.documents.remove(&uri) {
+                    Some(document) => document,
+                    None => return Ok(()),
+                };
+                // changes are applied in order, statements away from them are not parsed again
+                for change in params.content_changes {
+                    document.apply_change(change.range, &change.text);
+                }
+                document.set_version(params.text_document.version);
+                self.open_document(uri, document)?;
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: DidCloseTextDocumentParams = serde_json::from_value(not.params)?;
+                let uri = params.text_document.uri;
+                self.documents.remove(&uri);
+                self.compiled.remove(&uri);
+                self.runtime_errors.remove(&uri);
+                self.send_notification(
+                    PublishDiagnostics::METHOD,
+                    PublishDiagnosticsParams::new(uri, Vec::new(), None),
+                )?;
+            }
+            DidChangeWatchedFiles::METHOD => {
+                let params: DidChangeWatchedFilesParams = serde_json::from_value(not.params)?;
+                for change in params.changes {
+                    let path = match change.uri.to_file_path() {
+                        Ok(path) => path,
+                        Err(_) => continue,
+                    };
+                    if change.typ == FileChangeType::DELETED {
+                        self.pending_files.remove(&path);
+                        self.index.remove(&change.uri);
+                    } else if workspace::is_kes_file(&path) {
+                        self.pending_files.insert(path);
+                    }
+                }
+            }
+            DidChangeConfiguration::METHOD => {
+                let params: DidChangeConfigurationParams = serde_json::from_value(not.params)?;
+                let settings = params.settings.get("kes").cloned();
+                self.settings = Settings::from_value(settings.unwrap_or(params.settings));
+
+                let options = self.settings.compile_options();
+                for (uri, document) in &self.documents {
+                    let (_, found) = document.compile(&options);
+                    self.compiled.insert(uri.clone(), found);
+                }
+                for uri in self.documents.keys() {
+                    self.publish_diagnostics(uri)?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn run(&mut self) -> ServerResult<()> {
+        let receiver = self.connection.receiver.clone();
+
+        loop {
+            let msg = if self.pending_files.is_empty() {
+                match receiver.recv() {
+                    Ok(msg) => msg,
+                    Err(_) => return Ok(()),
+                }
+            } else {
+                match receiver.recv_timeout(REINDEX_DELAY) {
+                    Ok(msg) => msg,
+                    Err(err) if err.is_timeout() => {
+                        let paths = std::mem::take(&mut self.pending_files);
+                        self.index_files(paths.into_iter().collect())?;
+                        continue;
+                    }
+                    Err(_) => return Ok(()),
+                }
+            };
+
+            match msg {
+                Message::Request(req) => {
+                    if self.connection.handle_shutdown(&req)? {
+                        return Ok(());
+                    }
+                    self.handle_request(req)?;
+                }
+                Message::Notification(not) => match self.handle_notification(not) {
+                    Ok(()) => {}
+                    // notification has no response, malformed one is only reported
+                    Err(err) if err.is::<serde_json::Error>() => {
+                        self.show_message(MessageType::WARNING, err.to_string())?
+                    }
+                    Err(err) => return Err(err),
+                },
+                Message::Response(response) => self.handle_response(response)?,
+            }
+        }
+    }
+}
+
+fn main() -> ServerResult<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(
+            TextDocumentSyncKind::INCREMENTAL,
+        )),
+        workspace_symbol_provider: Some(OneOf::Left(true)),
+        selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(vec!["(".into(), ",".into()]),
+            ..SignatureHelpOptions::default()
+        }),
+        document_formatting_provider: Some(OneOf::Left(true)),
+        code_lens_provider: Some(CodeLensOptions {
+            resolve_provider: Some(false),
+        }),
+        execute_command_provider: Some(ExecuteCommandOptions {
+            commands: vec![run::RUN_FILE.into(), eval::EVALUATE.into()],
+            ..ExecuteCommandOptions::default()
+        }),
+        ..ServerCapabilities::default()
+    };
+    let params: InitializeParams =
+        serde_json::from_value(connection.initialize(serde_json::to_value(capabilities)?)?)?;
+
+    let mut server = Server {
+        connection,
+        documents: HashMap::new(),
+        index: WorkspaceIndex::default(),
+        compiled: HashMap::new(),
+        runtime_errors: HashMap::new(),
+        settings: params
+            .initialization_options
+            .map(Settings::from_value)
+            .unwrap_or_default(),
+        work_done_progress: params
+            .capabilities
+            .window
+            .as_ref()
+            .and_then(|window| window.work_done_progress)
+            .unwrap_or(false),
+        pending_files: BTreeSet::new(),
+        index_runs: 0,
+        pending_index: None,
+    };
+
+    let can_watch = params
+        .capabilities
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.did_change_watched_files.as_ref())
+        .and_then(|watch| watch.dynamic_registration)
+        .unwrap_or(false);
+    if can_watch {
+        server.send_request(
+            "kes/watch",
+            RegisterCapability::METHOD,
+            RegistrationParams {
+                registrations: vec![Registration {
+                    id: "kes/watch".into(),
+                    method: DidChangeWatchedFiles::METHOD.into(),
+                    register_options: Some(serde_json::json!({
+                        "watchers": [{ "globPattern": "**/*.kes" }],
+                    })),
+                }],
+            },
+        )?;
+    }
+
+    let mut paths = Vec::new();
+    for folder in params.workspace_folders.unwrap_or_default() {
+        if let Ok(path) = folder.uri.to_file_path() {
+            workspace::kes_files(&path, &mut paths);
+        }
+    }
+    server.index_files(paths)?;
+
+    server.run()?;
+
+    // writer thread finishes after every sender is dropped
+    drop(server);
+    io_threads.join()?;
+
+    Ok(())
+}
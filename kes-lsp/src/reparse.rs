@@ -0,0 +1,449 @@
+use kes::ast::{Expr, ExprKind, Stmt};
+use kes::error::ParseError;
+use kes::interner::Interner;
+use kes::location::{Location, Span};
+use kes::parser::{duplicate_label, parse_recovering_at, statement_spans, Position};
+use kes::source::Source;
+
+/// Edits kept before whole text is parsed again
+///
+/// Interner only grows, names which were typed and removed stay in it until then
+const REBUILD_EDITS: usize = 1000;
+
+/// Top-level statements parsed together, one statement unless lexer failed
+struct Chunk {
+    /// Byte offset in text, chunk ends at start of next chunk
+    start: usize,
+    /// Line of `start`
+    line: usize,
+    /// Number of statements of chunk in `ParsedDocument::stmts`
+    len: usize,
+    errors: Vec<ParseError>,
+}
+
+/// Statements of document which are kept between edits
+///
+/// Document is split into top-level statements with `statement_spans`,
+/// edit parses only statements around it and moves statements after it
+pub struct ParsedDocument {
+    interner: Interner,
+    stmts: Vec<Stmt>,
+    chunks: Vec<Chunk>,
+    /// Length of front matter, statements start after it
+    header_len: usize,
+    /// Edits since text was parsed from scratch
+    edits: usize,
+}
+
+fn shift(value: usize, delta: isize) -> usize {
+    (value as isize + delta) as usize
+}
+
+fn shift_location(location: &mut Location, lines: isize) {
+    location.line = shift(location.line, lines);
+}
+
+fn shift_expr(expr: &mut Expr, offset: isize) {
+    expr.span = Span::new(shift(expr.span.start, offset), shift(expr.span.end, offset));
+
+    match &mut expr.kind {
+        ExprKind::Number(_)
+        | ExprKind::String(_)
+        | ExprKind::Bool(_)
+        | ExprKind::Null
+        | ExprKind::Asset(_)
+        | ExprKind::Variable(_) => {}
+        ExprKind::BuiltinFunc { args: items, .. } | ExprKind::List(items) => {
+            shift_exprs(items, offset)
+        }
+        ExprKind::Index { value, index } => {
+            shift_expr(value, offset);
+            shift_expr(index, offset);
+        }
+        ExprKind::Nop(value) | ExprKind::UnaryOp { value, .. } => shift_expr(value, offset),
+        ExprKind::BinaryOp { lhs, rhs, .. } => {
+            shift_expr(lhs, offset);
+            shift_expr(rhs, offset);
+        }
+        ExprKind::TernaryOp { lhs, mhs, rhs, .. } => {
+            shift_expr(lhs, offset);
+            shift_expr(mhs, offset);
+            shift_expr(rhs, offset);
+        }
+    }
+}
+
+fn shift_exprs(exprs: &mut [Expr], offset: isize) {
+    for expr in exprs {
+        shift_expr(expr, offset);
+    }
+}
+
+/// Move statements by `lines` lines and `offset` bytes
+fn shift_body(body: &mut [Stmt], lines: isize, offset: isize) {
+    for stmt in body {
+        match stmt {
+            Stmt::Assign {
+                value, location, ..
+            }
+            | Stmt::Expression {
+                expr: value,
+                location,
+            } => {
+                shift_expr(value, offset);
+                shift_location(location, lines);
+            }
+            Stmt::Declare {
+                value, location, ..
+            } => {
+                if let Some(value) = value {
+                    shift_expr(value, offset);
+                }
+                shift_location(location, lines);
+            }
+            Stmt::Print {
+                values, location, ..
+            }
+            | Stmt::Spawn {
+                args: values,
+                location,
+                ..
+            } => {
+                shift_exprs(values, offset);
+                shift_location(location, lines);
+            }
+            Stmt::If {
+                arms,
+                other,
+                other_location,
+            } => {
+                for (cond, body, location) in arms {
+                    shift_expr(cond, offset);
+                    shift_body(body, lines, offset);
+                    shift_location(location, lines);
+                }
+                shift_body(other, lines, offset);
+                shift_location(other_location, lines);
+            }
+            Stmt::While {
+                cond: value,
+                body,
+                location,
+            }
+            | Stmt::Timer {
+                delay: value,
+                body,
+                location,
+            }
+            | Stmt::ForEach {
+                iter: value,
+                body,
+                location,
+                ..
+            } => {
+                shift_expr(value, offset);
+                shift_body(body, lines, offset);
+                shift_location(location, lines);
+            }
+            Stmt::SetIndex {
+                indices,
+                value,
+                location,
+                ..
+            } => {
+                shift_exprs(indices, offset);
+                shift_expr(value, offset);
+                shift_location(location, lines);
+            }
+            Stmt::Select {
+                value,
+                arms,
+                other,
+                other_location,
+                location,
+            } => {
+                shift_expr(value, offset);
+                for (values, body, location) in arms {
+                    shift_exprs(values, offset);
+                    shift_body(body, lines, offset);
+                    shift_location(location, lines);
+                }
+                shift_body(other, lines, offset);
+                shift_location(other_location, lines);
+                shift_location(location, lines);
+            }
+            Stmt::Label { location, .. }
+            | Stmt::Exit { location }
+            | Stmt::Join { location }
+            | Stmt::Include { location, .. } => shift_location(location, lines),
+        }
+    }
+}
+
+fn shift_error(error: ParseError, lines: isize) -> ParseError {
+    let line = |mut location: Location| {
+        shift_location(&mut location, lines);
+        location
+    };
+
+    match error.map_location(line) {
+        ParseError::User { error } => ParseError::User {
+            error: error.map_location(line),
+        },
+        error => error,
+    }
+}
+
+impl ParsedDocument {
+    pub fn new(text: &str) -> Self {
+        let header_len = Source::new(text).header().len();
+        let mut parsed = Self {
+            interner: Interner::new(),
+            stmts: Vec::new(),
+            chunks: Vec::new(),
+            header_len,
+            edits: 0,
+        };
+
+        let starts = parsed
+            .statement_starts(text, header_len, text.len())
+            .unwrap_or_else(|| vec![header_len]);
+        let line = text[..header_len].matches('\n').count() + 1;
+        let (stmts, chunks) = parsed.parse_chunks(text, header_len, &starts, text.len(), line);
+        parsed.stmts = stmts;
+        parsed.chunks = chunks;
+        parsed
+    }
+
+    pub fn interner(&self) -> &Interner {
+        &self.interner
+    }
+
+    pub fn stmts(&self) -> &[Stmt] {
+        &self.stmts
+    }
+
+    /// Errors of every statement and duplicated labels
+    pub fn errors(&self) -> Vec<ParseError> {
+        let mut errors = self
+            .chunks
+            .iter()
+            .flat_map(|chunk| chunk.errors.iter().cloned())
+            .collect::<Vec<_>>();
+        errors.extend(duplicate_label(&self.stmts, &self.interner));
+        errors
+    }
+
+    /// Start of every top-level statement in `text[start..end]`, `None` on lexer error
+    fn statement_starts(&mut self, text: &str, start: usize, end: usize) -> Option<Vec<usize>> {
+        let spans = statement_spans(&text[start..end], &mut self.interner).ok()?;
+        Some(spans.iter().map(|stmt| start + stmt.span.start).collect())
+    }
+
+    /// Parse `text[start..end]` split at `starts`, `line` is line of `start`
+    ///
+    /// Text before first statement belongs to first chunk
+    fn parse_chunks(
+        &mut self,
+        text: &str,
+        start: usize,
+        starts: &[usize],
+        end: usize,
+        mut line: usize,
+    ) -> (Vec<Stmt>, Vec<Chunk>) {
+        let mut stmts = Vec::new();
+        let mut chunks = Vec::with_capacity(starts.len());
+
+        for (idx, &stmt_start) in starts.iter().enumerate() {
+            let chunk_start = if idx == 0 { start } else { stmt_start };
+            let chunk_end = starts.get(idx + 1).copied().unwrap_or(end);
+            let chunk_text = &text[chunk_start..chunk_end];
+            let position = Position {
+                location: Location::new(line),
+                offset: chunk_start,
+            };
+            let (parsed, errors) = parse_recovering_at(chunk_text, &mut self.interner, position);
+
+            chunks.push(Chunk {
+                start: chunk_start,
+                line,
+                len: parsed.len(),
+                errors,
+            });
+            stmts.extend(parsed);
+            line += chunk_text.matches('\n').count();
+        }
+
+        (stmts, chunks)
+    }
+
+    /// `old_start..old_end` of previous text is replaced and ends at `new_end` of `text`
+    ///
+    /// `lines` is number of lines added by edit, negative when lines are removed
+    pub fn edit(
+        &mut self,
+        text: &str,
+        old_start: usize,
+        old_end: usize,
+        new_end: usize,
+        lines: isize,
+    ) {
+        self.edits += 1;
+        if self.edits >= REBUILD_EDITS {
+            *self = Self::new(text);
+            return;
+        }
+
+        // previous statement is parsed again too, edit can continue it with `혹은` or `;`
+        let first = match self
+            .chunks
+            .iter()
+            .rposition(|chunk| chunk.start < old_start)
+        {
+            Some(first) if Source::new(text).header().len() == self.header_len => first,
+            _ => {
+                *self = Self::new(text);
+                return;
+            }
+        };
+        let offset = new_end as isize - old_end as isize;
+        let start = self.chunks[first].start;
+        let mut next = self
+            .chunks
+            .iter()
+            .rposition(|chunk| chunk.start <= old_end)
+            .map_or(0, |last| last + 1);
+
+        // statements after edit are kept only if statement still starts at same token,
+        // statement before it can't swallow it after that
+        let boundary = self
+            .chunks
+            .get(next)
+            .map(|chunk| shift(chunk.start, offset));
+        let probe_end = self
+            .chunks
+            .get(next + 1)
+            .map_or(text.len(), |chunk| shift(chunk.start, offset));
+        let kept = boundary.and_then(|boundary| {
+            let mut starts = self.statement_starts(text, start, probe_end)?;
+            if !starts.contains(&boundary) {
+                return None;
+            }
+            starts.retain(|&stmt_start| stmt_start < boundary);
+            Some((starts, boundary))
+        });
+
+        let (starts, end) = match kept {
+            Some(kept) => kept,
+            None => {
+                next = self.chunks.len();
+                let starts = self
+                    .statement_starts(text, start, text.len())
+                    .unwrap_or_else(|| vec![start]);
+                (starts, text.len())
+            }
+        };
+
+        let stmt_start = self.chunks[..first]
+            .iter()
+            .map(|chunk| chunk.len)
+            .sum::<usize>();
+        let stmt_end = stmt_start
+            + self.chunks[first..next]
+                .iter()
+                .map(|chunk| chunk.len)
+                .sum::<usize>();
+        let line = self.chunks[first].line;
+        let (stmts, chunks) = self.parse_chunks(text, start, &starts, end, line);
+
+        let moved = stmt_start + stmts.len();
+        self.stmts.splice(stmt_start..stmt_end, stmts);
+        shift_body(&mut self.stmts[moved..], lines, offset);
+
+        for chunk in &mut self.chunks[next..] {
+            chunk.start = shift(chunk.start, offset);
+            chunk.line = shift(chunk.line, lines);
+            chunk.errors = std::mem::take(&mut chunk.errors)
+                .into_iter()
+                .map(|error| shift_error(error, lines))
+                .collect();
+        }
+        self.chunks.splice(first..next, chunks);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ParsedDocument;
+
+    /// Debug output with symbols replaced by their names, symbols of edited document
+    /// are numbered in order of edits instead of order in text
+    fn resolved(parsed: &ParsedDocument, value: &impl std::fmt::Debug) -> String {
+        parsed
+            .interner()
+            .into_iter()
+            .fold(format!("{:?}", value), |text, (symbol, name)| {
+                text.replace(&format!("{:?}", symbol), &format!("{:?}", name))
+            })
+    }
+
+    /// Replace `old` in `text` with `new` and compare with document parsed from scratch
+    fn check_edit(text: &str, old: &str, new: &str) {
+        let start = text.find(old).unwrap();
+        let end = start + old.len();
+        let edited = format!("{}{}{}", &text[..start], new, &text[end..]);
+        let lines = new.matches('\n').count() as isize - old.matches('\n').count() as isize;
+
+        let mut parsed = ParsedDocument::new(text);
+        parsed.edit(&edited, start, end, start + new.len(), lines);
+        let expected = ParsedDocument::new(&edited);
+
+        assert_eq!(
+            resolved(&parsed, &parsed.stmts()),
+            resolved(&expected, &expected.stmts()),
+            "{}",
+            edited
+        );
+        assert_eq!(
+            resolved(&parsed, &parsed.errors()),
+            resolved(&expected, &expected.errors()),
+            "{}",
+            edited
+        );
+    }
+
+    const TEXT: &str = "$1 = 1;\n만약 $1 > 0 {\n    @$1;\n}\n$2 = 2;\n@$2;\n";
+
+    #[test]
+    fn edit_in_statement() {
+        check_edit(TEXT, "$1 > 0", "$1 > 10 + 2");
+        check_edit(TEXT, "$2 = 2", "$2 = (2");
+    }
+
+    #[test]
+    fn edit_at_statement_start() {
+        check_edit(TEXT, "$2 = 2;", "$3 = 3;\n$2 = 2;");
+        check_edit(TEXT, "$1 = 1;", "");
+    }
+
+    #[test]
+    fn edit_joins_statements() {
+        check_edit(TEXT, "}\n$2 = 2;", "} 혹은 $1 < 0 {\n    @$2;\n}\n$2 = 2;");
+        check_edit(TEXT, "$2 = 2;\n", "$2 = 2\n");
+        check_edit(TEXT, "}\n$2", "} 그외 {}\n$2");
+    }
+
+    #[test]
+    fn edit_deletes_lines() {
+        check_edit(TEXT, "    @$1;\n}\n$2 = 2;\n", "}\n");
+        check_edit(TEXT, "\n$2 = 2;", "");
+    }
+
+    #[test]
+    fn edit_front_matter() {
+        let text = format!("---\n제목: 가\n---\n{}", TEXT);
+        check_edit(&text, "제목: 가", "제목: 나\n작가: 다");
+        check_edit(&text, "---\n제목: 가\n---\n", "");
+        check_edit(TEXT, "$1 = 1;", "---\n제목: 가\n---\n$1 = 1;");
+    }
+}
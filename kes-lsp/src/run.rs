@@ -0,0 +1,107 @@
+use crate::document::Document;
+use kes::async_trait;
+use kes::builtin::Builtin;
+use kes::context::{Context, ContextBuilder};
+use kes::error::{ParseError, RuntimeError};
+use kes::location::Location;
+use kes::program::Program;
+use kes::signature::Signatures;
+use kes::trace::TraceEvent;
+use kes::value::Value;
+use lsp_types::notification::Notification;
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Url};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Command which runs current document for preview
+pub const RUN_FILE: &str = "kes.runFile";
+
+/// Preview stops after this many instructions, script can loop forever
+const FUEL: u64 = 1_000_000;
+
+/// Only first statements are reported, loops can run many times
+const MAX_STATEMENTS: usize = 10_000;
+
+/// Lines of statements run by `kes.runFile` in order, client can replay them as highlights
+pub enum ExecutedLines {}
+
+impl Notification for ExecutedLines {
+    type Params = ExecutedLinesParams;
+    const METHOD: &'static str = "kes/executedLines";
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExecutedLinesParams {
+    pub uri: Url,
+    /// Zero based like LSP positions
+    pub lines: Vec<u32>,
+}
+
+/// Records printed text only, every builtin returns `0`
+#[derive(Default)]
+struct PreviewBuiltin {
+    text: String,
+}
+
+#[async_trait]
+impl Builtin for PreviewBuiltin {
+    async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> Value {
+        Value::Int(0)
+    }
+    fn print(&mut self, v: Value) {
+        write!(self.text, "{}", v).unwrap();
+    }
+    fn new_line(&mut self) {
+        self.text.push('\n');
+    }
+    async fn wait(&mut self) {}
+}
+
+pub struct RunOutput {
+    pub text: String,
+    pub error: Option<RuntimeError>,
+    /// Locations of executed statements in order
+    pub statements: Vec<Location>,
+}
+
+impl RunOutput {
+    pub fn error_diagnostic(&self, document: &Document) -> Option<Diagnostic> {
+        self.error.as_ref().map(|error| Diagnostic {
+            range: document.line_range(Location::new(error.line())),
+            severity: Some(DiagnosticSeverity::ERROR),
+            code: Some(NumberOrString::String("runtime-error".into())),
+            source: Some("kes".into()),
+            message: error.to_string(),
+            ..Diagnostic::default()
+        })
+    }
+}
+
+/// Arguments of builtin calls are checked with `signatures` of settings
+pub fn run(document: &Document, signatures: Option<&Signatures>) -> Result<RunOutput, ParseError> {
+    let program = Program::from_source(document.text())?;
+    let mut builtin = PreviewBuiltin::default();
+    let mut statements = Vec::new();
+    let mut builder = ContextBuilder::new(&program)
+        .fuel(FUEL)
+        // every builtin returns `0` in preview whatever its signature says
+        .check_returns(false)
+        .tracer(|event: TraceEvent| {
+            if let TraceEvent::Statement(location) = event {
+                if statements.len() < MAX_STATEMENTS {
+                    statements.push(location);
+                }
+            }
+        });
+    if let Some(signatures) = signatures {
+        builder = builder.signatures(signatures);
+    }
+    let ctx = builder.build();
+    let error = futures_executor::block_on(ctx.run(&mut builtin)).err();
+
+    Ok(RunOutput {
+        text: builtin.text,
+        error,
+        statements,
+    })
+}
@@ -0,0 +1,83 @@
+use crate::document::Document;
+use crate::walk::{expr_children, stmt_bodies, stmt_exprs};
+use kes::ast::{Expr, Stmt};
+use kes::interner::Interner;
+use kes::location::Span;
+use kes::parser::{parse_recovering, statement_spans, StmtSpan};
+use lsp_types::{Position, SelectionRange};
+
+fn contains(span: Span, offset: usize) -> bool {
+    span.start <= offset && offset <= span.end
+}
+
+fn expr_spans(expr: &Expr, offset: usize, out: &mut Vec<Span>) {
+    out.push(expr.span);
+
+    if let Some(child) = expr_children(expr)
+        .into_iter()
+        .find(|child| contains(child.span, offset))
+    {
+        expr_spans(child, offset, out);
+    }
+}
+
+/// `ast` is `None` when it doesn't match spans because of parse errors
+fn select_in_body(spans: &[StmtSpan], ast: Option<&[Stmt]>, offset: usize, out: &mut Vec<Span>) {
+    let idx = match spans.iter().position(|stmt| contains(stmt.span, offset)) {
+        Some(idx) => idx,
+        None => return,
+    };
+    let span = &spans[idx];
+    let stmt = ast.and_then(|ast| ast.get(idx));
+
+    out.push(span.span);
+
+    if let Some(expr) = stmt
+        .map(stmt_exprs)
+        .unwrap_or_default()
+        .into_iter()
+        .find(|expr| contains(expr.span, offset))
+    {
+        return expr_spans(expr, offset, out);
+    }
+
+    let bodies = stmt.map(stmt_bodies);
+    for (block_idx, block) in span.blocks.iter().enumerate() {
+        if contains(block.span, offset) {
+            out.push(block.span);
+            let body = bodies
+                .as_ref()
+                .and_then(|bodies| bodies.get(block_idx).copied());
+            return select_in_body(&block.stmts, body, offset, out);
+        }
+    }
+}
+
+/// Token, expressions, statement and blocks around `position`, innermost first
+pub fn selection_range(document: &Document, position: Position) -> SelectionRange {
+    let offset = document.offset(position);
+    let mut interner = Interner::new();
+    let (ast, errors) = parse_recovering(document.text(), &mut interner);
+    let ast = if errors.is_empty() {
+        Some(ast.as_slice())
+    } else {
+        None
+    };
+
+    let mut spans = vec![Span::new(0, document.text().len())];
+    if let Ok(stmts) = statement_spans(document.text(), &mut interner) {
+        select_in_body(&stmts, ast, offset, &mut spans);
+    }
+    spans.dedup();
+
+    let mut ranges = spans.into_iter().map(|span| document.span_range(span));
+    let outermost = SelectionRange {
+        range: ranges.next().unwrap(),
+        parent: None,
+    };
+
+    ranges.fold(outermost, |parent, range| SelectionRange {
+        range,
+        parent: Some(Box::new(parent)),
+    })
+}
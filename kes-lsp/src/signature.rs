@@ -0,0 +1,173 @@
+use crate::document::Document;
+use crate::walk::visit_exprs;
+use kes::ast::ExprKind;
+use kes::signature::Signatures;
+use lsp_types::{
+    Hover, HoverContents, MarkupContent, MarkupKind, ParameterInformation, ParameterLabel,
+    Position, Range, SignatureHelp, SignatureInformation,
+};
+
+fn is_name_char(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
+}
+
+/// Length of string literal after opening `'` including closing quote, `None` when unclosed
+fn string_len(text: &str) -> Option<usize> {
+    let mut chars = text.char_indices();
+    while let Some((idx, ch)) = chars.next() {
+        match ch {
+            '\\' => {
+                chars.next();
+            }
+            '\'' => return Some(idx + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Name of innermost builtin call which is open at end of `prefix` and index of argument
+///
+/// Text is scanned instead of parsed because call is usually incomplete while typing it
+fn enclosing_call(prefix: &str) -> Option<(&str, u32)> {
+    // name before each open `(`, `None` for grouping and list, with count of commas in it
+    let mut opened: Vec<(Option<&str>, u32)> = Vec::new();
+    let mut pos = 0;
+
+    while let Some(ch) = prefix[pos..].chars().next() {
+        let next = pos + ch.len_utf8();
+        pos = match ch {
+            '\'' if prefix[pos..].starts_with("'''") => match prefix[pos + 3..].find("'''") {
+                Some(end) => pos + 3 + end + 3,
+                None => break,
+            },
+            '\'' => match string_len(&prefix[next..]) {
+                Some(len) => next + len,
+                None => break,
+            },
+            '#' => prefix[next..]
+                .find('\n')
+                .map_or(prefix.len(), |end| next + end),
+            '(' => {
+                let before = &prefix[..pos];
+                let start = before
+                    .char_indices()
+                    .rev()
+                    .take_while(|(_, c)| is_name_char(*c))
+                    .last()
+                    .map_or(pos, |(idx, _)| idx);
+                let is_call = start != pos && !before[..start].ends_with('$');
+                opened.push((is_call.then(|| &before[start..]), 0));
+                next
+            }
+            '[' => {
+                opened.push((None, 0));
+                next
+            }
+            ')' | ']' => {
+                opened.pop();
+                next
+            }
+            ',' => {
+                if let Some((_, commas)) = opened.last_mut() {
+                    *commas += 1;
+                }
+                next
+            }
+            ';' | '{' | '}' => {
+                opened.clear();
+                next
+            }
+            _ => next,
+        };
+    }
+
+    opened
+        .into_iter()
+        .rev()
+        .find_map(|(name, commas)| Some((name?, commas)))
+}
+
+/// Signature of builtin call under cursor with parameter being typed
+pub fn signature_help(
+    document: &Document,
+    position: Position,
+    signatures: &Signatures,
+) -> Option<SignatureHelp> {
+    let offset = document.offset(position);
+    let (name, active) = enclosing_call(&document.text()[..offset])?;
+    let signature = signatures.get(name)?;
+
+    // offsets are in UTF-16 like positions, type names are ASCII
+    let mut start = name.encode_utf16().count() as u32 + 1;
+    let parameters = signature
+        .params()
+        .iter()
+        .map(|param| {
+            let end = start + param.name().len() as u32;
+            let label = ParameterLabel::LabelOffsets([start, end]);
+            start = end + 2;
+            ParameterInformation {
+                label,
+                documentation: None,
+            }
+        })
+        .collect();
+
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label: signature.label(name),
+            documentation: None,
+            parameters: Some(parameters),
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active),
+    })
+}
+
+/// Signature of builtin whose name is under cursor
+pub fn hover(document: &Document, position: Position, signatures: &Signatures) -> Option<Hover> {
+    let offset = document.offset(position);
+    let parsed = document.parsed();
+    let mut found = None;
+    visit_exprs(parsed.stmts(), &mut |expr| {
+        if let ExprKind::BuiltinFunc { name, .. } = expr.kind {
+            let name = parsed.interner().resolve(name).unwrap_or_default();
+            let end = expr.span.start + name.len();
+            if expr.span.start <= offset && offset <= end {
+                found = Some((name, expr.span.start, end));
+            }
+        }
+    });
+
+    let (name, start, end) = found?;
+    let signature = signatures.get(name)?;
+
+    Some(Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value: format!("```kes\n{}\n```", signature.label(name)),
+        }),
+        range: Some(Range::new(document.position(start), document.position(end))),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::enclosing_call;
+
+    #[test]
+    fn enclosing_call_test() {
+        assert_eq!(enclosing_call("@이름표("), Some(("이름표", 0)));
+        assert_eq!(
+            enclosing_call("이름표('a, (b', [1, 2], "),
+            Some(("이름표", 2))
+        );
+        assert_eq!(enclosing_call("가(1, 나(2), (3 + "), Some(("가", 2)));
+        assert_eq!(enclosing_call("가(1, 나("), Some(("나", 0)));
+        assert_eq!(enclosing_call("가(1); $1 = ("), None);
+        assert_eq!(enclosing_call("$목록("), None);
+        assert_eq!(enclosing_call("가('''a)''', "), Some(("가", 1)));
+    }
+}
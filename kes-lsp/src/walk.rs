@@ -0,0 +1,80 @@
+use kes::ast::{Expr, ExprKind, Stmt};
+
+/// Expressions directly in statement, not in its bodies
+pub fn stmt_exprs(stmt: &Stmt) -> Vec<&Expr> {
+    match stmt {
+        Stmt::Assign { value, .. } => vec![value],
+        Stmt::SetIndex { indices, value, .. } => {
+            indices.iter().chain(std::iter::once(value)).collect()
+        }
+        Stmt::Declare { value, .. } => value.iter().collect(),
+        Stmt::Print { values, .. } => values.iter().collect(),
+        Stmt::If { arms, .. } => arms.iter().map(|(cond, ..)| cond).collect(),
+        Stmt::Select { value, arms, .. } => std::iter::once(value)
+            .chain(arms.iter().flat_map(|(values, ..)| values.iter()))
+            .collect(),
+        Stmt::While { cond, .. } => vec![cond],
+        Stmt::ForEach { iter, .. } => vec![iter],
+        Stmt::Timer { delay, .. } => vec![delay],
+        Stmt::Expression { expr, .. } => vec![expr],
+        Stmt::Spawn { args, .. } => args.iter().collect(),
+        Stmt::Exit { .. } | Stmt::Join { .. } | Stmt::Label { .. } | Stmt::Include { .. } => {
+            Vec::new()
+        }
+    }
+}
+
+/// Bodies in same order with `StmtSpan::blocks`
+pub fn stmt_bodies(stmt: &Stmt) -> Vec<&[Stmt]> {
+    match stmt {
+        Stmt::If { arms, other, .. } => arms
+            .iter()
+            .map(|(_, body, _)| body.as_slice())
+            .chain(std::iter::once(other.as_slice()))
+            .collect(),
+        Stmt::Select { arms, other, .. } => arms
+            .iter()
+            .map(|(_, body, _)| body.as_slice())
+            .chain(std::iter::once(other.as_slice()))
+            .collect(),
+        Stmt::While { body, .. } | Stmt::Timer { body, .. } | Stmt::ForEach { body, .. } => {
+            vec![body.as_slice()]
+        }
+        _ => Vec::new(),
+    }
+}
+
+pub fn expr_children(expr: &Expr) -> Vec<&Expr> {
+    match &expr.kind {
+        ExprKind::Number(_)
+        | ExprKind::String(_)
+        | ExprKind::Bool(_)
+        | ExprKind::Null
+        | ExprKind::Asset(_)
+        | ExprKind::Variable(_) => Vec::new(),
+        ExprKind::BuiltinFunc { args: items, .. } | ExprKind::List(items) => items.iter().collect(),
+        ExprKind::Index { value, index } => vec![&**value, &**index],
+        ExprKind::Nop(value) | ExprKind::UnaryOp { value, .. } => vec![&**value],
+        ExprKind::BinaryOp { lhs, rhs, .. } => vec![&**lhs, &**rhs],
+        ExprKind::TernaryOp { lhs, mhs, rhs, .. } => vec![&**lhs, &**mhs, &**rhs],
+    }
+}
+
+/// Visit every expression in `body` including nested ones
+pub fn visit_exprs<'a>(body: &'a [Stmt], f: &mut impl FnMut(&'a Expr)) {
+    fn visit_expr<'a>(expr: &'a Expr, f: &mut impl FnMut(&'a Expr)) {
+        f(expr);
+        for child in expr_children(expr) {
+            visit_expr(child, f);
+        }
+    }
+
+    for stmt in body {
+        for expr in stmt_exprs(stmt) {
+            visit_expr(expr, f);
+        }
+        for body in stmt_bodies(stmt) {
+            visit_exprs(body, f);
+        }
+    }
+}
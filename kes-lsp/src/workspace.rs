@@ -0,0 +1,279 @@
+use crate::document::Document;
+use crate::walk::{stmt_bodies, visit_exprs};
+use kes::ast::{ExprKind, Stmt};
+use kes::interner::Interner;
+use kes::location::Location;
+use kes::program::{CompileOptions, Program};
+use kes::source::Source;
+use lsp_types::{OneOf, SymbolKind, Url, WorkspaceSymbol};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Variable defined by assignment or `변수` declaration, or `장면`
+pub struct Definition {
+    pub name: String,
+    pub location: Location,
+    /// Line range in document
+    pub range: lsp_types::Range,
+}
+
+fn collect_definitions(body: &[Stmt], interner: &Interner, out: &mut HashMap<String, Location>) {
+    for stmt in body {
+        if let Stmt::Assign { var, location, .. } | Stmt::Declare { var, location, .. } = stmt {
+            let name = interner.resolve(*var).unwrap();
+            // temporaries are cleared after every print, they are not worth jumping to
+            if !name.starts_with('_') {
+                out.entry(name.into()).or_insert(*location);
+            }
+        }
+
+        for body in stmt_bodies(stmt) {
+            collect_definitions(body, interner, out);
+        }
+    }
+}
+
+/// Last line of `body` including nested blocks, `line` when body is empty
+fn last_line(body: &[Stmt], line: usize) -> usize {
+    body.iter().fold(line, |line, stmt| {
+        stmt_bodies(stmt)
+            .into_iter()
+            .fold(line.max(stmt.location().line), |line, body| {
+                last_line(body, line)
+            })
+    })
+}
+
+/// Lines of every `선택` arm, first and last line of arm
+fn collect_arms(body: &[Stmt], out: &mut Vec<(Location, Location)>) {
+    for stmt in body {
+        if let Stmt::Select { arms, .. } = stmt {
+            out.extend(arms.iter().map(|(_, body, location)| {
+                (*location, Location::new(last_line(body, location.line)))
+            }));
+        }
+
+        for body in stmt_bodies(stmt) {
+            collect_arms(body, out);
+        }
+    }
+}
+
+/// Definitions and variable uses of one file
+#[derive(Default)]
+pub struct FileIndex {
+    /// First definition of each variable
+    pub definitions: Vec<Definition>,
+    /// Name and range of every variable read
+    pub uses: Vec<(String, lsp_types::Range)>,
+    /// Every `장면` in source order
+    pub scenes: Vec<Definition>,
+    /// Name and line of every builtin call from call graph
+    pub calls: Vec<(String, Location)>,
+    /// First and last line of every `선택` arm
+    pub arms: Vec<(Location, Location)>,
+}
+
+impl FileIndex {
+    /// Broken statements are skipped, `program` is compiled from `document`
+    pub fn new(document: &Document, program: &Program) -> Self {
+        let parsed = document.parsed();
+        let (ast, interner) = (parsed.stmts(), parsed.interner());
+        let mut found = HashMap::new();
+        collect_definitions(ast, interner, &mut found);
+
+        let mut definitions = found
+            .into_iter()
+            .map(|(name, location)| Definition {
+                name,
+                location,
+                range: document.line_range(location),
+            })
+            .collect::<Vec<_>>();
+        definitions.sort_by_key(|definition| definition.location);
+
+        let mut uses = Vec::new();
+        visit_exprs(ast, &mut |expr| {
+            if let ExprKind::Variable(var) = expr.kind {
+                let name = interner.resolve(var).unwrap();
+                uses.push((name.into(), document.span_range(expr.span)));
+            }
+        });
+
+        let scenes = program
+            .entry_points()
+            .map(|(name, location)| Definition {
+                name: name.into(),
+                location,
+                range: document.line_range(location),
+            })
+            .collect();
+        let mut calls = program
+            .call_graph()
+            .builtins
+            .into_iter()
+            .flat_map(|(name, lines)| lines.into_iter().map(move |line| (name.clone(), line)))
+            .collect::<Vec<_>>();
+        calls.sort_by_key(|(_, location)| *location);
+
+        let mut arms = Vec::new();
+        collect_arms(ast, &mut arms);
+
+        Self {
+            definitions,
+            uses,
+            scenes,
+            calls,
+            arms,
+        }
+    }
+
+    /// Builtin calls in lines `start..=end`
+    pub fn calls_between(&self, start: Location, end: Location) -> &[(String, Location)] {
+        let from = self
+            .calls
+            .partition_point(|(_, location)| *location < start);
+        let to = self.calls.partition_point(|(_, location)| *location <= end);
+        &self.calls[from..to]
+    }
+
+    /// Builtin calls from `장면` at `idx` to next `장면`
+    pub fn scene_calls(&self, idx: usize) -> &[(String, Location)] {
+        let start = self.scenes[idx].location;
+        let end = self
+            .scenes
+            .get(idx + 1)
+            .map_or(Location::new(usize::MAX), |next| {
+                Location::new(next.location.line - 1)
+            });
+        self.calls_between(start, end)
+    }
+}
+
+/// Every characters of `query` appear in `name` in order, ignoring case
+fn fuzzy_match(query: &str, name: &str) -> bool {
+    let mut name = name.chars().flat_map(char::to_lowercase);
+    query
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|q| name.any(|c| c == q))
+}
+
+/// Find `.kes` files under `dir`, hidden directories and `target` are skipped
+pub fn kes_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+
+        if path.is_dir() {
+            if !name.starts_with('.') && name != "target" {
+                kes_files(&path, out);
+            }
+        } else if is_kes_file(&path) {
+            out.push(path);
+        }
+    }
+}
+
+pub fn is_kes_file(path: &Path) -> bool {
+    path.extension().map_or(false, |ext| ext == "kes")
+}
+
+/// Definitions, uses and calls of every `.kes` files in workspace
+#[derive(Default)]
+pub struct WorkspaceIndex {
+    files: HashMap<Url, FileIndex>,
+}
+
+impl WorkspaceIndex {
+    /// Index file on disk, file which can't be read is removed
+    pub fn update_file(&mut self, path: &Path) {
+        let uri = match Url::from_file_path(path) {
+            Ok(uri) => uri,
+            Err(_) => return,
+        };
+
+        match Source::read(path) {
+            Ok(source) => {
+                let document = Document::new(source.as_str(), 0);
+                let (program, _) = document.compile(&CompileOptions::default());
+                self.update(uri, &document, &program);
+            }
+            Err(_) => self.remove(&uri),
+        }
+    }
+
+    pub fn remove(&mut self, uri: &Url) {
+        self.files.remove(uri);
+    }
+
+    pub fn update(&mut self, uri: Url, document: &Document, program: &Program) {
+        self.files.insert(uri, FileIndex::new(document, program));
+    }
+
+    pub fn symbols(&self, query: &str) -> Vec<WorkspaceSymbol> {
+        let mut symbols = self
+            .files
+            .iter()
+            .flat_map(|(uri, file)| {
+                let variables = file.definitions.iter().map(|definition| {
+                    (
+                        format!("${}", definition.name),
+                        SymbolKind::VARIABLE,
+                        definition,
+                    )
+                });
+                let scenes = file
+                    .scenes
+                    .iter()
+                    .map(|scene| (format!("장면 {}", scene.name), SymbolKind::FUNCTION, scene));
+
+                variables
+                    .chain(scenes)
+                    .filter(move |(_, _, definition)| fuzzy_match(query, &definition.name))
+                    .map(move |(name, kind, definition)| WorkspaceSymbol {
+                        name,
+                        kind,
+                        tags: None,
+                        container_name: None,
+                        location: OneOf::Left(lsp_types::Location::new(
+                            uri.clone(),
+                            definition.range,
+                        )),
+                        data: None,
+                    })
+            })
+            .collect::<Vec<_>>();
+        symbols.sort_by(|a, b| a.name.cmp(&b.name));
+        symbols
+    }
+
+    pub fn file(&self, uri: &Url) -> Option<&FileIndex> {
+        self.files.get(uri)
+    }
+
+    /// Every read of variable `name` in workspace
+    pub fn references(&self, name: &str) -> Vec<lsp_types::Location> {
+        let mut references = self
+            .files
+            .iter()
+            .flat_map(|(uri, file)| {
+                file.uses
+                    .iter()
+                    .filter(move |(var, _)| var == name)
+                    .map(move |(_, range)| lsp_types::Location::new(uri.clone(), *range))
+            })
+            .collect::<Vec<_>>();
+        references.sort_by(|a, b| {
+            (a.uri.as_str(), a.range.start.line).cmp(&(b.uri.as_str(), b.range.start.line))
+        });
+        references
+    }
+}
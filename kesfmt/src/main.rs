@@ -1,21 +1,154 @@
+use kes::error::{Language, ParseError, ParseErrorMessage};
+use kes::formatter::{FormatError, FormatOptions};
 use rayon::prelude::*;
-use std::io::Write;
+use std::path::{Path, PathBuf};
+
+const USAGE: &str =
+    "usage: kesfmt [--jobs N] [--quiet | --verbose] [--tight-punctuation] [--lossy]";
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Verbosity {
+    /// Only errors
+    Quiet,
+    /// Errors and changed files
+    Normal,
+    /// Every file
+    Verbose,
+}
+
+struct Args {
+    options: FormatOptions,
+    /// Format files with parse errors, broken statements are kept as they are
+    lossy: bool,
+    jobs: Option<usize>,
+    verbosity: Verbosity,
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    std::process::exit(2);
+}
+
+fn parse_args() -> Args {
+    let mut args = Args {
+        options: FormatOptions::default(),
+        lossy: false,
+        jobs: None,
+        verbosity: Verbosity::Normal,
+    };
+    let mut iter = std::env::args().skip(1);
+
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--tight-punctuation" => args.options.tight_punctuation = true,
+            "--lossy" => args.lossy = true,
+            "-q" | "--quiet" => args.verbosity = Verbosity::Quiet,
+            "-v" | "--verbose" => args.verbosity = Verbosity::Verbose,
+            "-j" | "--jobs" => {
+                args.jobs = match iter.next().and_then(|n| n.parse().ok()) {
+                    Some(jobs) if jobs > 0 => Some(jobs),
+                    _ => usage(),
+                }
+            }
+            _ => usage(),
+        }
+    }
+
+    args
+}
+
+/// Format file in place, returns whether file is changed and errors of statements kept as they are
+fn format_file(
+    path: &Path,
+    options: FormatOptions,
+    lossy: bool,
+) -> Result<(bool, Vec<ParseError>), FormatError> {
+    let source = kes::source::Source::read(path)?;
+    let mut out = Vec::new();
+
+    let errors = if lossy {
+        kes::formatter::format_code_lossy(source.as_str(), &mut out, options)?
+    } else {
+        kes::formatter::format_code_with_options(source.as_str(), &mut out, options)?;
+        Vec::new()
+    };
+
+    if out == source.as_str().as_bytes() {
+        return Ok((false, errors));
+    }
+
+    std::fs::write(path, out)?;
+
+    Ok((true, errors))
+}
 
 fn main() {
-    glob::glob("**/*.kes")
+    let args = parse_args();
+
+    let mut paths = glob::glob("**/*.kes")
         .expect("glob files")
-        .par_bridge()
         .filter_map(Result::ok)
-        .try_for_each(|path| -> Result<(), kes::formatter::FormatError> {
-            let source = std::fs::read_to_string(&path)?;
+        .collect::<Vec<PathBuf>>();
+    // summary follows path order whatever order files are formatted in
+    paths.sort();
+
+    let mut pool = rayon::ThreadPoolBuilder::new();
+    if let Some(jobs) = args.jobs {
+        pool = pool.num_threads(jobs);
+    }
+    let pool = pool.build().expect("build thread pool");
+
+    let (options, lossy) = (args.options, args.lossy);
+    let results = pool.install(|| {
+        paths
+            .par_iter()
+            .map(|path| format_file(path, options, lossy))
+            .collect::<Vec<_>>()
+    });
+
+    let mut changed = 0;
+    let mut failed = 0;
 
-            let mut out = std::fs::File::create(&path)?;
+    for (path, result) in paths.iter().zip(results) {
+        if let Ok((_, errors)) = &result {
+            for error in errors {
+                eprintln!(
+                    "warning {}: {}",
+                    path.display(),
+                    ParseErrorMessage::new(error, Language::Korean)
+                );
+            }
+        }
 
-            kes::formatter::format_code(&source, &out)?;
+        match result.map(|(changed, _)| changed) {
+            Ok(true) => {
+                changed += 1;
+                if args.verbosity >= Verbosity::Normal {
+                    println!("formatted {}", path.display());
+                }
+            }
+            Ok(false) => {
+                if args.verbosity >= Verbosity::Verbose {
+                    println!("unchanged {}", path.display());
+                }
+            }
+            Err(err) => {
+                failed += 1;
+                eprintln!("error {}: {}", path.display(), err);
+            }
+        }
+    }
 
-            out.flush()?;
+    if args.verbosity >= Verbosity::Normal {
+        println!(
+            "{} files, {} formatted, {} failed",
+            paths.len(),
+            changed,
+            failed
+        );
+    }
 
-            Ok(())
-        })
-        .unwrap();
+    if failed > 0 {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,964 @@
+use crate::ast::{Expr, ExprKind, Stmt};
+use crate::compiler::static_type;
+use crate::error::{CompileWarning, LexicalError};
+use crate::interner::{Interner, Symbol};
+use crate::location::Location;
+use crate::operator::{BinaryOperator, TernaryOperator, UnaryOperator};
+use crate::signature::ParamType;
+use crate::suggest::closest;
+use crate::value::Value;
+use ahash::{AHashMap, AHashSet};
+use std::collections::BTreeMap;
+
+/// Evaluate expression at compile time, returns `None` when it depends on runtime state or fails
+pub fn fold_constant(expr: &Expr, interner: &Interner) -> Option<Value> {
+    fold_constant_with(expr, interner, &AHashMap::new())
+}
+
+/// [`fold_constant`] with known values of variables, see [`constant_variables`]
+pub fn fold_constant_with(
+    expr: &Expr,
+    interner: &Interner,
+    variables: &AHashMap<Symbol, Value>,
+) -> Option<Value> {
+    match &expr.kind {
+        ExprKind::Number(num) => Some(Value::Int(*num)),
+        ExprKind::Bool(value) => Some(Value::Bool(*value)),
+        ExprKind::Null => Some(Value::Null),
+        ExprKind::String(sym) | ExprKind::Asset(sym) => interner.resolve(*sym).map(Value::from),
+        ExprKind::Variable(var) => variables.get(var).cloned(),
+        ExprKind::BuiltinFunc { .. } | ExprKind::List(..) | ExprKind::Index { .. } => None,
+        ExprKind::Nop(value) => fold_constant_with(value, interner, variables),
+        ExprKind::UnaryOp {
+            value,
+            op: UnaryOperator::Not,
+        } => Some((!fold_constant_with(value, interner, variables)?.into_bool()).into()),
+        ExprKind::BinaryOp { lhs, rhs, op } => {
+            let lhs = fold_constant_with(lhs, interner, variables)?;
+            let rhs = fold_constant_with(rhs, interner, variables)?;
+
+            let value = match op {
+                BinaryOperator::Equal => (lhs == rhs).into(),
+                BinaryOperator::NotEqual => (lhs != rhs).into(),
+                // ordering different types fails at runtime
+                BinaryOperator::Greater => lhs.compare(&rhs)?.is_gt().into(),
+                BinaryOperator::GreaterOrEqual => lhs.compare(&rhs)?.is_ge().into(),
+                BinaryOperator::Less => lhs.compare(&rhs)?.is_lt().into(),
+                BinaryOperator::LessOrEqual => lhs.compare(&rhs)?.is_le().into(),
+                BinaryOperator::And | BinaryOperator::LogicalAnd => {
+                    (lhs.into_bool() & rhs.into_bool()).into()
+                }
+                BinaryOperator::Or | BinaryOperator::LogicalOr => {
+                    (lhs.into_bool() | rhs.into_bool()).into()
+                }
+                BinaryOperator::Xor => (lhs.into_bool() ^ rhs.into_bool()).into(),
+                BinaryOperator::Add => match (lhs, rhs) {
+                    (Value::Int(l), Value::Int(r)) => Value::Int(l.checked_add(r)?),
+                    (l @ Value::Str(..), r) | (l, r @ Value::Str(..)) => {
+                        format!("{}{}", l, r).into()
+                    }
+                    _ => return None,
+                },
+                BinaryOperator::Sub
+                | BinaryOperator::Mul
+                | BinaryOperator::Div
+                | BinaryOperator::Rem
+                | BinaryOperator::Shl
+                | BinaryOperator::Shr => {
+                    let (l, r) = match (lhs, rhs) {
+                        (Value::Int(l), Value::Int(r)) => (l, r),
+                        _ => return None,
+                    };
+
+                    Value::Int(match op {
+                        BinaryOperator::Sub => l.checked_sub(r)?,
+                        BinaryOperator::Mul => l.checked_mul(r)?,
+                        BinaryOperator::Div => l.checked_div(r)?,
+                        BinaryOperator::Shl => l.checked_shl(r).unwrap_or(0),
+                        BinaryOperator::Shr => l.checked_shr(r).unwrap_or(0),
+                        _ => l.checked_rem(r)?,
+                    })
+                }
+            };
+
+            Some(value)
+        }
+        ExprKind::TernaryOp {
+            lhs,
+            mhs,
+            rhs,
+            op: TernaryOperator::Conditional,
+        } => {
+            if fold_constant_with(lhs, interner, variables)?.into_bool() {
+                fold_constant_with(mhs, interner, variables)
+            } else {
+                fold_constant_with(rhs, interner, variables)
+            }
+        }
+    }
+}
+
+/// Values of variables which are constant after `program` runs to the end
+///
+/// Only top level assignments with constant value are known,
+/// variable assigned in body of block or declared by host is unknown after it.
+/// Builtins changing variables aren't considered
+pub fn constant_variables(program: &[Stmt], interner: &Interner) -> AHashMap<Symbol, Value> {
+    let mut variables = AHashMap::new();
+
+    for stmt in program {
+        match stmt {
+            Stmt::Assign { var, value, .. }
+            | Stmt::Declare {
+                var,
+                value: Some(value),
+                ..
+            } => match fold_constant_with(value, interner, &variables) {
+                Some(value) => {
+                    variables.insert(*var, value);
+                }
+                None => {
+                    variables.remove(var);
+                }
+            },
+            Stmt::Declare { var, .. } | Stmt::SetIndex { var, .. } => {
+                variables.remove(var);
+            }
+            _ => {
+                let mut assigned = AHashSet::new();
+                collect_assigned(std::slice::from_ref(stmt), &mut assigned);
+                variables.retain(|var, _| !assigned.contains(var));
+            }
+        }
+    }
+
+    variables
+}
+
+/// Set of variables assigned on every path reaching current statement
+#[derive(Clone)]
+struct Assigned {
+    vars: AHashSet<Symbol>,
+    reachable: bool,
+}
+
+impl Assigned {
+    fn merge(self, other: Self) -> Self {
+        match (self.reachable, other.reachable) {
+            (true, true) => Self {
+                vars: self.vars.intersection(&other.vars).copied().collect(),
+                reachable: true,
+            },
+            (true, false) => self,
+            (false, _) => other,
+        }
+    }
+}
+
+struct UseBeforeAssign<'a> {
+    interner: &'a Interner,
+    candidates: AHashSet<Symbol>,
+    reported: AHashSet<(Symbol, Location)>,
+    warnings: Vec<CompileWarning>,
+}
+
+fn collect_assigned(body: &[Stmt], out: &mut AHashSet<Symbol>) {
+    for stmt in body {
+        match stmt {
+            Stmt::Assign { var, .. }
+            | Stmt::Declare {
+                var,
+                value: Some(_),
+                ..
+            } => {
+                out.insert(*var);
+            }
+            Stmt::If { arms, other, .. } => {
+                for (_, body, _) in arms {
+                    collect_assigned(body, out);
+                }
+                collect_assigned(other, out);
+            }
+            Stmt::Select { arms, other, .. } => {
+                for (_, body, _) in arms {
+                    collect_assigned(body, out);
+                }
+                collect_assigned(other, out);
+            }
+            Stmt::ForEach { var, body, .. } => {
+                out.insert(*var);
+                collect_assigned(body, out);
+            }
+            Stmt::While { body, .. } | Stmt::Timer { body, .. } => collect_assigned(body, out),
+            Stmt::Declare { value: None, .. }
+            | Stmt::SetIndex { .. }
+            | Stmt::Print { .. }
+            | Stmt::Expression { .. }
+            | Stmt::Exit { .. }
+            | Stmt::Spawn { .. }
+            | Stmt::Label { .. }
+            | Stmt::Join { .. }
+            | Stmt::Include { .. } => {}
+        }
+    }
+}
+
+impl<'a> UseBeforeAssign<'a> {
+    fn check_var(&mut self, var: Symbol, state: &Assigned, location: Location) {
+        if state.reachable
+            && self.candidates.contains(&var)
+            && !state.vars.contains(&var)
+            && self.reported.insert((var, location))
+        {
+            self.warnings.push(CompileWarning::UseBeforeAssign(
+                self.interner.resolve(var).unwrap_or_default().into(),
+                location,
+            ));
+        }
+    }
+
+    fn check_expr(&mut self, expr: &Expr, state: &Assigned, location: Location) {
+        match &expr.kind {
+            ExprKind::Number(..)
+            | ExprKind::String(..)
+            | ExprKind::Bool(..)
+            | ExprKind::Null
+            | ExprKind::Asset(..) => {}
+            ExprKind::Variable(var) => self.check_var(*var, state, location),
+            ExprKind::BuiltinFunc { args: items, .. } | ExprKind::List(items) => {
+                for item in items {
+                    self.check_expr(item, state, location);
+                }
+            }
+            ExprKind::Index { value, index } => {
+                self.check_expr(value, state, location);
+                self.check_expr(index, state, location);
+            }
+            ExprKind::Nop(value) | ExprKind::UnaryOp { value, .. } => {
+                self.check_expr(value, state, location)
+            }
+            ExprKind::BinaryOp { lhs, rhs, .. } => {
+                self.check_expr(lhs, state, location);
+                self.check_expr(rhs, state, location);
+            }
+            ExprKind::TernaryOp { lhs, mhs, rhs, .. } => {
+                self.check_expr(lhs, state, location);
+                self.check_expr(mhs, state, location);
+                self.check_expr(rhs, state, location);
+            }
+        }
+    }
+
+    fn check_body(&mut self, body: &[Stmt], mut state: Assigned) -> Assigned {
+        for stmt in body {
+            state = self.check_stmt(stmt, state);
+        }
+        state
+    }
+
+    fn check_stmt(&mut self, stmt: &Stmt, mut state: Assigned) -> Assigned {
+        match stmt {
+            Stmt::Assign {
+                var,
+                value,
+                location,
+                ..
+            } => {
+                self.check_expr(value, &state, *location);
+                state.vars.insert(*var);
+                state
+            }
+            Stmt::Declare {
+                var,
+                value,
+                location,
+            } => {
+                if let Some(value) = value {
+                    self.check_expr(value, &state, *location);
+                }
+                state.vars.insert(*var);
+                state
+            }
+            Stmt::SetIndex {
+                var,
+                indices,
+                value,
+                location,
+            } => {
+                self.check_var(*var, &state, *location);
+                for index in indices {
+                    self.check_expr(index, &state, *location);
+                }
+                self.check_expr(value, &state, *location);
+                state
+            }
+            Stmt::Print {
+                values, location, ..
+            } => {
+                for value in values {
+                    self.check_expr(value, &state, *location);
+                }
+                state
+            }
+            Stmt::Expression { expr, location } => {
+                self.check_expr(expr, &state, *location);
+                state
+            }
+            Stmt::Spawn { args, location, .. } => {
+                for arg in args {
+                    self.check_expr(arg, &state, *location);
+                }
+                state
+            }
+            Stmt::Join { .. } | Stmt::Include { .. } => state,
+            // code after `종료` is reached from `장면` by `Context::new_at`
+            Stmt::Label { .. } if !state.reachable => Assigned {
+                vars: AHashSet::new(),
+                reachable: true,
+            },
+            Stmt::Label { .. } => state,
+            Stmt::Timer {
+                delay,
+                body,
+                location,
+            } => {
+                self.check_expr(delay, &state, *location);
+                self.check_body(body, state.clone());
+                state
+            }
+            Stmt::Exit { .. } => Assigned {
+                vars: AHashSet::new(),
+                reachable: false,
+            },
+            Stmt::If { arms, other, .. } => {
+                let mut out: Option<Assigned> = None;
+
+                for (cond, body, location) in arms {
+                    self.check_expr(cond, &state, *location);
+                    let arm = self.check_body(body, state.clone());
+                    out = Some(match out {
+                        Some(out) => out.merge(arm),
+                        None => arm,
+                    });
+                }
+
+                let other = self.check_body(other, state);
+
+                match out {
+                    Some(out) => out.merge(other),
+                    None => other,
+                }
+            }
+            Stmt::Select {
+                value,
+                arms,
+                other,
+                location,
+                ..
+            } => {
+                self.check_expr(value, &state, *location);
+                let other = self.check_body(other, state.clone());
+
+                arms.iter().fold(other, |out, (_, body, _)| {
+                    out.merge(self.check_body(body, state.clone()))
+                })
+            }
+            Stmt::While {
+                cond,
+                body,
+                location,
+            } => {
+                self.check_expr(cond, &state, *location);
+                self.check_body(body, state.clone());
+                state
+            }
+            Stmt::ForEach {
+                var,
+                iter,
+                body,
+                location,
+            } => {
+                self.check_expr(iter, &state, *location);
+                // body may not run, `var` is assigned only in it
+                let mut inner = state.clone();
+                inner.vars.insert(*var);
+                self.check_body(body, inner);
+                state
+            }
+        }
+    }
+}
+
+/// Find variables which may be read before any assignment along some path
+///
+/// Variables never assigned in script are assumed to be provided by host
+pub fn use_before_assign(program: &[Stmt], interner: &Interner) -> Vec<CompileWarning> {
+    let mut candidates = AHashSet::new();
+    collect_assigned(program, &mut candidates);
+
+    let mut checker = UseBeforeAssign {
+        interner,
+        candidates,
+        reported: AHashSet::new(),
+        warnings: Vec::new(),
+    };
+
+    checker.check_body(
+        program,
+        Assigned {
+            vars: AHashSet::new(),
+            reachable: true,
+        },
+    );
+
+    checker.warnings
+}
+
+struct Reachability<'a> {
+    interner: &'a Interner,
+    warnings: Vec<CompileWarning>,
+}
+
+impl<'a> Reachability<'a> {
+    fn check_cond(&mut self, cond: &Expr, location: Location, allow_true: bool) {
+        if let Some(value) = fold_constant(cond, self.interner) {
+            let value = value.into_bool();
+            if !(value && allow_true) {
+                self.warnings
+                    .push(CompileWarning::ConstantCondition(value, location));
+            }
+        }
+    }
+
+    fn check_body(&mut self, body: &[Stmt]) {
+        let mut exited = false;
+
+        for stmt in body {
+            // `Context::new_at` can start from `장면`
+            if let Stmt::Label { .. } = stmt {
+                exited = false;
+            }
+
+            if exited {
+                self.warnings
+                    .push(CompileWarning::UnreachableCode(stmt.location()));
+                break;
+            }
+
+            match stmt {
+                Stmt::Exit { .. } => exited = true,
+                Stmt::If { arms, other, .. } => {
+                    for (cond, body, location) in arms {
+                        self.check_cond(cond, *location, false);
+                        self.check_body(body);
+                    }
+                    self.check_body(other);
+                }
+                Stmt::While {
+                    cond,
+                    body,
+                    location,
+                } => {
+                    // `반복 1 { ... }` is common idiom for infinite loop
+                    self.check_cond(cond, *location, true);
+                    self.check_body(body);
+                }
+                Stmt::Timer { body, .. } | Stmt::ForEach { body, .. } => self.check_body(body),
+                Stmt::Select { arms, other, .. } => {
+                    for (_, body, _) in arms {
+                        self.check_body(body);
+                    }
+                    self.check_body(other);
+                }
+                Stmt::Assign { .. }
+                | Stmt::SetIndex { .. }
+                | Stmt::Declare { .. }
+                | Stmt::Print { .. }
+                | Stmt::Expression { .. }
+                | Stmt::Spawn { .. }
+                | Stmt::Label { .. }
+                | Stmt::Join { .. }
+                | Stmt::Include { .. } => {}
+            }
+        }
+    }
+}
+
+struct Undeclared<'a> {
+    interner: &'a Interner,
+    declared: AHashSet<Symbol>,
+    reported: AHashSet<Symbol>,
+    errors: Vec<LexicalError>,
+}
+
+impl<'a> Undeclared<'a> {
+    fn check_var(&mut self, var: Symbol, location: Location) {
+        if !self.declared.contains(&var) && self.reported.insert(var) {
+            let name = self.interner.resolve(var).unwrap_or_default();
+            let interner = self.interner;
+            let suggestion = closest(
+                name,
+                self.declared
+                    .iter()
+                    .filter_map(|declared| interner.resolve(*declared)),
+            );
+            self.errors.push(LexicalError::UndeclaredVariable(
+                name.into(),
+                suggestion.map(Into::into),
+                location,
+            ));
+        }
+    }
+
+    fn check_expr(&mut self, expr: &Expr, location: Location) {
+        match &expr.kind {
+            ExprKind::Number(..)
+            | ExprKind::String(..)
+            | ExprKind::Bool(..)
+            | ExprKind::Null
+            | ExprKind::Asset(..) => {}
+            ExprKind::Variable(var) => self.check_var(*var, location),
+            ExprKind::BuiltinFunc { args: items, .. } | ExprKind::List(items) => {
+                for item in items {
+                    self.check_expr(item, location);
+                }
+            }
+            ExprKind::Index { value, index } => {
+                self.check_expr(value, location);
+                self.check_expr(index, location);
+            }
+            ExprKind::Nop(value) | ExprKind::UnaryOp { value, .. } => {
+                self.check_expr(value, location)
+            }
+            ExprKind::BinaryOp { lhs, rhs, .. } => {
+                self.check_expr(lhs, location);
+                self.check_expr(rhs, location);
+            }
+            ExprKind::TernaryOp { lhs, mhs, rhs, .. } => {
+                self.check_expr(lhs, location);
+                self.check_expr(mhs, location);
+                self.check_expr(rhs, location);
+            }
+        }
+    }
+
+    fn check_body(&mut self, body: &[Stmt]) {
+        for stmt in body {
+            match stmt {
+                Stmt::Assign {
+                    var,
+                    value,
+                    location,
+                    ..
+                } => {
+                    self.check_expr(value, *location);
+                    self.declared.insert(*var);
+                }
+                Stmt::Declare {
+                    var,
+                    value,
+                    location,
+                } => {
+                    if let Some(value) = value {
+                        self.check_expr(value, *location);
+                    }
+                    self.declared.insert(*var);
+                }
+                Stmt::SetIndex {
+                    var,
+                    indices,
+                    value,
+                    location,
+                } => {
+                    self.check_var(*var, *location);
+                    for index in indices {
+                        self.check_expr(index, *location);
+                    }
+                    self.check_expr(value, *location);
+                }
+                Stmt::Print {
+                    values, location, ..
+                } => {
+                    for value in values {
+                        self.check_expr(value, *location);
+                    }
+                }
+                Stmt::Expression { expr, location } => self.check_expr(expr, *location),
+                Stmt::Spawn { args, location, .. } => {
+                    for arg in args {
+                        self.check_expr(arg, *location);
+                    }
+                }
+                Stmt::If { arms, other, .. } => {
+                    for (cond, body, location) in arms {
+                        self.check_expr(cond, *location);
+                        self.check_body(body);
+                    }
+                    self.check_body(other);
+                }
+                Stmt::While {
+                    cond,
+                    body,
+                    location,
+                } => {
+                    self.check_expr(cond, *location);
+                    self.check_body(body);
+                }
+                Stmt::Timer {
+                    delay,
+                    body,
+                    location,
+                } => {
+                    self.check_expr(delay, *location);
+                    self.check_body(body);
+                }
+                Stmt::ForEach {
+                    var,
+                    iter,
+                    body,
+                    location,
+                } => {
+                    self.check_expr(iter, *location);
+                    self.declared.insert(*var);
+                    self.check_body(body);
+                }
+                Stmt::Select {
+                    value,
+                    arms,
+                    other,
+                    location,
+                    ..
+                } => {
+                    self.check_expr(value, *location);
+                    for (_, body, _) in arms {
+                        self.check_body(body);
+                    }
+                    self.check_body(other);
+                }
+                Stmt::Exit { .. }
+                | Stmt::Join { .. }
+                | Stmt::Label { .. }
+                | Stmt::Include { .. } => {}
+            }
+        }
+    }
+}
+
+/// Find variables read without assignment or `변수` declaration lexically before, used by strict mode
+///
+/// Each variable is reported only once
+pub fn undeclared_variables(program: &[Stmt], interner: &Interner) -> Vec<LexicalError> {
+    let mut checker = Undeclared {
+        interner,
+        declared: AHashSet::new(),
+        reported: AHashSet::new(),
+        errors: Vec::new(),
+    };
+    checker.check_body(program);
+    checker.errors
+}
+
+/// Find code after `종료;` and conditions which are constant after folding
+pub fn unreachable_code(program: &[Stmt], interner: &Interner) -> Vec<CompileWarning> {
+    let mut checker = Reachability {
+        interner,
+        warnings: Vec::new(),
+    };
+    checker.check_body(program);
+    checker.warnings
+}
+
+/// Call `f` with every statement in `body` and blocks in it, outer statement first
+fn visit_stmts<'a>(body: &'a [Stmt], f: &mut impl FnMut(&'a Stmt)) {
+    for stmt in body {
+        f(stmt);
+        match stmt {
+            Stmt::If { arms, other, .. } => {
+                for (_, body, _) in arms {
+                    visit_stmts(body, f);
+                }
+                visit_stmts(other, f);
+            }
+            Stmt::Select { arms, other, .. } => {
+                for (_, body, _) in arms {
+                    visit_stmts(body, f);
+                }
+                visit_stmts(other, f);
+            }
+            Stmt::While { body, .. } | Stmt::Timer { body, .. } | Stmt::ForEach { body, .. } => {
+                visit_stmts(body, f)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Types of variables declared by `$이름: 수 = 값;` with errors of unknown or conflicting types
+///
+/// Declared type applies to every assignment of variable wherever annotation is
+pub fn variable_types(
+    program: &[Stmt],
+    interner: &Interner,
+) -> (BTreeMap<String, ParamType>, Vec<LexicalError>) {
+    let mut types = BTreeMap::new();
+    let errors = extend_variable_types(&mut types, program, interner);
+    (types, errors)
+}
+
+/// Add types declared in `program` to `types` which are declared before
+pub fn extend_variable_types(
+    types: &mut BTreeMap<String, ParamType>,
+    program: &[Stmt],
+    interner: &Interner,
+) -> Vec<LexicalError> {
+    let mut errors = Vec::new();
+
+    visit_stmts(program, &mut |stmt| {
+        if let Stmt::Assign {
+            var,
+            ty: Some(ty),
+            location,
+            ..
+        } = stmt
+        {
+            let name = interner.resolve(*var).unwrap_or_default();
+            let ty_name = interner.resolve(*ty).unwrap_or_default();
+            match ParamType::from_keyword(ty_name) {
+                Some(ty) => {
+                    if *types.entry(name.to_string()).or_insert(ty) != ty {
+                        errors.push(LexicalError::TypeConflict(name.into(), *location));
+                    }
+                }
+                None => errors.push(LexicalError::UnknownType(ty_name.into(), *location)),
+            }
+        }
+    });
+
+    errors
+}
+
+/// Find assignments whose value never has declared type of variable, see [`variable_types`]
+pub fn variable_type_warnings(
+    program: &[Stmt],
+    interner: &Interner,
+    types: &BTreeMap<String, ParamType>,
+) -> Vec<CompileWarning> {
+    let mut warnings = Vec::new();
+    if types.is_empty() {
+        return warnings;
+    }
+
+    visit_stmts(program, &mut |stmt| {
+        let (var, value, location) = match stmt {
+            Stmt::Assign {
+                var,
+                value,
+                location,
+                ..
+            }
+            | Stmt::Declare {
+                var,
+                value: Some(value),
+                location,
+            } => (*var, value, *location),
+            _ => return,
+        };
+        let name = interner.resolve(var).unwrap_or_default();
+        if let (Some(&ty), Some(found)) = (types.get(name), static_type(value)) {
+            if ty != ParamType::Any && ty != found {
+                warnings.push(CompileWarning::VariableType(
+                    name.into(),
+                    ty.name(),
+                    location,
+                    value.span,
+                ));
+            }
+        }
+    });
+
+    warnings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        constant_variables, fold_constant, fold_constant_with, unreachable_code, use_before_assign,
+        variable_type_warnings, variable_types,
+    };
+    use crate::error::{CompileWarning, LexicalError};
+    use crate::interner::Interner;
+    use crate::location::{Location, Span};
+    use crate::parser::parse;
+    use crate::signature::ParamType;
+    use crate::value::Value;
+    use pretty_assertions::assert_eq;
+
+    fn test_impl(source: &str) -> Vec<CompileWarning> {
+        let mut interner = Interner::new();
+        let ast = parse(source, &mut interner).unwrap();
+        use_before_assign(&ast, &interner)
+    }
+
+    fn unreachable_impl(source: &str) -> Vec<CompileWarning> {
+        let mut interner = Interner::new();
+        let ast = parse(source, &mut interner).unwrap();
+        unreachable_code(&ast, &interner)
+    }
+
+    #[test]
+    fn fold() {
+        let mut interner = Interner::new();
+        let ast = parse(
+            "(1 + 2) * 3 == 9 ? 'a' + 1 : 2; 1 / 0; $1 + 1; (1 < 2) & 참; 참 + 1;",
+            &mut interner,
+        )
+        .unwrap();
+        let folded = ast
+            .iter()
+            .map(|stmt| match stmt {
+                crate::ast::Stmt::Expression { expr, .. } => fold_constant(expr, &interner),
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            folded,
+            [
+                Some(Value::from("a1")),
+                None,
+                None,
+                Some(Value::Bool(true)),
+                None
+            ]
+        );
+    }
+
+    #[test]
+    fn variables() {
+        let mut interner = Interner::new();
+        let ast = parse(
+            "$1 = 2; $2 = $1 * 3; $3 = 가(); $4 = 1; 반복 $4 { $4 = 0; } $5 = $3 + 1; 변수 $2;\n$1 + 1; $2;",
+            &mut interner,
+        )
+        .unwrap();
+        let (program, exprs) = ast.split_at(ast.len() - 2);
+        let variables = constant_variables(program, &interner);
+        let var = |name: &str| interner.get(name).unwrap();
+
+        assert_eq!(variables.len(), 1);
+        assert_eq!(variables.get(&var("1")), Some(&Value::Int(2)));
+
+        let folded = exprs
+            .iter()
+            .map(|stmt| match stmt {
+                crate::ast::Stmt::Expression { expr, .. } => {
+                    fold_constant_with(expr, &interner, &variables)
+                }
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(folded, [Some(Value::Int(3)), None]);
+    }
+
+    #[test]
+    fn constant_condition() {
+        assert_eq!(
+            unreachable_impl("만약 0 {\n@1;\n} 혹은 $1 {\n}\n반복 1 {\n종료;\n}\n반복 1 - 1 {\n}"),
+            [
+                CompileWarning::ConstantCondition(false, Location::new(1)),
+                CompileWarning::ConstantCondition(false, Location::new(8)),
+            ]
+        );
+    }
+
+    #[test]
+    fn after_exit() {
+        assert_eq!(
+            unreachable_impl("만약 $1 {\n종료;\n@1;\n@2;\n}\n@3;"),
+            [CompileWarning::UnreachableCode(Location::new(3))]
+        );
+        assert_eq!(unreachable_impl("@1;\n종료;\n장면 끝;\n@2;"), []);
+    }
+
+    #[test]
+    fn simple() {
+        assert_eq!(
+            test_impl("@$1;\n$1 = 2;\n@$1 $2;"),
+            [CompileWarning::UseBeforeAssign(
+                "1".into(),
+                Location::new(1)
+            )]
+        );
+    }
+
+    #[test]
+    fn branch() {
+        assert_eq!(
+            test_impl("만약 $0 {\n$1 = 1;\n} 그외 {\n$1 = 2;\n}\n@$1;"),
+            []
+        );
+        assert_eq!(
+            test_impl("만약 $0 {\n$1 = 1;\n}\n@$1;"),
+            [CompileWarning::UseBeforeAssign(
+                "1".into(),
+                Location::new(4)
+            )]
+        );
+        assert_eq!(
+            test_impl("만약 $0 {\n$1 = 1;\n} 그외 {\n종료;\n}\n@$1;"),
+            []
+        );
+    }
+
+    #[test]
+    fn loop_body() {
+        assert_eq!(
+            test_impl("반복 1 {\n@$1;\n$1 = 1;\n}"),
+            [CompileWarning::UseBeforeAssign(
+                "1".into(),
+                Location::new(2)
+            )]
+        );
+    }
+
+    #[test]
+    fn types() {
+        let mut interner = Interner::new();
+        let ast = parse(
+            "$체력: 수 = 100;\n만약 $0 {\n$체력 = '백';\n$이름: 문자열 = 1 < 2;\n}\n$체력: 수 = 함수();",
+            &mut interner,
+        )
+        .unwrap();
+        let (types, errors) = variable_types(&ast, &interner);
+        assert_eq!(types.get("체력"), Some(&ParamType::Int));
+        assert_eq!(types.get("이름"), Some(&ParamType::Str));
+        assert!(errors.is_empty());
+
+        assert_eq!(
+            variable_type_warnings(&ast, &interner, &types),
+            [
+                CompileWarning::VariableType(
+                    "체력".into(),
+                    "int",
+                    Location::new(3),
+                    Span::new(42, 47)
+                ),
+                CompileWarning::VariableType(
+                    "이름".into(),
+                    "str",
+                    Location::new(4),
+                    Span::new(70, 75)
+                ),
+            ]
+        );
+
+        let ast = parse("$1: 수 = 1;\n$1: 목록 = [];\n$2: 숫자 = 1;", &mut interner).unwrap();
+        let errors = variable_types(&ast, &interner).1;
+        assert!(matches!(
+            &errors[..],
+            [
+                LexicalError::TypeConflict(var, Location { line: 2 }),
+                LexicalError::UnknownType(ty, Location { line: 3 }),
+            ] if var == "1" && ty == "숫자"
+        ));
+    }
+}
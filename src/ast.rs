@@ -1,15 +1,31 @@
 use crate::operator::{BinaryOperator, UnaryOperator};
-use crate::{interner::Symbol, location::Location, operator::TernaryOperator};
+use crate::{
+    interner::{Interner, Symbol},
+    location::{Location, Span},
+    operator::TernaryOperator,
+};
+use std::borrow::Cow;
 
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Stmt {
+    /// `$이름 = 값;`, `$이름: 수 = 값;` also declares type of variable
     Assign {
         var: Symbol,
+        /// Type name after `:`, see `ParamType::from_keyword`
+        ty: Option<Symbol>,
         value: Expr,
         location: Location,
     },
+    /// `변수 $이름;` declares variable provided by host
+    Declare {
+        var: Symbol,
+        value: Option<Expr>,
+        location: Location,
+    },
     Print {
         values: Vec<Expr>,
+        /// `로그` of `@#로그`, `None` for main text
+        channel: Option<Symbol>,
         newline: bool,
         wait: bool,
         location: Location,
@@ -24,6 +40,40 @@ pub enum Stmt {
         body: Vec<Stmt>,
         location: Location,
     },
+    Timer {
+        delay: Expr,
+        body: Vec<Stmt>,
+        location: Location,
+    },
+    /// `각각 $글자 $문장 { ... }` runs body with each element of value in `var`
+    ForEach {
+        var: Symbol,
+        iter: Expr,
+        body: Vec<Stmt>,
+        location: Location,
+    },
+    /// `장면 이름;` names position where `Context::new_at` can start, only at top level
+    Label {
+        name: Symbol,
+        location: Location,
+    },
+    /// `$목록[0][1] = 값;` changes element of list in variable
+    SetIndex {
+        var: Symbol,
+        indices: Vec<Expr>,
+        value: Expr,
+        location: Location,
+    },
+    /// `선택 $값 { 1, 2 { ... } '가' { ... } 그외 { ... } }`
+    ///
+    /// Values of arms are number or string literals, first matching arm runs
+    Select {
+        value: Expr,
+        arms: Vec<(Vec<Expr>, Vec<Stmt>, Location)>,
+        other: Vec<Stmt>,
+        other_location: Location,
+        location: Location,
+    },
     Expression {
         expr: Expr,
         location: Location,
@@ -31,41 +81,159 @@ pub enum Stmt {
     Exit {
         location: Location,
     },
+    Spawn {
+        name: Symbol,
+        args: Vec<Expr>,
+        location: Location,
+    },
+    Join {
+        location: Location,
+    },
+    /// `포함 'other.kes';` is replaced with statements of other file by `Program::from_entry`,
+    /// only at top level
+    Include {
+        path: Symbol,
+        location: Location,
+    },
 }
 
 impl Stmt {
     pub fn location(&self) -> Location {
         match self {
             Stmt::Assign { location, .. }
+            | Stmt::Declare { location, .. }
             | Stmt::Print { location, .. }
             | Stmt::While { location, .. }
+            | Stmt::Timer { location, .. }
+            | Stmt::Select { location, .. }
+            | Stmt::ForEach { location, .. }
+            | Stmt::SetIndex { location, .. }
+            | Stmt::Label { location, .. }
             | Stmt::Expression { location, .. }
+            | Stmt::Spawn { location, .. }
+            | Stmt::Join { location }
+            | Stmt::Include { location, .. }
             | Stmt::Exit { location } => *location,
             Stmt::If { arms, .. } => arms[0].2,
         }
     }
 
+    /// Move every location in statement and its blocks down by `lines`,
+    /// returns last line of statement after moving
+    #[cfg(feature = "compiler")]
+    pub(crate) fn shift_lines(&mut self, lines: usize) -> usize {
+        // line `0` is missing `그외`
+        fn shift(location: &mut Location, lines: usize) -> usize {
+            if location.line != 0 {
+                location.line += lines;
+            }
+            location.line
+        }
+
+        fn shift_body(body: &mut [Stmt], lines: usize, last: usize) -> usize {
+            body.iter_mut()
+                .fold(last, |last, stmt| last.max(stmt.shift_lines(lines)))
+        }
+
+        match self {
+            Stmt::Assign { location, .. }
+            | Stmt::Declare { location, .. }
+            | Stmt::Print { location, .. }
+            | Stmt::SetIndex { location, .. }
+            | Stmt::Label { location, .. }
+            | Stmt::Expression { location, .. }
+            | Stmt::Spawn { location, .. }
+            | Stmt::Join { location }
+            | Stmt::Include { location, .. }
+            | Stmt::Exit { location } => shift(location, lines),
+            Stmt::While { body, location, .. }
+            | Stmt::Timer { body, location, .. }
+            | Stmt::ForEach { body, location, .. } => {
+                let last = shift(location, lines);
+                shift_body(body, lines, last)
+            }
+            Stmt::If {
+                arms,
+                other,
+                other_location,
+            } => {
+                let last = arms.iter_mut().fold(0, |last, (_, body, location)| {
+                    let last = last.max(shift(location, lines));
+                    shift_body(body, lines, last)
+                });
+                let last = last.max(shift(other_location, lines));
+                shift_body(other, lines, last)
+            }
+            Stmt::Select {
+                arms,
+                other,
+                other_location,
+                location,
+                ..
+            } => {
+                let last = shift(location, lines);
+                let last = arms.iter_mut().fold(last, |last, (_, body, location)| {
+                    let last = last.max(shift(location, lines));
+                    shift_body(body, lines, last)
+                });
+                let last = last.max(shift(other_location, lines));
+                shift_body(other, lines, last)
+            }
+        }
+    }
+
     pub fn is_block(&self) -> bool {
         match self {
-            Stmt::If { .. } | Stmt::While { .. } => true,
+            Stmt::If { .. }
+            | Stmt::While { .. }
+            | Stmt::Timer { .. }
+            | Stmt::Select { .. }
+            | Stmt::ForEach { .. } => true,
             Stmt::Assign { .. }
+            | Stmt::SetIndex { .. }
+            | Stmt::Label { .. }
+            | Stmt::Declare { .. }
             | Stmt::Print { .. }
             | Stmt::Expression { .. }
+            | Stmt::Spawn { .. }
+            | Stmt::Join { .. }
+            | Stmt::Include { .. }
             | Stmt::Exit { .. } => false,
         }
     }
 }
 
+/// Expression with range of source text it came from
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum Expr {
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExprKind {
     Number(u32),
     String(Symbol),
+    /// `참` or `거짓`
+    Bool(bool),
+    /// `없음`
+    Null,
+    /// `자원('voice/001.ogg')`, path of asset is string at runtime
+    Asset(Symbol),
     Variable(Symbol),
     BuiltinFunc {
         name: Symbol,
         args: Vec<Expr>,
     },
 
+    /// `[1, 2, 3]`
+    List(Vec<Expr>),
+    /// `$목록[0]`
+    Index {
+        value: Box<Expr>,
+        index: Box<Expr>,
+    },
+
     Nop(Box<Expr>),
 
     UnaryOp {
@@ -88,27 +256,79 @@ pub enum Expr {
 }
 
 impl Expr {
-    pub fn unary_op(self, op: UnaryOperator) -> Self {
-        Expr::UnaryOp {
-            value: Box::new(self),
-            op,
-        }
+    pub fn new(kind: ExprKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    /// `span` is range of whole expression including operator
+    pub fn unary_op(self, op: UnaryOperator, span: Span) -> Self {
+        Self::new(
+            ExprKind::UnaryOp {
+                value: Box::new(self),
+                op,
+            },
+            span,
+        )
+    }
+
+    /// `span` is range of whole expression including brackets
+    pub fn index(self, index: Self, span: Span) -> Self {
+        Self::new(
+            ExprKind::Index {
+                value: Box::new(self),
+                index: Box::new(index),
+            },
+            span,
+        )
     }
 
     pub fn binary_op(self, rhs: Self, op: BinaryOperator) -> Self {
-        Expr::BinaryOp {
-            lhs: Box::new(self),
-            rhs: Box::new(rhs),
-            op,
-        }
+        let span = self.span.to(rhs.span);
+        Self::new(
+            ExprKind::BinaryOp {
+                lhs: Box::new(self),
+                rhs: Box::new(rhs),
+                op,
+            },
+            span,
+        )
     }
 
     pub fn ternary_op(self, mhs: Self, rhs: Self, op: TernaryOperator) -> Self {
-        Expr::TernaryOp {
-            lhs: Box::new(self),
-            mhs: Box::new(mhs),
-            rhs: Box::new(rhs),
-            op,
-        }
+        let span = self.span.to(rhs.span);
+        Self::new(
+            ExprKind::TernaryOp {
+                lhs: Box::new(self),
+                mhs: Box::new(mhs),
+                rhs: Box::new(rhs),
+                op,
+            },
+            span,
+        )
+    }
+}
+
+/// Other syntaxes (dialogue files, markup) can target kes VM by converting into kes AST
+///
+/// Names and strings must be interned into `interner` which is passed to `Program`
+pub trait IntoKesAst<'a> {
+    fn into_kes_ast(self, interner: &mut Interner) -> Cow<'a, [Stmt]>;
+}
+
+impl<'a> IntoKesAst<'a> for &'a [Stmt] {
+    fn into_kes_ast(self, _interner: &mut Interner) -> Cow<'a, [Stmt]> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl<'a> IntoKesAst<'a> for &'a Vec<Stmt> {
+    fn into_kes_ast(self, _interner: &mut Interner) -> Cow<'a, [Stmt]> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl IntoKesAst<'static> for Vec<Stmt> {
+    fn into_kes_ast(self, _interner: &mut Interner) -> Cow<'static, [Stmt]> {
+        Cow::Owned(self)
     }
 }
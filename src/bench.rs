@@ -0,0 +1,137 @@
+//! Measure script performance without async runtime
+//!
+//! ```rust
+//! use kes::program::Program;
+//! let program = Program::from_source("$1 = 0; 반복 $1 < 100 { $1 = $1 + 1; }").unwrap();
+//! let stats = kes::bench::run_headless(&program, 10).unwrap();
+//! assert_eq!(stats.iterations, 10);
+//! assert!(stats.min <= stats.mean() && stats.mean() <= stats.max);
+//! ```
+
+use crate::builtin::Builtin;
+use crate::context::Context;
+use crate::error::RuntimeResult;
+use crate::program::Program;
+use crate::value::Value;
+use async_trait::async_trait;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Poll, Wake, Waker};
+use std::thread::{self, Thread};
+use std::time::{Duration, Instant};
+
+/// Builtin which ignores every call and print
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NoopBuiltin;
+
+#[async_trait]
+impl Builtin for NoopBuiltin {
+    #[inline]
+    async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> Value {
+        Value::Int(0)
+    }
+    #[inline]
+    fn print(&mut self, _v: Value) {}
+    #[inline]
+    fn print_group(&mut self, _values: Vec<Value>) {}
+    #[inline]
+    fn new_line(&mut self) {}
+    #[inline]
+    async fn wait(&mut self) {}
+}
+
+/// Timing of `run_headless`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BenchStats {
+    pub iterations: u32,
+    pub total: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl BenchStats {
+    pub fn mean(&self) -> Duration {
+        self.total / self.iterations.max(1)
+    }
+}
+
+/// Wakes thread blocked in `block_on`
+struct ThreadWaker(Thread);
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+/// Run `future` on current thread, parks thread while it is pending
+fn block_on<F: Future>(future: F) -> F::Output {
+    let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+    let mut cx = std::task::Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        match Pin::as_mut(&mut future).poll(&mut cx) {
+            Poll::Ready(output) => return output,
+            Poll::Pending => thread::park(),
+        }
+    }
+}
+
+/// Run `program` from start `iterations` times with `NoopBuiltin`
+pub fn run_headless(program: &Program, iterations: u32) -> RuntimeResult<BenchStats> {
+    let mut stats = BenchStats {
+        iterations,
+        total: Duration::default(),
+        min: Duration::MAX,
+        max: Duration::default(),
+    };
+
+    for _ in 0..iterations {
+        let ctx = Context::new(program);
+        let start = Instant::now();
+        block_on(ctx.run(NoopBuiltin))?;
+        let elapsed = start.elapsed();
+
+        stats.total += elapsed;
+        stats.min = stats.min.min(elapsed);
+        stats.max = stats.max.max(elapsed);
+    }
+
+    if iterations == 0 {
+        stats.min = Duration::default();
+    }
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run_headless;
+    use crate::error::RuntimeError;
+    use crate::program::Program;
+    use std::time::Duration;
+
+    #[test]
+    fn stats() {
+        let program = Program::from_source("@'가' 함수(); @!;").unwrap();
+        let stats = run_headless(&program, 3).unwrap();
+        assert_eq!(stats.iterations, 3);
+        assert!(stats.min <= stats.max);
+        assert!(stats.total >= stats.max);
+
+        let stats = run_headless(&program, 0).unwrap();
+        assert_eq!(stats.mean(), Duration::default());
+        assert_eq!(stats.min, Duration::default());
+    }
+
+    #[test]
+    fn error() {
+        let program = Program::from_source("@$없음;").unwrap();
+        assert!(matches!(
+            run_headless(&program, 1),
+            Err(RuntimeError::UndefinedVariable(..))
+        ));
+    }
+}
@@ -2,17 +2,65 @@ use crate::context::Context;
 use crate::value::Value;
 use async_trait::async_trait;
 
+/// Host side handle of builtin returned by `Builtin::resolve`
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct Handle(pub u32);
+
 /// Script Builtin trait you can provide your system methods for script
 #[async_trait]
 pub trait Builtin: Send {
     async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value;
+    /// Resolve `name` once, later calls use `call` with returned handle instead of `run`
+    ///
+    /// `Context` caches result for its lifetime, so keep same builtin for one context
+    #[allow(unused_variables)]
+    fn resolve(&mut self, name: &str) -> Option<Handle> {
+        None
+    }
+    /// Run builtin resolved by `resolve`
+    #[allow(unused_variables)]
+    async fn call(&mut self, handle: Handle, name: &str, ctx: &mut Context<'_>) -> Value {
+        self.run(name, ctx).await
+    }
     #[allow(unused_variables)]
     fn load(&mut self, name: &str) -> Option<Value> {
         None
     }
     fn print(&mut self, v: Value);
+    /// Values of one print statement, host can lay them out at once
+    ///
+    /// Default implementation calls `print` for each value
+    fn print_group(&mut self, values: Vec<Value>) {
+        for v in values {
+            self.print(v);
+        }
+    }
+    /// Values of print statement with channel like `@#로그`, host can route them
+    /// to name plate or log window
+    ///
+    /// Default implementation prints them like `print_group`, `new_line` and `wait` follow as usual
+    #[allow(unused_variables)]
+    fn print_channel(&mut self, channel: &str, values: Vec<Value>) {
+        self.print_group(values);
+    }
     fn new_line(&mut self);
+    /// Called after every print statement
+    ///
+    /// Slow frontend can apply back-pressure by awaiting until output is consumed
+    async fn flush(&mut self) {}
     async fn wait(&mut self);
+    /// Start builtin without awaiting it for `시작` statement
+    ///
+    /// Host can run it in background and wait it on `join`, default implementation just runs it
+    async fn spawn(&mut self, name: &str, ctx: &mut Context<'_>) {
+        self.run(name, ctx).await;
+    }
+    /// Wait every spawned builtin for `기다리기` statement
+    async fn join(&mut self) {}
+    /// Called instead of `wait` in auto mode, host can advance after a delay
+    async fn auto_wait(&mut self) {
+        self.wait().await;
+    }
 }
 
 #[async_trait]
@@ -21,6 +69,107 @@ impl<'a, B: Builtin> Builtin for &'a mut B {
     async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value {
         (**self).run(name, ctx).await
     }
+    fn resolve(&mut self, name: &str) -> Option<Handle> {
+        (**self).resolve(name)
+    }
+    #[inline]
+    async fn call(&mut self, handle: Handle, name: &str, ctx: &mut Context<'_>) -> Value {
+        (**self).call(handle, name, ctx).await
+    }
+    fn load(&mut self, name: &str) -> Option<Value> {
+        (**self).load(name)
+    }
+    #[inline]
+    fn print(&mut self, v: Value) {
+        (**self).print(v);
+    }
+    #[inline]
+    fn print_group(&mut self, values: Vec<Value>) {
+        (**self).print_group(values);
+    }
+    #[inline]
+    fn print_channel(&mut self, channel: &str, values: Vec<Value>) {
+        (**self).print_channel(channel, values);
+    }
+    #[inline]
+    fn new_line(&mut self) {
+        (**self).new_line();
+    }
+    #[inline]
+    async fn flush(&mut self) {
+        (**self).flush().await;
+    }
+    #[inline]
+    async fn wait(&mut self) {
+        (**self).wait().await;
+    }
+    #[inline]
+    async fn auto_wait(&mut self) {
+        (**self).auto_wait().await;
+    }
+    #[inline]
+    async fn spawn(&mut self, name: &str, ctx: &mut Context<'_>) {
+        (**self).spawn(name, ctx).await;
+    }
+    #[inline]
+    async fn join(&mut self) {
+        (**self).join().await;
+    }
+}
+
+/// `Builtin` for single threaded hosts like browser, its futures don't have to be `Send`
+///
+/// Run it with `Context::run_local`
+#[async_trait(?Send)]
+pub trait LocalBuiltin {
+    async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value;
+    #[allow(unused_variables)]
+    fn resolve(&mut self, name: &str) -> Option<Handle> {
+        None
+    }
+    #[allow(unused_variables)]
+    async fn call(&mut self, handle: Handle, name: &str, ctx: &mut Context<'_>) -> Value {
+        self.run(name, ctx).await
+    }
+    #[allow(unused_variables)]
+    fn load(&mut self, name: &str) -> Option<Value> {
+        None
+    }
+    fn print(&mut self, v: Value);
+    fn print_group(&mut self, values: Vec<Value>) {
+        for v in values {
+            self.print(v);
+        }
+    }
+    #[allow(unused_variables)]
+    fn print_channel(&mut self, channel: &str, values: Vec<Value>) {
+        self.print_group(values);
+    }
+    fn new_line(&mut self);
+    async fn flush(&mut self) {}
+    async fn wait(&mut self);
+    async fn spawn(&mut self, name: &str, ctx: &mut Context<'_>) {
+        self.run(name, ctx).await;
+    }
+    async fn join(&mut self) {}
+    async fn auto_wait(&mut self) {
+        self.wait().await;
+    }
+}
+
+#[async_trait(?Send)]
+impl<B: LocalBuiltin> LocalBuiltin for &mut B {
+    #[inline]
+    async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value {
+        (**self).run(name, ctx).await
+    }
+    fn resolve(&mut self, name: &str) -> Option<Handle> {
+        (**self).resolve(name)
+    }
+    #[inline]
+    async fn call(&mut self, handle: Handle, name: &str, ctx: &mut Context<'_>) -> Value {
+        (**self).call(handle, name, ctx).await
+    }
     fn load(&mut self, name: &str) -> Option<Value> {
         (**self).load(name)
     }
@@ -29,13 +178,37 @@ impl<'a, B: Builtin> Builtin for &'a mut B {
         (**self).print(v);
     }
     #[inline]
+    fn print_group(&mut self, values: Vec<Value>) {
+        (**self).print_group(values);
+    }
+    #[inline]
+    fn print_channel(&mut self, channel: &str, values: Vec<Value>) {
+        (**self).print_channel(channel, values);
+    }
+    #[inline]
     fn new_line(&mut self) {
         (**self).new_line();
     }
     #[inline]
+    async fn flush(&mut self) {
+        (**self).flush().await;
+    }
+    #[inline]
     async fn wait(&mut self) {
         (**self).wait().await;
     }
+    #[inline]
+    async fn auto_wait(&mut self) {
+        (**self).auto_wait().await;
+    }
+    #[inline]
+    async fn spawn(&mut self, name: &str, ctx: &mut Context<'_>) {
+        (**self).spawn(name, ctx).await;
+    }
+    #[inline]
+    async fn join(&mut self) {
+        (**self).join().await;
+    }
 }
 
 pub struct RecordBuiltin(String);
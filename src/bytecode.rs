@@ -0,0 +1,176 @@
+//! Bytecode of compiled `Program` for external backends and analyzers
+//!
+//! Items in this module follow semver, `Instruction` is `#[non_exhaustive]`
+//! so new instructions can be added in minor versions
+//!
+//! ## Examples
+//! ```rust
+//! use kes::bytecode::{Builder, Instruction};
+//! use kes::location::Location;
+//! let mut builder = Builder::new();
+//! let text = builder.intern("안녕");
+//! builder.push(Instruction::LoadStr(text), Location::new(1));
+//! builder.push(Instruction::Print { newline: false, wait: false }, Location::new(1));
+//! let program = builder.build().unwrap();
+//! assert_eq!(program.instructions().len(), 2);
+//! ```
+
+pub use crate::error::VerifyError;
+pub use crate::instruction::{Instruction, InstructionWithDebug};
+use crate::interner::{Interner, Symbol};
+use crate::location::Location;
+pub use crate::operator::{BinaryOperator, TernaryOperator, UnaryOperator};
+use crate::program::Program;
+
+/// Construct `Program` from instructions
+pub struct Builder {
+    interner: Interner,
+    instructions: Vec<InstructionWithDebug>,
+    statements: Vec<u32>,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self {
+            interner: Interner::new(),
+            instructions: Vec::new(),
+            statements: Vec::new(),
+        }
+    }
+
+    pub fn intern(&mut self, text: &str) -> Symbol {
+        self.interner.get_or_intern(text)
+    }
+
+    /// Position of next instruction, use it for jump targets
+    pub fn position(&self) -> u32 {
+        self.instructions.len() as u32
+    }
+
+    /// Append instruction and return its position
+    pub fn push(&mut self, inst: Instruction, location: Location) -> u32 {
+        let pos = self.position();
+        self.instructions
+            .push(InstructionWithDebug { inst, location });
+        pos
+    }
+
+    /// Mark next instruction as start of statement for `TraceEvent::Statement`
+    pub fn statement(&mut self) {
+        let pos = self.position();
+        if self.statements.last() != Some(&pos) {
+            self.statements.push(pos);
+        }
+    }
+
+    /// Replace instruction at `pos`, for jumps whose target is known later
+    pub fn patch(&mut self, pos: u32, inst: Instruction) {
+        self.instructions[pos as usize].inst = inst;
+    }
+
+    /// Verify instructions and build `Program`
+    pub fn build(self) -> Result<Program, VerifyError> {
+        let program = Program::from_instructions(self.interner, self.instructions, self.statements);
+        verify(&program)?;
+        Ok(program)
+    }
+}
+
+/// Check that jump targets are in range, symbols are interned
+/// and timer bodies end with `EndTimer`
+pub fn verify(program: &Program) -> Result<(), VerifyError> {
+    let instructions = program.instructions();
+    let len = instructions.len() as u32;
+
+    for (pos, inst) in instructions.iter().enumerate() {
+        match inst.inst {
+            Instruction::LoadStr(sym)
+            | Instruction::LoadVar(sym)
+            | Instruction::StoreVar(sym)
+            | Instruction::CallBuiltin(sym)
+            | Instruction::SpawnBuiltin(sym)
+            | Instruction::PrintChannel { channel: sym, .. }
+            | Instruction::LoadAsset(sym)
+            | Instruction::StoreIndex { var: sym, .. }
+                if program.resolve(sym).is_none() =>
+            {
+                return Err(VerifyError::UnknownSymbol(pos));
+            }
+            Instruction::Goto(target)
+            | Instruction::GotoIfNot(target)
+            | Instruction::IterNext(target)
+                if target > len =>
+            {
+                return Err(VerifyError::InvalidTarget(pos, target));
+            }
+            Instruction::JumpTable { len, .. } => {
+                let table = (len as usize)
+                    .checked_add(pos + 2)
+                    .and_then(|end| instructions.get(pos + 1..end));
+                if !table.map_or(false, |table| {
+                    table
+                        .iter()
+                        .all(|inst| matches!(inst.inst, Instruction::Goto(..)))
+                }) {
+                    return Err(VerifyError::InvalidJumpTable(pos));
+                }
+            }
+            Instruction::Timer(end) => {
+                if end > len || end as usize <= pos + 1 {
+                    return Err(VerifyError::InvalidTarget(pos, end));
+                }
+                if instructions[end as usize - 1].inst != Instruction::EndTimer {
+                    return Err(VerifyError::UnclosedTimer(pos));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify, Builder, Instruction, VerifyError};
+    use crate::location::Location;
+    use crate::program::Program;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn compiled_program_is_valid() {
+        let program =
+            Program::from_source("$1 = 0; 반복 $1 < 3 { $1 = $1 + 1; } 타이머 500 { ㄱ($1); }")
+                .unwrap();
+        assert_eq!(verify(&program), Ok(()));
+    }
+
+    #[test]
+    fn builder() {
+        let location = Location::new(1);
+        let mut builder = Builder::new();
+        let jump = builder.push(Instruction::Nop, location);
+        builder.push(Instruction::LoadInt(1), location);
+        let end = builder.position();
+        builder.patch(jump, Instruction::Goto(end));
+        assert!(builder.build().is_ok());
+
+        let mut builder = Builder::new();
+        builder.push(Instruction::Goto(5), location);
+        assert_eq!(
+            builder.build().unwrap_err(),
+            VerifyError::InvalidTarget(0, 5)
+        );
+
+        let mut builder = Builder::new();
+        builder.push(Instruction::Timer(2), location);
+        builder.push(Instruction::Pop, location);
+        assert_eq!(builder.build().unwrap_err(), VerifyError::UnclosedTimer(0));
+    }
+}
@@ -0,0 +1,253 @@
+//! Check whether host can run program before running it
+//!
+//! Requirements come from metadata of program and instructions it uses
+//! - `엔진` metadata is version constraint like `>=0.16, <0.18`
+//! - `빌트인` metadata is comma separated builtin names, builtins called by program are added
+//!
+//! ## Examples
+//! ```rust
+//! use kes::compat::{Feature, HostCapabilities, Requirement};
+//! use kes::program::Program;
+//!
+//! let program = Program::from_source("---\n엔진: >=0.1\n---\n타이머 1 { 소리(); }").unwrap();
+//! let host = HostCapabilities::new().builtins(["소리"]).without(Feature::Timer);
+//! assert_eq!(host.missing(&program.requires()), [Requirement::Feature(Feature::Timer)]);
+//! ```
+
+use crate::error::CompatError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Metadata key of engine version constraint
+pub const ENGINE_KEY: &str = "엔진";
+/// Metadata key of builtins which host must provide
+pub const BUILTINS_KEY: &str = "빌트인";
+
+/// Language feature which host may not support
+///
+/// New features are added in minor versions
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[non_exhaustive]
+pub enum Feature {
+    /// `타이머` statement
+    Timer,
+    /// `시작` statement
+    Spawn,
+    /// List literal, indexing or `$목록[0] = 값;`
+    List,
+}
+
+impl Feature {
+    pub const ALL: [Feature; 3] = [Feature::Timer, Feature::Spawn, Feature::List];
+}
+
+impl fmt::Display for Feature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Feature::Timer => f.write_str("`타이머` 문"),
+            Feature::Spawn => f.write_str("`시작` 문"),
+            Feature::List => f.write_str("목록"),
+        }
+    }
+}
+
+/// Something host must provide for running program
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Requirement {
+    /// Engine version constraint
+    Engine(String),
+    Builtin(String),
+    Feature(Feature),
+}
+
+impl fmt::Display for Requirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Requirement::Engine(constraint) => write!(f, "엔진 버전 `{}`", constraint),
+            Requirement::Builtin(name) => write!(f, "`{}` 함수", name),
+            Requirement::Feature(feature) => feature.fmt(f),
+        }
+    }
+}
+
+/// Numeric components of version, missing components are 0
+fn parse_version(version: &str) -> Option<[u64; 3]> {
+    let mut out = [0; 3];
+    let mut parts = version.trim().split('.');
+
+    for (idx, part) in parts.by_ref().take(3).enumerate() {
+        out[idx] = part.parse().ok()?;
+    }
+
+    match parts.next() {
+        Some(_) => None,
+        None => Some(out),
+    }
+}
+
+/// Whether `version` satisfies every comma separated part of `constraint`
+///
+/// Part without operator means `>=`, constraint which can't be parsed is never satisfied
+pub fn version_satisfies(version: &str, constraint: &str) -> bool {
+    let version = match parse_version(version) {
+        Some(version) => version,
+        None => return false,
+    };
+
+    constraint.split(',').all(|part| {
+        let part = part.trim();
+        let (op, target) = [">=", "<=", ">", "<", "="]
+            .iter()
+            .find_map(|op| part.strip_prefix(op).map(|target| (*op, target)))
+            .unwrap_or((">=", part));
+
+        match parse_version(target) {
+            Some(target) => match op {
+                ">=" => version >= target,
+                "<=" => version <= target,
+                ">" => version > target,
+                "<" => version < target,
+                _ => version == target,
+            },
+            None => false,
+        }
+    })
+}
+
+/// What host supports, everything is supported by default
+#[derive(Clone, Debug)]
+pub struct HostCapabilities {
+    version: String,
+    /// `None` when every builtin is provided
+    builtins: Option<BTreeSet<String>>,
+    features: BTreeSet<Feature>,
+}
+
+impl Default for HostCapabilities {
+    fn default() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").into(),
+            builtins: None,
+            features: Feature::ALL.iter().copied().collect(),
+        }
+    }
+}
+
+impl HostCapabilities {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Engine version checked against `엔진` metadata, default is version of this crate
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = version.into();
+        self
+    }
+
+    /// Only these builtins are provided
+    pub fn builtins<S: Into<String>>(mut self, names: impl IntoIterator<Item = S>) -> Self {
+        self.builtins
+            .get_or_insert_with(BTreeSet::new)
+            .extend(names.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn without(mut self, feature: Feature) -> Self {
+        self.features.remove(&feature);
+        self
+    }
+
+    pub fn supports(&self, requirement: &Requirement) -> bool {
+        match requirement {
+            Requirement::Engine(constraint) => version_satisfies(&self.version, constraint),
+            Requirement::Builtin(name) => self
+                .builtins
+                .as_ref()
+                .map_or(true, |builtins| builtins.contains(name)),
+            Requirement::Feature(feature) => self.features.contains(feature),
+        }
+    }
+
+    /// Requirements which aren't supported, sorted
+    pub fn missing(&self, requirements: &BTreeSet<Requirement>) -> Vec<Requirement> {
+        requirements
+            .iter()
+            .filter(|requirement| !self.supports(requirement))
+            .cloned()
+            .collect()
+    }
+
+    pub fn check(&self, requirements: &BTreeSet<Requirement>) -> Result<(), CompatError> {
+        let missing = self.missing(requirements);
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(CompatError(missing))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{version_satisfies, Feature, HostCapabilities, Requirement};
+    use crate::error::CompatError;
+    use crate::program::Program;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn version() {
+        assert!(version_satisfies("0.16.0", ">=0.16"));
+        assert!(version_satisfies("0.16.2", "0.16.1, <0.17"));
+        assert!(!version_satisfies("0.17.0", ">=0.16, <0.17"));
+        assert!(version_satisfies("1.0.0", "=1"));
+        assert!(!version_satisfies("1.0.0", ">=abc"));
+    }
+
+    #[test]
+    fn requires() {
+        let program = Program::from_source(
+            "---\n엔진: >=0.16\n빌트인: 소리, 표\n---\n시작 배경음('bgm'); 타이머 10 { 소리(); }",
+        )
+        .unwrap();
+        for code in ["$1 = [1];", "@$1[0];", "$1[0] = 2;"] {
+            assert!(Program::from_source(code)
+                .unwrap()
+                .requires()
+                .contains(&Requirement::Feature(Feature::List)));
+        }
+        let builtin = |name: &str| Requirement::Builtin(name.into());
+
+        assert_eq!(
+            program.requires().into_iter().collect::<Vec<_>>(),
+            [
+                Requirement::Engine(">=0.16".into()),
+                builtin("배경음"),
+                builtin("소리"),
+                builtin("표"),
+                Requirement::Feature(Feature::Timer),
+                Requirement::Feature(Feature::Spawn),
+            ]
+        );
+
+        let host = HostCapabilities::new()
+            .version("0.15.3")
+            .builtins(["소리", "배경음"])
+            .without(Feature::Spawn);
+        let err = host.check(&program.requires()).unwrap_err();
+        assert_eq!(
+            err,
+            CompatError(vec![
+                Requirement::Engine(">=0.16".into()),
+                builtin("표"),
+                Requirement::Feature(Feature::Spawn),
+            ])
+        );
+        assert_eq!(
+            err.to_string(),
+            "스크립트 실행에 필요한 기능이 없습니다: 엔진 버전 `>=0.16`, `표` 함수, `시작` 문"
+        );
+
+        assert_eq!(HostCapabilities::new().check(&program.requires()), Ok(()));
+    }
+}
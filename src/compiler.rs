@@ -1,19 +1,235 @@
+use crate::error::CompileWarning;
 use crate::instruction::{Instruction, InstructionWithDebug};
+use crate::interner::{Interner, Symbol};
 use crate::location::Location;
-use crate::{ast::Expr, ast::Stmt};
+use crate::operator::{BinaryOperator, UnaryOperator};
+use crate::signature::{ParamType, Signatures};
+use crate::suggest::closest;
+use crate::{ast::Expr, ast::ExprKind, ast::Stmt};
 use arrayvec::ArrayVec;
+use serde::{Deserialize, Serialize};
 
 /// Compile ast with instructions
-pub struct Compiler {
+pub struct Compiler<'a> {
     out: Vec<InstructionWithDebug>,
+    /// Position of first instruction of each statement
+    statements: Vec<u32>,
+    labels: Vec<Label>,
     location: Location,
+    signatures: Option<(&'a Signatures, &'a Interner)>,
+    warnings: Vec<CompileWarning>,
+    /// `(position of first instruction, file id)` of each file region
+    files: Vec<(u32, u32)>,
 }
 
-impl Compiler {
+/// `장면` with position of instruction after it
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Label {
+    pub name: Symbol,
+    pub pos: u32,
+    pub location: Location,
+}
+
+/// Output of `Compiler::compile_program`
+pub struct Compiled {
+    pub instructions: Vec<InstructionWithDebug>,
+    /// Position of first instruction of each statement, sorted
+    pub statements: Vec<u32>,
+    /// In source order
+    pub labels: Vec<Label>,
+    pub warnings: Vec<CompileWarning>,
+    /// `(position of first instruction, file id)` sorted, empty unless `compile_files` is used
+    pub files: Vec<(u32, u32)>,
+}
+
+/// Type of expression if it can be known without running
+pub(crate) fn static_type(expr: &Expr) -> Option<ParamType> {
+    match &expr.kind {
+        ExprKind::Number(..) => Some(ParamType::Int),
+        ExprKind::String(..) | ExprKind::Asset(..) => Some(ParamType::Str),
+        ExprKind::Bool(..) => Some(ParamType::Bool),
+        ExprKind::List(..) => Some(ParamType::List),
+        ExprKind::Null
+        | ExprKind::Variable(..)
+        | ExprKind::BuiltinFunc { .. }
+        | ExprKind::Index { .. } => None,
+        ExprKind::Nop(value) => static_type(value),
+        ExprKind::UnaryOp { .. } => Some(ParamType::Bool),
+        ExprKind::BinaryOp {
+            lhs,
+            rhs,
+            op: BinaryOperator::Add,
+        } => match (static_type(lhs), static_type(rhs)) {
+            (Some(ParamType::Str), _) | (_, Some(ParamType::Str)) => Some(ParamType::Str),
+            (Some(ParamType::Int), Some(ParamType::Int)) => Some(ParamType::Int),
+            (Some(ParamType::List), Some(ParamType::List)) => Some(ParamType::List),
+            _ => None,
+        },
+        ExprKind::BinaryOp { op, .. } => Some(match op {
+            BinaryOperator::Sub
+            | BinaryOperator::Mul
+            | BinaryOperator::Div
+            | BinaryOperator::Rem
+            | BinaryOperator::Shl
+            | BinaryOperator::Shr => ParamType::Int,
+            _ => ParamType::Bool,
+        }),
+        ExprKind::TernaryOp { mhs, rhs, .. } => match (static_type(mhs), static_type(rhs)) {
+            (Some(l), Some(r)) if l == r => Some(l),
+            _ => None,
+        },
+    }
+}
+
+/// `$변수 == 숫자` or `숫자 == $변수`
+fn case_value(cond: &Expr) -> Option<(Symbol, u32)> {
+    match &cond.kind {
+        ExprKind::BinaryOp {
+            lhs,
+            rhs,
+            op: BinaryOperator::Equal,
+        } => match (&lhs.kind, &rhs.kind) {
+            (ExprKind::Variable(var), ExprKind::Number(num))
+            | (ExprKind::Number(num), ExprKind::Variable(var)) => Some((*var, *num)),
+            _ => None,
+        },
+        ExprKind::Nop(value) => case_value(value),
+        _ => None,
+    }
+}
+
+/// Arms which compare same variable with distinct dense numbers, returns variable and numbers
+fn dense_cases(arms: &[(Expr, Vec<Stmt>, Location)]) -> Option<(Symbol, Vec<u32>)> {
+    if arms.len() < 4 {
+        return None;
+    }
+
+    let mut var = None;
+    let mut nums = Vec::with_capacity(arms.len());
+    for (cond, ..) in arms {
+        let (arm_var, num) = case_value(cond)?;
+        if *var.get_or_insert(arm_var) != arm_var || nums.contains(&num) {
+            return None;
+        }
+        nums.push(num);
+    }
+
+    let min = *nums.iter().min()?;
+    let max = *nums.iter().max()?;
+    let len = (max - min) as usize + 1;
+    if len > arms.len() * 2 || len > u16::MAX as usize {
+        return None;
+    }
+
+    Some((var?, nums))
+}
+
+/// `선택` arms with distinct dense numbers only, returns number with index of arm
+fn dense_select(arms: &[(Vec<Expr>, Vec<Stmt>, Location)]) -> Option<Vec<(u32, usize)>> {
+    let mut cases = Vec::new();
+    for (idx, (values, ..)) in arms.iter().enumerate() {
+        for value in values {
+            match value.kind {
+                ExprKind::Number(num) if cases.iter().all(|(case, _)| *case != num) => {
+                    cases.push((num, idx))
+                }
+                _ => return None,
+            }
+        }
+    }
+
+    if cases.len() < 4 {
+        return None;
+    }
+
+    let min = cases.iter().map(|(num, _)| *num).min()?;
+    let max = cases.iter().map(|(num, _)| *num).max()?;
+    let len = (max - min) as usize + 1;
+    if len > cases.len() * 2 || len > u16::MAX as usize {
+        return None;
+    }
+
+    Some(cases)
+}
+
+/// Operands of `+` chain from left, `a + b + c` gives `[a, b, c]`
+fn add_operands<'e>(expr: &'e Expr, out: &mut Vec<&'e Expr>) {
+    match &expr.kind {
+        ExprKind::BinaryOp {
+            lhs,
+            rhs,
+            op: BinaryOperator::Add,
+        } => {
+            add_operands(lhs, out);
+            out.push(rhs);
+        }
+        _ => out.push(expr),
+    }
+}
+
+impl<'a> Compiler<'a> {
     pub fn new() -> Self {
         Self {
             out: Vec::new(),
+            statements: Vec::new(),
+            labels: Vec::new(),
             location: Location::default(),
+            signatures: None,
+            warnings: Vec::new(),
+            files: Vec::new(),
+        }
+    }
+
+    /// Check builtin calls with `signatures`, `interner` is used for resolving builtin names
+    pub fn with_signatures(signatures: &'a Signatures, interner: &'a Interner) -> Self {
+        Self {
+            signatures: Some((signatures, interner)),
+            ..Self::new()
+        }
+    }
+
+    fn check_call(&mut self, name: &str, args: &[Expr]) {
+        let signatures = match self.signatures {
+            Some((signatures, _)) => signatures,
+            None => return,
+        };
+
+        let signature = match signatures.get(name) {
+            Some(signature) => signature,
+            None => {
+                let suggestion = closest(name, signatures.iter().map(|(name, _)| name));
+                self.warnings.push(CompileWarning::UnknownBuiltin(
+                    name.into(),
+                    suggestion.map(Into::into),
+                    self.location,
+                ));
+                return;
+            }
+        };
+
+        if signature.arity() != args.len() {
+            self.warnings.push(CompileWarning::ArgumentCount(
+                name.into(),
+                signature.arity(),
+                args.len(),
+                self.location,
+            ));
+            return;
+        }
+
+        for (idx, (param, arg)) in signature.params().iter().zip(args).enumerate() {
+            match static_type(arg) {
+                Some(ty) if *param != ParamType::Any && *param != ty => {
+                    self.warnings.push(CompileWarning::ArgumentType(
+                        name.into(),
+                        idx + 1,
+                        param.name(),
+                        self.location,
+                        arg.span,
+                    ));
+                }
+                _ => {}
+            }
         }
     }
 
@@ -35,6 +251,10 @@ impl Compiler {
     }
 
     fn compile_stmt(&mut self, stmt: &Stmt) {
+        if self.statements.last() != Some(&self.next_pos()) {
+            self.statements.push(self.next_pos());
+        }
+
         match stmt {
             Stmt::Exit { location } => {
                 self.location = *location;
@@ -42,6 +262,7 @@ impl Compiler {
             }
             Stmt::Print {
                 values,
+                channel,
                 newline,
                 wait,
                 location,
@@ -50,30 +271,110 @@ impl Compiler {
                 for value in values {
                     self.push_expr(value);
                 }
-                self.push(Instruction::Print {
-                    wait: *wait,
-                    newline: *newline,
+                self.push(match channel {
+                    Some(channel) => Instruction::PrintChannel {
+                        channel: *channel,
+                        wait: *wait,
+                        newline: *newline,
+                    },
+                    None => Instruction::Print {
+                        wait: *wait,
+                        newline: *newline,
+                    },
                 });
             }
             Stmt::Assign {
                 var,
                 value,
                 location,
+                ..
             } => {
                 self.location = *location;
                 self.push_expr(value);
                 self.push(Instruction::StoreVar(*var));
             }
+            Stmt::SetIndex {
+                var,
+                indices,
+                value,
+                location,
+            } => {
+                self.location = *location;
+                for index in indices {
+                    self.push_expr(index);
+                }
+                self.push_expr(value);
+                self.push(Instruction::StoreIndex {
+                    var: *var,
+                    depth: indices.len() as u32,
+                });
+            }
+            Stmt::Declare {
+                var,
+                value,
+                location,
+            } => {
+                if let Some(value) = value {
+                    self.location = *location;
+                    self.push_expr(value);
+                    self.push(Instruction::StoreVar(*var));
+                }
+            }
             Stmt::Expression { expr, location } => {
                 self.location = *location;
                 self.push_expr(expr);
                 self.push(Instruction::Pop);
             }
+            Stmt::Spawn {
+                name,
+                args,
+                location,
+            } => {
+                self.location = *location;
+                self.push_call_args(*name, args);
+                self.push(Instruction::SpawnBuiltin(*name));
+            }
+            Stmt::Timer {
+                delay,
+                body,
+                location,
+            } => {
+                self.location = *location;
+                self.push_expr(delay);
+                let start = self.mark_pos();
+                self.compile_body(body);
+                self.push(Instruction::EndTimer);
+                self.out[start as usize].inst = Instruction::Timer(self.next_pos());
+            }
+            Stmt::Join { location } => {
+                self.location = *location;
+                self.push(Instruction::Join);
+            }
+            Stmt::Label { name, location } => {
+                self.labels.push(Label {
+                    name: *name,
+                    pos: self.next_pos(),
+                    location: *location,
+                });
+            }
+            // `Program::from_entry` replaced it already, other constructors reject it
+            Stmt::Include { .. } => {}
             Stmt::If {
                 arms,
                 other,
                 other_location,
             } => {
+                if let Some((var, nums)) = dense_cases(arms) {
+                    self.location = arms[0].2;
+                    self.push(Instruction::LoadVar(var));
+                    let cases: Vec<_> = nums.into_iter().zip(0..).collect();
+                    let bodies: Vec<_> = arms
+                        .iter()
+                        .map(|(_, body, location)| (body.as_slice(), *location))
+                        .collect();
+                    return self.compile_jump_table(&cases, &bodies, other, *other_location);
+                }
+
                 let mut mark = 0;
                 let mut else_mark = ArrayVec::<[_; 20]>::new();
 
@@ -108,6 +409,79 @@ impl Compiler {
                     self.out[mark as usize].inst = Instruction::Goto(self.next_pos() as u32);
                 }
             }
+            Stmt::Select {
+                value,
+                arms,
+                other,
+                other_location,
+                location,
+            } => {
+                self.location = *location;
+                self.push_expr(value);
+
+                if let Some(cases) = dense_select(arms) {
+                    let bodies: Vec<_> = arms
+                        .iter()
+                        .map(|(_, body, location)| (body.as_slice(), *location))
+                        .collect();
+                    return self.compile_jump_table(&cases, &bodies, other, *other_location);
+                }
+
+                // value stays on stack while arms are compared
+                let mut end_marks = Vec::with_capacity(arms.len());
+                for (values, body, location) in arms {
+                    self.location = *location;
+                    // every value but last jumps into body on match
+                    let (last, rest) = values.split_last().unwrap();
+                    let mut match_marks = Vec::with_capacity(rest.len());
+                    for value in rest {
+                        self.push(Instruction::Duplicate);
+                        self.push_expr(value);
+                        self.push(Instruction::BinaryOperator(BinaryOperator::Equal));
+                        self.push(Instruction::UnaryOperator(UnaryOperator::Not));
+                        match_marks.push(self.mark_pos());
+                    }
+                    self.push(Instruction::Duplicate);
+                    self.push_expr(last);
+                    self.push(Instruction::BinaryOperator(BinaryOperator::Equal));
+                    let next = self.mark_pos();
+                    for mark in match_marks {
+                        self.out[mark as usize].inst = Instruction::GotoIfNot(self.next_pos());
+                    }
+                    self.push(Instruction::Pop);
+                    self.compile_body(body);
+                    end_marks.push(self.mark_pos());
+                    self.out[next as usize].inst = Instruction::GotoIfNot(self.next_pos());
+                }
+
+                self.location = if other.is_empty() {
+                    *location
+                } else {
+                    *other_location
+                };
+                self.push(Instruction::Pop);
+                self.compile_body(other);
+
+                for mark in end_marks {
+                    self.out[mark as usize].inst = Instruction::Goto(self.next_pos());
+                }
+            }
+            Stmt::ForEach {
+                var,
+                iter,
+                body,
+                location,
+            } => {
+                self.location = *location;
+                self.push_expr(iter);
+                self.push(Instruction::IterStart);
+                let next = self.mark_pos();
+                self.push(Instruction::StoreVar(*var));
+
+                self.compile_body(body);
+                self.push(Instruction::Goto(next));
+                self.out[next as usize].inst = Instruction::IterNext(self.next_pos());
+            }
             Stmt::While {
                 cond,
                 body,
@@ -125,30 +499,116 @@ impl Compiler {
         }
     }
 
+    /// Dispatch value on top of stack by `JumpTable` in O(1)
+    ///
+    /// `cases` are numbers with index of arm in `arms`, numbers not in `cases` run `other`
+    fn compile_jump_table(
+        &mut self,
+        cases: &[(u32, usize)],
+        arms: &[(&[Stmt], Location)],
+        other: &[Stmt],
+        other_location: Location,
+    ) {
+        let min = cases.iter().map(|(num, _)| *num).min().unwrap();
+        let len = (cases.iter().map(|(num, _)| *num).max().unwrap() - min + 1) as u16;
+
+        self.push(Instruction::JumpTable { min, len });
+        let table = self.next_pos();
+        for _ in 0..=len {
+            self.push(Instruction::Nop);
+        }
+
+        let mut starts = Vec::with_capacity(arms.len());
+        let mut end_marks = Vec::with_capacity(arms.len());
+        for (body, location) in arms {
+            self.location = *location;
+            starts.push(self.next_pos());
+            self.compile_body(body);
+            end_marks.push(self.mark_pos());
+        }
+
+        let default = self.next_pos();
+        if !other.is_empty() {
+            self.location = other_location;
+        }
+        self.compile_body(other);
+        let end = self.next_pos();
+
+        for slot in 0..=len as u32 {
+            let target = cases
+                .iter()
+                .find(|(num, _)| *num == min + slot)
+                .map_or(default, |(_, arm)| starts[*arm]);
+            self.out[(table + slot) as usize].inst = Instruction::Goto(target);
+        }
+        for mark in end_marks {
+            self.out[mark as usize].inst = Instruction::Goto(end);
+        }
+    }
+
     fn push_expr(&mut self, expr: &Expr) {
-        match expr {
-            Expr::Number(num) => self.push(Instruction::LoadInt(*num)),
-            Expr::String(str) => self.push(Instruction::LoadStr(*str)),
-            Expr::Variable(var) => self.push(Instruction::LoadVar(*var)),
-            Expr::BuiltinFunc { name, args } => {
-                for arg in args.iter() {
-                    self.push_expr(arg);
+        match &expr.kind {
+            ExprKind::Number(num) => self.push(Instruction::LoadInt(*num)),
+            ExprKind::String(str) => self.push(Instruction::LoadStr(*str)),
+            ExprKind::Bool(value) => self.push(Instruction::LoadBool(*value)),
+            ExprKind::Null => self.push(Instruction::LoadNull),
+            ExprKind::Asset(path) => self.push(Instruction::LoadAsset(*path)),
+            ExprKind::Variable(var) => self.push(Instruction::LoadVar(*var)),
+            ExprKind::List(items) => {
+                for item in items {
+                    self.push_expr(item);
                 }
+                self.push(Instruction::BuildList(items.len() as u32));
+            }
+            ExprKind::Index { value, index } => {
+                self.push_expr(value);
+                self.push_expr(index);
+                self.push(Instruction::LoadIndex);
+            }
+            ExprKind::BuiltinFunc { name, args } => {
+                self.push_call_args(*name, args);
                 self.push(Instruction::CallBuiltin(*name));
             }
-            Expr::Nop(value) => {
+            ExprKind::Nop(value) => {
                 self.push_expr(value);
             }
-            Expr::UnaryOp { value, op } => {
+            ExprKind::UnaryOp { value, op } => {
                 self.push_expr(value);
                 self.push(Instruction::UnaryOperator(*op));
             }
-            Expr::BinaryOp { lhs, rhs, op } => {
+            ExprKind::BinaryOp {
+                op: BinaryOperator::Add,
+                ..
+            } if static_type(expr) == Some(ParamType::Str) => {
+                let mut operands = Vec::new();
+                add_operands(expr, &mut operands);
+                let mut count = 0;
+                for operand in operands {
+                    // long chain is concatenated in chunks, previous result is first value of next chunk
+                    if count == u8::MAX {
+                        self.push(Instruction::ConcatN(count));
+                        count = 1;
+                    }
+                    self.push_expr(operand);
+                    count += 1;
+                }
+                if count == 2 {
+                    self.push(Instruction::BinaryOperator(BinaryOperator::Add));
+                } else {
+                    self.push(Instruction::ConcatN(count));
+                }
+            }
+            ExprKind::BinaryOp { lhs, rhs, op }
+                if matches!(op, BinaryOperator::LogicalAnd | BinaryOperator::LogicalOr) =>
+            {
+                self.push_short_circuit(lhs, rhs, *op);
+            }
+            ExprKind::BinaryOp { lhs, rhs, op } => {
                 self.push_expr(lhs);
                 self.push_expr(rhs);
                 self.push(Instruction::BinaryOperator(*op));
             }
-            Expr::TernaryOp { lhs, mhs, rhs, op } => {
+            ExprKind::TernaryOp { lhs, mhs, rhs, op } => {
                 self.push_expr(lhs);
                 self.push_expr(mhs);
                 self.push_expr(rhs);
@@ -157,15 +617,81 @@ impl Compiler {
         }
     }
 
+    /// `&&` and `||` skip `rhs` when `lhs` decides result, result is bool like `&` and `|`
+    fn push_short_circuit(&mut self, lhs: &Expr, rhs: &Expr, op: BinaryOperator) {
+        let mut to_false = ArrayVec::<[_; 2]>::new();
+        let mut to_end = ArrayVec::<[_; 2]>::new();
+
+        self.push_expr(lhs);
+        let lhs_mark = self.mark_pos();
+        if op == BinaryOperator::LogicalOr {
+            self.push(Instruction::LoadBool(true));
+            to_end.push(self.mark_pos());
+            self.out[lhs_mark as usize].inst = Instruction::GotoIfNot(self.next_pos());
+        } else {
+            to_false.push(lhs_mark);
+        }
+
+        self.push_expr(rhs);
+        to_false.push(self.mark_pos());
+        self.push(Instruction::LoadBool(true));
+        to_end.push(self.mark_pos());
+
+        let false_pos = self.next_pos();
+        self.push(Instruction::LoadBool(false));
+
+        for mark in to_false {
+            self.out[mark as usize].inst = Instruction::GotoIfNot(false_pos);
+        }
+        for mark in to_end {
+            self.out[mark as usize].inst = Instruction::Goto(self.next_pos());
+        }
+    }
+
+    fn push_call_args(&mut self, name: Symbol, args: &[Expr]) {
+        if let Some(name) = self.signatures.and_then(|(_, i)| i.resolve(name)) {
+            self.check_call(name, args);
+        }
+        for arg in args.iter() {
+            self.push_expr(arg);
+        }
+    }
+
     fn compile_body(&mut self, body: &[Stmt]) {
         for stmt in body.iter() {
             self.compile_stmt(stmt);
         }
     }
 
-    pub fn compile(mut self, program: &[Stmt]) -> Vec<InstructionWithDebug> {
+    pub fn compile_program(mut self, program: &[Stmt]) -> Compiled {
         self.compile_body(program);
-        self.out
+        self.finish()
+    }
+
+    /// `compile_program` for statements of several files,
+    /// `regions` are `(index of first statement, file id)` sorted by statement
+    pub fn compile_files(mut self, program: &[Stmt], regions: &[(usize, u32)]) -> Compiled {
+        for (idx, &(start, file)) in regions.iter().enumerate() {
+            let end = regions.get(idx + 1).map_or(program.len(), |next| next.0);
+            self.files.push((self.next_pos(), file));
+            self.compile_body(&program[start..end]);
+        }
+        self.finish()
+    }
+
+    fn finish(mut self) -> Compiled {
+        // statement without instruction at end of program
+        if self.statements.last() == Some(&self.next_pos()) {
+            self.statements.pop();
+        }
+
+        Compiled {
+            instructions: self.out,
+            statements: self.statements,
+            labels: self.labels,
+            warnings: self.warnings,
+            files: self.files,
+        }
     }
 }
 
@@ -173,7 +699,7 @@ impl Compiler {
 mod tests {
     use super::Compiler;
     use crate::interner::Interner;
-    use crate::operator::TernaryOperator;
+    use crate::operator::{TernaryOperator, UnaryOperator};
     use crate::parser::parse;
     use crate::{instruction::Instruction, operator::BinaryOperator};
     use pretty_assertions::assert_eq;
@@ -181,7 +707,8 @@ mod tests {
     fn test_impl(source: &str, interner: &mut Interner, insts: &[Instruction]) {
         let ast = parse(source, interner).unwrap();
         let compiled = Compiler::new()
-            .compile(&ast)
+            .compile_program(&ast)
+            .instructions
             .into_iter()
             .map(|i| i.inst)
             .collect::<Vec<_>>();
@@ -204,6 +731,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn concat() {
+        let mut i = Interner::new();
+        let a = i.get_or_intern_static("a");
+        let b = i.get_or_intern_static("b");
+        test_impl(
+            "'a' + 1 + 'b' + (2 + 3); 'a' + 'b';",
+            &mut i,
+            &[
+                Instruction::LoadStr(a),
+                Instruction::LoadInt(1),
+                Instruction::LoadStr(b),
+                Instruction::LoadInt(2),
+                Instruction::LoadInt(3),
+                Instruction::BinaryOperator(BinaryOperator::Add),
+                Instruction::ConcatN(4),
+                Instruction::Pop,
+                Instruction::LoadStr(a),
+                Instruction::LoadStr(b),
+                Instruction::BinaryOperator(BinaryOperator::Add),
+                Instruction::Pop,
+            ],
+        );
+    }
+
     #[test]
     fn and_or() {
         let mut i = Interner::new();
@@ -221,6 +773,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn short_circuit() {
+        let mut i = Interner::new();
+        test_impl(
+            "1 && 2 || 3;",
+            &mut i,
+            &[
+                Instruction::LoadInt(1),
+                Instruction::GotoIfNot(6),
+                Instruction::LoadInt(2),
+                Instruction::GotoIfNot(6),
+                Instruction::LoadBool(true),
+                Instruction::Goto(7),
+                Instruction::LoadBool(false),
+                Instruction::GotoIfNot(10),
+                Instruction::LoadBool(true),
+                Instruction::Goto(15),
+                Instruction::LoadInt(3),
+                Instruction::GotoIfNot(14),
+                Instruction::LoadBool(true),
+                Instruction::Goto(15),
+                Instruction::LoadBool(false),
+                Instruction::Pop,
+            ],
+        );
+    }
+
     #[test]
     fn print() {
         let mut i = Interner::new();
@@ -274,6 +853,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn jump_table() {
+        let mut i = Interner::new();
+        let var = i.get_or_intern_static("선택");
+        test_impl(
+            "만약 $선택 == 1 { 1; } 혹은 $선택 == 2 { 2; } 혹은 4 == $선택 { 4; } 혹은 $선택 == 3 { 3; } 그외 { 0; }",
+            &mut i,
+            &[
+                Instruction::LoadVar(var),
+                Instruction::JumpTable { min: 1, len: 4 },
+                Instruction::Goto(7),
+                Instruction::Goto(10),
+                Instruction::Goto(16),
+                Instruction::Goto(13),
+                Instruction::Goto(19),
+                Instruction::LoadInt(1),
+                Instruction::Pop,
+                Instruction::Goto(21),
+                Instruction::LoadInt(2),
+                Instruction::Pop,
+                Instruction::Goto(21),
+                Instruction::LoadInt(4),
+                Instruction::Pop,
+                Instruction::Goto(21),
+                Instruction::LoadInt(3),
+                Instruction::Pop,
+                Instruction::Goto(21),
+                Instruction::LoadInt(0),
+                Instruction::Pop,
+            ],
+        );
+    }
+
+    #[test]
+    fn select_chain() {
+        let mut i = Interner::new();
+        let var = i.get_or_intern_static("값");
+        let str = i.get_or_intern_static("가");
+        test_impl(
+            "선택 $값 { 1, '가' { 1; } 그외 { 0; } }",
+            &mut i,
+            &[
+                Instruction::LoadVar(var),
+                Instruction::Duplicate,
+                Instruction::LoadInt(1),
+                Instruction::BinaryOperator(BinaryOperator::Equal),
+                Instruction::UnaryOperator(UnaryOperator::Not),
+                Instruction::GotoIfNot(10),
+                Instruction::Duplicate,
+                Instruction::LoadStr(str),
+                Instruction::BinaryOperator(BinaryOperator::Equal),
+                Instruction::GotoIfNot(14),
+                Instruction::Pop,
+                Instruction::LoadInt(1),
+                Instruction::Pop,
+                Instruction::Goto(17),
+                Instruction::Pop,
+                Instruction::LoadInt(0),
+                Instruction::Pop,
+            ],
+        );
+    }
+
+    #[test]
+    fn select_jump_table() {
+        let mut i = Interner::new();
+        let var = i.get_or_intern_static("값");
+        test_impl(
+            "선택 $값 { 1, 2 { 1; } 4 { 4; } 3 { 3; } }",
+            &mut i,
+            &[
+                Instruction::LoadVar(var),
+                Instruction::JumpTable { min: 1, len: 4 },
+                Instruction::Goto(7),
+                Instruction::Goto(7),
+                Instruction::Goto(13),
+                Instruction::Goto(10),
+                Instruction::Goto(16),
+                Instruction::LoadInt(1),
+                Instruction::Pop,
+                Instruction::Goto(16),
+                Instruction::LoadInt(4),
+                Instruction::Pop,
+                Instruction::Goto(16),
+                Instruction::LoadInt(3),
+                Instruction::Pop,
+                Instruction::Goto(16),
+            ],
+        );
+    }
+
     #[test]
     fn while_simple() {
         let mut i = Interner::new();
@@ -294,6 +964,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn for_each() {
+        let mut i = Interner::new();
+        let var = i.get_or_intern_static("글자");
+        let str = i.get_or_intern_static("가나");
+        test_impl(
+            "각각 $글자 '가나' { $글자; } 3;",
+            &mut i,
+            &[
+                Instruction::LoadStr(str),
+                Instruction::IterStart,
+                Instruction::IterNext(7),
+                Instruction::StoreVar(var),
+                Instruction::LoadVar(var),
+                Instruction::Pop,
+                Instruction::Goto(2),
+                Instruction::LoadInt(3),
+                Instruction::Pop,
+            ],
+        );
+    }
+
+    #[test]
+    fn list() {
+        let mut i = Interner::new();
+        let var = i.get_or_intern_static("목록");
+        test_impl(
+            "$목록 = [1, [2]]; $목록[1][0] = $목록[0];",
+            &mut i,
+            &[
+                Instruction::LoadInt(1),
+                Instruction::LoadInt(2),
+                Instruction::BuildList(1),
+                Instruction::BuildList(2),
+                Instruction::StoreVar(var),
+                Instruction::LoadInt(1),
+                Instruction::LoadInt(0),
+                Instruction::LoadVar(var),
+                Instruction::LoadInt(0),
+                Instruction::LoadIndex,
+                Instruction::StoreIndex { var, depth: 2 },
+            ],
+        );
+    }
+
     #[test]
     fn conditional() {
         let mut i = Interner::new();
@@ -330,6 +1045,105 @@ mod tests {
         );
     }
 
+    #[test]
+    fn signature_warnings() {
+        use crate::error::CompileWarning;
+        use crate::location::{Location, Span};
+        use crate::signature::{ParamType, Signature, Signatures};
+
+        let mut signatures = Signatures::new();
+        signatures.insert("함수", Signature::new([ParamType::Int]));
+
+        let mut i = Interner::new();
+        let ast = parse(
+            "함수(1);\n함수();\n함수('1');\n함수(1 + '2');\n험수(1);\n소리();\n함수(1 < 2);",
+            &mut i,
+        )
+        .unwrap();
+        let warnings = Compiler::with_signatures(&signatures, &i)
+            .compile_program(&ast)
+            .warnings;
+
+        assert_eq!(
+            warnings,
+            [
+                CompileWarning::ArgumentCount("함수".into(), 1, 0, Location::new(2)),
+                CompileWarning::ArgumentType(
+                    "함수".into(),
+                    1,
+                    "int",
+                    Location::new(3),
+                    Span::new(28, 31)
+                ),
+                CompileWarning::ArgumentType(
+                    "함수".into(),
+                    1,
+                    "int",
+                    Location::new(4),
+                    Span::new(41, 48)
+                ),
+                CompileWarning::UnknownBuiltin(
+                    "험수".into(),
+                    Some("함수".into()),
+                    Location::new(5)
+                ),
+                CompileWarning::UnknownBuiltin("소리".into(), None, Location::new(6)),
+                CompileWarning::ArgumentType(
+                    "함수".into(),
+                    1,
+                    "int",
+                    Location::new(7),
+                    Span::new(79, 84)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn spawn() {
+        let mut i = Interner::new();
+        let f = i.get_or_intern_static("소리");
+        let bgm = i.get_or_intern_static("bgm");
+        test_impl(
+            "시작 소리('bgm'); 기다리기;",
+            &mut i,
+            &[
+                Instruction::LoadStr(bgm),
+                Instruction::SpawnBuiltin(f),
+                Instruction::Join,
+            ],
+        );
+    }
+
+    #[test]
+    fn timer() {
+        let mut i = Interner::new();
+        test_impl(
+            "타이머 1000 { 1; } 2;",
+            &mut i,
+            &[
+                Instruction::LoadInt(1000),
+                Instruction::Timer(5),
+                Instruction::LoadInt(1),
+                Instruction::Pop,
+                Instruction::EndTimer,
+                Instruction::LoadInt(2),
+                Instruction::Pop,
+            ],
+        );
+    }
+
+    #[test]
+    fn declare() {
+        let mut i = Interner::new();
+        let a = i.get_or_intern_static("1");
+        test_impl(
+            "변수 $1; 변수 $1 = 2;",
+            &mut i,
+            &[Instruction::LoadInt(2), Instruction::StoreVar(a)],
+        );
+    }
+
     #[test]
     fn exit() {
         let mut i = Interner::new();
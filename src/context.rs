@@ -1,36 +1,875 @@
-use crate::builtin::Builtin;
-use crate::error::{RuntimeError, RuntimeResult};
+use crate::builtin::{Builtin, Handle, LocalBuiltin};
+use crate::compat::HostCapabilities;
+use crate::error::{CompatError, RuntimeError, RuntimeResult};
+use crate::history::{BranchLog, Choice, Journal};
 use crate::instruction::Instruction;
 use crate::instruction::InstructionWithDebug;
+use crate::intercept::{Action, Intercepted, Interceptor};
 use crate::interner::Symbol;
 use crate::location::Location;
 use crate::operator::{BinaryOperator, TernaryOperator};
 use crate::program::Program;
+use crate::sandbox::Sandbox;
+use crate::signature::{ParamType, Signatures};
+use crate::snapshot::{Iteration, PendingTimer, RuntimeState, Snapshot};
+use crate::suggest::closest;
+use crate::trace::{TraceEvent, TraceWriter, Tracer};
+#[cfg(feature = "typewriter")]
+use crate::typewriter::Pacer;
 use crate::value::{Value, ValueConvertError};
 use ahash::AHashMap;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
 use std::convert::{TryFrom, TryInto};
 use std::fmt::Write;
+use std::mem::size_of;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Arc, Mutex};
+use string_interner::symbol::Symbol as _;
 
 static_assertions::assert_impl_all!(Context: Send, Sync);
 
+/// How `@!` waits are delivered to `Builtin`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WaitPolicy {
+    /// Call `Builtin::wait`
+    Normal,
+    /// Call `Builtin::auto_wait`
+    Auto,
+    /// Don't wait at all
+    Skip,
+}
+
+/// Event posted from script for host
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Message {
+    pub name: String,
+    pub value: Value,
+    pub location: Location,
+}
+
+/// Bytes used by `Context`, see `Context::memory_usage`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MemoryUsage {
+    /// Value stack and running `각각` loops
+    pub stack: usize,
+    /// Variables including `$_` temporaries
+    pub variables: usize,
+    /// Branch log, rewind journal, replayed choices and queued messages
+    pub logs: usize,
+}
+
+impl MemoryUsage {
+    pub fn total(&self) -> usize {
+        self.stack + self.variables + self.logs
+    }
+}
+
+/// Bytes of hash table with values in it, control bytes of table are left out
+fn table_usage(table: &AHashMap<Symbol, Value>) -> usize {
+    table.capacity() * size_of::<(Symbol, Value)>()
+        + table.values().map(Value::heap_size).sum::<usize>()
+}
+
+/// Condition of watchpoint on variable
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Watch {
+    /// Stored value differs from previous value
+    Changed,
+    /// Stored value equals to given value
+    Equals(Value),
+}
+
+impl Watch {
+    fn is_hit(&self, prev: Option<&Value>, value: &Value) -> bool {
+        match self {
+            Watch::Changed => prev != Some(value),
+            Watch::Equals(expected) => expected == value,
+        }
+    }
+}
+
+/// Shared handle for changing `WaitPolicy` while script is running
+#[derive(Clone, Debug)]
+pub struct WaitControl(Arc<AtomicU8>);
+
+impl WaitControl {
+    fn new() -> Self {
+        Self(Arc::new(AtomicU8::new(WaitPolicy::Normal as u8)))
+    }
+
+    pub fn policy(&self) -> WaitPolicy {
+        match self.0.load(Ordering::Relaxed) {
+            1 => WaitPolicy::Auto,
+            2 => WaitPolicy::Skip,
+            _ => WaitPolicy::Normal,
+        }
+    }
+
+    pub fn set_policy(&self, policy: WaitPolicy) {
+        self.0.store(policy as u8, Ordering::Relaxed);
+    }
+}
+
+/// Variables by name, cloning it only increases reference count
+pub type VariablesSnapshot = Arc<BTreeMap<String, Value>>;
+
+/// Shared handle for reading variables while script is running
+///
+/// Updated when run is suspended at wait or breakpoint, debugger UIs can read it from other task
+#[derive(Clone, Debug, Default)]
+pub struct VariablesView(Arc<Mutex<VariablesSnapshot>>);
+
+impl VariablesView {
+    pub fn snapshot(&self) -> VariablesSnapshot {
+        self.0.lock().unwrap().clone()
+    }
+}
+
 /// Script Context type
 ///
 /// you can run `Program` with `Builtin`
 pub struct Context<'c> {
     program: &'c Program,
     stack: Vec<Value>,
+    /// Call `Context::mark_variables_changed` after changing it directly
     pub variables: AHashMap<Symbol, Value>,
+    /// Last published variables, rebuilt only when `variables_changed`
+    variables_view: VariablesView,
+    variables_changed: bool,
+    /// `$_이름` variables, cleared after every print statement and never saved
+    temporaries: AHashMap<Symbol, Value>,
+    /// Whether symbol is name of `$_` temporary, indexed by symbol
+    temporary_names: Vec<bool>,
     cursor: usize,
+    signatures: Option<&'c Signatures>,
+    /// Check values returned by builtins with `signatures`, on by default in debug build
+    check_returns: bool,
+    translator: Option<Box<Translator<'c>>>,
+    wait_control: WaitControl,
+    /// RNG, seen lines and timers, saved in `Snapshot`
+    state: RuntimeState,
+    branch_log: BranchLog,
+    replay: VecDeque<Choice>,
+    clock: Option<Box<Clock<'c>>>,
+    /// Running `각각` loops, innermost is last
+    iterations: Vec<Iteration>,
+    journal: Option<Journal>,
+    rewound: bool,
+    messages: Vec<Message>,
+    /// Host settings read by `설정`, separate from script variables and never saved
+    config: BTreeMap<String, Value>,
+    fuel: Option<u64>,
+    stack_limit: Option<usize>,
+    /// Read variable which is never assigned as `없음` instead of error
+    missing_as_null: bool,
+    /// `Program::variable_types` by symbol, checked on every assignment
+    variable_types: AHashMap<Symbol, ParamType>,
+    sandbox: Option<Sandbox>,
+    tracer: Option<Box<dyn Tracer + 'c>>,
+    interceptor: Option<Box<dyn Interceptor + 'c>>,
+    /// File id and line of last `TraceEvent::Line`
+    traced_line: Option<(u32, Location)>,
+    tracing: bool,
+    /// File id and line, see `Program::file`
+    breakpoints: BTreeSet<(u32, usize)>,
+    watchpoints: AHashMap<Symbol, Watch>,
+    watch_hit: Option<Symbol>,
+    /// Name and result of `Builtin::resolve` of builtin, indexed by symbol
+    builtin_handles: Vec<Option<(&'c str, Option<Handle>)>>,
+    #[cfg(feature = "typewriter")]
+    pacer: Option<Box<dyn Pacer + 'c>>,
+}
+
+/// Builder for `Context` with execution options
+pub struct ContextBuilder<'c> {
+    ctx: Context<'c>,
+}
+
+impl<'c> ContextBuilder<'c> {
+    pub fn new(program: &'c Program) -> Self {
+        Self {
+            ctx: Context::new(program),
+        }
+    }
+
+    /// Limit count of instructions which can be run
+    pub fn fuel(mut self, fuel: u64) -> Self {
+        self.ctx.fuel = Some(fuel);
+        self
+    }
+
+    /// Limit length of value stack
+    pub fn stack_limit(mut self, limit: usize) -> Self {
+        self.ctx.stack_limit = Some(limit);
+        self
+    }
+
+    /// Variable which is never assigned is read as `없음` instead of undefined variable error
+    pub fn missing_as_null(mut self) -> Self {
+        self.ctx.missing_as_null = true;
+        self
+    }
+
+    /// Only builtins allowed by `sandbox` can be called
+    pub fn sandbox(mut self, sandbox: Sandbox) -> Self {
+        self.ctx.sandbox = Some(sandbox);
+        self
+    }
+
+    /// Seed of `Context::random`, same seed gives same sequence
+    pub fn seed(mut self, seed: u64) -> Self {
+        // xorshift can't escape from zero
+        self.ctx.state.rng = seed.max(1);
+        self
+    }
+
+    pub fn tracer(mut self, tracer: impl Tracer + 'c) -> Self {
+        self.ctx.tracer = Some(Box::new(tracer));
+        self
+    }
+
+    pub fn interceptor(mut self, interceptor: impl Interceptor + 'c) -> Self {
+        self.ctx.set_interceptor(interceptor);
+        self
+    }
+
+    /// Initial value of variable, ignored when program doesn't use it
+    pub fn variable(mut self, name: &str, value: impl Into<Value>) -> Self {
+        if let Some(name) = self.ctx.program.symbol(name) {
+            self.ctx.variables.insert(name, value.into());
+            self.ctx.variables_changed = true;
+        }
+        self
+    }
+
+    /// Host setting like platform, language or difficulty
+    pub fn config(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.ctx.set_config(key, value);
+        self
+    }
+
+    pub fn signatures(mut self, signatures: &'c Signatures) -> Self {
+        self.ctx.set_signatures(signatures);
+        self
+    }
+
+    /// Reject builtin return values which don't match `Signature::ret`
+    ///
+    /// Enabled by default only in debug build
+    pub fn check_returns(mut self, check: bool) -> Self {
+        self.ctx.check_returns = check;
+        self
+    }
+
+    pub fn translator(
+        mut self,
+        translator: impl Fn(&str) -> Option<String> + Send + Sync + 'c,
+    ) -> Self {
+        self.ctx.set_translator(translator);
+        self
+    }
+
+    pub fn clock(mut self, clock: impl Fn() -> u64 + Send + Sync + 'c) -> Self {
+        self.ctx.set_clock(clock);
+        self
+    }
+
+    pub fn journal(mut self, capacity: usize) -> Self {
+        self.ctx.enable_journal(capacity);
+        self
+    }
+
+    pub fn wait_policy(mut self, policy: WaitPolicy) -> Self {
+        self.ctx.set_wait_policy(policy);
+        self
+    }
+
+    #[cfg(feature = "typewriter")]
+    pub fn pacer(mut self, pacer: impl Pacer + 'c) -> Self {
+        self.ctx.set_pacer(pacer);
+        self
+    }
+
+    pub fn build(self) -> Context<'c> {
+        self.ctx
+    }
+
+    /// Build context which starts from `장면 label;`, see [`Program::entry_points`]
+    pub fn build_at(mut self, label: &str) -> RuntimeResult<Context<'c>> {
+        self.ctx.cursor = self
+            .ctx
+            .program
+            .entry_point(label)
+            .ok_or(RuntimeError::ExecutionError("장면을 찾을수 없습니다", 0))?;
+        Ok(self.ctx)
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn random_seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(1, |time| time.as_nanos() as u64 | 1)
+}
+
+/// `SystemTime::now` panics on `wasm32-unknown-unknown`, use `ContextBuilder::seed` there
+#[cfg(target_arch = "wasm32")]
+fn random_seed() -> u64 {
+    0x853C_49E6_748F_EA9B
 }
 
+type Translator<'c> = dyn Fn(&str) -> Option<String> + Send + Sync + 'c;
+type Clock<'c> = dyn Fn() -> u64 + Send + Sync + 'c;
+
 impl<'c> Context<'c> {
     pub fn new(program: &'c Program) -> Self {
         Self {
             program,
             stack: Vec::with_capacity(50),
             variables: AHashMap::new(),
+            variables_view: VariablesView::default(),
+            variables_changed: false,
+            temporaries: AHashMap::new(),
+            temporary_names: program.temporaries(),
             cursor: 0,
+            signatures: None,
+            check_returns: cfg!(debug_assertions),
+            translator: None,
+            wait_control: WaitControl::new(),
+            state: RuntimeState {
+                rng: random_seed(),
+                ..RuntimeState::default()
+            },
+            branch_log: BranchLog::new(),
+            replay: VecDeque::new(),
+            clock: None,
+            iterations: Vec::new(),
+            journal: None,
+            rewound: false,
+            messages: Vec::new(),
+            config: BTreeMap::new(),
+            fuel: None,
+            stack_limit: None,
+            missing_as_null: false,
+            variable_types: program
+                .variable_types()
+                .filter_map(|(name, ty)| Some((program.symbol(name)?, ty)))
+                .collect(),
+            sandbox: None,
+            tracer: None,
+            interceptor: None,
+            traced_line: None,
+            tracing: true,
+            breakpoints: BTreeSet::new(),
+            watchpoints: AHashMap::new(),
+            watch_hit: None,
+            builtin_handles: Vec::new(),
+            #[cfg(feature = "typewriter")]
+            pacer: None,
+        }
+    }
+
+    /// Continue from `snapshot` with `program`
+    ///
+    /// When program is extended by `Program::extend_from_source`,
+    /// snapshot taken at end of previous program continues with appended statements
+    pub fn resume(program: &'c Program, snapshot: Snapshot) -> Self {
+        let mut ctx = Self::new(program);
+        ctx.restore(snapshot);
+        ctx
+    }
+
+    /// Start from `장면 label;` instead of first statement
+    ///
+    /// `장면` is only allowed at top level so nothing of skipped code is needed
+    pub fn new_at(program: &'c Program, label: &str) -> RuntimeResult<Self> {
+        Self::builder(program).build_at(label)
+    }
+
+    pub fn builder(program: &'c Program) -> ContextBuilder<'c> {
+        ContextBuilder::new(program)
+    }
+
+    /// Check `Program::requires` before running, error lists everything `host` is missing
+    pub fn check_compat(&self, host: &HostCapabilities) -> Result<(), CompatError> {
+        host.check(&self.program.requires())
+    }
+
+    /// Next pseudo random number, seeded by `ContextBuilder::seed`
+    pub fn random(&mut self) -> u32 {
+        // xorshift64*
+        let rng = &mut self.state.rng;
+        *rng ^= *rng >> 12;
+        *rng ^= *rng << 25;
+        *rng ^= *rng >> 27;
+        (rng.wrapping_mul(0x2545_F491_4F6C_DD1D) >> 32) as u32
+    }
+
+    /// Mark print statement at `location` as read
+    #[inline]
+    pub fn mark_seen(&mut self, location: Location) {
+        self.state.seen.insert(location);
+    }
+
+    /// Check print statement at `location` was already executed
+    #[inline]
+    pub fn is_seen(&self, location: Location) -> bool {
+        self.state.seen.contains(&location)
+    }
+
+    /// Record choice made at current location
+    pub fn record_choice(&mut self, index: u32) {
+        let location = self.current_instruction_location();
+        self.branch_log.push(Choice { location, index });
+    }
+
+    #[inline]
+    pub fn branch_log(&self) -> &BranchLog {
+        &self.branch_log
+    }
+
+    /// Replay choices of `log`, choice builtins should ask `replayed_choice` first
+    pub fn replay(&mut self, log: BranchLog) {
+        self.replay = log.choices().iter().copied().collect();
+    }
+
+    /// Take next replayed choice and record it
+    ///
+    /// Returns `None` when replay is over or script went different path
+    pub fn replayed_choice(&mut self) -> Option<u32> {
+        let location = self.current_instruction_location();
+
+        match self.replay.pop_front() {
+            Some(choice) if choice.location == location => {
+                self.branch_log.push(choice);
+                Some(choice.index)
+            }
+            _ => {
+                self.replay.clear();
+                None
+            }
+        }
+    }
+
+    /// Queue event for host at current location
+    pub fn post_message(&mut self, name: impl Into<String>, value: Value) {
+        let location = self.current_instruction_location();
+        self.messages.push(Message {
+            name: name.into(),
+            value,
+            location,
+        });
+    }
+
+    /// Drain every queued message
+    pub fn take_messages(&mut self) -> Vec<Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Bytes used by values and logs of script, string contents are included
+    ///
+    /// Hosts can check it between statements to enforce memory budget of scripts
+    pub fn memory_usage(&self) -> MemoryUsage {
+        let stack = self.stack.capacity() * size_of::<Value>()
+            + self.stack.iter().map(Value::heap_size).sum::<usize>()
+            + self.iterations.capacity() * size_of::<Iteration>()
+            + self
+                .iterations
+                .iter()
+                .map(|iteration| iteration.value.heap_size())
+                .sum::<usize>();
+        let variables = table_usage(&self.variables) + table_usage(&self.temporaries);
+        let logs = self.branch_log.memory_usage()
+            + self.journal.as_ref().map_or(0, Journal::memory_usage)
+            + self.replay.capacity() * size_of::<Choice>()
+            + self.messages.capacity() * size_of::<Message>()
+            + self
+                .messages
+                .iter()
+                .map(|message| message.name.capacity() + message.value.heap_size())
+                .sum::<usize>();
+
+        MemoryUsage {
+            stack,
+            variables,
+            logs,
+        }
+    }
+
+    pub fn set_config(&mut self, key: impl Into<String>, value: impl Into<Value>) {
+        self.config.insert(key.into(), value.into());
+    }
+
+    #[inline]
+    pub fn config(&self, key: &str) -> Option<&Value> {
+        self.config.get(key)
+    }
+
+    /// Set clock returning script time in milliseconds
+    ///
+    /// `타이머` blocks are run at wait points after their delay is passed, they never run without clock
+    pub fn set_clock(&mut self, clock: impl Fn() -> u64 + Send + Sync + 'c) {
+        self.clock = Some(Box::new(clock));
+    }
+
+    /// Time of clock, `0` without clock
+    fn now(&self) -> u64 {
+        self.clock.as_ref().map_or(0, |clock| clock())
+    }
+
+    /// Jump to timer body whose deadline is passed
+    fn fire_timer(&mut self, ret: usize) -> bool {
+        let now = match self.clock.as_ref() {
+            Some(clock) => clock(),
+            None => return false,
+        };
+
+        match self.state.timers.iter().position(|t| t.deadline <= now) {
+            Some(idx) => {
+                let timer = self.state.timers.remove(idx);
+                self.state.timer_returns.push(ret);
+                self.cursor = timer.start;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record changes for rewinding up to `capacity` print statements
+    ///
+    /// Changes made by host through `variables` are not recorded
+    pub fn enable_journal(&mut self, capacity: usize) {
+        self.journal = Some(Journal::new(capacity));
+    }
+
+    /// Roll back to the start of `count`th print statement from last, it will be printed again
+    ///
+    /// Returns `false` when nothing is recorded
+    pub fn rewind(&mut self, count: usize) -> bool {
+        self.variables_changed = true;
+        let variables = &mut self.variables;
+        let rewound = match self.journal.as_mut() {
+            Some(journal) => journal.rewind(count, |var, prev| match prev {
+                Some(prev) => {
+                    variables.insert(var, prev);
+                }
+                None => {
+                    variables.remove(&var);
+                }
+            }),
+            None => None,
+        };
+
+        match rewound {
+            Some(rewound) => {
+                self.cursor = rewound.cursor;
+                self.stack = rewound.stack;
+                self.iterations = rewound.iterations;
+                self.branch_log.truncate(rewound.branch_len);
+                self.replay.clear();
+                self.rewound = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Location of instruction currently running
+    #[inline]
+    pub fn location(&self) -> Location {
+        self.current_instruction_location()
+    }
+
+    /// `true` when every instruction is executed
+    #[inline]
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.program.instructions().len()
+    }
+
+    /// Values pushed by current statement
+    #[inline]
+    pub fn stack(&self) -> &[Value] {
+        &self.stack
+    }
+
+    /// File of instruction currently running, see [`Program::file`]
+    ///
+    /// Lines of `location` and runtime errors are lines in this file
+    #[inline]
+    pub fn file(&self) -> Option<&'c str> {
+        self.program.file(self.cursor)
+    }
+
+    /// Stop `run_to_breakpoint` when `line` of entry file starts
+    pub fn set_breakpoint(&mut self, line: usize) {
+        self.breakpoints.insert((0, line));
+    }
+
+    pub fn remove_breakpoint(&mut self, line: usize) {
+        self.breakpoints.remove(&(0, line));
+    }
+
+    /// Breakpoints of entry file
+    pub fn breakpoints(&self) -> impl Iterator<Item = usize> + '_ {
+        self.breakpoints
+            .iter()
+            .filter(|(file, _)| *file == 0)
+            .map(|(_, line)| *line)
+    }
+
+    /// Stop `run_to_breakpoint` when `line` of included `file` starts,
+    /// returns `false` when program doesn't have `file`
+    pub fn set_file_breakpoint(&mut self, file: &str, line: usize) -> bool {
+        match self.program.files().iter().position(|path| path == file) {
+            Some(id) => {
+                self.breakpoints.insert((id as u32, line));
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove_file_breakpoint(&mut self, file: &str, line: usize) {
+        if let Some(id) = self.program.files().iter().position(|path| path == file) {
+            self.breakpoints.remove(&(id as u32, line));
+        }
+    }
+
+    /// Stop `run_to_breakpoint` after `$name` is stored and `watch` is hit
+    ///
+    /// Returns `false` when program never uses `$name`
+    pub fn set_watchpoint(&mut self, name: &str, watch: Watch) -> bool {
+        match self.program.symbol(name) {
+            Some(symbol) => {
+                self.watchpoints.insert(symbol, watch);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn remove_watchpoint(&mut self, name: &str) {
+        if let Some(symbol) = self.program.symbol(name) {
+            self.watchpoints.remove(&symbol);
+        }
+    }
+
+    pub fn watchpoints(&self) -> impl Iterator<Item = (&str, &Watch)> + '_ {
+        self.watchpoints
+            .iter()
+            .map(move |(name, watch)| (self.program.resolve(*name).unwrap(), watch))
+    }
+
+    /// Variable whose watchpoint stopped last `run_to_breakpoint`
+    pub fn watch_hit(&self) -> Option<&str> {
+        self.watch_hit.and_then(|name| self.program.resolve(name))
+    }
+
+    fn check_watch(&mut self, name: Symbol, prev: Option<&Value>, value: &Value) {
+        if let Some(watch) = self.watchpoints.get(&name) {
+            if watch.is_hit(prev, value) {
+                self.watch_hit = Some(name);
+            }
+        }
+    }
+
+    /// Export state of context
+    ///
+    /// Snapshot taken in `Builtin::run` will call builtin again after restored,
+    /// pending timers keep time left until their deadline
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot {
+            cursor: self.cursor,
+            stack: self.stack.clone(),
+            variables: self
+                .variables
+                .iter()
+                .filter_map(|(name, value)| {
+                    Some((self.program.resolve(*name)?.into(), value.clone()))
+                })
+                .collect(),
+            branch_log: self.branch_log.clone(),
+            iterations: self.iterations.clone(),
+            runtime: self.state.export(self.now()),
+        }
+    }
+
+    /// Restore state from `snapshot`, variables which program doesn't know are dropped
+    pub fn restore(&mut self, snapshot: Snapshot) {
+        let program = self.program;
+        self.cursor = snapshot.cursor;
+        self.stack = snapshot.stack;
+        self.variables = snapshot
+            .variables
+            .into_iter()
+            .filter_map(|(name, value)| Some((program.symbol(&name)?, value)))
+            .collect();
+        self.variables_changed = true;
+        self.branch_log = snapshot.branch_log;
+        self.iterations = snapshot.iterations;
+
+        let rng = self.state.rng;
+        self.state = snapshot.runtime.import(self.now());
+        if self.state.rng == 0 {
+            self.state.rng = rng;
+        }
+    }
+
+    /// Set variable by name, returns `false` when program never uses `$name`
+    pub fn set_variable(&mut self, name: &str, value: impl Into<Value>) -> bool {
+        match self.program.symbol(name) {
+            Some(symbol) => {
+                self.variables.insert(symbol, value.into());
+                self.variables_changed = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn mark_variables_changed(&mut self) {
+        self.variables_changed = true;
+    }
+
+    /// Variables by name, map is shared until variables are changed
+    pub fn variables_snapshot(&mut self) -> VariablesSnapshot {
+        self.publish_variables();
+        self.variables_view.snapshot()
+    }
+
+    /// Handle which sees variables at every wait and breakpoint
+    pub fn variables_view(&self) -> VariablesView {
+        self.variables_view.clone()
+    }
+
+    fn publish_variables(&mut self) {
+        if !self.variables_changed {
+            return;
+        }
+
+        let program = self.program;
+        let variables = self
+            .variables
+            .iter()
+            .filter_map(|(name, value)| Some((program.resolve(*name)?.into(), value.clone())))
+            .collect();
+        *self.variables_view.0.lock().unwrap() = Arc::new(variables);
+        self.variables_changed = false;
+    }
+
+    /// Handle for skip and auto mode which can be used while running
+    pub fn wait_control(&self) -> WaitControl {
+        self.wait_control.clone()
+    }
+
+    #[inline]
+    pub fn set_wait_policy(&mut self, policy: WaitPolicy) {
+        self.wait_control.set_policy(policy);
+    }
+
+    /// Print strings chunk by chunk with delay of `pacer`
+    #[cfg(feature = "typewriter")]
+    pub fn set_pacer(&mut self, pacer: impl Pacer + 'c) {
+        self.pacer = Some(Box::new(pacer));
+    }
+
+    /// Translate string literals while loading them
+    ///
+    /// Returning `None` from `translator` keeps original text
+    pub fn set_translator(
+        &mut self,
+        translator: impl Fn(&str) -> Option<String> + Send + Sync + 'c,
+    ) {
+        self.translator = Some(Box::new(translator));
+    }
+
+    /// Log lines, builtin calls and prints to `out`, see `TraceWriter`
+    pub fn set_trace_writer(&mut self, out: impl std::io::Write + Send + Sync + 'c) {
+        self.tracer = Some(Box::new(TraceWriter::new(out)));
+    }
+
+    /// Pause or resume reporting to tracer without removing it
+    pub fn set_tracing(&mut self, tracing: bool) {
+        self.tracing = tracing;
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.tracing && self.tracer.is_some()
+    }
+
+    /// Call `interceptor` before every instruction, see `Interceptor`
+    pub fn set_interceptor(&mut self, interceptor: impl Interceptor + 'c) {
+        self.interceptor = Some(Box::new(interceptor));
+    }
+
+    /// Check arguments of builtin calls with `signatures` before running them
+    pub fn set_signatures(&mut self, signatures: &'c Signatures) {
+        self.signatures = Some(signatures);
+    }
+
+    fn check_args(&self, name: &str) -> RuntimeResult<()> {
+        let signature = match self.signatures.and_then(|s| s.get(name)) {
+            Some(signature) => signature,
+            None => return Ok(()),
+        };
+
+        let args = self
+            .stack
+            .len()
+            .checked_sub(signature.arity())
+            .map(|start| &self.stack[start..])
+            .ok_or_else(|| self.make_err("인자가 부족합니다"))?;
+
+        for (param, arg) in signature.params().iter().zip(args) {
+            if !param.accepts(arg) {
+                return Err(RuntimeError::TypeError(
+                    arg.type_name(),
+                    self.current_instruction_location().line,
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn check_return(&self, name: &str, ret: &Value) -> RuntimeResult<()> {
+        if !self.check_returns {
+            return Ok(());
+        }
+
+        match self.signatures.and_then(|s| s.get(name)) {
+            Some(signature) if !signature.ret().accepts(ret) => Err(RuntimeError::ReturnType(
+                name.into(),
+                signature.ret().name(),
+                ret.type_name(),
+                self.current_instruction_location().line,
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check sandbox and arguments, then report call to tracer
+    fn check_call(&mut self, name: &str) -> RuntimeResult<()> {
+        let line = self.current_instruction_location().line;
+
+        if let Some(sandbox) = self.sandbox.as_ref() {
+            if !sandbox.is_allowed(name) {
+                return Err(RuntimeError::Forbidden(name.into(), line));
+            }
+        }
+
+        self.check_args(name)?;
+
+        if let (true, Some(tracer)) = (self.tracing, self.tracer.as_mut()) {
+            // `check_args` made sure stack has enough values
+            let args = match self.signatures.and_then(|s| s.get(name)) {
+                Some(signature) => &self.stack[self.stack.len() - signature.arity()..],
+                None => &self.stack[..],
+            };
+            tracer.trace(TraceEvent::Call(name, args, Location::new(line)));
         }
+
+        Ok(())
     }
 
     pub fn args(&self) -> &[Value] {
@@ -95,15 +934,20 @@ impl<'c> Context<'c> {
             };
         }
 
+        // shifting by 32 or more clears every bit instead of panicking
+        macro_rules! binop_shift {
+            ($op:ident) => {
+                let rhs: u32 = self.pop_into_ret()?;
+                let lhs: u32 = self.pop_into_ret()?;
+                self.push(lhs.$op(rhs).unwrap_or(0));
+            };
+        }
+
         macro_rules! binop_bool {
             ($op:tt) => {
                 let rhs = self.pop_ret()?.into_bool();
                 let lhs = self.pop_ret()?.into_bool();
-                self.push(if lhs $op rhs {
-                    1
-                } else {
-                    0
-                });
+                self.push(lhs $op rhs);
             };
         }
 
@@ -111,37 +955,45 @@ impl<'c> Context<'c> {
             ($op:tt) => {
                 let rhs = self.pop_ret()?;
                 let lhs = self.pop_ret()?;
-                self.push(if lhs $op rhs {
-                    1
-                } else {
-                    0
-                });
+                self.push(lhs $op rhs);
             }
         }
 
-        match op {
-            BinaryOperator::Equal => {
+        macro_rules! binop_ord {
+            ($($ordering:pat)|+) => {
+                let rhs = self.pop_ret()?;
+                let lhs = self.pop_ret()?;
+                let ordering = lhs.compare(&rhs).ok_or_else(|| {
+                    RuntimeError::TypeError(rhs.type_name(), self.current_instruction_location().line)
+                })?;
+                self.push(matches!(ordering, $($ordering)|+));
+            };
+        }
+
+        match op {
+            BinaryOperator::Equal => {
                 binop_raw_bool!(==);
             }
             BinaryOperator::NotEqual => {
                 binop_raw_bool!(!=);
             }
             BinaryOperator::Greater => {
-                binop_raw_bool!(>);
+                binop_ord!(std::cmp::Ordering::Greater);
             }
             BinaryOperator::Less => {
-                binop_raw_bool!(<);
+                binop_ord!(std::cmp::Ordering::Less);
             }
             BinaryOperator::GreaterOrEqual => {
-                binop_raw_bool!(>=);
+                binop_ord!(std::cmp::Ordering::Greater | std::cmp::Ordering::Equal);
             }
             BinaryOperator::LessOrEqual => {
-                binop_raw_bool!(<=);
+                binop_ord!(std::cmp::Ordering::Less | std::cmp::Ordering::Equal);
             }
-            BinaryOperator::And => {
+            // `&&` and `||` are compiled to jumps, only hand-written bytecode gets here
+            BinaryOperator::And | BinaryOperator::LogicalAnd => {
                 binop_bool!(&);
             }
-            BinaryOperator::Or => {
+            BinaryOperator::Or | BinaryOperator::LogicalOr => {
                 binop_bool!(|);
             }
             BinaryOperator::Xor => {
@@ -150,19 +1002,8 @@ impl<'c> Context<'c> {
             BinaryOperator::Add => {
                 let rhs = self.pop_ret()?;
                 let lhs = self.pop_ret()?;
-
-                self.push(match (lhs, rhs) {
-                    (Value::Int(l), Value::Int(r)) => Value::Int(l + r),
-                    (Value::Int(l), Value::Str(r)) => {
-                        let str = format!("{}{}", l, r);
-                        Value::Str(str)
-                    }
-                    (Value::Str(mut l), Value::Int(r)) => {
-                        write!(&mut l, "{}", r).unwrap();
-                        Value::Str(l)
-                    }
-                    (Value::Str(l), Value::Str(r)) => Value::Str(l + &r),
-                });
+                let value = self.add(lhs, rhs)?;
+                self.push(value);
             }
             BinaryOperator::Sub => {
                 binop!(-);
@@ -176,15 +1017,95 @@ impl<'c> Context<'c> {
             BinaryOperator::Rem => {
                 binop!(%);
             }
+            BinaryOperator::Shl => {
+                binop_shift!(checked_shl);
+            }
+            BinaryOperator::Shr => {
+                binop_shift!(checked_shr);
+            }
         }
 
         Ok(())
     }
 
-    pub fn flush_print<B: Builtin>(&mut self, builtin: &mut B) {
-        for v in self.stack.drain(..) {
-            builtin.print(v);
+    /// `+` joins strings and lists, string with other value joins its text
+    fn add(&self, lhs: Value, rhs: Value) -> RuntimeResult<Value> {
+        Ok(match (lhs, rhs) {
+            (Value::Int(l), Value::Int(r)) => Value::Int(l + r),
+            (Value::Str(l), Value::Str(r)) => (*l + r.as_str()).into(),
+            (Value::Str(l), r) => {
+                let mut l = *l;
+                write!(&mut l, "{}", r).unwrap();
+                l.into()
+            }
+            (l, Value::Str(r)) => format!("{}{}", l, r).into(),
+            (Value::List(l), Value::List(r)) => {
+                let mut l = *l;
+                l.extend(*r);
+                l.into()
+            }
+            (_, r) => {
+                return Err(RuntimeError::TypeError(
+                    r.type_name(),
+                    self.current_instruction_location().line,
+                ))
+            }
+        })
+    }
+
+    /// Same result as `+` on `count` values from left, intermediate strings are not created
+    fn concat(&mut self, count: usize) -> RuntimeResult<()> {
+        let start = self
+            .stack
+            .len()
+            .checked_sub(count)
+            .ok_or(self.make_err("인자가 부족합니다"))?;
+        let values = &self.stack[start..];
+
+        if values
+            .iter()
+            .any(|value| !matches!(value, Value::Int(..) | Value::Str(..)))
+        {
+            let mut values = self.stack.split_off(start).into_iter();
+            let first = values.next().ok_or(self.make_err("인자가 부족합니다"))?;
+            let result = values.try_fold(first, |lhs, rhs| self.add(lhs, rhs))?;
+            self.push(result);
+            return Ok(());
         }
+
+        // numbers are added until first string
+        let leading = values
+            .iter()
+            .position(|value| matches!(value, Value::Str(..)))
+            .unwrap_or(values.len());
+        let sum = values[..leading].iter().fold(0, |sum, value| match value {
+            Value::Int(num) => sum + num,
+            _ => unreachable!(),
+        });
+
+        let result = if leading == values.len() {
+            Value::Int(sum)
+        } else {
+            let capacity = values
+                .iter()
+                .map(|value| match value {
+                    Value::Str(str) => str.len(),
+                    _ => 10,
+                })
+                .sum();
+            let mut out = String::with_capacity(capacity);
+            if leading != 0 {
+                write!(out, "{}", sum).unwrap();
+            }
+            for value in &values[leading..] {
+                write!(out, "{}", value).unwrap();
+            }
+            out.into()
+        };
+
+        self.stack.truncate(start);
+        self.push(result);
+        Ok(())
     }
 
     pub fn pop_ret(&mut self) -> RuntimeResult<Value> {
@@ -197,112 +1118,613 @@ impl<'c> Context<'c> {
     }
 
     fn current_instruction_location(&self) -> Location {
-        self.program.instructions()[self.cursor].location
+        self.program
+            .instructions()
+            .get(self.cursor)
+            .map_or_else(Location::default, |inst| inst.location)
+    }
+
+    #[inline]
+    fn is_temporary(&self, name: Symbol) -> bool {
+        self.temporary_names
+            .get(name.to_usize())
+            .copied()
+            .unwrap_or(false)
+    }
+
+    /// Value assigned to variable must have type declared by `$이름: 수 = 값;`
+    fn check_type(&self, name: Symbol, value: &Value) -> RuntimeResult<()> {
+        match self.variable_types.get(&name) {
+            Some(ty) if !ty.accepts(value) => Err(RuntimeError::VariableType(
+                self.program.resolve(name).unwrap_or_default().into(),
+                ty.name(),
+                value.type_name(),
+                self.current_instruction_location().line,
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn undefined_variable(&self, name: &str) -> RuntimeError {
+        let program = self.program;
+        let suggestion = closest(
+            name,
+            self.variables
+                .keys()
+                .chain(self.temporaries.keys())
+                .filter_map(|var| program.resolve(*var)),
+        );
+
+        RuntimeError::UndefinedVariable(
+            name.into(),
+            suggestion.map(Into::into),
+            self.current_instruction_location().line,
+        )
     }
 
     fn make_err(&self, msg: &'static str) -> RuntimeError {
         RuntimeError::ExecutionError(msg, self.current_instruction_location().line)
     }
 
-    pub async fn run_instruction<B: Builtin>(
-        &mut self,
-        builtin: &mut B,
-        inst: InstructionWithDebug,
-    ) -> RuntimeResult<()> {
-        match inst.inst {
-            Instruction::Exit => {
-                self.cursor = self.program.instructions().len();
-                return Ok(());
-            }
-            Instruction::LoadInt(num) => self.push(num),
-            Instruction::LoadStr(str) => self.push(self.program.resolve(str).unwrap()),
-            Instruction::LoadVar(name) => {
-                let item = self
-                    .variables
-                    .get(&name)
-                    .cloned()
-                    .or_else(|| builtin.load(self.program.resolve(name).unwrap()))
-                    .ok_or(self.make_err("변수를 찾을수 없습니다"))?;
-                self.push(item);
-            }
-            Instruction::StoreVar(name) => {
-                let item = self.pop_ret()?;
-                self.variables.insert(name, item);
-            }
-            Instruction::CallBuiltin(name) => {
-                let ret = builtin
-                    .run(
-                        self.program
-                            .resolve(name)
+    /// Set element of list in variable in place, list is not copied
+    fn store_index(&mut self, name: Symbol, depth: usize) -> RuntimeResult<()> {
+        let value = self.pop_ret()?;
+        let start = self
+            .stack
+            .len()
+            .checked_sub(depth)
+            .ok_or(self.make_err("인자가 부족합니다"))?;
+        let indices = self.stack.split_off(start);
+        let line = self.current_instruction_location().line;
+        let program = self.program;
+        let temporary = self.is_temporary(name);
+        let track = !self.watchpoints.is_empty() || (!temporary && self.journal.is_some());
+
+        let store = if temporary {
+            &mut self.temporaries
+        } else {
+            &mut self.variables
+        };
+        let prev = if track {
+            store.get(&name).cloned()
+        } else {
+            None
+        };
+        let mut target = match store.get_mut(&name) {
+            Some(target) => target,
+            None => return Err(self.undefined_variable(program.resolve(name).unwrap_or_default())),
+        };
+
+        for index in indices {
+            let index = match index {
+                Value::Int(index) => index as usize,
+                other => return Err(RuntimeError::TypeError(other.type_name(), line)),
+            };
+            target = match target {
+                Value::List(list) => match list.get_mut(index) {
+                    Some(element) => element,
+                    None => {
+                        return Err(RuntimeError::ExecutionError(
+                            "목록의 범위를 벗어났습니다",
+                            line,
+                        ))
+                    }
+                },
+                other => return Err(RuntimeError::TypeError(other.type_name(), line)),
+            };
+        }
+        *target = value;
+
+        if !self.watchpoints.is_empty() {
+            let store = if temporary {
+                &self.temporaries
+            } else {
+                &self.variables
+            };
+            if let Some(current) = store.get(&name).cloned() {
+                self.check_watch(name, prev.as_ref(), &current);
+            }
+        }
+        if !temporary {
+            self.variables_changed = true;
+            if let Some(journal) = self.journal.as_mut() {
+                journal.record_write(name, prev);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Runner of instructions for `Builtin` and `LocalBuiltin`
+///
+/// They only differ in `Send` bound of builtin futures
+macro_rules! impl_runner {
+    (
+        $builtin:ident,
+        $flush_print:ident,
+        $paced_print:ident,
+        $print:ident,
+        $run_instruction:ident,
+        $step:ident,
+        $next_line:ident,
+        $run_to_breakpoint:ident,
+        $run:ident
+    ) => {
+        pub fn $flush_print<B: $builtin>(&mut self, builtin: &mut B) {
+            builtin.print_group(self.stack.drain(..).collect());
+        }
+
+        /// Chunks are delivered by `print` so host can show them one by one
+        #[cfg(feature = "typewriter")]
+        async fn $paced_print<B: $builtin>(&mut self, builtin: &mut B) {
+            let pacer = match self.pacer.as_mut() {
+                Some(pacer) => pacer,
+                None => return self.$flush_print(builtin),
+            };
+
+            let mut first = true;
+
+            for v in self.stack.drain(..) {
+                let text = v.to_string();
+                for chunk in crate::typewriter::chunks(&text) {
+                    if !first && !pacer.is_skipped() {
+                        pacer.delay().await;
+                    }
+                    first = false;
+                    builtin.print(Value::from(chunk));
+                }
+            }
+        }
+
+        /// Run print statement, returns `true` when timer is fired and cursor is moved
+        async fn $print<B: $builtin>(
+            &mut self,
+            builtin: &mut B,
+            location: Location,
+            channel: Option<Symbol>,
+            newline: bool,
+            wait: bool,
+        ) -> bool {
+            self.state.seen.insert(location);
+            if let Some(journal) = self.journal.as_mut() {
+                journal.checkpoint(
+                    self.cursor,
+                    &self.stack,
+                    &self.iterations,
+                    self.branch_log.choices().len(),
+                );
+            }
+            if let (true, Some(tracer)) = (self.tracing, self.tracer.as_mut()) {
+                tracer.trace(TraceEvent::Print(&self.stack, location));
+            }
+            match channel.and_then(|channel| self.program.resolve(channel)) {
+                Some(channel) => builtin.print_channel(channel, self.stack.drain(..).collect()),
+                #[cfg(feature = "typewriter")]
+                None => self.$paced_print(builtin).await,
+                #[cfg(not(feature = "typewriter"))]
+                None => self.$flush_print(builtin),
+            }
+
+            if newline || wait {
+                builtin.new_line();
+            }
+
+            self.temporaries.clear();
+
+            builtin.flush().await;
+
+            if wait {
+                self.publish_variables();
+                match self.wait_control.policy() {
+                    WaitPolicy::Normal => builtin.wait().await,
+                    WaitPolicy::Auto => builtin.auto_wait().await,
+                    WaitPolicy::Skip => {}
+                }
+
+                if self.fire_timer(self.cursor + 1) {
+                    return true;
+                }
+            }
+
+            false
+        }
+
+        pub async fn $run_instruction<B: $builtin>(
+            &mut self,
+            builtin: &mut B,
+            inst: InstructionWithDebug,
+        ) -> RuntimeResult<()> {
+            match inst.inst {
+                Instruction::Exit => {
+                    self.cursor = self.program.instructions().len();
+                    return Ok(());
+                }
+                Instruction::LoadInt(num) => self.push(num),
+                Instruction::LoadBool(value) => self.push(value),
+                Instruction::LoadNull => self.push(Value::Null),
+                Instruction::LoadAsset(path) => self.push(self.program.resolve(path).unwrap()),
+                Instruction::LoadStr(str) => {
+                    let text = self.program.resolve(str).unwrap();
+                    match self.translator.as_ref().and_then(|t| t(text)) {
+                        Some(translated) => self.push(translated),
+                        None => self.push(text),
+                    }
+                }
+                Instruction::LoadVar(name) => {
+                    let store = if self.is_temporary(name) {
+                        &self.temporaries
+                    } else {
+                        &self.variables
+                    };
+                    let var = self.program.resolve(name).unwrap();
+                    let item = match store.get(&name).cloned().or_else(|| builtin.load(var)) {
+                        Some(item) => item,
+                        None if self.missing_as_null => Value::Null,
+                        None => return Err(self.undefined_variable(var)),
+                    };
+                    self.push(item);
+                }
+                Instruction::StoreVar(name) if self.is_temporary(name) => {
+                    let item = self.pop_ret()?;
+                    self.check_type(name, &item)?;
+                    if !self.watchpoints.is_empty() {
+                        let prev = self.temporaries.get(&name).cloned();
+                        self.check_watch(name, prev.as_ref(), &item);
+                    }
+                    self.temporaries.insert(name, item);
+                }
+                Instruction::StoreVar(name) => {
+                    let item = self.pop_ret()?;
+                    self.check_type(name, &item)?;
+                    if !self.watchpoints.is_empty() {
+                        let prev = self.variables.get(&name).cloned();
+                        self.check_watch(name, prev.as_ref(), &item);
+                    }
+                    let prev = self.variables.insert(name, item);
+                    self.variables_changed = true;
+                    if let Some(journal) = self.journal.as_mut() {
+                        journal.record_write(name, prev);
+                    }
+                }
+                Instruction::CallBuiltin(symbol) => {
+                    let idx = symbol.to_usize();
+                    let cached = self.builtin_handles.get(idx).copied().flatten();
+                    let name = match cached {
+                        Some((name, _)) => name,
+                        None => self
+                            .program
+                            .resolve(symbol)
                             .ok_or(self.make_err("알수없는 심볼입니다"))?,
-                        self,
-                    )
-                    .await;
-                self.push(ret);
-            }
-            Instruction::BinaryOperator(op) => self.run_bin_operator(op)?,
-            Instruction::UnaryOperator(crate::operator::UnaryOperator::Not) => {
-                let v: bool = self.pop_ret()?.into_bool();
-                self.push(!v);
-            }
-            Instruction::Goto(pos) => {
-                self.cursor = pos as usize;
-                return Ok(());
-            }
-            Instruction::GotoIfNot(pos) => {
-                if !self.pop_ret()?.into_bool() {
+                    };
+                    self.check_call(name)?;
+                    self.rewound = false;
+                    let handle = match cached {
+                        Some((_, handle)) => handle,
+                        None => {
+                            let handle = builtin.resolve(name);
+                            if self.builtin_handles.len() <= idx {
+                                self.builtin_handles.resize(idx + 1, None);
+                            }
+                            self.builtin_handles[idx] = Some((name, handle));
+                            handle
+                        }
+                    };
+                    let ret = match handle {
+                        Some(handle) => builtin.call(handle, name, self).await,
+                        None => builtin.run(name, self).await,
+                    };
+                    if self.rewound {
+                        return Ok(());
+                    }
+                    self.check_return(name, &ret)?;
+                    if let (true, Some(tracer)) = (self.tracing, self.tracer.as_mut()) {
+                        tracer.trace(TraceEvent::Return(name, &ret, inst.location));
+                    }
+                    self.push(ret);
+                }
+                Instruction::SpawnBuiltin(name) => {
+                    let name = self
+                        .program
+                        .resolve(name)
+                        .ok_or(self.make_err("알수없는 심볼입니다"))?;
+                    self.check_call(name)?;
+                    builtin.spawn(name, self).await;
+                }
+                Instruction::Join => builtin.join().await,
+                Instruction::BinaryOperator(op) => self.run_bin_operator(op)?,
+                Instruction::ConcatN(count) => self.concat(count as usize)?,
+                Instruction::UnaryOperator(crate::operator::UnaryOperator::Not) => {
+                    let v: bool = self.pop_ret()?.into_bool();
+                    self.push(!v);
+                }
+                Instruction::Goto(pos) => {
                     self.cursor = pos as usize;
                     return Ok(());
                 }
+                Instruction::JumpTable { min, len } => {
+                    let idx = match self.pop_ret()? {
+                        Value::Int(num) if num.wrapping_sub(min) < len as u32 => num - min,
+                        _ => len as u32,
+                    };
+                    match self.program.instructions()[self.cursor + 1 + idx as usize].inst {
+                        Instruction::Goto(pos) => {
+                            self.cursor = pos as usize;
+                            return Ok(());
+                        }
+                        _ => return Err(self.make_err("잘못된 점프 테이블입니다")),
+                    }
+                }
+                Instruction::BuildList(count) => {
+                    let start = self
+                        .stack
+                        .len()
+                        .checked_sub(count as usize)
+                        .ok_or(self.make_err("인자가 부족합니다"))?;
+                    let list = self.stack.split_off(start);
+                    self.push(list);
+                }
+                Instruction::LoadIndex => {
+                    let index: usize = self.pop_into_ret()?;
+                    let element = match self.pop_ret()? {
+                        Value::List(mut list) if index < list.len() => list.swap_remove(index),
+                        Value::List(..) => return Err(self.make_err("목록의 범위를 벗어났습니다")),
+                        other => {
+                            return Err(RuntimeError::TypeError(
+                                other.type_name(),
+                                inst.location.line,
+                            ))
+                        }
+                    };
+                    self.push(element);
+                }
+                Instruction::StoreIndex { var, depth } => self.store_index(var, depth as usize)?,
+                Instruction::IterStart => {
+                    let value = self.pop_ret()?;
+                    if !value.is_iterable() {
+                        return Err(RuntimeError::TypeError(
+                            value.type_name(),
+                            inst.location.line,
+                        ));
+                    }
+                    self.iterations.push(Iteration { value, pos: 0 });
+                }
+                Instruction::IterNext(end) => {
+                    let element = match self.iterations.last_mut() {
+                        Some(iteration) => {
+                            iteration
+                                .value
+                                .element_at(iteration.pos)
+                                .map(|(element, next)| {
+                                    iteration.pos = next;
+                                    element
+                                })
+                        }
+                        None => return Err(self.make_err("각각 밖에서 다음 항목을 읽었습니다")),
+                    };
+                    match element {
+                        Some(element) => self.push(element),
+                        None => {
+                            self.iterations.pop();
+                            self.cursor = end as usize;
+                            return Ok(());
+                        }
+                    }
+                }
+                Instruction::GotoIfNot(pos) => {
+                    if !self.pop_ret()?.into_bool() {
+                        self.cursor = pos as usize;
+                        return Ok(());
+                    }
+                }
+                Instruction::Print { newline, wait } => {
+                    if self
+                        .$print(builtin, inst.location, None, newline, wait)
+                        .await
+                    {
+                        return Ok(());
+                    }
+                }
+                Instruction::PrintChannel {
+                    channel,
+                    newline,
+                    wait,
+                } => {
+                    if self
+                        .$print(builtin, inst.location, Some(channel), newline, wait)
+                        .await
+                    {
+                        return Ok(());
+                    }
+                }
+                Instruction::Timer(end) => {
+                    let delay: u32 = self.pop_into_ret()?;
+                    let now = self.now();
+                    self.state.timers.push(PendingTimer {
+                        deadline: now + delay as u64,
+                        start: self.cursor + 1,
+                    });
+                    self.cursor = end as usize;
+                    return Ok(());
+                }
+                Instruction::EndTimer => {
+                    let ret = self
+                        .state
+                        .timer_returns
+                        .pop()
+                        .ok_or_else(|| self.make_err("타이머 밖에서 타이머가 끝났습니다"))?;
+
+                    if !self.fire_timer(ret) {
+                        self.cursor = ret;
+                    }
+                    return Ok(());
+                }
+                Instruction::Duplicate => {
+                    let item = self.peek_ret()?.clone();
+                    self.push(item);
+                }
+                Instruction::Nop => {}
+                Instruction::Pop => {
+                    self.pop();
+                }
+                Instruction::TernaryOperator(TernaryOperator::Conditional) => {
+                    let rhs = self.pop_ret()?;
+                    let lhs = self.pop_ret()?;
+                    let cond = self.pop_bool();
+
+                    self.push(if cond { lhs } else { rhs });
+                }
             }
-            Instruction::Print { newline, wait } => {
-                self.flush_print(builtin);
 
-                if newline || wait {
-                    builtin.new_line();
+            self.cursor += 1;
+
+            Ok(())
+        }
+
+        /// Run single instruction, returns `false` when program is finished
+        ///
+        /// Host can interrupt script by not calling it and resume later
+        pub async fn $step<B: $builtin>(&mut self, builtin: &mut B) -> RuntimeResult<bool> {
+            let instruction = match self.program.instructions().get(self.cursor) {
+                Some(&instruction) => instruction,
+                None => return Ok(false),
+            };
+
+            if let Some(fuel) = self.fuel.as_mut() {
+                match fuel.checked_sub(1) {
+                    Some(left) => *fuel = left,
+                    None => return Err(RuntimeError::OutOfFuel(instruction.location.line)),
                 }
+            }
 
-                if wait {
-                    builtin.wait().await;
+            if let (true, Some(tracer)) = (self.tracing, self.tracer.as_mut()) {
+                let file = self.program.file_id(self.cursor);
+                if self.traced_line != Some((file, instruction.location)) {
+                    if self.traced_line.map_or(true, |(prev, _)| prev != file) {
+                        if let Some(path) = self.program.file(self.cursor) {
+                            tracer.trace(TraceEvent::File(path));
+                        }
+                    }
+                    self.traced_line = Some((file, instruction.location));
+                    tracer.trace(TraceEvent::Line(instruction.location));
+                }
+                if self.program.is_statement_start(self.cursor) {
+                    tracer.trace(TraceEvent::Statement(instruction.location));
                 }
             }
-            Instruction::Duplicate => {
-                let item = self.peek_ret()?.clone();
-                self.push(item);
+
+            if let Some(interceptor) = self.interceptor.as_mut() {
+                let name = match instruction.inst {
+                    Instruction::CallBuiltin(name) | Instruction::SpawnBuiltin(name) => {
+                        self.program.resolve(name)
+                    }
+                    _ => None,
+                };
+                match interceptor.before(Intercepted {
+                    instruction: instruction.inst,
+                    location: instruction.location,
+                    builtin: name,
+                    stack: &mut self.stack,
+                }) {
+                    Action::Continue => {}
+                    Action::Skip => {
+                        self.cursor += 1;
+                        return Ok(true);
+                    }
+                    Action::Abort => return Err(RuntimeError::Aborted(instruction.location.line)),
+                }
             }
-            Instruction::Nop => {}
-            Instruction::Pop => {
-                self.pop();
+
+            self.$run_instruction(builtin, instruction).await?;
+
+            match self.stack_limit {
+                Some(limit) if self.stack.len() > limit => {
+                    Err(RuntimeError::StackOverflow(instruction.location.line))
+                }
+                _ => Ok(true),
             }
-            Instruction::TernaryOperator(TernaryOperator::Conditional) => {
-                let rhs = self.pop_ret()?;
-                let lhs = self.pop_ret()?;
-                let cond = self.pop_bool();
+        }
+
+        /// Run until next line starts, returns `false` when program is finished
+        pub async fn $next_line<B: $builtin>(&mut self, builtin: &mut B) -> RuntimeResult<bool> {
+            let line = self.location();
 
-                self.push(if cond { lhs } else { rhs });
+            while self.$step(builtin).await? {
+                if self.location() != line {
+                    return Ok(true);
+                }
             }
+
+            Ok(false)
         }
 
-        self.cursor += 1;
+        /// Run until line with breakpoint starts or watchpoint is hit,
+        /// returns `false` when program is finished
+        pub async fn $run_to_breakpoint<B: $builtin>(
+            &mut self,
+            builtin: &mut B,
+        ) -> RuntimeResult<bool> {
+            self.watch_hit = None;
+            let mut line = (self.program.file_id(self.cursor), self.location().line);
 
-        Ok(())
-    }
+            while self.$step(builtin).await? {
+                if self.watch_hit.is_some() {
+                    self.publish_variables();
+                    return Ok(true);
+                }
+
+                let next = (self.program.file_id(self.cursor), self.location().line);
+                if next != line && self.breakpoints.contains(&next) {
+                    self.publish_variables();
+                    return Ok(true);
+                }
+                line = next;
+            }
 
-    pub async fn run<B: Builtin>(mut self, mut builtin: B) -> RuntimeResult<()> {
-        while let Some(&instruction) = self.program.instructions().get(self.cursor) {
-            self.run_instruction(&mut builtin, instruction).await?;
+            Ok(false)
         }
 
-        Ok(())
-    }
+        pub async fn $run<B: $builtin>(mut self, mut builtin: B) -> RuntimeResult<()> {
+            while self.$step(&mut builtin).await? {}
+
+            Ok(())
+        }
+    };
+}
+
+impl<'c> Context<'c> {
+    impl_runner!(
+        Builtin,
+        flush_print,
+        paced_print,
+        print_statement,
+        run_instruction,
+        step,
+        next_line,
+        run_to_breakpoint,
+        run
+    );
+
+    impl_runner!(
+        LocalBuiltin,
+        flush_print_local,
+        paced_print_local,
+        print_statement_local,
+        run_instruction_local,
+        step_local,
+        next_line_local,
+        run_to_breakpoint_local,
+        run_local
+    );
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Context;
+    use super::{Context, Watch};
     use crate::builtin::RecordBuiltin;
     use crate::error::{RuntimeError, RuntimeResult};
     use crate::program::Program;
+    use crate::value::Value;
     use pretty_assertions::assert_eq;
 
     fn test_impl(code: &str) -> RuntimeResult<crate::builtin::RecordBuiltin> {
@@ -338,6 +1760,605 @@ mod tests {
         }
     }
 
+    #[test]
+    fn compare_test() {
+        try_test(
+            "@(1 == '1') ('a' != 'a') (2 < 10) ('10' < '2') ('가' >= '가');",
+            "거짓거짓참참참",
+        );
+        assert!(matches!(
+            test_impl("@1;\n@'2' > 1;"),
+            Err(RuntimeError::TypeError("int", 2))
+        ));
+    }
+
+    #[test]
+    fn step_resume() {
+        let program = Program::from_source("@1;\n@2;").unwrap();
+        let mut builtin = RecordBuiltin::new();
+        let mut ctx = Context::new(&program);
+
+        while ctx.location().line == 1 {
+            assert!(futures_executor::block_on(ctx.step(&mut builtin)).unwrap());
+        }
+        assert_eq!(builtin.text(), "1");
+
+        while futures_executor::block_on(ctx.step(&mut builtin)).unwrap() {}
+        assert_eq!(builtin.text(), "12");
+    }
+
+    #[test]
+    fn print_group_flush() {
+        use crate::builtin::Builtin;
+        use crate::value::Value;
+        use async_trait::async_trait;
+
+        #[derive(Default)]
+        struct GroupBuiltin(Vec<Vec<Value>>);
+
+        #[async_trait]
+        impl Builtin for GroupBuiltin {
+            async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> Value {
+                Value::Int(0)
+            }
+            fn print(&mut self, _v: Value) {
+                unreachable!("values should be grouped");
+            }
+            fn print_group(&mut self, values: Vec<Value>) {
+                self.0.push(values);
+            }
+            fn new_line(&mut self) {}
+            async fn flush(&mut self) {
+                self.0.push(Vec::new());
+            }
+            async fn wait(&mut self) {}
+        }
+
+        let program = Program::from_source("@1 '가' 2;\n@@3;").unwrap();
+        let mut builtin = GroupBuiltin::default();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+
+        assert_eq!(
+            builtin.0,
+            [
+                vec![Value::Int(1), Value::from("가"), Value::Int(2)],
+                vec![],
+                vec![Value::Int(3)],
+                vec![],
+            ]
+        );
+    }
+
+    #[test]
+    fn print_channel() {
+        use crate::builtin::Builtin;
+        use crate::value::Value;
+        use async_trait::async_trait;
+
+        #[derive(Default)]
+        struct ChannelBuiltin(String);
+
+        #[async_trait]
+        impl Builtin for ChannelBuiltin {
+            async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> Value {
+                Value::Int(0)
+            }
+            fn print(&mut self, v: Value) {
+                self.0.push_str(&v.to_string());
+            }
+            fn print_channel(&mut self, channel: &str, values: Vec<Value>) {
+                self.0.push_str(&format!("[{}:{}]", channel, values.len()));
+            }
+            fn new_line(&mut self) {
+                self.0.push('@');
+            }
+            async fn wait(&mut self) {
+                self.0.push('#');
+            }
+        }
+
+        let program = Program::from_source("@#이름 '철수';\n@!'안녕' 1;\n@@#로그;").unwrap();
+        let mut builtin = ChannelBuiltin::default();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.0, "[이름:1]안녕1@#[로그:0]@");
+
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "철수안녕1@#@");
+    }
+
+    #[test]
+    fn memory_usage() {
+        let usage = |text: &str| {
+            let program = Program::from_source(&format!("$1 = '{}';\n$2 = [1, 2];", text)).unwrap();
+            let mut ctx = Context::new(&program);
+            let mut builtin = RecordBuiltin::new();
+            while futures_executor::block_on(ctx.step(&mut builtin)).unwrap() {}
+            ctx.memory_usage()
+        };
+
+        let small = usage("a");
+        let big = usage(&"a".repeat(1000));
+        assert_eq!(big.variables - small.variables, 999);
+        assert_eq!(big.stack, small.stack);
+        assert_eq!(big.total() - small.total(), 999);
+    }
+
+    #[test]
+    fn run_local() {
+        use crate::builtin::LocalBuiltin;
+        use crate::value::Value;
+        use async_trait::async_trait;
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        // `Rc` is not `Send`
+        struct LocalRecord(Rc<RefCell<String>>);
+
+        #[async_trait(?Send)]
+        impl LocalBuiltin for LocalRecord {
+            async fn run(&mut self, name: &str, _ctx: &mut Context<'_>) -> Value {
+                self.0.borrow_mut().push_str(name);
+                Value::Int(0)
+            }
+            fn print(&mut self, v: Value) {
+                self.0.borrow_mut().push_str(&v.to_string());
+            }
+            fn new_line(&mut self) {
+                self.0.borrow_mut().push('@');
+            }
+            async fn wait(&mut self) {
+                self.0.borrow_mut().push('#');
+            }
+        }
+
+        let text = Rc::new(RefCell::new(String::new()));
+        let program = Program::from_source("@!1;\n함수();").unwrap();
+        futures_executor::block_on(Context::new(&program).run_local(LocalRecord(text.clone())))
+            .unwrap();
+
+        assert_eq!(*text.borrow(), "1@#함수");
+    }
+
+    #[test]
+    fn builder_limits() {
+        use crate::sandbox::Sandbox;
+
+        let program = Program::from_source("반복 1 {\n}").unwrap();
+        let ctx = Context::builder(&program).fuel(100).build();
+        match futures_executor::block_on(ctx.run(RecordBuiltin::new())) {
+            Err(RuntimeError::OutOfFuel(_)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let program = Program::from_source("@1 2 3;").unwrap();
+        let ctx = Context::builder(&program).stack_limit(2).build();
+        match futures_executor::block_on(ctx.run(RecordBuiltin::new())) {
+            Err(RuntimeError::StackOverflow(1)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        let program = Program::from_source("허용();\n금지();").unwrap();
+        let mut builtin = RecordBuiltin::new();
+        let ctx = Context::builder(&program)
+            .sandbox(Sandbox::new().allow("허용"))
+            .build();
+        match futures_executor::block_on(ctx.run(&mut builtin)) {
+            Err(RuntimeError::Forbidden(name, 2)) if name == "금지" => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert_eq!(builtin.text(), "허용");
+    }
+
+    #[test]
+    fn builder_options() {
+        use crate::trace::TraceEvent;
+        use std::sync::{Arc, Mutex};
+
+        let program = Program::from_source("@$이름;\n함수();").unwrap();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let traced = events.clone();
+        let mut builtin = RecordBuiltin::new();
+        let ctx = Context::builder(&program)
+            .variable("이름", "철수")
+            .variable("없는변수", 1)
+            .tracer(move |event: TraceEvent| {
+                let text = match event {
+                    TraceEvent::Line(location) => format!("line {}", location),
+                    TraceEvent::Statement(location) => format!("statement {}", location),
+                    TraceEvent::Call(name, _, location) => format!("call {} {}", name, location),
+                    _ => return,
+                };
+                traced.lock().unwrap().push(text);
+            })
+            .build();
+
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+
+        assert_eq!(builtin.text(), "철수함수");
+        assert_eq!(
+            *events.lock().unwrap(),
+            [
+                "line L1",
+                "statement L1",
+                "line L2",
+                "statement L2",
+                "call 함수 L2"
+            ]
+        );
+
+        let mut a = Context::builder(&program).seed(42).build();
+        let mut b = Context::builder(&program).seed(42).build();
+        let numbers = (0..4).map(|_| a.random()).collect::<Vec<_>>();
+        assert_eq!(numbers, (0..4).map(|_| b.random()).collect::<Vec<_>>());
+        assert_ne!(numbers[0], numbers[1]);
+    }
+
+    #[test]
+    fn signature_check() {
+        use crate::signature::{ParamType, Signature, Signatures};
+
+        let mut signatures = Signatures::new();
+        signatures.insert("함수", Signature::new([ParamType::Int, ParamType::Str]));
+
+        let program = Program::from_source("함수(1, '2');\n함수('1', 2);").unwrap();
+        let mut builtin = RecordBuiltin::new();
+        let mut ctx = Context::new(&program);
+        ctx.set_signatures(&signatures);
+
+        match futures_executor::block_on(ctx.run(&mut builtin)) {
+            Err(RuntimeError::TypeError("str", 2)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+
+        assert_eq!(builtin.text(), "함수");
+    }
+
+    #[test]
+    fn trace_call_args() {
+        use crate::signature::{ParamType, Signature, Signatures};
+        use crate::trace::{literals, TraceEvent};
+        use std::sync::{Arc, Mutex};
+
+        let mut signatures = Signatures::new();
+        signatures.insert("함수", Signature::new([ParamType::Int]));
+
+        let program = Program::from_source("@'가' 함수(1) 다른함수(2);").unwrap();
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let traced = calls.clone();
+        let ctx = Context::builder(&program)
+            .signatures(&signatures)
+            .tracer(move |event: TraceEvent| {
+                if let TraceEvent::Call(name, args, _) = event {
+                    traced
+                        .lock()
+                        .unwrap()
+                        .push(format!("{}({})", name, literals(args)));
+                }
+            })
+            .build();
+        futures_executor::block_on(ctx.run(&mut RecordBuiltin::new())).unwrap();
+
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls[0], "함수(1)");
+        // no signature, every value of statement is passed
+        assert!(calls[1].starts_with("다른함수('가', "));
+    }
+
+    #[test]
+    fn return_check() {
+        use crate::signature::{ParamType, Signature, Signatures};
+
+        let mut signatures = Signatures::new();
+        signatures.insert("함수", Signature::default().returns(ParamType::Str));
+
+        let program = Program::from_source("@1;\n$1 = 함수();").unwrap();
+        let run = |check| {
+            let ctx = Context::builder(&program)
+                .signatures(&signatures)
+                .check_returns(check)
+                .build();
+            futures_executor::block_on(ctx.run(&mut RecordBuiltin::new()))
+        };
+
+        match run(true) {
+            Err(RuntimeError::ReturnType(name, "str", "int", 2)) if name == "함수" => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+        run(false).unwrap();
+    }
+
+    #[test]
+    fn interceptor() {
+        use crate::instruction::Instruction;
+        use crate::intercept::{Action, Intercepted};
+
+        let program = Program::from_source("@함수(1);\n금지(2);\n@'끝';\n종료;").unwrap();
+        let mut builtin = RecordBuiltin::new();
+        let ctx = Context::builder(&program)
+            .interceptor(|inst: Intercepted<'_>| match inst.builtin {
+                Some("함수") => {
+                    *inst.stack.last_mut().unwrap() = Value::Int(10);
+                    Action::Continue
+                }
+                Some("금지") => {
+                    inst.stack.pop();
+                    inst.stack.push(Value::Null);
+                    Action::Skip
+                }
+                _ if inst.instruction == Instruction::Exit => Action::Abort,
+                _ => Action::Continue,
+            })
+            .build();
+
+        match futures_executor::block_on(ctx.run(&mut builtin)) {
+            Err(RuntimeError::Aborted(4)) => {}
+            other => panic!("unexpected result {:?}", other),
+        }
+        assert_eq!(builtin.text(), "함수100끝");
+    }
+
+    #[test]
+    fn translator() {
+        let program = Program::from_source("@@'안녕' '잘가';").unwrap();
+        let mut builtin = RecordBuiltin::new();
+        let mut ctx = Context::new(&program);
+        ctx.set_translator(|text| match text {
+            "안녕" => Some("Hello".into()),
+            _ => None,
+        });
+
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "Hello잘가@");
+    }
+
+    #[test]
+    fn wait_policy() {
+        use super::WaitPolicy;
+
+        let program = Program::from_source("@!1; 전환(); @!2; @!3;").unwrap();
+        let mut ctx = Context::new(&program);
+        ctx.set_wait_policy(WaitPolicy::Skip);
+        let control = ctx.wait_control();
+
+        struct Switch(RecordBuiltin, super::WaitControl);
+
+        #[crate::async_trait]
+        impl crate::builtin::Builtin for Switch {
+            async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> crate::value::Value {
+                self.1.set_policy(WaitPolicy::Auto);
+                crate::value::Value::Int(0)
+            }
+            fn print(&mut self, v: crate::value::Value) {
+                self.0.print(v);
+            }
+            fn new_line(&mut self) {
+                self.0.new_line();
+            }
+            async fn wait(&mut self) {
+                self.0.wait().await;
+            }
+            async fn auto_wait(&mut self) {
+                self.0.print("auto".into());
+            }
+        }
+
+        let mut builtin = Switch(RecordBuiltin::new(), control);
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.0.text(), "1@2@auto3@auto");
+    }
+
+    #[test]
+    fn seen_snapshot() {
+        use crate::location::Location;
+
+        let program = Program::from_source("$1 = 1;\n@@$1;\n저장();\n@@2;").unwrap();
+
+        struct Save(RecordBuiltin, Option<crate::snapshot::Snapshot>);
+
+        #[crate::async_trait]
+        impl crate::builtin::Builtin for Save {
+            async fn run(&mut self, _name: &str, ctx: &mut Context<'_>) -> crate::value::Value {
+                self.1 = Some(ctx.snapshot());
+                crate::value::Value::Int(0)
+            }
+            fn print(&mut self, v: crate::value::Value) {
+                self.0.print(v);
+            }
+            fn new_line(&mut self) {
+                self.0.new_line();
+            }
+            async fn wait(&mut self) {}
+        }
+
+        let mut builtin = Save(RecordBuiltin::new(), None);
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        let snapshot = builtin.1.unwrap();
+        assert!(snapshot.runtime.seen.contains(&Location::new(2)));
+        assert!(!snapshot.runtime.seen.contains(&Location::new(4)));
+
+        let bytes = bincode::serialize(&snapshot).unwrap();
+        let mut ctx = Context::new(&program);
+        ctx.restore(bincode::deserialize(&bytes).unwrap());
+        assert!(ctx.is_seen(Location::new(2)));
+        assert_eq!(ctx.variables.len(), 1);
+    }
+
+    #[test]
+    fn branch_log() {
+        use crate::history::BranchLog;
+
+        struct Choose(RecordBuiltin, u32, BranchLog);
+
+        #[crate::async_trait]
+        impl crate::builtin::Builtin for Choose {
+            async fn run(&mut self, _name: &str, ctx: &mut Context<'_>) -> crate::value::Value {
+                let index = match ctx.replayed_choice() {
+                    Some(index) => index,
+                    None => {
+                        ctx.record_choice(self.1);
+                        self.1
+                    }
+                };
+                self.2 = ctx.branch_log().clone();
+                crate::value::Value::Int(index)
+            }
+            fn print(&mut self, v: crate::value::Value) {
+                self.0.print(v);
+            }
+            fn new_line(&mut self) {}
+            async fn wait(&mut self) {}
+        }
+
+        let program =
+            Program::from_source("@선택();\n만약 선택() {\n@선택();\n} 그외 {\n@선택();\n}")
+                .unwrap();
+
+        let mut builtin = Choose(RecordBuiltin::new(), 1, BranchLog::new());
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.0.text(), "11");
+        let mut log = builtin.2;
+        assert_eq!(log.choices().len(), 3);
+        assert_eq!(log.choices()[2].location.line, 3);
+
+        log.truncate(1);
+        let mut ctx = Context::new(&program);
+        ctx.replay(log);
+        let mut builtin = Choose(RecordBuiltin::new(), 0, BranchLog::new());
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.0.text(), "10");
+        assert_eq!(builtin.2.choices()[2].location.line, 5);
+    }
+
+    #[test]
+    fn rewind() {
+        struct Back(RecordBuiltin, bool);
+
+        #[crate::async_trait]
+        impl crate::builtin::Builtin for Back {
+            async fn run(&mut self, _name: &str, ctx: &mut Context<'_>) -> crate::value::Value {
+                if !self.1 {
+                    self.1 = true;
+                    assert!(ctx.rewind(2));
+                }
+                crate::value::Value::Int(0)
+            }
+            fn print(&mut self, v: crate::value::Value) {
+                self.0.print(v);
+            }
+            fn new_line(&mut self) {
+                self.0.new_line();
+            }
+            async fn wait(&mut self) {}
+        }
+
+        let program =
+            Program::from_source("$1 = 1; @@$1; $1 = 2; @@$1; $1 = 3; $2 = 1; 뒤로(); @@$1 $2;")
+                .unwrap();
+        let mut ctx = Context::new(&program);
+        ctx.enable_journal(10);
+        let mut builtin = Back(RecordBuiltin::new(), false);
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.0.text(), "1@2@1@2@31@");
+    }
+
+    #[test]
+    fn spawn_join() {
+        try_test("시작 소리(); @1; 기다리기; @2;", "소리12");
+    }
+
+    #[test]
+    fn timer() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::Arc;
+
+        struct Tick(RecordBuiltin, Arc<AtomicU64>);
+
+        #[crate::async_trait]
+        impl crate::builtin::Builtin for Tick {
+            async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> crate::value::Value {
+                crate::value::Value::Int(0)
+            }
+            fn print(&mut self, v: crate::value::Value) {
+                self.0.print(v);
+            }
+            fn new_line(&mut self) {
+                self.0.new_line();
+            }
+            async fn wait(&mut self) {
+                self.1.fetch_add(100, Ordering::Relaxed);
+                self.0.wait().await;
+            }
+        }
+
+        let program =
+            Program::from_source("타이머 150 { @'a'; } 타이머 50 { @!'b'; } @!1; @!2; @!3;")
+                .unwrap();
+        let time = Arc::new(AtomicU64::new(0));
+        let mut ctx = Context::new(&program);
+        let clock = time.clone();
+        ctx.set_clock(move || clock.load(Ordering::Relaxed));
+        let mut builtin = Tick(RecordBuiltin::new(), time);
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.0.text(), "1@#b@#a2@#3@#");
+    }
+
+    #[test]
+    fn runtime_state_snapshot() {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::Arc;
+
+        struct Tick(RecordBuiltin, Arc<AtomicU64>);
+
+        #[crate::async_trait]
+        impl crate::builtin::Builtin for Tick {
+            async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> crate::value::Value {
+                crate::value::Value::Int(0)
+            }
+            fn print(&mut self, v: crate::value::Value) {
+                self.0.print(v);
+            }
+            fn new_line(&mut self) {
+                self.0.new_line();
+            }
+            async fn wait(&mut self) {
+                self.1.fetch_add(100, Ordering::Relaxed);
+                self.0.wait().await;
+            }
+        }
+
+        let program = Program::from_source("타이머 150 { @'a'; } @!1; @!2; @!3;").unwrap();
+        let time = Arc::new(AtomicU64::new(0));
+        let clock = time.clone();
+        let mut ctx = Context::builder(&program)
+            .seed(7)
+            .clock(move || clock.load(Ordering::Relaxed))
+            .build();
+        let mut builtin = Tick(RecordBuiltin::new(), time);
+        while builtin.0.text() != "1@#" {
+            assert!(futures_executor::block_on(ctx.step(&mut builtin)).unwrap());
+        }
+        ctx.random();
+
+        let snapshot = ctx.snapshot();
+        assert_eq!(snapshot.runtime.timers[0].deadline, 50);
+        let bytes = bincode::serialize(&snapshot).unwrap();
+
+        // clock of restored game starts from different time
+        let time = Arc::new(AtomicU64::new(1000));
+        let clock = time.clone();
+        let mut restored = Context::builder(&program)
+            .clock(move || clock.load(Ordering::Relaxed))
+            .build();
+        restored.restore(bincode::deserialize(&bytes).unwrap());
+        assert_eq!(restored.random(), ctx.random());
+        assert!(restored.is_seen(crate::location::Location::new(1)));
+
+        let mut builtin = Tick(RecordBuiltin::new(), time);
+        futures_executor::block_on(restored.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.0.text(), "2@#a3@#");
+    }
+
     #[test]
     fn if_test() {
         try_test(
@@ -346,6 +2367,254 @@ mod tests {
         );
     }
 
+    #[test]
+    fn short_circuit_test() {
+        try_test(
+            "$0 = 0 && 함수(); @$0 (1 || 함수()) (1 && 함수()) (없음 || '가') (1 && 0);",
+            "함수거짓참거짓참거짓",
+        );
+    }
+
+    #[test]
+    fn shift_test() {
+        try_test(
+            "@(3 << 4) ' ' (256 >> 4 >> 1) ' ' (1 << 32) ' ' (1 << 2 + 1) ' ' (5 > 1 << 2);",
+            "48 8 0 8 참",
+        );
+    }
+
+    #[test]
+    fn concat_test() {
+        try_test("$0 = 2; @1 + $0 + '개' + 3 + (4 + 5) + '';", "3개39");
+    }
+
+    #[test]
+    fn jump_table_test() {
+        try_test(
+            "$0 = 0; 반복 $0 < 7 { 만약 $0 == 2 { @'가'; } 혹은 $0 == 3 { @'나'; } 혹은 $0 == 5 { @'다'; } 혹은 $0 == 6 { @'라'; } 그외 { @$0; } $0 = $0 + 1; } $0 = '3'; 만약 $0 == 2 { @'가'; } 혹은 $0 == 3 { @'나'; } 혹은 $0 == 5 { @'다'; } 혹은 $0 == 6 { @'라'; }",
+            "01가나4다라",
+        );
+    }
+
+    #[test]
+    fn select_test() {
+        try_test(
+            "$0 = 0; 반복 $0 < 7 { 선택 $0 { 2 { @'가'; } 3, 6 { @'나'; } 5 { @'다'; } 4 { @'라'; } 그외 { @$0; } } $0 = $0 + 1; }",
+            "01가나라다나",
+        );
+        try_test(
+            "$0 = '나'; 선택 $0 { '가' { @1; } 0, '나' { @2; } } 선택 1 { '1' { @3; } } @4;",
+            "24",
+        );
+    }
+
+    #[test]
+    fn for_each_test() {
+        try_test(
+            "$0 = '가나'; 각각 $1 $0 { 각각 $2 '12' { @$1 + $2; } } @$1;",
+            "가1가2나1나2나",
+        );
+        try_test("각각 $1 '' { @1; } @2;", "2");
+        assert!(matches!(
+            test_impl("@1;\n각각 $1 3 { }"),
+            Err(RuntimeError::TypeError("int", 2))
+        ));
+    }
+
+    #[test]
+    fn for_each_snapshot() {
+        let program = Program::from_source("각각 $1 '가나다' { @$1; }").unwrap();
+        let mut builtin = RecordBuiltin::new();
+        let mut ctx = Context::new(&program);
+        while builtin.text() != "가" {
+            assert!(futures_executor::block_on(ctx.step(&mut builtin)).unwrap());
+        }
+
+        let snapshot = ctx.snapshot();
+        assert_eq!(snapshot.iterations.len(), 1);
+        let mut ctx = Context::new(&program);
+        ctx.restore(snapshot);
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "나다");
+    }
+
+    #[test]
+    fn list_test() {
+        try_test(
+            "$0 = [1, '가', [2]]; $0[2][0] = $0[0] + 1; $0[1] = $0 + [3]; @$0[1] $0[2] [$0[1][3]];",
+            "[1, 가, [2], 3][2][3]",
+        );
+        try_test(
+            "$0 = []; 만약 $0 { @1; } $0 = $0 + [1, 2]; 각각 $1 $0 { @$1; } @$0 == [1, 2];",
+            "12참",
+        );
+        assert!(matches!(
+            test_impl("$0 = [1];\n@$0[1];"),
+            Err(RuntimeError::ExecutionError(_, 2))
+        ));
+        assert!(matches!(
+            test_impl("$0 = 1;\n$0[0] = 2;"),
+            Err(RuntimeError::TypeError("int", 2))
+        ));
+    }
+
+    #[test]
+    fn bool_test() {
+        try_test(
+            "$0 = 참; @$0 !$0 (1 < 2) == 참 (참 & 0) (거짓 | '가') ('가' + 거짓);",
+            "참거짓참거짓참가거짓",
+        );
+        try_test("만약 거짓 { @1; } 그외 { @2; } 반복 참 { @3; 종료; }", "23");
+        try_test("@참 == 1 (1 == 1) + '';", "거짓참");
+        assert!(matches!(
+            test_impl("@(1 == 1) + 1;"),
+            Err(RuntimeError::TypeError(..))
+        ));
+    }
+
+    #[test]
+    fn null_test() {
+        try_test(
+            "$0 = 없음; @$0 ($0 == 없음) ($0 == 0) !$0 ('' + $0) [없음][0];",
+            "없음참거짓참없음없음",
+        );
+        try_test("만약 없음 { @1; } 그외 { @2; }", "2");
+        assert!(matches!(
+            test_impl("@없음 + 1;"),
+            Err(RuntimeError::TypeError(..))
+        ));
+
+        let program = Program::from_source("@$체력 == 없음;").unwrap();
+        let mut builtin = RecordBuiltin::new();
+        let ctx = Context::builder(&program).missing_as_null().build();
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "$체력참");
+    }
+
+    #[test]
+    fn typed_variable_test() {
+        try_test(
+            "$체력: 수 = 100; $체력 = $체력 - 1; $목록: 목록 = []; @$체력 $목록;",
+            "99[]",
+        );
+
+        let err = match test_impl("$체력: 수 = 100;\n@$체력;\n$체력 = '백';") {
+            Err(err) => err,
+            Ok(_) => panic!("expected type error"),
+        };
+        assert!(matches!(
+            &err,
+            RuntimeError::VariableType(var, "int", "str", 3) if var == "체력"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "3번째 줄 실행중 `int` 타입인 `$체력` 변수에 `str` 타입이 들어왔습니다"
+        );
+
+        // assignment before annotation in source is checked too
+        assert!(matches!(
+            test_impl("각각 $글자 '가' { }\n$글자: 수 = 1;"),
+            Err(RuntimeError::VariableType(_, "int", "str", 1))
+        ));
+    }
+
+    #[test]
+    fn label_test() {
+        let program =
+            Program::from_source("@1;\n종료;\n장면 숲;\n@2;\n종료;\n장면 성;\n@3;").unwrap();
+        let run = |label: &str| -> RuntimeResult<String> {
+            let mut builtin = RecordBuiltin::new();
+            futures_executor::block_on(Context::new_at(&program, label)?.run(&mut builtin))?;
+            Ok(builtin.text().into())
+        };
+
+        assert_eq!(run("숲").unwrap(), "2");
+        assert_eq!(run("성").unwrap(), "3");
+        assert!(matches!(
+            run("바다"),
+            Err(RuntimeError::ExecutionError(_, 0))
+        ));
+        try_test("@1;\n장면 숲;\n@2;", "12");
+    }
+
+    #[test]
+    fn resolve_once() {
+        use crate::builtin::{Builtin, Handle};
+        use crate::value::Value;
+
+        #[derive(Default)]
+        struct Resolve {
+            resolved: Vec<String>,
+            calls: Vec<u32>,
+        }
+
+        #[crate::async_trait]
+        impl Builtin for Resolve {
+            async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> Value {
+                unreachable!("every builtin is resolved")
+            }
+            fn resolve(&mut self, name: &str) -> Option<Handle> {
+                self.resolved.push(name.into());
+                Some(Handle(self.resolved.len() as u32))
+            }
+            async fn call(&mut self, handle: Handle, _name: &str, _ctx: &mut Context<'_>) -> Value {
+                self.calls.push(handle.0);
+                Value::Int(0)
+            }
+            fn print(&mut self, _v: Value) {}
+            fn new_line(&mut self) {}
+            async fn wait(&mut self) {}
+        }
+
+        let program =
+            Program::from_source("$0 = 0; 반복 $0 < 3 { 가(); 나(); $0 = $0 + 1; } 가();").unwrap();
+        let mut builtin = Resolve::default();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.resolved, ["가", "나"]);
+        assert_eq!(builtin.calls, [1, 2, 1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn variables_view() {
+        use super::VariablesView;
+        use crate::builtin::Builtin;
+        use crate::value::Value;
+        use std::sync::Arc;
+
+        struct Inspect(VariablesView, Vec<Option<Value>>);
+
+        #[crate::async_trait]
+        impl Builtin for Inspect {
+            async fn run(&mut self, _name: &str, _ctx: &mut Context<'_>) -> Value {
+                Value::Int(0)
+            }
+            fn print(&mut self, _v: Value) {}
+            fn new_line(&mut self) {}
+            async fn wait(&mut self) {
+                self.1.push(self.0.snapshot().get("체력").cloned());
+            }
+        }
+
+        let program =
+            Program::from_source("$체력 = 10; @!'가'; $체력 = $체력 - 3; @!'나'; @!'다';").unwrap();
+        let mut ctx = Context::builder(&program).variable("체력", 1).build();
+        let first = ctx.variables_snapshot();
+        assert_eq!(first.get("체력"), Some(&Value::Int(1)));
+        assert!(Arc::ptr_eq(&first, &ctx.variables_snapshot()));
+
+        let mut builtin = Inspect(ctx.variables_view(), Vec::new());
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(
+            builtin.1,
+            [
+                Some(Value::Int(10)),
+                Some(Value::Int(7)),
+                Some(Value::Int(7))
+            ]
+        );
+    }
+
     #[test]
     fn loop_test() {
         try_test(
@@ -353,4 +2622,96 @@ mod tests {
             "12345678910",
         );
     }
+
+    #[test]
+    fn temporaries() {
+        try_test("$_0 = 1; $_1 = $_0 + 1; @$_1;", "2");
+
+        let program = Program::from_source("$_0 = 1; $0 = 2; @$_0; $1 = $_0;").unwrap();
+        let mut ctx = Context::new(&program);
+        let mut builtin = RecordBuiltin::new();
+        let result = futures_executor::block_on(async {
+            while ctx.step(&mut builtin).await? {}
+            Ok(())
+        });
+        assert!(matches!(result, Err(RuntimeError::UndefinedVariable(..))));
+        assert_eq!(builtin.text(), "1$_0");
+        assert_eq!(ctx.snapshot().variables.len(), 1);
+    }
+
+    #[test]
+    fn undefined_variable() {
+        let err = test_impl("$체력 = 10;\n@$체럭;").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "2번째 줄 실행중 `$체럭` 변수를 찾을수 없습니다 (혹시 `$체력`?)"
+        );
+
+        let err = test_impl("@$마나;").err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "1번째 줄 실행중 `$마나` 변수를 찾을수 없습니다"
+        );
+    }
+
+    #[test]
+    fn breakpoints() {
+        let program = Program::from_source("$0 = 1;\n@$0;\n$0 = 2;\n@$0;\n@3;").unwrap();
+        let mut ctx = Context::new(&program);
+        let mut builtin = RecordBuiltin::new();
+        ctx.set_breakpoint(4);
+        ctx.set_breakpoint(5);
+        ctx.remove_breakpoint(5);
+        assert_eq!(ctx.breakpoints().collect::<Vec<_>>(), [4]);
+
+        futures_executor::block_on(async {
+            assert!(ctx.next_line(&mut builtin).await.unwrap());
+            assert_eq!(ctx.location().line, 2);
+
+            assert!(ctx.run_to_breakpoint(&mut builtin).await.unwrap());
+            assert_eq!(ctx.location().line, 4);
+            assert_eq!(builtin.text(), "1");
+
+            assert!(!ctx.run_to_breakpoint(&mut builtin).await.unwrap());
+            assert!(ctx.is_finished());
+            assert_eq!(builtin.text(), "123");
+        });
+    }
+
+    #[test]
+    fn watchpoints() {
+        let program =
+            Program::from_source("$호감도 = 3;\n$호감도 = 3;\n$호감도 = 0;\n$기분 = 0;\n@$호감도;")
+                .unwrap();
+        let mut ctx = Context::new(&program);
+        let mut builtin = RecordBuiltin::new();
+        assert!(ctx.set_watchpoint("호감도", Watch::Changed));
+        assert!(ctx.set_watchpoint("기분", Watch::Equals(Value::Int(0))));
+        assert!(!ctx.set_watchpoint("없음", Watch::Changed));
+
+        futures_executor::block_on(async {
+            assert!(ctx.run_to_breakpoint(&mut builtin).await.unwrap());
+            assert_eq!(ctx.watch_hit(), Some("호감도"));
+            assert_eq!(
+                ctx.variables[&program.symbol("호감도").unwrap()],
+                Value::Int(3)
+            );
+
+            assert!(ctx.run_to_breakpoint(&mut builtin).await.unwrap());
+            assert_eq!(ctx.watch_hit(), Some("호감도"));
+            assert_eq!(
+                ctx.variables[&program.symbol("호감도").unwrap()],
+                Value::Int(0)
+            );
+
+            assert!(ctx.run_to_breakpoint(&mut builtin).await.unwrap());
+            assert_eq!(ctx.watch_hit(), Some("기분"));
+
+            ctx.remove_watchpoint("기분");
+            assert_eq!(ctx.watchpoints().count(), 1);
+            assert!(!ctx.run_to_breakpoint(&mut builtin).await.unwrap());
+            assert_eq!(ctx.watch_hit(), None);
+            assert_eq!(builtin.text(), "0");
+        });
+    }
 }
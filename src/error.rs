@@ -1,24 +1,229 @@
-use crate::location::Location;
+use crate::location::{Location, Span};
 use crate::token::Token;
+pub use crate::token::TokenKind;
+use lalrpop_util::ParseError as LalrpopError;
 use std::fmt::{self, Debug, Formatter};
 use thiserror::Error;
 
 pub type ParseError = lalrpop_util::ParseError<Location, Token, LexicalError>;
 
+/// Language of diagnostic messages
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Language {
+    #[default]
+    Korean,
+    English,
+}
+
+impl Language {
+    pub(crate) fn pick(self, korean: &'static str, english: &'static str) -> &'static str {
+        match self {
+            Language::Korean => korean,
+            Language::English => english,
+        }
+    }
+}
+
+/// Structured information of `ParseError` for editor tooling
+#[derive(Clone, Copy)]
+pub struct ParseErrorInfo<'a> {
+    error: &'a ParseError,
+}
+
+impl<'a> ParseErrorInfo<'a> {
+    pub fn new(error: &'a ParseError) -> Self {
+        Self { error }
+    }
+
+    /// Location where parsing failed
+    pub fn location(&self) -> Option<Location> {
+        match self.error {
+            LalrpopError::InvalidToken { location }
+            | LalrpopError::UnrecognizedEOF { location, .. }
+            | LalrpopError::UnrecognizedToken {
+                token: (location, ..),
+                ..
+            }
+            | LalrpopError::ExtraToken {
+                token: (location, ..),
+            } => Some(*location),
+            LalrpopError::User { error } => error.location(),
+        }
+    }
+
+    /// Tokens which parser could accept at error location
+    pub fn expected(&self) -> Vec<TokenKind> {
+        match self.error {
+            LalrpopError::UnrecognizedEOF { expected, .. }
+            | LalrpopError::UnrecognizedToken { expected, .. } => expected
+                .iter()
+                .filter_map(|terminal| TokenKind::from_terminal(terminal))
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// Human readable message of `ParseError`
+pub struct ParseErrorMessage<'a> {
+    error: &'a ParseError,
+    language: Language,
+}
+
+impl<'a> ParseErrorMessage<'a> {
+    pub fn new(error: &'a ParseError, language: Language) -> Self {
+        Self { error, language }
+    }
+
+    fn write_expected(&self, f: &mut Formatter<'_>, expected: &[String]) -> fmt::Result {
+        if expected.is_empty() {
+            return Ok(());
+        }
+
+        let expected = expected
+            .iter()
+            .filter_map(|terminal| TokenKind::from_terminal(terminal))
+            .map(|kind| kind.describe(self.language))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        match self.language {
+            Language::Korean => write!(f, " ({} 중 하나가 필요합니다)", expected),
+            Language::English => write!(f, ", expected one of {}", expected),
+        }
+    }
+}
+
+impl<'a> fmt::Display for ParseErrorMessage<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let language = self.language;
+
+        match self.error {
+            LalrpopError::InvalidToken { location } => match language {
+                Language::Korean => write!(f, "{}에서 잘못된 토큰이 발견됐습니다", location),
+                Language::English => write!(f, "invalid token at {}", location),
+            },
+            LalrpopError::UnrecognizedEOF { location, expected } => {
+                match language {
+                    Language::Korean => {
+                        write!(f, "{}에서 코드가 예상치 못하게 끝났습니다", location)?
+                    }
+                    Language::English => write!(f, "unexpected end of code at {}", location)?,
+                }
+                self.write_expected(f, expected)
+            }
+            LalrpopError::UnrecognizedToken {
+                token: (location, token, _),
+                expected,
+            } => {
+                let token = token.describe(language);
+                match language {
+                    Language::Korean => {
+                        write!(f, "{}에서 예상치 못한 {}가 발견됐습니다", location, token)?
+                    }
+                    Language::English => write!(f, "unexpected {} at {}", token, location)?,
+                }
+                self.write_expected(f, expected)
+            }
+            LalrpopError::ExtraToken {
+                token: (location, token, _),
+            } => {
+                let token = token.describe(language);
+                match language {
+                    Language::Korean => {
+                        write!(f, "{}에서 불필요한 {}가 발견됐습니다", location, token)
+                    }
+                    Language::English => write!(f, "extra {} at {}", token, location),
+                }
+            }
+            LalrpopError::User { error } => write!(f, "{}", error),
+        }
+    }
+}
+
+/// " (혹시 `$체력`?)" suffix of error messages
+fn did_you_mean(sigil: &str, suggestion: &Option<String>) -> String {
+    match suggestion {
+        Some(suggestion) => format!(" (혹시 `{}{}`?)", sigil, suggestion),
+        None => String::new(),
+    }
+}
+
 #[derive(Clone, Error)]
 pub enum LexicalError {
     #[error("코드해석중 {1}에서 에러가 발생했습니다 `{0}`")]
     InvalidCode(&'static str, Location),
     #[error("잘못된 문자 `{0}`가 {1}에서 발견됐습니다")]
     InvalidChar(char, Location),
+    #[error("{1}의 숫자 `{0}`가 너무 큽니다 (최대 {})", u32::MAX)]
+    IntegerOverflow(String, Location),
     #[error("예상치 못한 토큰 `{0}`가 {1}에서 발견됐습니다")]
     UnexpectedToken(String, Location),
     #[error("컴파일중 {1}에서 에러가 발생했습니다 `{0}`")]
     CompileError(String, Location),
+    #[error("{2}에서 선언되지 않은 `${0}` 변수가 사용됐습니다{}", did_you_mean("$", .1))]
+    UndeclaredVariable(String, Option<String>, Location),
+    #[error("{1}에서 `{0}` 장면이 다시 정의됐습니다")]
+    DuplicateLabel(String, Location),
+    #[error("{1}에서 알수 없는 `{0}` 타입이 사용됐습니다 (`수`, `문자열`, `참거짓`, `목록` 중 하나가 필요합니다)")]
+    UnknownType(String, Location),
+    #[error("{1}에서 `${0}` 변수의 타입이 다르게 선언됐습니다")]
+    TypeConflict(String, Location),
+    #[error("{1}에서 사용된 `{0}` 자원이 목록에 없습니다")]
+    MissingAsset(String, Location),
+    #[error("{1}에서 포함한 `{0}` 파일을 찾을 수 없습니다")]
+    IncludeNotFound(String, Location),
+    #[error("{1}에서 포함한 `{0}` 파일이 자기 자신을 다시 포함합니다")]
+    IncludeCycle(String, Location),
+    /// Error of included file, location is `포함` statement of including file
+    #[error("{2}에서 포함한 `{0}` 파일에 에러가 있습니다: {1}")]
+    InInclude(String, Box<ParseError>, Location),
     #[error("예상치 못하게 코드가 끝났습니다")]
     UnexpectedEndOfToken,
 }
 
+impl LexicalError {
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            LexicalError::InvalidCode(_, location)
+            | LexicalError::InvalidChar(_, location)
+            | LexicalError::IntegerOverflow(_, location)
+            | LexicalError::UnexpectedToken(_, location)
+            | LexicalError::CompileError(_, location)
+            | LexicalError::UndeclaredVariable(.., location)
+            | LexicalError::DuplicateLabel(_, location)
+            | LexicalError::UnknownType(_, location)
+            | LexicalError::TypeConflict(_, location)
+            | LexicalError::MissingAsset(_, location)
+            | LexicalError::IncludeNotFound(_, location)
+            | LexicalError::IncludeCycle(_, location)
+            | LexicalError::InInclude(.., location) => Some(*location),
+            LexicalError::UnexpectedEndOfToken => None,
+        }
+    }
+
+    /// Same error at location returned by `f`
+    pub fn map_location(mut self, f: impl FnOnce(Location) -> Location) -> Self {
+        match &mut self {
+            LexicalError::InvalidCode(_, location)
+            | LexicalError::InvalidChar(_, location)
+            | LexicalError::IntegerOverflow(_, location)
+            | LexicalError::UnexpectedToken(_, location)
+            | LexicalError::CompileError(_, location)
+            | LexicalError::UndeclaredVariable(.., location)
+            | LexicalError::DuplicateLabel(_, location)
+            | LexicalError::UnknownType(_, location)
+            | LexicalError::TypeConflict(_, location)
+            | LexicalError::MissingAsset(_, location)
+            | LexicalError::IncludeNotFound(_, location)
+            | LexicalError::IncludeCycle(_, location)
+            | LexicalError::InInclude(.., location) => *location = f(*location),
+            LexicalError::UnexpectedEndOfToken => {}
+        }
+        self
+    }
+}
+
 impl Debug for LexicalError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self)
@@ -27,12 +232,198 @@ impl Debug for LexicalError {
 
 pub type LexicalResult<T> = Result<T, LexicalError>;
 
+#[derive(Error)]
+pub enum SourceError {
+    #[error("{0}번째 바이트에서 잘못된 UTF-8 인코딩이 발견됐습니다")]
+    InvalidUtf8(usize),
+    #[error("IO 에러: {0}")]
+    IoError(#[from] std::io::Error),
+}
+
+impl Debug for SourceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[derive(Clone, Error, Eq, PartialEq)]
+pub enum CompileWarning {
+    #[error("{3}에서 `{0}` 함수는 인자 {1}개가 필요하지만 {2}개가 주어졌습니다")]
+    ArgumentCount(String, usize, usize, Location),
+    #[error("{3}에서 `{0}` 함수의 {1}번째 인자는 `{2}` 타입이어야 합니다")]
+    ArgumentType(String, usize, &'static str, Location, Span),
+    #[error("{2}에서 등록되지 않은 `{0}` 함수를 호출합니다{}", did_you_mean("", .1))]
+    UnknownBuiltin(String, Option<String>, Location),
+    #[error("{1}에서 `${0}` 변수가 할당되기 전에 사용될수 있습니다")]
+    UseBeforeAssign(String, Location),
+    #[error("{}에서 조건식이 항상 {}입니다", .1, if *.0 { "참" } else { "거짓" })]
+    ConstantCondition(bool, Location),
+    #[error("{0}의 코드는 실행될수 없습니다")]
+    UnreachableCode(Location),
+    #[error("{2}에서 `{1}` 타입인 `${0}` 변수에 다른 타입의 값을 할당합니다")]
+    VariableType(String, &'static str, Location, Span),
+    /// Warning of included file, id is index of `Program::files`
+    #[error("{0}번 파일의 {1}")]
+    InFile(u32, Box<CompileWarning>),
+}
+
+impl CompileWarning {
+    pub fn location(&self) -> Location {
+        match self {
+            CompileWarning::ArgumentCount(.., location)
+            | CompileWarning::ArgumentType(.., location, _)
+            | CompileWarning::UnknownBuiltin(.., location)
+            | CompileWarning::UseBeforeAssign(.., location)
+            | CompileWarning::ConstantCondition(.., location)
+            | CompileWarning::UnreachableCode(location)
+            | CompileWarning::VariableType(.., location, _) => *location,
+            CompileWarning::InFile(_, warning) => warning.location(),
+        }
+    }
+
+    /// Same warning at location returned by `f`
+    pub fn map_location(mut self, f: impl FnOnce(Location) -> Location) -> Self {
+        match &mut self {
+            CompileWarning::ArgumentCount(.., location)
+            | CompileWarning::ArgumentType(.., location, _)
+            | CompileWarning::UnknownBuiltin(.., location)
+            | CompileWarning::UseBeforeAssign(.., location)
+            | CompileWarning::ConstantCondition(.., location)
+            | CompileWarning::UnreachableCode(location)
+            | CompileWarning::VariableType(.., location, _) => *location = f(*location),
+            CompileWarning::InFile(_, warning) => **warning = warning.clone().map_location(f),
+        }
+        self
+    }
+
+    /// Id of file of warning, `0` is entry file
+    pub fn file(&self) -> u32 {
+        match self {
+            CompileWarning::InFile(file, _) => *file,
+            _ => 0,
+        }
+    }
+
+    /// Stable name of warning kind, tools can use it for filtering or configuring severity
+    pub fn code(&self) -> &'static str {
+        match self {
+            CompileWarning::ArgumentCount(..) => "argument-count",
+            CompileWarning::ArgumentType(..) => "argument-type",
+            CompileWarning::UnknownBuiltin(..) => "unknown-builtin",
+            CompileWarning::UseBeforeAssign(..) => "use-before-assign",
+            CompileWarning::ConstantCondition(..) => "constant-condition",
+            CompileWarning::UnreachableCode(..) => "unreachable-code",
+            CompileWarning::VariableType(..) => "variable-type",
+            CompileWarning::InFile(_, warning) => warning.code(),
+        }
+    }
+
+    /// Source range of expression which caused warning, for pointing at exact operand
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            CompileWarning::ArgumentType(.., span) | CompileWarning::VariableType(.., span) => {
+                Some(*span)
+            }
+            CompileWarning::InFile(_, warning) => warning.span(),
+            _ => None,
+        }
+    }
+}
+
+impl Debug for CompileWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
 #[derive(Clone, Error)]
 pub enum RuntimeError {
     #[error("{1}번째 줄 실행중 에러발생 {0}")]
     ExecutionError(&'static str, usize),
+    #[error("{2}번째 줄 실행중 `${0}` 변수를 찾을수 없습니다{}", did_you_mean("$", .1))]
+    UndefinedVariable(String, Option<String>, usize),
     #[error("{1}번째 줄 실행중 잘못된 `{0}` 타입이 들어왔습니다")]
     TypeError(&'static str, usize),
+    #[error("{3}번째 줄 실행중 `{1}` 타입인 `${0}` 변수에 `{2}` 타입이 들어왔습니다")]
+    VariableType(String, &'static str, &'static str, usize),
+    #[error("{3}번째 줄 실행중 `{1}` 타입을 반환하는 `{0}` 함수가 `{2}` 타입을 반환했습니다")]
+    ReturnType(String, &'static str, &'static str, usize),
+    #[error("{0}번째 줄 실행중 실행 한도를 초과했습니다")]
+    OutOfFuel(usize),
+    #[error("{0}번째 줄 실행중 스택 한도를 초과했습니다")]
+    StackOverflow(usize),
+    #[error("{1}번째 줄에서 허용되지 않은 `{0}` 함수를 호출했습니다")]
+    Forbidden(String, usize),
+    #[error("{0}번째 줄 실행이 중단되었습니다")]
+    Aborted(usize),
+    /// Error in nested script with frames from innermost call
+    #[error("{0}{}", render_frames(.1))]
+    Traced(Box<RuntimeError>, Vec<Frame>),
+}
+
+/// Script and line of one call in `RuntimeError::Traced`
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Frame {
+    pub script: String,
+    pub line: usize,
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}번째 줄", self.script, self.line)
+    }
+}
+
+fn render_frames(frames: &[Frame]) -> String {
+    frames
+        .iter()
+        .map(|frame| format!("\n    {}", frame))
+        .collect()
+}
+
+impl RuntimeError {
+    /// Line where error occurred
+    pub fn line(&self) -> usize {
+        match self {
+            RuntimeError::ExecutionError(_, line)
+            | RuntimeError::UndefinedVariable(.., line)
+            | RuntimeError::TypeError(_, line)
+            | RuntimeError::VariableType(.., line)
+            | RuntimeError::ReturnType(.., line)
+            | RuntimeError::OutOfFuel(line)
+            | RuntimeError::StackOverflow(line)
+            | RuntimeError::Forbidden(_, line)
+            | RuntimeError::Aborted(line) => *line,
+            RuntimeError::Traced(err, _) => err.line(),
+        }
+    }
+
+    /// Add caller frame, first frame is where error occurred
+    pub fn with_frame(self, frame: Frame) -> Self {
+        match self {
+            RuntimeError::Traced(err, mut frames) => {
+                frames.push(frame);
+                RuntimeError::Traced(err, frames)
+            }
+            err => RuntimeError::Traced(Box::new(err), vec![frame]),
+        }
+    }
+
+    /// Call stack from innermost frame, empty when error isn't traced
+    pub fn frames(&self) -> &[Frame] {
+        match self {
+            RuntimeError::Traced(_, frames) => frames,
+            _ => &[],
+        }
+    }
+
+    /// Error without trace
+    pub fn root(&self) -> &RuntimeError {
+        match self {
+            RuntimeError::Traced(err, _) => err,
+            err => err,
+        }
+    }
 }
 
 impl Debug for RuntimeError {
@@ -42,3 +433,182 @@ impl Debug for RuntimeError {
 }
 
 pub type RuntimeResult<T> = Result<T, RuntimeError>;
+
+#[cfg(feature = "runtime")]
+#[derive(Clone, Error, Eq, PartialEq)]
+pub enum SaveError {
+    #[error(
+        "지원하지 않는 {0}버전 세이브입니다 (최대 {})",
+        crate::snapshot::SAVE_FORMAT_VERSION
+    )]
+    UnsupportedVersion(u32),
+    #[error("현재 스크립트와 맞지 않는 세이브입니다")]
+    Incompatible,
+}
+
+#[cfg(feature = "runtime")]
+impl Debug for SaveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Table file which can't be loaded by `kes::table::Table`
+#[cfg(feature = "runtime")]
+#[derive(Clone, Error, Eq, PartialEq)]
+pub enum TableError {
+    #[error("CSV {1}번째 줄: {0}")]
+    Csv(&'static str, usize),
+    #[error("JSON: {0}")]
+    Json(String),
+    #[error("지원하지 않는 표 형식입니다 `{0}`")]
+    UnsupportedFormat(String),
+}
+
+#[cfg(feature = "runtime")]
+impl Debug for TableError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Ink story which can't be converted by `kes::ink`
+#[cfg(feature = "ink")]
+#[derive(Clone, Error, Eq, PartialEq)]
+pub enum ImportError {
+    #[error("{1}의 {0}는 지원하지 않습니다")]
+    Unsupported(&'static str, Location),
+    #[error("{1}의 식 `{0}`를 해석할수 없습니다")]
+    InvalidExpression(String, Location),
+    #[error("{0}에서 시작한 조건문이 닫히지 않았습니다")]
+    UnclosedConditional(Location),
+}
+
+#[cfg(feature = "ink")]
+impl Debug for ImportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Problem in instructions found by `kes::bytecode::verify`
+#[cfg(feature = "compiler")]
+#[derive(Clone, Error, Eq, PartialEq)]
+pub enum VerifyError {
+    #[error("{0}번째 명령어가 범위를 벗어난 {1}번째 명령어로 이동합니다")]
+    InvalidTarget(usize, u32),
+    #[error("{0}번째 명령어가 없는 심볼을 사용합니다")]
+    UnknownSymbol(usize),
+    #[error("{0}번째 명령어의 타이머가 `EndTimer`로 끝나지 않습니다")]
+    UnclosedTimer(usize),
+    #[error("{0}번째 명령어의 점프 테이블 뒤에 `Goto`가 부족합니다")]
+    InvalidJumpTable(usize),
+}
+
+#[cfg(feature = "compiler")]
+impl Debug for VerifyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Serialized `Program` made by other format, see `kes::program::PROGRAM_FORMAT_VERSION`
+#[cfg(feature = "compiler")]
+#[derive(Clone, Error, Eq, PartialEq)]
+pub enum ProgramFormatError {
+    #[error(
+        "지원하지 않는 {0}버전 프로그램입니다 (현재 {})",
+        crate::program::PROGRAM_FORMAT_VERSION
+    )]
+    UnsupportedVersion(u32),
+    #[error("kes 프로그램이 아니거나 버전 정보가 없는 이전 형식입니다, 다시 컴파일해야 합니다")]
+    Unknown,
+}
+
+#[cfg(feature = "compiler")]
+impl Debug for ProgramFormatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+/// Requirements of program which host doesn't support, see `kes::compat`
+#[cfg(feature = "compiler")]
+#[derive(Clone, Error, Eq, PartialEq)]
+#[error("스크립트 실행에 필요한 기능이 없습니다: {}", join_requirements(.0))]
+pub struct CompatError(pub Vec<crate::compat::Requirement>);
+
+#[cfg(feature = "compiler")]
+fn join_requirements(requirements: &[crate::compat::Requirement]) -> String {
+    requirements
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(feature = "compiler")]
+impl Debug for CompatError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Language, ParseErrorInfo, ParseErrorMessage, TokenKind};
+    use crate::interner::Interner;
+    use crate::location::Location;
+    use crate::parser::parse;
+    use pretty_assertions::assert_eq;
+
+    fn message(code: &str, language: Language) -> String {
+        let err = parse(code, &mut Interner::new()).unwrap_err();
+        ParseErrorMessage::new(&err, language).to_string()
+    }
+
+    #[test]
+    fn unrecognized_token() {
+        assert_eq!(
+            message("$1 = ;", Language::Korean),
+            "L1에서 예상치 못한 `;`가 발견됐습니다 (`!`, `(`, `[`, `거짓`, `없음`, `자원`, `참`, 함수 이름, 숫자, 문자열, 변수 중 하나가 필요합니다)"
+        );
+        assert_eq!(
+            message("@1 )", Language::English),
+            "unexpected `)` at L1, expected one of `!`, `(`, `;`, `?`, `[`, `거짓`, `없음`, `자원`, `참`, function name, number, string, variable"
+        );
+    }
+
+    #[test]
+    fn unrecognized_eof() {
+        assert_eq!(
+            message("만약 1 {", Language::Korean),
+            "L1에서 코드가 예상치 못하게 끝났습니다 (`!`, `(`, `@`, `@@`, `@!`, `[`, `}`, `각각`, `거짓`, `기다리기`, `만약`, `반복`, `변수`, `선택`, `시작`, `없음`, `자원`, `종료`, `참`, `타이머`, 함수 이름, 숫자, 문자열, 변수 중 하나가 필요합니다)"
+        );
+    }
+
+    #[test]
+    fn expected_kinds() {
+        let err = parse("@1;\n$1 = ", &mut Interner::new()).unwrap_err();
+        let info = ParseErrorInfo::new(&err);
+
+        assert_eq!(info.location(), Some(Location::new(2)));
+        assert_eq!(
+            info.expected(),
+            [
+                TokenKind::Punct("!"),
+                TokenKind::Punct("("),
+                TokenKind::Punct("["),
+                TokenKind::Keyword("거짓"),
+                TokenKind::Keyword("없음"),
+                TokenKind::Keyword("자원"),
+                TokenKind::Keyword("참"),
+                TokenKind::Builtin,
+                TokenKind::IntLit,
+                TokenKind::StrLit,
+                TokenKind::Variable,
+            ]
+        );
+        assert_eq!(info.expected()[1].text(), Some("("));
+    }
+}
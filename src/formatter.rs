@@ -1,7 +1,10 @@
-use crate::error::ParseError;
+use crate::error::{Language, ParseError, ParseErrorMessage, SourceError};
 use crate::interner::Symbol;
-use crate::parser::parse_with_comments;
-use crate::{ast::Expr, location::Location};
+use crate::lexer::{escape_str, Lexer, Position, StoreComment};
+use crate::operator::BinaryOperator;
+use crate::parser::{parse_recovering_at, parse_with_comments, statement_spans, StmtSpan};
+use crate::source::Source;
+use crate::{ast::Expr, ast::ExprKind, location::Location, location::Span};
 use crate::{ast::Stmt, interner::Interner};
 use std::collections::BTreeMap;
 use std::fmt;
@@ -10,10 +13,12 @@ use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum FormatError {
-    #[error("파싱에러: {0:?}")]
+    #[error("파싱에러: {}", ParseErrorMessage::new(.0, Language::Korean))]
     ParseError(ParseError),
     #[error("IO 에러: {0}")]
     IoError(#[from] io::Error),
+    #[error("소스 에러: {0}")]
+    SourceError(#[from] SourceError),
 }
 
 impl<'s> From<ParseError> for FormatError {
@@ -22,6 +27,37 @@ impl<'s> From<ParseError> for FormatError {
     }
 }
 
+/// Options of formatter, default keeps output of previous versions
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FormatOptions {
+    /// Print values aren't separated by space next to full-width brackets and punctuations
+    ///
+    /// `@'「' $이름 '」';` becomes `@'「'$이름'」';`, output of script is same
+    pub tight_punctuation: bool,
+}
+
+/// Opening quotes and brackets, value after them isn't separated
+const OPENING_PUNCTS: &[char] = &['「', '『', '“', '‘', '《', '〈', '（', '［', '【'];
+
+/// Closing quotes, brackets and punctuations, value before them isn't separated
+const CLOSING_PUNCTS: &[char] = &[
+    '」', '』', '”', '’', '》', '〉', '）', '］', '】', '。', '，', '、', '！', '？', '：', '；',
+    '…',
+];
+
+/// Whether adjacent print values are written without space by `tight_punctuation`
+///
+/// Only characters at the edge of string literals are checked, text in literal is never changed
+fn is_tight_pair(lhs: &Expr, rhs: &Expr, interner: &Interner) -> bool {
+    let text = |expr: &Expr| match expr.kind {
+        ExprKind::String(sym) => interner.resolve(sym),
+        _ => None,
+    };
+
+    text(lhs).map_or(false, |text| text.ends_with(OPENING_PUNCTS))
+        || text(rhs).map_or(false, |text| text.starts_with(CLOSING_PUNCTS))
+}
+
 struct IndentWriter<W: Write> {
     out: W,
     indent_writed: bool,
@@ -58,8 +94,9 @@ impl<W: Write> Write for IndentWriter<W> {
             self.indent_writed = true;
         }
 
-        if memchr::memchr(b'\n', buf).is_some() {
-            self.indent_writed = false;
+        // line break inside `'''` literal isn't start of line
+        if let Some(last) = buf.last() {
+            self.indent_writed = *last != b'\n';
         }
 
         self.out.write(buf)
@@ -75,6 +112,22 @@ struct ExprDisplay<'a> {
     interner: &'a Interner,
 }
 
+/// Check operand of binary operator needs parentheses to keep same AST
+fn binary_operand_needs_paren(operand: &Expr, op: BinaryOperator, is_rhs: bool) -> bool {
+    match &operand.kind {
+        ExprKind::BinaryOp { op: child, .. } => {
+            // every binary operator is left associative
+            if is_rhs {
+                child.precedence() <= op.precedence()
+            } else {
+                child.precedence() < op.precedence()
+            }
+        }
+        ExprKind::TernaryOp { .. } => true,
+        _ => false,
+    }
+}
+
 impl<'a> ExprDisplay<'a> {
     fn display(&self, expr: &'a Expr) -> Self {
         Self {
@@ -83,18 +136,62 @@ impl<'a> ExprDisplay<'a> {
         }
     }
 
+    fn write_operand(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        operand: &'a Expr,
+        needs_paren: bool,
+    ) -> fmt::Result {
+        if needs_paren {
+            write!(f, "({})", self.display(operand))
+        } else {
+            write!(f, "{}", self.display(operand))
+        }
+    }
+
     fn resolve(&self, sym: Symbol) -> &str {
         self.interner.resolve(sym).unwrap()
     }
 }
 
+/// Multi-line text is kept as `'''` literal when it can be written raw
+fn write_str_literal(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    if text.contains('\n') && !text.contains("'''") && !text.ends_with('\'') {
+        // single write so `IndentWriter` never indents inside literal
+        f.write_str(&format!("'''{}'''", text))
+    } else {
+        write!(f, "'{}'", escape_str(text))
+    }
+}
+
 impl<'a> fmt::Display for ExprDisplay<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.expr {
-            Expr::Number(num) => write!(f, "{}", num),
-            Expr::String(sym) => write!(f, "'{}'", self.resolve(*sym)),
-            Expr::Variable(sym) => write!(f, "${}", self.resolve(*sym)),
-            Expr::BuiltinFunc { name, args } => {
+        match &self.expr.kind {
+            ExprKind::Number(num) => write!(f, "{}", num),
+            ExprKind::Bool(true) => f.write_str("참"),
+            ExprKind::Bool(false) => f.write_str("거짓"),
+            ExprKind::Null => f.write_str("없음"),
+            ExprKind::String(sym) => write_str_literal(f, self.resolve(*sym)),
+            ExprKind::Asset(sym) => {
+                f.write_str("자원(")?;
+                write_str_literal(f, self.resolve(*sym))?;
+                f.write_str(")")
+            }
+            ExprKind::Variable(sym) => write!(f, "${}", self.resolve(*sym)),
+            ExprKind::List(items) => {
+                f.write_str("[")?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{}", self.display(item))?;
+                }
+                f.write_str("]")
+            }
+            ExprKind::Index { value, index } => {
+                write!(f, "{}[{}]", self.display(value), self.display(index))
+            }
+            ExprKind::BuiltinFunc { name, args } => {
                 write!(f, "{}(", self.resolve(*name))?;
 
                 for (idx, arg) in args.iter().enumerate() {
@@ -106,29 +203,28 @@ impl<'a> fmt::Display for ExprDisplay<'a> {
 
                 write!(f, ")")
             }
-            Expr::Nop(value) => write!(f, "({})", self.display(value)),
-            Expr::BinaryOp { lhs, rhs, op } => {
-                write!(
-                    f,
-                    "{} {} {}",
-                    self.display(lhs),
-                    op.name(),
-                    self.display(rhs)
-                )
+            ExprKind::Nop(value) => write!(f, "({})", self.display(value)),
+            ExprKind::BinaryOp { lhs, rhs, op } => {
+                self.write_operand(f, lhs, binary_operand_needs_paren(lhs, *op, false))?;
+                write!(f, " {} ", op.name())?;
+                self.write_operand(f, rhs, binary_operand_needs_paren(rhs, *op, true))
             }
-            Expr::UnaryOp { value, op } => {
-                write!(f, "{}{}", op.name(), self.display(value))
+            ExprKind::UnaryOp { value, op } => {
+                f.write_str(op.name())?;
+                let needs_paren = matches!(
+                    value.kind,
+                    ExprKind::UnaryOp { .. }
+                        | ExprKind::BinaryOp { .. }
+                        | ExprKind::TernaryOp { .. }
+                );
+                self.write_operand(f, value, needs_paren)
             }
-            Expr::TernaryOp { lhs, mhs, rhs, op } => {
-                write!(
-                    f,
-                    "{} {} {} {} {}",
-                    self.display(lhs),
-                    op.first_name(),
-                    self.display(mhs),
-                    op.second_name(),
-                    self.display(rhs)
-                )
+            ExprKind::TernaryOp { lhs, mhs, rhs, op } => {
+                // ternary operator is left associative
+                write!(f, "{} {} ", self.display(lhs), op.first_name())?;
+                self.write_operand(f, mhs, matches!(mhs.kind, ExprKind::TernaryOp { .. }))?;
+                write!(f, " {} ", op.second_name())?;
+                self.write_operand(f, rhs, matches!(rhs.kind, ExprKind::TernaryOp { .. }))
             }
         }
     }
@@ -139,6 +235,7 @@ struct CodeFormatter<'a, W: Write> {
     interner: &'a Interner,
     comments: &'a BTreeMap<Location, &'a str>,
     last_location: Location,
+    options: FormatOptions,
 }
 
 impl<'a, W: Write> CodeFormatter<'a, W> {
@@ -148,9 +245,15 @@ impl<'a, W: Write> CodeFormatter<'a, W> {
             interner,
             comments,
             last_location: Location::new(0),
+            options: FormatOptions::default(),
         }
     }
 
+    pub fn options(mut self, options: FormatOptions) -> Self {
+        self.options = options;
+        self
+    }
+
     pub fn write_program(&mut self, program: &[Stmt]) -> io::Result<()> {
         for stmt in program.iter() {
             self.write_stmt(stmt)?;
@@ -211,7 +314,7 @@ impl<'a, W: Write> CodeFormatter<'a, W> {
 
     pub fn write_stmt(&mut self, stmt: &Stmt) -> io::Result<()> {
         {
-            if stmt.is_block() {
+            if stmt.is_block() || matches!(stmt, Stmt::Label { .. }) {
                 writeln!(self.o)?;
             }
             self.write_comment(stmt.location())?;
@@ -226,20 +329,86 @@ impl<'a, W: Write> CodeFormatter<'a, W> {
         }
 
         match stmt {
-            Stmt::Assign { var, value, .. } => {
+            Stmt::Assign { var, ty, value, .. } => {
+                write!(self.o, "${}", res!(*var))?;
+                if let Some(ty) = ty {
+                    write!(self.o, ": {}", res!(*ty))?;
+                }
                 writeln!(
                     self.o,
-                    "${} = {};",
-                    res!(*var),
+                    " = {};",
                     ExprDisplay {
                         expr: &value,
                         interner
                     }
                 )?;
             }
+            Stmt::SetIndex {
+                var,
+                indices,
+                value,
+                ..
+            } => {
+                write!(self.o, "${}", res!(*var))?;
+                for index in indices {
+                    write!(
+                        self.o,
+                        "[{}]",
+                        ExprDisplay {
+                            expr: index,
+                            interner
+                        }
+                    )?;
+                }
+                writeln!(
+                    self.o,
+                    " = {};",
+                    ExprDisplay {
+                        expr: value,
+                        interner
+                    }
+                )?;
+            }
+            Stmt::Declare { var, value, .. } => match value {
+                Some(value) => writeln!(
+                    self.o,
+                    "변수 ${} = {};",
+                    res!(*var),
+                    ExprDisplay {
+                        expr: value,
+                        interner
+                    }
+                )?,
+                None => writeln!(self.o, "변수 ${};", res!(*var))?,
+            },
             Stmt::Exit { .. } => {
                 writeln!(self.o, "종료;")?;
             }
+            Stmt::Spawn { name, args, .. } => {
+                writeln!(
+                    self.o,
+                    "시작 {};",
+                    ExprDisplay {
+                        expr: &Expr::new(
+                            ExprKind::BuiltinFunc {
+                                name: *name,
+                                args: args.clone(),
+                            },
+                            Span::default(),
+                        ),
+                        interner
+                    }
+                )?;
+            }
+            Stmt::Join { .. } => {
+                writeln!(self.o, "기다리기;")?;
+            }
+            Stmt::Label { name, .. } => {
+                writeln!(self.o, "장면 {};", res!(*name))?;
+            }
+            Stmt::Include { path, .. } => {
+                writeln!(self.o, "포함 '{}';", escape_str(res!(*path)))?;
+            }
             Stmt::If {
                 arms,
                 other,
@@ -283,10 +452,82 @@ impl<'a, W: Write> CodeFormatter<'a, W> {
                 self.write_stmt_block(body)?;
                 self.o.write_all(b"\n\n")?;
             }
+            Stmt::Timer { delay, body, .. } => {
+                write!(
+                    self.o,
+                    "타이머 {} ",
+                    ExprDisplay {
+                        expr: delay,
+                        interner
+                    }
+                )?;
+                self.write_stmt_block(body)?;
+                self.o.write_all(b"\n\n")?;
+            }
+            Stmt::ForEach {
+                var, iter, body, ..
+            } => {
+                write!(
+                    self.o,
+                    "각각 ${} {} ",
+                    res!(*var),
+                    ExprDisplay {
+                        expr: iter,
+                        interner
+                    }
+                )?;
+                self.write_stmt_block(body)?;
+                self.o.write_all(b"\n\n")?;
+            }
+            Stmt::Select {
+                value,
+                arms,
+                other,
+                other_location,
+                ..
+            } => {
+                writeln!(
+                    self.o,
+                    "선택 {} {{",
+                    ExprDisplay {
+                        expr: value,
+                        interner
+                    }
+                )?;
+                self.o.push_block();
+                for (values, body, location) in arms.iter() {
+                    self.write_comment(*location)?;
+                    for (idx, value) in values.iter().enumerate() {
+                        if idx != 0 {
+                            self.o.write_all(b", ")?;
+                        }
+                        write!(
+                            self.o,
+                            "{}",
+                            ExprDisplay {
+                                expr: value,
+                                interner
+                            }
+                        )?;
+                    }
+                    self.o.write_all(b" ")?;
+                    self.write_stmt_block(body)?;
+                    writeln!(self.o)?;
+                }
+                if !other.is_empty() {
+                    self.write_comment(*other_location)?;
+                    self.o.write_all("그외 ".as_bytes())?;
+                    self.write_stmt_block(other)?;
+                    writeln!(self.o)?;
+                }
+                self.o.pop_block();
+                self.o.write_all(b"}\n\n")?;
+            }
             Stmt::Print {
                 newline,
                 wait,
                 values,
+                channel,
                 ..
             } => {
                 let prefix = if *wait {
@@ -299,6 +540,13 @@ impl<'a, W: Write> CodeFormatter<'a, W> {
 
                 self.o.write_all(prefix.as_bytes())?;
 
+                if let Some(channel) = channel {
+                    write!(self.o, "#{}", interner.resolve(*channel).unwrap())?;
+                    if !values.is_empty() {
+                        self.o.write_all(b" ")?;
+                    }
+                }
+
                 for (idx, value) in values.iter().enumerate() {
                     write!(
                         self.o,
@@ -309,8 +557,12 @@ impl<'a, W: Write> CodeFormatter<'a, W> {
                         }
                     )?;
 
-                    if idx != values.len() - 1 {
-                        self.o.write_all(b" ")?;
+                    match values.get(idx + 1) {
+                        Some(next)
+                            if self.options.tight_punctuation
+                                && is_tight_pair(value, next, interner) => {}
+                        Some(_) => self.o.write_all(b" ")?,
+                        None => {}
                     }
                 }
 
@@ -326,12 +578,232 @@ impl<'a, W: Write> CodeFormatter<'a, W> {
 }
 
 pub fn format_code(code: &str, out: impl Write) -> Result<(), FormatError> {
+    format_code_with_options(code, out, FormatOptions::default())
+}
+
+/// Comment which changes formatting of top-level statements
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Directive {
+    /// `# kesfmt: off`, statements until `# kesfmt: on` are kept as written
+    Off,
+    /// `# kesfmt: on`
+    On,
+    /// `# kesfmt: skip`, next statement is kept as written
+    Skip,
+}
+
+fn directive(comment: &str) -> Option<Directive> {
+    match comment.trim().strip_prefix("kesfmt:")?.trim() {
+        "off" => Some(Directive::Off),
+        "on" => Some(Directive::On),
+        "skip" => Some(Directive::Skip),
+        _ => None,
+    }
+}
+
+/// Whether each top-level statement is kept as written by directives in `comments`
+///
+/// Directive in block applies to whole top-level statement which has it
+fn kept_by_directives(
+    text: &str,
+    spans: &[StmtSpan],
+    comments: &BTreeMap<Location, &str>,
+) -> Vec<bool> {
+    // first and last line of each statement
+    let mut lines = Vec::with_capacity(spans.len());
+    let mut line = 1;
+    let mut end = 0;
+    for span in spans.iter().map(|stmt| stmt.span) {
+        line += text[end..span.start].matches('\n').count();
+        let first = line;
+        line += text[span.start..span.end].matches('\n').count();
+        lines.push((first, line));
+        end = span.end;
+    }
+
+    let mut keep = vec![false; spans.len()];
+    let mut mark = |from: usize, to: usize| {
+        for (keep, &(first, last)) in keep.iter_mut().zip(&lines) {
+            *keep |= last > from && first < to;
+        }
+    };
+    let mut off = None;
+
+    for (location, comment) in comments {
+        match directive(comment) {
+            Some(Directive::Off) => {
+                off.get_or_insert(location.line);
+            }
+            Some(Directive::On) => {
+                if let Some(from) = off.take() {
+                    mark(from, location.line);
+                }
+            }
+            // comment at end of line is after statement on that line
+            Some(Directive::Skip) => {
+                if let Some(idx) = lines.iter().position(|&(_, last)| last > location.line) {
+                    mark(location.line, lines[idx].0 + 1);
+                }
+            }
+            None => {}
+        }
+    }
+
+    if let Some(from) = off {
+        mark(from, usize::MAX);
+    }
+
+    keep
+}
+
+/// Write top-level statements with `keep` as written with comments before them, format others
+///
+/// Statements after last kept one are written as written with their errors in `errors`
+/// when they can't be parsed together, like tokens after `}` which doesn't close any block
+fn write_kept(
+    mut out: impl Write,
+    text: &str,
+    interner: &mut Interner,
+    spans: &[StmtSpan],
+    keep: &[bool],
+    options: FormatOptions,
+    errors: &mut Vec<ParseError>,
+) -> Result<(), FormatError> {
+    // formatted statements start at `start`, `end` is end of last statement
+    let mut start = 0;
+    let mut end = 0;
+
+    for (span, &keep) in spans.iter().map(|stmt| stmt.span).zip(keep) {
+        if keep {
+            let (program, comments) = parse_with_comments(&text[start..end], interner)?;
+            CodeFormatter::new(&mut out, interner, &comments)
+                .options(options)
+                .write_program(&program)?;
+            writeln!(out, "{}", text[end..span.end].trim_start())?;
+            start = span.end;
+        }
+
+        end = span.end;
+    }
+
+    match parse_with_comments(&text[start..], interner) {
+        Ok((program, comments)) => CodeFormatter::new(&mut out, interner, &comments)
+            .options(options)
+            .write_program(&program)?,
+        Err(_) => {
+            let position = Position {
+                location: Location::new(text[..start].matches('\n').count() + 1),
+                offset: start,
+            };
+            writeln!(out, "{}", text[start..].trim())?;
+            errors.extend(parse_recovering_at(&text[start..], interner, position).1);
+        }
+    }
+
+    Ok(())
+}
+
+/// Format code, string literals are always written as they are
+///
+/// Top-level statements between `# kesfmt: off` and `# kesfmt: on` comments
+/// and statement after `# kesfmt: skip` are kept as written
+pub fn format_code_with_options(
+    code: &str,
+    mut out: impl Write,
+    options: FormatOptions,
+) -> Result<(), FormatError> {
+    let mut interner = Interner::new();
+    let source = Source::new(code);
+    let text = source.as_str();
+    let (program, comments) = parse_with_comments(text, &mut interner)?;
+
+    // front matter is kept as written
+    if !source.header().is_empty() {
+        writeln!(out, "{}", source.header()).map_err(FormatError::IoError)?;
+    }
+
+    if !comments
+        .values()
+        .any(|comment| directive(comment).is_some())
+    {
+        return CodeFormatter::new(out, &interner, &comments)
+            .options(options)
+            .write_program(&program)
+            .map_err(FormatError::IoError);
+    }
+
+    let spans = statement_spans(text, &mut interner).map_err(|error| ParseError::User { error })?;
+    let keep = kept_by_directives(text, &spans, &comments);
+    write_kept(
+        out,
+        text,
+        &mut interner,
+        &spans,
+        &keep,
+        options,
+        &mut Vec::new(),
+    )
+}
+
+/// Format code like `format_code_with_options` even if some statements can't be parsed
+///
+/// Top-level statements with parse error are written as they are with comments before them,
+/// returns their errors. Whole code is written as it is when lexer fails
+pub fn format_code_lossy(
+    code: &str,
+    mut out: impl Write,
+    options: FormatOptions,
+) -> Result<Vec<ParseError>, FormatError> {
     let mut interner = Interner::new();
-    let (program, comments) = parse_with_comments(code, &mut interner)?;
+    let source = Source::new(code);
+    let text = source.as_str();
+    let header = source.header();
+
+    let spans = match statement_spans(text, &mut interner) {
+        Ok(spans) => spans,
+        Err(error) => {
+            write!(out, "{}{}", header, &text[header.len()..])?;
+            return Ok(vec![ParseError::User { error }]);
+        }
+    };
+
+    if !header.is_empty() {
+        writeln!(out, "{}", header)?;
+    }
+
+    let mut comments = StoreComment::new();
+    for _ in Lexer::new(text, &mut interner, &mut comments) {}
+    let mut keep = kept_by_directives(text, &spans, &comments.into_comments());
+    let mut errors = Vec::new();
+    let mut line = 1;
+    let mut end = 0;
+
+    for (span, keep) in spans.iter().map(|stmt| stmt.span).zip(&mut keep) {
+        line += text[end..span.start].matches('\n').count();
+        let position = Position {
+            location: Location::new(line),
+            offset: span.start,
+        };
+        let stmt = &text[span.start..span.end];
+        let (_, stmt_errors) = parse_recovering_at(stmt, &mut interner, position);
+        line += stmt.matches('\n').count();
+        end = span.end;
+
+        *keep |= !stmt_errors.is_empty();
+        errors.extend(stmt_errors);
+    }
 
-    CodeFormatter::new(out, &interner, &comments)
-        .write_program(&program)
-        .map_err(FormatError::IoError)
+    write_kept(
+        out,
+        text,
+        &mut interner,
+        &spans,
+        &keep,
+        options,
+        &mut errors,
+    )?;
+
+    Ok(errors)
 }
 
 pub fn format_code_to_string(code: &str) -> Result<String, FormatError> {
@@ -344,11 +816,12 @@ pub fn format_code_to_string(code: &str) -> Result<String, FormatError> {
 
 #[cfg(test)]
 mod tests {
-    use super::format_code_to_string;
-    use crate::builtin::RecordBuiltin;
-    use crate::context::Context;
-    use crate::program::Program;
-    use futures_executor::block_on;
+    use super::{
+        format_code_lossy, format_code_to_string, format_code_with_options, FormatOptions,
+    };
+    use crate::ast::{Expr, ExprKind};
+    use crate::error::ParseErrorInfo;
+    use crate::location::Span;
 
     use pretty_assertions::assert_eq;
     #[test]
@@ -396,13 +869,330 @@ mod tests {
         );
     }
 
+    #[test]
+    fn select() {
+        assert_eq!(
+            format_code_to_string("선택$1{1,2{@1;}'가'{}\n#기타\n그외{@0;}}").unwrap(),
+            "\n선택 $1 {\n    1, 2 {\n        @1;\n    }\n    '가' {\n    }\n    #기타\n    그외 {\n        @0;\n    }\n}\n\n"
+        );
+    }
+
+    #[test]
+    fn tight_punctuation() {
+        let format = |code: &str, tight_punctuation| {
+            let mut out = Vec::new();
+            format_code_with_options(code, &mut out, FormatOptions { tight_punctuation }).unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        let code = "@'그가 「' $이름 '」이라고 했다'  '『 책 』' 1;";
+
+        assert_eq!(
+            format(code, false),
+            "@'그가 「' $이름 '」이라고 했다' '『 책 』' 1;\n"
+        );
+        assert_eq!(
+            format(code, true),
+            "@'그가 「'$이름'」이라고 했다' '『 책 』' 1;\n"
+        );
+
+        let tight = format(code, true);
+        assert_eq!(format(&tight, true), tight);
+    }
+
+    #[test]
+    fn label() {
+        assert_eq!(
+            format_code_to_string("@1;장면   숲 ;@2;").unwrap(),
+            "@1;\n\n장면 숲;\n@2;\n"
+        );
+    }
+
+    #[test]
+    fn include() {
+        assert_eq!(
+            format_code_to_string("포함   'a.kes' ;@1;").unwrap(),
+            "포함 'a.kes';\n@1;\n"
+        );
+    }
+
+    #[test]
+    fn directives() {
+        let code = "$1=2;\n# kesfmt: off\n$가   = 1;\n$나   = 22;\n# kesfmt: on\n$3=4;\n# kesfmt: skip\n@1+2  ;\n@3+4;";
+        let formatted = format_code_to_string(code).unwrap();
+
+        assert_eq!(
+            formatted,
+            "$1 = 2;\n# kesfmt: off\n$가   = 1;\n$나   = 22;\n# kesfmt: on\n$3 = 4;\n# kesfmt: skip\n@1+2  ;\n@3 + 4;\n"
+        );
+        assert_eq!(format_code_to_string(&formatted).unwrap(), formatted);
+    }
+
+    #[test]
+    fn lossy() {
+        let format = |code: &str| {
+            let mut out = Vec::new();
+            let errors = format_code_lossy(code, &mut out, FormatOptions::default()).unwrap();
+            let lines = errors
+                .iter()
+                .map(|error| ParseErrorInfo::new(error).location().unwrap().line)
+                .collect::<Vec<_>>();
+            (String::from_utf8(out).unwrap(), lines)
+        };
+
+        assert_eq!(
+            format("$1=2;\n# 설명\n@1 +;\n만약1{@2;}"),
+            (
+                "$1 = 2;\n# 설명\n@1 +;\n\n만약 1 {\n    @2;\n}\n\n".into(),
+                vec![3]
+            )
+        );
+        assert_eq!(format("$1=2;"), ("$1 = 2;\n".into(), vec![]));
+        assert_eq!(
+            format("$1=2;\n@'닫히지"),
+            ("$1=2;\n@'닫히지".into(), vec![2])
+        );
+    }
+
+    #[test]
+    fn typed_assign() {
+        assert_eq!(
+            format_code_to_string("$체력:수=100;$이름 :문자열= '';").unwrap(),
+            "$체력: 수 = 100;\n$이름: 문자열 = '';\n"
+        );
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(
+            format_code_to_string(r"@'It\'s' '\u{AC00}\\' '\t';").unwrap(),
+            "@'It\\'s' '가\\\\' '\\t';\n"
+        );
+    }
+
+    #[test]
+    fn raw_string() {
+        assert_eq!(
+            format_code_to_string("만약 1 {\n@'''첫줄\n  둘째줄 \\n''';\n@'a\\nb\\'';}").unwrap(),
+            "\n만약 1 {\n    @'''첫줄\n  둘째줄 \\n''';\n    @'a\\nb\\'';\n}\n\n"
+        );
+    }
+
+    #[test]
+    fn channel() {
+        assert_eq!(
+            format_code_to_string("@#로그'가'1;@@#이름;@!#효과음 '문';").unwrap(),
+            "@#로그 '가' 1;\n@@#이름;\n@!#효과음 '문';\n"
+        );
+    }
+
+    #[test]
+    fn asset() {
+        assert_eq!(
+            format_code_to_string("@자원 ( 'voice/001.ogg' );$1=자원('a\\'b');").unwrap(),
+            "@자원('voice/001.ogg');\n$1 = 자원('a\\'b');\n"
+        );
+    }
+
+    #[test]
+    fn short_circuit() {
+        assert_eq!(
+            format_code_to_string("$1=$2&&($3||$4)|| $5&&참;").unwrap(),
+            "$1 = $2 && ($3 || $4) || $5 && 참;\n"
+        );
+    }
+
+    #[test]
+    fn list() {
+        assert_eq!(
+            format_code_to_string("$1=[1,[ ]];$1[1][0]=$1[0];@$1 [2];").unwrap(),
+            "$1 = [1, []];\n$1[1][0] = $1[0];\n@$1 [2];\n"
+        );
+    }
+
+    #[test]
+    fn for_each() {
+        assert_eq!(
+            format_code_to_string("각각$1'가나'{@$1;}").unwrap(),
+            "\n각각 $1 '가나' {\n    @$1;\n}\n\n"
+        );
+    }
+
+    #[test]
+    fn front_matter() {
+        assert_eq!(
+            format_code_to_string("---\r\n제목 :상점\r\n---\r\n$1=2;").unwrap(),
+            "---\n제목 :상점\n---\n$1 = 2;\n"
+        );
+    }
+
+    #[test]
+    fn declare() {
+        assert_eq!(
+            format_code_to_string("변수$1;변수 $2=1+2;").unwrap(),
+            "변수 $1;\n변수 $2 = 1 + 2;\n"
+        );
+    }
+
+    #[test]
+    fn spawn() {
+        assert_eq!(
+            format_code_to_string("시작 소리('bgm',1);기다리기;").unwrap(),
+            "시작 소리('bgm', 1);\n기다리기;\n"
+        );
+    }
+
+    #[test]
+    fn trailing_comma() {
+        assert_eq!(
+            format_code_to_string("함수(1,함수(),);@;@@;").unwrap(),
+            "함수(1, 함수());\n@;\n@@;\n"
+        );
+    }
+
     #[test]
     fn paren_test() {
         assert_eq!(format_code_to_string("1*(2+3);").unwrap(), "1 * (2 + 3);\n");
     }
 
+    /// Remove parens and spans which generated expression doesn't have
+    fn strip_nop(expr: Expr) -> Expr {
+        let kind = match expr.kind {
+            ExprKind::Nop(value) => return strip_nop(*value),
+            ExprKind::UnaryOp { value, op } => ExprKind::UnaryOp {
+                value: Box::new(strip_nop(*value)),
+                op,
+            },
+            ExprKind::BinaryOp { lhs, rhs, op } => ExprKind::BinaryOp {
+                lhs: Box::new(strip_nop(*lhs)),
+                rhs: Box::new(strip_nop(*rhs)),
+                op,
+            },
+            ExprKind::TernaryOp { lhs, mhs, rhs, op } => ExprKind::TernaryOp {
+                lhs: Box::new(strip_nop(*lhs)),
+                mhs: Box::new(strip_nop(*mhs)),
+                rhs: Box::new(strip_nop(*rhs)),
+                op,
+            },
+            ExprKind::BuiltinFunc { name, args } => ExprKind::BuiltinFunc {
+                name,
+                args: args.into_iter().map(strip_nop).collect(),
+            },
+            kind => kind,
+        };
+
+        Expr::new(kind, Span::default())
+    }
+
+    fn number(num: u32) -> Expr {
+        Expr::new(ExprKind::Number(num), Span::default())
+    }
+
+    /// Generate every expression shape up to `depth` picking operators with `seed`
+    fn generate(depth: usize, seed: &mut u32) -> Expr {
+        use crate::operator::{BinaryOperator::*, TernaryOperator, UnaryOperator};
+
+        const OPS: [crate::operator::BinaryOperator; 14] = [
+            Add,
+            Sub,
+            Div,
+            Mul,
+            Rem,
+            And,
+            Or,
+            Xor,
+            Equal,
+            NotEqual,
+            Less,
+            LessOrEqual,
+            Greater,
+            GreaterOrEqual,
+        ];
+
+        *seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        let pick = (*seed >> 16) as usize;
+
+        if depth == 0 {
+            return number(pick as u32 % 10);
+        }
+
+        match pick % 4 {
+            0 => generate(depth - 1, seed).unary_op(UnaryOperator::Not, Span::default()),
+            1 => generate(depth - 1, seed).ternary_op(
+                generate(depth - 1, seed),
+                generate(depth - 1, seed),
+                TernaryOperator::Conditional,
+            ),
+            _ => generate(depth - 1, seed)
+                .binary_op(generate(depth - 1, seed), OPS[(pick / 4) % OPS.len()]),
+        }
+    }
+
+    #[test]
+    fn round_trip_generated() {
+        use crate::ast::Stmt;
+        use crate::interner::Interner;
+        use crate::location::Location;
+        use crate::parser::parse;
+
+        let interner = Interner::new();
+        let comments = Default::default();
+        let mut seed = 42;
+
+        for _ in 0..500 {
+            let expr = generate(4, &mut seed);
+            let mut out = Vec::new();
+            super::CodeFormatter::new(&mut out, &interner, &comments)
+                .write_program(&[Stmt::Expression {
+                    expr: expr.clone(),
+                    location: Location::new(1),
+                }])
+                .unwrap();
+            let code = String::from_utf8(out).unwrap();
+
+            let parsed = match parse(&code, &mut Interner::new()).unwrap().pop() {
+                Some(Stmt::Expression { expr, .. }) => strip_nop(expr),
+                _ => unreachable!(),
+            };
+
+            assert_eq!(parsed, expr, "{}", code);
+        }
+    }
+
+    #[test]
+    fn precedence_paren() {
+        use crate::ast::Stmt;
+        use crate::interner::Interner;
+        use crate::location::Location;
+        use crate::operator::BinaryOperator;
+
+        let interner = Interner::new();
+        let comments = Default::default();
+        let mut out = Vec::new();
+        let expr = number(1)
+            .binary_op(number(2), BinaryOperator::Add)
+            .binary_op(
+                number(3).binary_op(number(4), BinaryOperator::Sub),
+                BinaryOperator::Mul,
+            );
+
+        super::CodeFormatter::new(&mut out, &interner, &comments)
+            .write_program(&[Stmt::Expression {
+                expr,
+                location: Location::new(1),
+            }])
+            .unwrap();
+
+        assert_eq!(String::from_utf8(out).unwrap(), "(1 + 2) * (3 - 4);\n");
+    }
+
+    #[cfg(feature = "runtime")]
     #[test]
     fn work() {
+        use crate::builtin::RecordBuiltin;
+        use crate::context::Context;
+        use crate::program::Program;
+        use futures_executor::block_on;
+
         let code = "$1=2;만약1+2{@@123;}@!456;";
         let formatted_code = format_code_to_string(code).unwrap();
 
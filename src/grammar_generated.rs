@@ -0,0 +1,11413 @@
+// auto-generated: "lalrpop 0.19.12"
+// sha3: 4f75d71e8b4c71121fe09dd500e7d14b620b314cad76a6dd89b807648cd7c02c
+use crate::token::Token;
+use crate::ast::{Expr, ExprKind, Stmt};
+use crate::operator::{UnaryOperator, BinaryOperator, TernaryOperator};
+use crate::location::Location;
+use crate::lexer::Position;
+use crate::error::LexicalError;
+use crate::interner::Symbol;
+use lalrpop_util::ErrorRecovery;
+#[allow(unused_extern_crates)]
+extern crate lalrpop_util as __lalrpop_util;
+#[allow(unused_imports)]
+use self::__lalrpop_util::state_machine as __state_machine;
+extern crate core;
+extern crate alloc;
+
+#[cfg_attr(rustfmt, rustfmt_skip)]
+mod __parse__Program {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports, unused_parens, clippy::all)]
+
+    use crate::token::Token;
+    use crate::ast::{Expr, ExprKind, Stmt};
+    use crate::operator::{UnaryOperator, BinaryOperator, TernaryOperator};
+    use crate::location::Location;
+    use crate::lexer::Position;
+    use crate::error::LexicalError;
+    use crate::interner::Symbol;
+    use lalrpop_util::ErrorRecovery;
+    #[allow(unused_extern_crates)]
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(unused_imports)]
+    use self::__lalrpop_util::state_machine as __state_machine;
+    extern crate core;
+    extern crate alloc;
+    use super::__ToTriple;
+    #[allow(dead_code)]
+    pub(crate) enum __Symbol<>
+     {
+        Variant0(Token),
+        Variant1(Symbol),
+        Variant2(u32),
+        Variant3(__lalrpop_util::ErrorRecovery<Position, Token, LexicalError>),
+        Variant4(core::option::Option<Symbol>),
+        Variant5(Expr),
+        Variant6(core::option::Option<Expr>),
+        Variant7(alloc::vec::Vec<Expr>),
+        Variant8(Position),
+        Variant9(BinaryOperator),
+        Variant10(Vec<Stmt>),
+        Variant11(Vec<Expr>),
+        Variant12((Expr, Vec<Stmt>, Location)),
+        Variant13(alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>),
+        Variant14((Vec<Stmt>, Location)),
+        Variant15(core::option::Option<(Vec<Stmt>, Location)>),
+        Variant16((Symbol, Position, Vec<(Expr, Position)>)),
+        Variant17((Expr, Position)),
+        Variant18(alloc::vec::Vec<(Expr, Position)>),
+        Variant19(Location),
+        Variant20(Option<Stmt>),
+        Variant21(alloc::vec::Vec<Option<Stmt>>),
+        Variant22((Vec<Expr>, Vec<Stmt>, Location)),
+        Variant23(alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>),
+        Variant24(Stmt),
+        Variant25(UnaryOperator),
+    }
+    const __ACTION: &[i16] = &[
+        // State 0
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, 0, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, 0, -70, -70, -70, 86,
+        // State 1
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 16, 17, 18, 0, 0, 0, 0, 0, 0, 103, 104, 0, 105, 19, 20, 106, 21, 107, 108, 109, 110, 111, 112, 22, 113, 0, 114, 0, 115, 116, 23, 0,
+        // State 2
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, 0, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, 0, -70, -70, -70, 86,
+        // State 3
+        -23, -23, 0, -23, -23, -23, -23, 0, 121, -23, 122, 0, -23, -23, -23, -23, -23, 0, -23, -23, -23, -23, -23, 0, 0, 0, 0, -23, -23, -23, -23, -23, -23, 0, 0, -23, 0, 0, 0, 0, 0, 0, 0, -23, -23, 0, 0, -23, 0, 0, 0, -23, 0, -23, -23, -23, 0,
+        // State 4
+        -16, 0, 0, 123, -16, -16, -16, 0, 0, -16, 0, 0, -16, -16, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, 0, 0, 0, -16, -16, -16, -16, -16, -16, 0, 0, -16, 0, 0, 0, 0, 0, 0, 0, -16, -16, 0, 0, -16, 0, 0, 0, -16, 0, -16, -16, -16, 0,
+        // State 5
+        -26, 124, 0, -26, -26, -26, -26, 0, 0, -26, 0, 0, -26, -26, 125, 0, 126, 0, 127, 128, 129, 0, -26, 0, 0, 0, 0, -26, -26, -26, -26, -26, -26, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, -26, -26, 0, 0, -26, 0, 0, 0, -26, 0, -26, -26, -26, 0,
+        // State 6
+        -71, 0, 0, 0, 130, -71, -71, 0, 0, -71, 0, 0, -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, -71, 0, 0, 0, 0, -71, -71, 0, -71, 0, -71, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, -71, -71, 0, 0, -71, 0, 0, 0, -71, 0, -71, -71, -71, 0,
+        // State 7
+        -73, 0, 0, 0, 0, -73, -73, 0, 0, -73, 0, 0, -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, -73, -73, 0, -73, 0, 131, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, -73, -73, 0, 0, -73, 0, 0, 0, -73, 0, -73, -73, -73, 0,
+        // State 8
+        -58, -58, 132, -58, -58, -58, -58, 133, -58, -58, -58, 134, -58, -58, -58, -58, -58, 0, -58, -58, -58, -58, -58, 0, 0, 0, 0, -58, -58, -58, -58, -58, -58, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, -58, -58, 0, 0, -58, 0, 0, 0, -58, 0, -58, -58, -58, 0,
+        // State 9
+        -78, 0, 0, 0, -78, -78, -78, 0, 0, -78, 0, 0, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, -78, 0, 0, 0, 0, -78, -78, 0, -78, 135, -78, 0, 0, -78, 0, 0, 0, 0, 0, 0, 0, -78, -78, 0, 0, -78, 0, 0, 0, -78, 0, -78, -78, -78, 0,
+        // State 10
+        -94, -94, 0, -94, -94, -94, -94, 0, 0, -94, 0, 0, -94, -94, -94, 136, -94, 0, -94, -94, -94, 137, -94, 0, 0, 0, 0, -94, -94, -94, -94, -94, -94, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, -94, -94, 0, 0, -94, 0, 0, 0, -94, 0, -94, -94, -94, 0,
+        // State 11
+        -174, 0, 0, 0, -174, -174, -174, 0, 0, -174, 0, 0, -174, -174, 0, 0, 0, 0, 0, 0, 0, 0, -174, 0, 0, 0, 0, -174, -174, 138, -174, -174, -174, 0, 0, -174, 0, 0, 0, 0, 0, 0, 0, -174, -174, 0, 0, -174, 0, 0, 0, -174, 0, -174, -174, -174, 0,
+        // State 12
+        0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 13
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 14
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 37, 115, 116, 35, 0,
+        // State 15
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 39, 115, 116, 35, 0,
+        // State 16
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 41, 115, 116, 35, 0,
+        // State 17
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 43, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 18
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 19
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 20
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 21
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 22
+        0, -134, -134, -134, -134, 0, 0, -134, -134, 0, -134, -134, 159, -134, -134, -134, -134, 47, -134, -134, -134, -134, -134, 48, 0, 0, 0, 0, 0, -134, 0, -134, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 24
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 25
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 26
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 27
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 28
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 29
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 30
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 31
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 32
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 33
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 34
+        -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, -134, 0, -134, -134, -134, -134, -134, 48, 0, 0, 0, -134, -134, -134, -134, -134, -134, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, -134, -134, 0, 0, -134, 0, 0, 0, -134, 0, -134, -134, -134, 0,
+        // State 35
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 36
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 37
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 38
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 39
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 40
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 41
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 52, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 42
+        -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, -138, 0, -138, -138, -138, -138, -138, 48, 0, 0, 0, -138, -138, -138, -138, -138, -138, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, -138, -138, 0, 0, -138, 0, 0, 0, -138, 0, -138, -138, -138, 0,
+        // State 43
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 44
+        102, 0, 0, 0, 0, 14, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 45
+        -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, -59, 48, 0, 0, 0, -59, -59, -59, -59, -59, -59, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, -59, -59, 0, 0, -59, 0, 0, 0, -59, 0, -59, -59, -59, 0,
+        // State 46
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 47
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 48
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 49
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 50
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 51
+        -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, -142, 0, -142, -142, -142, -142, -142, 48, 0, 0, 0, -142, -142, -142, -142, -142, -142, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, -142, -142, 0, 0, -142, 0, 0, 0, -142, 0, -142, -142, -142, 0,
+        // State 52
+        -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, -136, 0, -136, -136, -136, -136, -136, 48, 0, 0, 0, -136, -136, -136, -136, -136, -136, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, -136, -136, 0, 0, -136, 0, 0, 0, -136, 0, -136, -136, -136, 0,
+        // State 53
+        -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, -139, 0, -139, -139, -139, -139, -139, 48, 0, 0, 0, -139, -139, -139, -139, -139, -139, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, -139, -139, 0, 0, -139, 0, 0, 0, -139, 0, -139, -139, -139, 0,
+        // State 54
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -24, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 55
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -24, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 56
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 203, 0, -70, -70, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, 0, 0, 0, 0, 0, -70, -70, 0, 0,
+        // State 58
+        102, 0, 0, 0, 0, 14, 205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 59
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -24, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 60
+        102, 0, 0, 0, 0, 14, 209, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 61
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 62
+        -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, -140, 0, -140, -140, -140, -140, -140, 48, 0, 0, 0, -140, -140, -140, -140, -140, -140, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, -140, -140, 0, 0, -140, 0, 0, 0, -140, 0, -140, -140, -140, 0,
+        // State 63
+        -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, -143, 0, -143, -143, -143, -143, -143, 48, 0, 0, 0, -143, -143, -143, -143, -143, -143, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, -143, -143, 0, 0, -143, 0, 0, 0, -143, 0, -143, -143, -143, 0,
+        // State 64
+        -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, -137, 0, -137, -137, -137, -137, -137, 48, 0, 0, 0, -137, -137, -137, -137, -137, -137, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, -137, -137, 0, 0, -137, 0, 0, 0, -137, 0, -137, -137, -137, 0,
+        // State 65
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -24, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 66
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 16, 17, 18, 0, 0, 0, 0, 0, 0, 103, 104, 0, 105, 19, 20, 106, 21, 107, 108, 109, 0, 111, 112, 22, 0, 0, 114, 0, 115, 116, 23, 0,
+        // State 67
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -25, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 220, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 112, 0, 0, 0, 0, 0, 115, 116, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 223, 0, -70, -70, 0, 0, 0, 0, 0, 0, -70, 0, 0, 0, -70, 0, 0, 0, 0, 0, -70, -70, 0, 0,
+        // State 70
+        102, 0, 0, 0, 0, 14, 225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 71
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 72
+        -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, -141, 0, -141, -141, -141, -141, -141, 48, 0, 0, 0, -141, -141, -141, -141, -141, -141, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, -141, -141, 0, 0, -141, 0, 0, 0, -141, 0, -141, -141, -141, 0,
+        // State 73
+        -121, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, -121, -121, -121, 0, 0, 0, 0, 0, -121, -121, -121, -70, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -70, -121, 0, -121, -121, -121, -121,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 0, 0, 0, 112, 0, 0, 0, 0, 0, 115, 116, 0, 0,
+        // State 75
+        -123, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, -123, -123, -123, 0, 0, 0, 0, 0, -123, -123, -123, -70, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -123, -70, -123, 0, -123, -123, -123, -123,
+        // State 76
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -24, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 77
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -24, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 78
+        102, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 104, 0, 0, 0, 0, 0, 0, 0, 108, 109, 0, 0, 112, 0, 0, 0, 114, 0, 115, 116, 35, 0,
+        // State 79
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -24, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 80
+        -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0, 0, 0, 0, 0, -24, -70, -70, 0, -70, -70, -70, -70, -70, -70, -70, -70, 0, -70, -70, -70, 0, 0, -70, 0, -70, -70, -70, 86,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 82
+        -164, 0, 0, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -164, -164, -164, -164, 0, 0, 0, 0, 0, 0, -164, -164, 0, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, -164, 0, -164, 0, -164, -164, -164, -164,
+        // State 83
+        -82, 0, 0, 0, 0, -82, 0, 0, 0, 0, 0, 0, 0, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, -82, -82, -82, 0, 0, 0, 0, 0, -82, -82, -82, 0, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, -82, 0, -82, 0, -82, -82, -82, -82,
+        // State 84
+        -169, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -169, -169, -169, -169, 0, 0, 0, 0, 0, 0, -169, -169, 0, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, -169, 0, -169, 0, -169, -169, -169, -169,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 86
+        -163, 0, 0, 0, -163, -163, -163, 0, 0, -163, 0, 0, -163, -163, 0, 0, 0, 0, 0, 0, 0, 0, -163, 0, 0, 0, 0, -163, -163, -163, -163, -163, -163, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, -163, -163, 0, 0, -163, 0, 0, 0, -163, 0, -163, -163, -163, 0,
+        // State 87
+        -161, -161, 0, -161, -161, -161, -161, 0, 0, -161, 0, 0, -161, -161, -161, -161, -161, 0, -161, -161, -161, -161, -161, 0, 0, 0, 0, -161, -161, -161, -161, -161, -161, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, -161, -161, 0, 0, -161, 0, 0, 0, -161, 0, -161, -161, -161, 0,
+        // State 88
+        -149, 0, 0, -149, -149, -149, -149, 0, 0, -149, 0, 0, -149, -149, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, -149, -149, -149, -149, -149, -149, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, -149, -149, 0, 0, -149, 0, 0, 0, -149, 0, -149, -149, -149, 0,
+        // State 89
+        -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, -172, 0, -172, -172, -172, -172, -172, 0, 0, 0, 0, -172, -172, -172, -172, -172, -172, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, -172, -172, 0, 0, -172, 0, 0, 0, -172, 0, -172, -172, -172, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        -147, -147, 0, -147, -147, -147, -147, 0, -147, -147, -147, 0, -147, -147, -147, -147, -147, 0, -147, -147, -147, -147, -147, 0, 0, 0, 0, -147, -147, -147, -147, -147, -147, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, -147, -147, 0, 0, -147, 0, 0, 0, -147, 0, -147, -147, -147, 0,
+        // State 92
+        0, -135, -135, -135, -135, 0, 0, -135, -135, 0, -135, -135, 0, -135, -135, -135, -135, 25, -135, -135, -135, -135, -135, 0, 0, 0, 0, 0, 0, -135, 0, -135, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, -133, 0, -133, -133, -133, -133, -133, 0, 0, 0, 0, -133, -133, -133, -133, -133, -133, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, -133, -133, 0, 0, -133, 0, 0, 0, -133, 0, -133, -133, -133, 0,
+        // State 94
+        -155, 0, 0, 0, 0, -155, -155, 0, 0, -155, 0, 0, -155, -155, 0, 0, 0, 0, 0, 0, 0, 0, -155, 0, 0, 0, 0, -155, -155, 0, -155, 0, -155, 0, 0, -155, 0, 0, 0, 0, 0, 0, 0, -155, -155, 0, 0, -155, 0, 0, 0, -155, 0, -155, -155, -155, 0,
+        // State 95
+        -51, 0, 0, 0, 0, -51, -51, 0, 0, -51, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, 0, -51, 0, 0, 0, 0, -51, -51, 0, -51, 0, 0, 0, 0, -51, 0, 0, 0, 0, 0, 0, 0, -51, -51, 0, 0, -51, 0, 0, 0, -51, 0, -51, -51, -51, 0,
+        // State 96
+        -153, 0, 0, 0, -153, -153, -153, 0, 0, -153, 0, 0, -153, -153, 0, 0, 0, 0, 0, 0, 0, 0, -153, 0, 0, 0, 0, -153, -153, 0, -153, 0, -153, 0, 0, -153, 0, 0, 0, 0, 0, 0, 0, -153, -153, 0, 0, -153, 0, 0, 0, -153, 0, -153, -153, -153, 0,
+        // State 97
+        -151, -151, 0, -151, -151, -151, -151, 0, 0, -151, 0, 0, -151, -151, -151, 0, -151, 0, -151, -151, -151, 0, -151, 0, 0, 0, 0, -151, -151, -151, -151, -151, -151, 0, 0, -151, 0, 0, 0, 0, 0, 0, 0, -151, -151, 0, 0, -151, 0, 0, 0, -151, 0, -151, -151, -151, 0,
+        // State 98
+        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, 0, -41, -41, -41, -41, -41, 0, 0, 0, 0, -41, -41, -41, -41, -41, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, -41, -41, 0, 0, -41, 0, 0, 0, -41, 0, -41, -41, -41, 0,
+        // State 99
+        -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, -157, 0, -157, -157, -157, -157, -157, 0, 0, 0, 0, -157, -157, -157, -157, -157, -157, 0, 0, -157, 0, 0, 0, 0, 0, 0, 0, -157, -157, 0, 0, -157, 0, 0, 0, -157, 0, -157, -157, -157, 0,
+        // State 100
+        -159, 0, 0, 0, -159, -159, -159, 0, 0, -159, 0, 0, -159, -159, 0, 0, 0, 0, 0, 0, 0, 0, -159, 0, 0, 0, 0, -159, -159, 0, -159, -159, -159, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, -159, -159, 0, 0, -159, 0, 0, 0, -159, 0, -159, -159, -159, 0,
+        // State 101
+        0, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, -173, -173, 0, 0, -173, 0, 0, 0, -173, 0, -173, -173, -173, 0,
+        // State 102
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0,
+        // State 103
+        -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, -68, 0, -68, -68, -68, -68, -68, 0, 0, 0, 0, -68, -68, -68, -68, -68, -68, 0, 0, -68, 0, 0, 0, 0, 0, 0, 0, -68, -68, 0, 0, -68, 0, 0, 0, -68, 0, -68, -68, -68, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 150, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0,
+        // State 107
+        -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, -69, 0, -69, -69, -69, -69, -69, 0, 0, 0, 0, -69, -69, -69, -69, -69, -69, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, -69, -69, 0, 0, -69, 0, 0, 0, -69, 0, -69, -69, -69, 0,
+        // State 108
+        0, 0, 0, 0, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 154, 0, 0, 0, 0, 0,
+        // State 110
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, -67, 0, -67, -67, -67, -67, -67, 0, 0, 0, 0, -67, -67, -67, -67, -67, -67, 0, 0, -67, 0, 0, 0, 0, 0, 0, 0, -67, -67, 0, 0, -67, 0, 0, 0, -67, 0, -67, -67, -67, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 157, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, -65, 0, -65, -65, -65, -65, -65, 0, 0, 0, 0, -65, -65, -65, -65, -65, -65, 0, 0, -65, 0, 0, 0, 0, 0, 0, 0, -65, -65, 0, 0, -65, 0, 0, 0, -65, 0, -65, -65, -65, 0,
+        // State 115
+        -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, -66, 0, -66, -66, -66, -66, -66, 0, 0, 0, 0, -66, -66, -66, -66, -66, -66, 0, 0, -66, 0, 0, 0, 0, 0, 0, 0, -66, -66, 0, 0, -66, 0, 0, 0, -66, 0, -66, -66, -66, 0,
+        // State 116
+        -132, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, -132, -132, 0, 0, 0, 0, 0, -132, -132, -132, 0, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, -132, 0, -132, 0, -132, -132, -132, -132,
+        // State 117
+        -170, 0, 0, 0, 0, -170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -170, -170, -170, -170, 0, 0, 0, 0, 0, 0, -170, -170, 0, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, -170, 0, -170, 0, -170, -170, -170, -170,
+        // State 118
+        -83, 0, 0, 0, 0, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, -83, -83, -83, 0, 0, 0, 0, 0, -83, -83, -83, 0, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, 0, -83, -83, -83, -83,
+        // State 119
+        -131, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, -131, -131, 0, 0, 0, 0, 0, -131, -131, -131, 0, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, -131, 0, -131, 0, -131, -131, -131, -131,
+        // State 120
+        -14, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, -14, -14, 0, 0, -14, 0, 0, 0, -14, 0, -14, -14, -14, 0,
+        // State 121
+        -15, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, -15, 0, 0, 0, 0, 0, 0, 0, -15, -15, 0, 0, -15, 0, 0, 0, -15, 0, -15, -15, -15, 0,
+        // State 122
+        -17, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, -17, 0, 0, 0, 0, 0, 0, 0, -17, -17, 0, 0, -17, 0, 0, 0, -17, 0, -17, -17, -17, 0,
+        // State 123
+        -32, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, -32, 0, 0, 0, 0, 0, 0, 0, -32, -32, 0, 0, -32, 0, 0, 0, -32, 0, -32, -32, -32, 0,
+        // State 124
+        -29, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29, 0, 0, 0, 0, 0, 0, 0, -29, -29, 0, 0, -29, 0, 0, 0, -29, 0, -29, -29, -29, 0,
+        // State 125
+        -30, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, -30, 0, 0, 0, 0, 0, 0, 0, -30, -30, 0, 0, -30, 0, 0, 0, -30, 0, -30, -30, -30, 0,
+        // State 126
+        -31, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, -31, 0, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, -31, 0, 0, 0, -31, 0, -31, -31, -31, 0,
+        // State 127
+        -27, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, -27, -27, 0, 0, -27, 0, 0, 0, -27, 0, -27, -27, -27, 0,
+        // State 128
+        -28, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, -28, -28, 0, 0, -28, 0, 0, 0, -28, 0, -28, -28, -28, 0,
+        // State 129
+        -72, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, -72, -72, 0, 0, -72, 0, 0, 0, -72, 0, -72, -72, -72, 0,
+        // State 130
+        -74, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, -74, -74, 0, 0, -74, 0, 0, 0, -74, 0, -74, -74, -74, 0,
+        // State 131
+        -77, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, -77, 0, 0, 0, 0, 0, 0, 0, -77, -77, 0, 0, -77, 0, 0, 0, -77, 0, -77, -77, -77, 0,
+        // State 132
+        -75, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, -75, -75, 0, 0, -75, 0, 0, 0, -75, 0, -75, -75, -75, 0,
+        // State 133
+        -76, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, -76, -76, 0, 0, -76, 0, 0, 0, -76, 0, -76, -76, -76, 0,
+        // State 134
+        -79, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, -79, -79, 0, 0, -79, 0, 0, 0, -79, 0, -79, -79, -79, 0,
+        // State 135
+        -95, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, -95, -95, 0, 0, -95, 0, 0, 0, -95, 0, -95, -95, -95, 0,
+        // State 136
+        -96, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, -96, -96, 0, 0, -96, 0, 0, 0, -96, 0, -96, -96, -96, 0,
+        // State 137
+        -175, 0, 0, 0, 0, -175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, 0, 0, 0, 0, 0, 0, 0, -175, 0, 0, 0, 0, 0, 0, 0, -175, -175, 0, 0, -175, 0, 0, 0, -175, 0, -175, -175, -175, 0,
+        // State 138
+        -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, -171, 0, -171, -171, -171, -171, -171, 0, 0, 0, 0, -171, -171, -171, -171, -171, -171, 0, 0, -171, 0, 0, 0, 0, 0, 0, 0, -171, -171, 0, 0, -171, 0, 0, 0, -171, 0, -171, -171, -171, 0,
+        // State 139
+        -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, -135, 0, -135, -135, -135, -135, -135, 0, 0, 0, 0, -135, -135, -135, -135, -135, -135, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, -135, -135, 0, 0, -135, 0, 0, 0, -135, 0, -135, -135, -135, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, -54, -54, 0, 0, -54, 0, 0, 0, -54, 0, -54, -54, -54, 0,
+        // State 142
+        -109, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, -109, -109, -109, 0, 0, 0, 0, 0, -109, -109, -109, 0, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, 0, -109, 0, -109, -109, -109, -109,
+        // State 143
+        -113, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, -113, -113, -113, 0, 0, 0, 0, 0, -113, -113, -113, 0, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, 0, -113, 0, -113, -113, -113, -113,
+        // State 144
+        -117, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, -117, -117, -117, 0, 0, 0, 0, 0, -117, -117, -117, 0, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, 0, -117, 0, -117, -117, -117, -117,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        -102, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, -102, -102, -102, 0, 0, 0, 0, 0, -102, -102, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, 0, -102, 0, -102, -102, -102, -102,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, 57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 154
+        -97, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, -97, 0, 0, 0, 0, 0, -97, -97, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, 0, -97, 0, -97, -97, -97, -97,
+        // State 155
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 157
+        -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, -63, 0, 0, 0, -63, -63, -63, -63, -63, -63, 0, 0, -63, 0, 0, 0, 0, 0, 0, 0, -63, -63, 0, 0, -63, 0, 0, 0, -63, 0, -63, -63, -63, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 161
+        -146, -146, 0, -146, -146, -146, -146, 0, -146, -146, -146, 0, -146, -146, -146, -146, -146, 0, -146, -146, -146, -146, -146, 0, 0, 0, 0, -146, -146, -146, -146, -146, -146, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, -146, -146, 0, 0, -146, 0, 0, 0, -146, 0, -146, -146, -146, 0,
+        // State 162
+        -148, 0, 0, -148, -148, -148, -148, 0, 0, -148, 0, 0, -148, -148, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, -148, -148, -148, -148, -148, -148, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, -148, -148, 0, 0, -148, 0, 0, 0, -148, 0, -148, -148, -148, 0,
+        // State 163
+        -150, -150, 0, -150, -150, -150, -150, 0, 0, -150, 0, 0, -150, -150, -150, 0, -150, 0, -150, -150, -150, 0, -150, 0, 0, 0, 0, -150, -150, -150, -150, -150, -150, 0, 0, -150, 0, 0, 0, 0, 0, 0, 0, -150, -150, 0, 0, -150, 0, 0, 0, -150, 0, -150, -150, -150, 0,
+        // State 164
+        -152, 0, 0, 0, -152, -152, -152, 0, 0, -152, 0, 0, -152, -152, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, -152, -152, 0, -152, 0, -152, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, -152, -152, 0, 0, -152, 0, 0, 0, -152, 0, -152, -152, -152, 0,
+        // State 165
+        -154, 0, 0, 0, 0, -154, -154, 0, 0, -154, 0, 0, -154, -154, 0, 0, 0, 0, 0, 0, 0, 0, -154, 0, 0, 0, 0, -154, -154, 0, -154, 0, -154, 0, 0, -154, 0, 0, 0, 0, 0, 0, 0, -154, -154, 0, 0, -154, 0, 0, 0, -154, 0, -154, -154, -154, 0,
+        // State 166
+        -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, -156, 0, -156, -156, -156, -156, -156, 0, 0, 0, 0, -156, -156, -156, -156, -156, -156, 0, 0, -156, 0, 0, 0, 0, 0, 0, 0, -156, -156, 0, 0, -156, 0, 0, 0, -156, 0, -156, -156, -156, 0,
+        // State 167
+        -158, 0, 0, 0, -158, -158, -158, 0, 0, -158, 0, 0, -158, -158, 0, 0, 0, 0, 0, 0, 0, 0, -158, 0, 0, 0, 0, -158, -158, 0, -158, -158, -158, 0, 0, -158, 0, 0, 0, 0, 0, 0, 0, -158, -158, 0, 0, -158, 0, 0, 0, -158, 0, -158, -158, -158, 0,
+        // State 168
+        -160, -160, 0, -160, -160, -160, -160, 0, 0, -160, 0, 0, -160, -160, -160, -160, -160, 0, -160, -160, -160, -160, -160, 0, 0, 0, 0, -160, -160, -160, -160, -160, -160, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, -160, -160, 0, 0, -160, 0, 0, 0, -160, 0, -160, -160, -160, 0,
+        // State 169
+        -162, 0, 0, 0, -162, -162, -162, 0, 0, -162, 0, 0, -162, -162, 0, 0, 0, 0, 0, 0, 0, 0, -162, 0, 0, 0, 0, -162, -162, -162, -162, -162, -162, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, -162, -162, 0, 0, -162, 0, 0, 0, -162, 0, -162, -162, -162, 0,
+        // State 170
+        -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, -144, 0, -144, -144, -144, -144, -144, 0, 0, 0, 0, -144, -144, -144, -144, -144, -144, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, -144, -144, 0, 0, -144, 0, 0, 0, -144, 0, -144, -144, -144, 0,
+        // State 171
+        -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, -55, -55, 0, 0, -55, 0, 0, 0, -55, 0, -55, -55, -55, 0,
+        // State 172
+        -111, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, -111, -111, -111, 0, 0, 0, 0, 0, -111, -111, -111, 0, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, 0, -111, 0, -111, -111, -111, -111,
+        // State 173
+        -108, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, -108, -108, 0, 0, 0, 0, 0, -108, -108, -108, 0, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, 0, -108, 0, -108, -108, -108, -108,
+        // State 174
+        -115, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, -115, -115, -115, 0, 0, 0, 0, 0, -115, -115, -115, 0, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, 0, -115, 0, -115, -115, -115, -115,
+        // State 175
+        -112, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, -112, -112, -112, 0, 0, 0, 0, 0, -112, -112, -112, 0, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, 0, -112, 0, -112, -112, -112, -112,
+        // State 176
+        -119, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, -119, -119, -119, 0, 0, 0, 0, 0, -119, -119, -119, 0, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, 0, -119, 0, -119, -119, -119, -119,
+        // State 177
+        -116, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, -116, -116, -116, 0, 0, 0, 0, 0, -116, -116, -116, 0, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, 0, -116, 0, -116, -116, -116, -116,
+        // State 178
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 179
+        -21, 0, 0, 0, 0, -21, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, -21, 0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, -21, -21, 0, 0, -21, 0, 0, 0, -21, 0, -21, -21, -21, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        -107, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, -107, -107, -107, 0, 0, 0, 0, 0, -107, -107, -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, 0, -107, 0, -107, -107, -107, -107,
+        // State 182
+        0, 0, 0, 0, 0, 0, 206, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        -165, 0, 0, 0, 0, -165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -165, -165, -165, -165, 0, 0, 0, 0, 0, 0, -165, -165, 0, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, -165, 0, -165, 0, -165, -165, -165, -165,
+        // State 184
+        -166, 0, 0, 0, 0, -166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -166, -166, -166, -166, 0, 0, 0, 0, 0, 0, -166, -166, 0, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, -166, 0, -166, 0, -166, -166, -166, -166,
+        // State 185
+        0, 0, 0, 0, 0, 0, 210, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, 0, -38, -38, -38, -38, -38, 0, 0, 0, 0, -38, -38, -38, -38, -38, -38, 0, 0, -38, 0, 0, 0, 0, 0, 0, 0, -38, -38, 0, 0, -38, 0, 0, 0, -38, 0, -38, -38, -38, 0,
+        // State 187
+        -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, -64, 0, 0, 0, -64, -64, -64, -64, -64, -64, 0, 0, -64, 0, 0, 0, 0, 0, 0, 0, -64, -64, 0, 0, -64, 0, 0, 0, -64, 0, -64, -64, -64, 0,
+        // State 188
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 211, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        -105, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, -105, -105, -105, 0, 0, 0, 0, 0, -105, -105, -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, 0, -105, 0, -105, -105, -105, -105,
+        // State 192
+        -110, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, -110, -110, -110, 0, 0, 0, 0, 0, -110, -110, -110, 0, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, 0, -110, 0, -110, -110, -110, -110,
+        // State 193
+        -114, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, -114, -114, -114, 0, 0, 0, 0, 0, -114, -114, -114, 0, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, 0, -114, 0, -114, -114, -114, -114,
+        // State 194
+        -118, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, -118, -118, -118, 0, 0, 0, 0, 0, -118, -118, -118, 0, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, 0, -118, 0, -118, -118, -118, -118,
+        // State 195
+        -22, 0, 0, 0, 0, -22, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, -22, 0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, -22, -22, 0, 0, -22, 0, 0, 0, -22, 0, -22, -22, -22, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 197
+        -86, 0, 0, 0, 0, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, -86, -86, 0, 0, 0, 0, 0, -86, -86, -86, 0, -86, -86, -86, -86, -86, -86, -86, -86, 0, -86, -86, -86, 0, 0, -86, 0, -86, -86, -86, -86,
+        // State 198
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 199
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 200
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 201
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, -92, -92, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, -92, 0, 0, 0, 0, 0, -92, -92, 0, 0,
+        // State 202
+        -129, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, -129, -129, -129, 0, 0, 0, 0, 0, -129, -129, -129, 0, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, -129, 0, -129, 0, -129, -129, -129, -129,
+        // State 203
+        0, 0, 0, 0, 0, 0, 226, 0, 0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 227, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 205
+        -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, -145, 0, -145, -145, -145, -145, -145, 0, 0, 0, 0, -145, -145, -145, -145, -145, -145, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, -145, -145, 0, 0, -145, 0, 0, 0, -145, 0, -145, -145, -145, 0,
+        // State 206
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 207
+        0, 0, 0, 0, 0, 0, 229, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 208
+        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, 0, -40, -40, -40, -40, -40, 0, 0, 0, 0, -40, -40, -40, -40, -40, -40, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, -40, -40, 0, 0, -40, 0, 0, 0, -40, 0, -40, -40, -40, 0,
+        // State 209
+        -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, -37, 0, -37, -37, -37, -37, -37, 0, 0, 0, 0, -37, -37, -37, -37, -37, -37, 0, 0, -37, 0, 0, 0, 0, 0, 0, 0, -37, -37, 0, 0, -37, 0, 0, 0, -37, 0, -37, -37, -37, 0,
+        // State 210
+        -104, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, -104, -104, -104, 0, 0, 0, 0, 0, -104, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, 0, -104, 0, -104, -104, -104, -104,
+        // State 211
+        -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, -60, 0, 0, 0, -60, -60, -60, -60, -60, -60, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, -60, -60, 0, 0, -60, 0, 0, 0, -60, 0, -60, -60, -60, 0,
+        // State 212
+        -50, 0, 0, 0, 0, -50, -50, 0, 0, -50, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0, 0, 0, 0, -50, -50, 0, -50, 0, 0, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, -50, -50, 0, 0, -50, 0, 0, 0, -50, 0, -50, -50, -50, 0,
+        // State 213
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 231, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 214
+        -87, 0, 0, 0, 0, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, -87, -87, 0, 0, 0, 0, 0, -87, -87, -87, 0, -87, -87, -87, -87, -87, -87, -87, -87, 0, -87, -87, -87, 0, 0, -87, 0, -87, -87, -87, -87,
+        // State 215
+        -124, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, -124, -124, -124, 0, 0, 0, 0, 0, -124, -124, -124, 0, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, -124, 0, -124, 0, -124, -124, -124, -124,
+        // State 216
+        -106, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, -106, -106, -106, 0, 0, 0, 0, 0, -106, -106, -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, 0, -106, 0, -106, -106, -106, -106,
+        // State 217
+        -127, 0, 0, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, -127, -127, -127, 0, 0, 0, 0, 0, -127, -127, -127, 0, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, -127, 0, -127, 0, -127, -127, -127, -127,
+        // State 218
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 219
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 220
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 221
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, -93, -93, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, -93, 0, 0, 0, 0, 0, -93, -93, 0, 0,
+        // State 222
+        -130, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, -130, -130, 0, 0, 0, 0, 0, -130, -130, -130, 0, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, -130, 0, -130, 0, -130, -130, -130, -130,
+        // State 223
+        0, 0, 0, 0, 0, 0, 238, 0, 0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 239, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 225
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 226
+        -99, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, -99, -99, 0, 0, 0, 0, 0, -99, -99, -99, 0, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, 0, -99, 0, -99, -99, -99, -99,
+        // State 227
+        -125, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, -125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, -125, -125, -125, 0, 0, 0, 0, 0, -125, -125, -125, 0, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, -125, 0, -125, 0, -125, -125, -125, -125,
+        // State 228
+        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, 0, -39, -39, -39, -39, -39, 0, 0, 0, 0, -39, -39, -39, -39, -39, -39, 0, 0, -39, 0, 0, 0, 0, 0, 0, 0, -39, -39, 0, 0, -39, 0, 0, 0, -39, 0, -39, -39, -39, 0,
+        // State 229
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 241, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 230
+        -126, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, -126, -126, -126, 0, 0, 0, 0, 0, -126, -126, -126, 0, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, -126, 0, -126, 0, -126, -126, -126, -126,
+        // State 231
+        -45, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, -45, -45, -45, 0, 0, 0, 0, 0, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, 0, -45, -45, -45, -45,
+        // State 232
+        -120, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, -120, -120, -120, 0, 0, 0, 0, 0, -120, -120, -120, 0, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, 0, -120, 0, -120, -120, -120, -120,
+        // State 233
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0,
+        // State 234
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 244, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 235
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, 0, 0, 0, 0, -10, 0, 0, 0, -10, 0, 0, 0, 0, 0, -10, -10, 0, 0,
+        // State 236
+        -128, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, -128, -128, -128, 0, 0, 0, 0, 0, -128, -128, -128, 0, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, -128, 0, -128, 0, -128, -128, -128, -128,
+        // State 237
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 238
+        -101, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, -101, -101, -101, 0, 0, 0, 0, 0, -101, -101, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, 0, -101, 0, -101, -101, -101, -101,
+        // State 239
+        -98, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, -98, -98, 0, 0, 0, 0, 0, -98, -98, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, 0, -98, 0, -98, -98, -98, -98,
+        // State 240
+        -103, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, -103, -103, -103, 0, 0, 0, 0, 0, -103, -103, -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, 0, -103, 0, -103, -103, -103, -103,
+        // State 241
+        -46, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, -46, -46, -46, 0, 0, 0, 0, 0, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, 0, -46, -46, -46, -46,
+        // State 242
+        -122, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, -122, -122, -122, 0, 0, 0, 0, 0, -122, -122, -122, 0, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, 0, -122, 0, -122, -122, -122, -122,
+        // State 243
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, 0, 0, 0, 0, -11, 0, 0, 0, -11, 0, 0, 0, 0, 0, -11, -11, 0, 0,
+        // State 244
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 250, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 246
+        -100, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100, -100, 0, 0, 0, 0, 0, -100, -100, -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, 0, -100, 0, -100, -100, -100, -100,
+        // State 247
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 248
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 249
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0, -88, -88, 0, 0, 0, 0, 0, 0, -88, 0, 0, 0, -88, 0, 0, 0, 0, 0, -88, -88, 0, 0,
+        // State 250
+        -47, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, -47, -47, -47, 0, 0, 0, 0, 0, -47, -47, -47, 0, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, 0, -47, 0, -47, -47, -47, -47,
+        // State 251
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, -89, -89, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, -89, 0, 0, 0, 0, 0, -89, -89, 0, 0,
+        // State 252
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 253
+        -42, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, -42, -42, -42, 0, 0, 0, 0, 0, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, 0, -42, -42, -42, -42,
+    ];
+    fn __action(state: i16, integer: usize) -> i16 {
+        __ACTION[(state as usize) * 57 + integer]
+    }
+    const __EOF_ACTION: &[i16] = &[
+        // State 0
+        -80,
+        // State 1
+        0,
+        // State 2
+        -81,
+        // State 3
+        0,
+        // State 4
+        0,
+        // State 5
+        0,
+        // State 6
+        0,
+        // State 7
+        0,
+        // State 8
+        0,
+        // State 9
+        0,
+        // State 10
+        0,
+        // State 11
+        0,
+        // State 12
+        0,
+        // State 13
+        0,
+        // State 14
+        0,
+        // State 15
+        0,
+        // State 16
+        0,
+        // State 17
+        0,
+        // State 18
+        0,
+        // State 19
+        0,
+        // State 20
+        0,
+        // State 21
+        0,
+        // State 22
+        0,
+        // State 23
+        0,
+        // State 24
+        0,
+        // State 25
+        0,
+        // State 26
+        0,
+        // State 27
+        0,
+        // State 28
+        0,
+        // State 29
+        0,
+        // State 30
+        0,
+        // State 31
+        0,
+        // State 32
+        0,
+        // State 33
+        0,
+        // State 34
+        0,
+        // State 35
+        0,
+        // State 36
+        0,
+        // State 37
+        0,
+        // State 38
+        0,
+        // State 39
+        0,
+        // State 40
+        0,
+        // State 41
+        0,
+        // State 42
+        0,
+        // State 43
+        0,
+        // State 44
+        0,
+        // State 45
+        0,
+        // State 46
+        0,
+        // State 47
+        0,
+        // State 48
+        0,
+        // State 49
+        0,
+        // State 50
+        0,
+        // State 51
+        0,
+        // State 52
+        0,
+        // State 53
+        0,
+        // State 54
+        0,
+        // State 55
+        0,
+        // State 56
+        0,
+        // State 57
+        0,
+        // State 58
+        0,
+        // State 59
+        0,
+        // State 60
+        0,
+        // State 61
+        0,
+        // State 62
+        0,
+        // State 63
+        0,
+        // State 64
+        0,
+        // State 65
+        0,
+        // State 66
+        0,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        0,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        -121,
+        // State 74
+        0,
+        // State 75
+        -123,
+        // State 76
+        0,
+        // State 77
+        0,
+        // State 78
+        0,
+        // State 79
+        0,
+        // State 80
+        0,
+        // State 81
+        -176,
+        // State 82
+        -164,
+        // State 83
+        -82,
+        // State 84
+        -169,
+        // State 85
+        0,
+        // State 86
+        0,
+        // State 87
+        0,
+        // State 88
+        0,
+        // State 89
+        0,
+        // State 90
+        0,
+        // State 91
+        0,
+        // State 92
+        0,
+        // State 93
+        0,
+        // State 94
+        0,
+        // State 95
+        0,
+        // State 96
+        0,
+        // State 97
+        0,
+        // State 98
+        0,
+        // State 99
+        0,
+        // State 100
+        0,
+        // State 101
+        0,
+        // State 102
+        0,
+        // State 103
+        0,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        0,
+        // State 107
+        0,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        -132,
+        // State 117
+        -170,
+        // State 118
+        -83,
+        // State 119
+        -131,
+        // State 120
+        0,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        0,
+        // State 130
+        0,
+        // State 131
+        0,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        0,
+        // State 140
+        0,
+        // State 141
+        0,
+        // State 142
+        -109,
+        // State 143
+        -113,
+        // State 144
+        -117,
+        // State 145
+        0,
+        // State 146
+        -102,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
+        // State 154
+        -97,
+        // State 155
+        0,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        0,
+        // State 159
+        0,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        0,
+        // State 163
+        0,
+        // State 164
+        0,
+        // State 165
+        0,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        0,
+        // State 170
+        0,
+        // State 171
+        0,
+        // State 172
+        -111,
+        // State 173
+        -108,
+        // State 174
+        -115,
+        // State 175
+        -112,
+        // State 176
+        -119,
+        // State 177
+        -116,
+        // State 178
+        0,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        -107,
+        // State 182
+        0,
+        // State 183
+        -165,
+        // State 184
+        -166,
+        // State 185
+        0,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        0,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        -105,
+        // State 192
+        -110,
+        // State 193
+        -114,
+        // State 194
+        -118,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        0,
+        // State 202
+        -129,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        0,
+        // State 207
+        0,
+        // State 208
+        0,
+        // State 209
+        0,
+        // State 210
+        -104,
+        // State 211
+        0,
+        // State 212
+        0,
+        // State 213
+        0,
+        // State 214
+        0,
+        // State 215
+        -124,
+        // State 216
+        -106,
+        // State 217
+        -127,
+        // State 218
+        0,
+        // State 219
+        0,
+        // State 220
+        0,
+        // State 221
+        0,
+        // State 222
+        -130,
+        // State 223
+        0,
+        // State 224
+        0,
+        // State 225
+        0,
+        // State 226
+        -99,
+        // State 227
+        -125,
+        // State 228
+        0,
+        // State 229
+        0,
+        // State 230
+        -126,
+        // State 231
+        -45,
+        // State 232
+        -120,
+        // State 233
+        0,
+        // State 234
+        0,
+        // State 235
+        0,
+        // State 236
+        -128,
+        // State 237
+        0,
+        // State 238
+        -101,
+        // State 239
+        -98,
+        // State 240
+        -103,
+        // State 241
+        -46,
+        // State 242
+        -122,
+        // State 243
+        0,
+        // State 244
+        0,
+        // State 245
+        0,
+        // State 246
+        -100,
+        // State 247
+        0,
+        // State 248
+        0,
+        // State 249
+        0,
+        // State 250
+        -47,
+        // State 251
+        0,
+        // State 252
+        0,
+        // State 253
+        -42,
+    ];
+    fn __goto(state: i16, nt: usize) -> i16 {
+        match nt {
+            6 => 74,
+            9 => 25,
+            10 => match state {
+                33 => 169,
+                _ => 86,
+            },
+            11 => 26,
+            14 => match state {
+                44 => 60,
+                58 => 70,
+                _ => 41,
+            },
+            15 => match state {
+                32 => 168,
+                _ => 87,
+            },
+            16 => match state {
+                55 => 198,
+                59 => 206,
+                65 => 213,
+                76 => 244,
+                77 => 245,
+                79 => 248,
+                80 => 252,
+                _ => 196,
+            },
+            17 => match state {
+                26 => 162,
+                _ => 88,
+            },
+            18 => 27,
+            20 => match state {
+                12 => 138,
+                _ => 89,
+            },
+            21 => match state {
+                75 => 241,
+                _ => 231,
+            },
+            23 => 75,
+            24 => match state {
+                69 => 220,
+                73 => 232,
+                75 => 242,
+                _ => 200,
+            },
+            26 => match state {
+                1 | 66 => 90,
+                13 => 140,
+                17 => 145,
+                18 => 147,
+                19 => 148,
+                20 => 150,
+                21 => 155,
+                24 => 160,
+                35 | 37 | 39 | 48..=50 => 171,
+                41 => 178,
+                43 => 180,
+                44 => 185,
+                46 => 189,
+                47 => 190,
+                56 => 199,
+                58 => 203,
+                60 => 207,
+                70 => 223,
+                71 => 229,
+                78 => 247,
+                _ => 141,
+            },
+            28 => match state {
+                15 => 37,
+                16 => 39,
+                36 => 48,
+                38 => 49,
+                40 => 50,
+                _ => 35,
+            },
+            30 => match state {
+                25 => 161,
+                _ => 91,
+            },
+            31 => match state {
+                1 | 66 => 92,
+                _ => 139,
+            },
+            32 => match state {
+                45 | 53 | 63..=64 | 72 => 187,
+                _ => 157,
+            },
+            34 => match state {
+                42 => 53,
+                51 => 63,
+                52 => 64,
+                62 => 72,
+                _ => 45,
+            },
+            35 => match state {
+                68 => 218,
+                74 => 234,
+                _ => 93,
+            },
+            36 => match state {
+                0 | 2 => 1,
+                57 | 69 => 68,
+                73 | 75 => 233,
+                _ => 66,
+            },
+            37 => match state {
+                29 => 165,
+                _ => 94,
+            },
+            38 => 28,
+            39 => match state {
+                23 => 159,
+                61 => 212,
+                _ => 95,
+            },
+            40 => 29,
+            41 => 30,
+            42 => match state {
+                28 => 164,
+                _ => 96,
+            },
+            43 => 31,
+            44 => 81,
+            45 => match state {
+                0 | 2 => 82,
+                67 => 214,
+                _ => 197,
+            },
+            47 => 67,
+            48 => match state {
+                69 => 221,
+                _ => 201,
+            },
+            50 => 69,
+            51 => match state {
+                27 => 163,
+                _ => 97,
+            },
+            52 => 32,
+            53 => 83,
+            54 => 98,
+            55 => 3,
+            56 => 4,
+            57 => 5,
+            58 => 6,
+            59 => 7,
+            60 => 8,
+            61 => 9,
+            62 => 10,
+            63 => 11,
+            64 => match state {
+                2 => 117,
+                _ => 84,
+            },
+            66 => 2,
+            67 => match state {
+                30 => 166,
+                _ => 99,
+            },
+            68 => 12,
+            69 => match state {
+                31 => 167,
+                _ => 100,
+            },
+            70 => 33,
+            _ => 0,
+        }
+    }
+    fn __expected_tokens(__state: i16) -> alloc::vec::Vec<alloc::string::String> {
+        const __TERMINAL: &[&str] = &[
+            r###""!""###,
+            r###""!=""###,
+            r###""%""###,
+            r###""&""###,
+            r###""&&""###,
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###"",""###,
+            r###""-""###,
+            r###""/""###,
+            r###"":""###,
+            r###"";""###,
+            r###""<""###,
+            r###""<<""###,
+            r###""<=""###,
+            r###""=""###,
+            r###""==""###,
+            r###"">""###,
+            r###"">=""###,
+            r###"">>""###,
+            r###""?""###,
+            r###""INDEX""###,
+            r###""PRINT""###,
+            r###""PRINTL""###,
+            r###""PRINTW""###,
+            r###""[""###,
+            r###""]""###,
+            r###""^""###,
+            r###""{""###,
+            r###""|""###,
+            r###""||""###,
+            r###""}""###,
+            r###""각각""###,
+            r###""거짓""###,
+            r###""그외""###,
+            r###""기다리기""###,
+            r###""만약""###,
+            r###""반복""###,
+            r###""변수""###,
+            r###""선택""###,
+            r###""시작""###,
+            r###""없음""###,
+            r###""자원""###,
+            r###""장면""###,
+            r###""종료""###,
+            r###""참""###,
+            r###""타이머""###,
+            r###""포함""###,
+            r###""혹은""###,
+            r###"built"###,
+            r###"channel"###,
+            r###"number"###,
+            r###"string"###,
+            r###"var"###,
+        ];
+        __TERMINAL.iter().enumerate().filter_map(|(index, terminal)| {
+            let next_state = __action(__state, index);
+            if next_state == 0 {
+                None
+            } else {
+                Some(alloc::string::ToString::to_string(terminal))
+            }
+        }).collect()
+    }
+    pub(crate) struct __StateMachine<'err>
+    where 
+    {
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __phantom: core::marker::PhantomData<(&'err ())>,
+    }
+    impl<'err> __state_machine::ParserDefinition for __StateMachine<'err>
+    where 
+    {
+        type Location = Position;
+        type Error = LexicalError;
+        type Token = Token;
+        type TokenIndex = usize;
+        type Symbol = __Symbol<>;
+        type Success = Vec<Stmt>;
+        type StateIndex = i16;
+        type Action = i16;
+        type ReduceIndex = i16;
+        type NonterminalIndex = usize;
+
+        #[inline]
+        fn start_location(&self) -> Self::Location {
+              Default::default()
+        }
+
+        #[inline]
+        fn start_state(&self) -> Self::StateIndex {
+              0
+        }
+
+        #[inline]
+        fn token_to_index(&self, token: &Self::Token) -> Option<usize> {
+            __token_to_integer(token, core::marker::PhantomData::<(&())>)
+        }
+
+        #[inline]
+        fn action(&self, state: i16, integer: usize) -> i16 {
+            __action(state, integer)
+        }
+
+        #[inline]
+        fn error_action(&self, state: i16) -> i16 {
+            __action(state, 57 - 1)
+        }
+
+        #[inline]
+        fn eof_action(&self, state: i16) -> i16 {
+            __EOF_ACTION[state as usize]
+        }
+
+        #[inline]
+        fn goto(&self, state: i16, nt: usize) -> i16 {
+            __goto(state, nt)
+        }
+
+        fn token_to_symbol(&self, token_index: usize, token: Self::Token) -> Self::Symbol {
+            __token_to_symbol(token_index, token, core::marker::PhantomData::<(&())>)
+        }
+
+        fn expected_tokens(&self, state: i16) -> alloc::vec::Vec<alloc::string::String> {
+            __expected_tokens(state)
+        }
+
+        #[inline]
+        fn uses_error_recovery(&self) -> bool {
+            true
+        }
+
+        #[inline]
+        fn error_recovery_symbol(
+            &self,
+            recovery: __state_machine::ErrorRecovery<Self>,
+        ) -> Self::Symbol {
+            __Symbol::Variant3(recovery)
+        }
+
+        fn reduce(
+            &mut self,
+            action: i16,
+            start_location: Option<&Self::Location>,
+            states: &mut alloc::vec::Vec<i16>,
+            symbols: &mut alloc::vec::Vec<__state_machine::SymbolTriple<Self>>,
+        ) -> Option<__state_machine::ParseResult<Self>> {
+            __reduce(
+                self.errors,
+                action,
+                start_location,
+                states,
+                symbols,
+                core::marker::PhantomData::<(&())>,
+            )
+        }
+
+        fn simulate_reduce(&self, action: i16) -> __state_machine::SimulatedReduce<Self> {
+            __simulate_reduce(action, core::marker::PhantomData::<(&())>)
+        }
+    }
+    fn __token_to_integer<
+        'err,
+    >(
+        __token: &Token,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> Option<usize>
+    {
+        match *__token {
+            Token::UnaryOp(UnaryOperator::Not) if true => Some(0),
+            Token::BinaryOp(BinaryOperator::NotEqual) if true => Some(1),
+            Token::BinaryOp(BinaryOperator::Rem) if true => Some(2),
+            Token::BinaryOp(BinaryOperator::And) if true => Some(3),
+            Token::BinaryOp(BinaryOperator::LogicalAnd) if true => Some(4),
+            Token::OpenParan if true => Some(5),
+            Token::CloseParan if true => Some(6),
+            Token::BinaryOp(BinaryOperator::Mul) if true => Some(7),
+            Token::BinaryOp(BinaryOperator::Add) if true => Some(8),
+            Token::Comma if true => Some(9),
+            Token::BinaryOp(BinaryOperator::Sub) if true => Some(10),
+            Token::BinaryOp(BinaryOperator::Div) if true => Some(11),
+            Token::TernaryOp(TernaryOperator::Conditional, false) if true => Some(12),
+            Token::SemiColon if true => Some(13),
+            Token::BinaryOp(BinaryOperator::Less) if true => Some(14),
+            Token::BinaryOp(BinaryOperator::Shl) if true => Some(15),
+            Token::BinaryOp(BinaryOperator::LessOrEqual) if true => Some(16),
+            Token::Assign if true => Some(17),
+            Token::BinaryOp(BinaryOperator::Equal) if true => Some(18),
+            Token::BinaryOp(BinaryOperator::Greater) if true => Some(19),
+            Token::BinaryOp(BinaryOperator::GreaterOrEqual) if true => Some(20),
+            Token::BinaryOp(BinaryOperator::Shr) if true => Some(21),
+            Token::TernaryOp(TernaryOperator::Conditional, true) if true => Some(22),
+            Token::OpenIndex if true => Some(23),
+            Token::Print if true => Some(24),
+            Token::PrintLine if true => Some(25),
+            Token::PrintWait if true => Some(26),
+            Token::OpenBracket if true => Some(27),
+            Token::CloseBracket if true => Some(28),
+            Token::BinaryOp(BinaryOperator::Xor) if true => Some(29),
+            Token::OpenBrace if true => Some(30),
+            Token::BinaryOp(BinaryOperator::Or) if true => Some(31),
+            Token::BinaryOp(BinaryOperator::LogicalOr) if true => Some(32),
+            Token::CloseBrace if true => Some(33),
+            Token::ForEach if true => Some(34),
+            Token::BoolLit(false) if true => Some(35),
+            Token::Else if true => Some(36),
+            Token::Join if true => Some(37),
+            Token::If if true => Some(38),
+            Token::While if true => Some(39),
+            Token::Declare if true => Some(40),
+            Token::Select if true => Some(41),
+            Token::Spawn if true => Some(42),
+            Token::NullLit if true => Some(43),
+            Token::Asset if true => Some(44),
+            Token::Label if true => Some(45),
+            Token::Exit if true => Some(46),
+            Token::BoolLit(true) if true => Some(47),
+            Token::Timer if true => Some(48),
+            Token::Include if true => Some(49),
+            Token::ElseIf if true => Some(50),
+            Token::Builtin(_) if true => Some(51),
+            Token::Channel(_) if true => Some(52),
+            Token::IntLit(_) if true => Some(53),
+            Token::StrLit(_) if true => Some(54),
+            Token::Variable(_) if true => Some(55),
+            _ => None,
+        }
+    }
+    fn __token_to_symbol<
+        'err,
+    >(
+        __token_index: usize,
+        __token: Token,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> __Symbol<>
+    {
+        match __token_index {
+            0 | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 | 12 | 13 | 14 | 15 | 16 | 17 | 18 | 19 | 20 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 | 40 | 41 | 42 | 43 | 44 | 45 | 46 | 47 | 48 | 49 | 50 => __Symbol::Variant0(__token),
+            51 | 52 | 54 | 55 => match __token {
+                Token::Builtin(__tok0) | Token::Channel(__tok0) | Token::StrLit(__tok0) | Token::Variable(__tok0) if true => __Symbol::Variant1(__tok0),
+                _ => unreachable!(),
+            },
+            53 => match __token {
+                Token::IntLit(__tok0) if true => __Symbol::Variant2(__tok0),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        }
+    }
+    fn __simulate_reduce<
+        'err,
+    >(
+        __reduce_index: i16,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> __state_machine::SimulatedReduce<__StateMachine<'err>>
+    {
+        match __reduce_index {
+            0 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 0,
+                }
+            }
+            1 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 1,
+                }
+            }
+            2 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 1,
+                }
+            }
+            3 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 2,
+                }
+            }
+            4 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 3,
+                }
+            }
+            5 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 3,
+                }
+            }
+            6 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 4,
+                }
+            }
+            7 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 5,
+                }
+            }
+            8 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 5,
+                }
+            }
+            9 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 6,
+                }
+            }
+            10 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 6,
+                }
+            }
+            11 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 7,
+                }
+            }
+            12 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 8,
+                }
+            }
+            13 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
+                }
+            }
+            14 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 9,
+                }
+            }
+            15 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 10,
+                }
+            }
+            16 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 11,
+                }
+            }
+            17 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 12,
+                }
+            }
+            18 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 13,
+                }
+            }
+            19 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 13,
+                }
+            }
+            20 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 14,
+                }
+            }
+            21 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 14,
+                }
+            }
+            22 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 15,
+                }
+            }
+            23 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 16,
+                }
+            }
+            24 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 16,
+                }
+            }
+            25 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 17,
+                }
+            }
+            26 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            27 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            28 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            29 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            30 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            31 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 18,
+                }
+            }
+            32 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 19,
+                }
+            }
+            33 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 19,
+                }
+            }
+            34 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 19,
+                }
+            }
+            35 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 19,
+                }
+            }
+            36 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 20,
+                }
+            }
+            37 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 20,
+                }
+            }
+            38 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 20,
+                }
+            }
+            39 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 20,
+                }
+            }
+            40 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 20,
+                }
+            }
+            41 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 21,
+                }
+            }
+            42 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 22,
+                }
+            }
+            43 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 22,
+                }
+            }
+            44 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 23,
+                }
+            }
+            45 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 23,
+                }
+            }
+            46 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 24,
+                }
+            }
+            47 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 25,
+                }
+            }
+            48 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 25,
+                }
+            }
+            49 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 26,
+                }
+            }
+            50 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 26,
+                }
+            }
+            51 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 27,
+                }
+            }
+            52 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
+                }
+            }
+            53 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 28,
+                }
+            }
+            54 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 28,
+                }
+            }
+            55 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            56 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 29,
+                }
+            }
+            57 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 30,
+                }
+            }
+            58 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 31,
+                }
+            }
+            59 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 32,
+                }
+            }
+            60 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 33,
+                }
+            }
+            61 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 33,
+                }
+            }
+            62 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 34,
+                }
+            }
+            63 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 34,
+                }
+            }
+            64 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            65 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            66 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            67 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            68 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 35,
+                }
+            }
+            69 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 36,
+                }
+            }
+            70 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 37,
+                }
+            }
+            71 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 38,
+                }
+            }
+            72 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 39,
+                }
+            }
+            73 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 40,
+                }
+            }
+            74 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 41,
+                }
+            }
+            75 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 41,
+                }
+            }
+            76 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 41,
+                }
+            }
+            77 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 42,
+                }
+            }
+            78 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 43,
+                }
+            }
+            79 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 44,
+                }
+            }
+            80 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 44,
+                }
+            }
+            81 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 45,
+                }
+            }
+            82 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 45,
+                }
+            }
+            83 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 46,
+                }
+            }
+            84 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 46,
+                }
+            }
+            85 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 47,
+                }
+            }
+            86 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 47,
+                }
+            }
+            87 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 48,
+                }
+            }
+            88 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 48,
+                }
+            }
+            89 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 49,
+                }
+            }
+            90 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 49,
+                }
+            }
+            91 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 50,
+                }
+            }
+            92 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 50,
+                }
+            }
+            93 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 51,
+                }
+            }
+            94 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            95 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 52,
+                }
+            }
+            96 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
+            }
+            97 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 53,
+                }
+            }
+            98 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 53,
+                }
+            }
+            99 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 53,
+                }
+            }
+            100 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 53,
+                }
+            }
+            101 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
+            }
+            102 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 53,
+                }
+            }
+            103 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 53,
+                }
+            }
+            104 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 53,
+                }
+            }
+            105 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 53,
+                }
+            }
+            106 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            107 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            108 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
+            }
+            109 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 53,
+                }
+            }
+            110 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            111 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            112 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
+            }
+            113 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 53,
+                }
+            }
+            114 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            115 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            116 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
+            }
+            117 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 53,
+                }
+            }
+            118 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 53,
+                }
+            }
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 53,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 53,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 8,
+                    nonterminal_produced: 53,
+                }
+            }
+            122 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 53,
+                }
+            }
+            123 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 53,
+                }
+            }
+            124 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 53,
+                }
+            }
+            125 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 53,
+                }
+            }
+            126 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 53,
+                }
+            }
+            127 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 7,
+                    nonterminal_produced: 53,
+                }
+            }
+            128 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 53,
+                }
+            }
+            129 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 6,
+                    nonterminal_produced: 53,
+                }
+            }
+            130 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 53,
+                }
+            }
+            131 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 53,
+                }
+            }
+            132 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            133 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            134 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 54,
+                }
+            }
+            135 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 54,
+                }
+            }
+            136 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 54,
+                }
+            }
+            137 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 54,
+                }
+            }
+            138 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 54,
+                }
+            }
+            139 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 54,
+                }
+            }
+            140 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 5,
+                    nonterminal_produced: 54,
+                }
+            }
+            141 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 54,
+                }
+            }
+            142 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 54,
+                }
+            }
+            143 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 54,
+                }
+            }
+            144 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 54,
+                }
+            }
+            145 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 55,
+                }
+            }
+            146 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 55,
+                }
+            }
+            147 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 56,
+                }
+            }
+            148 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 56,
+                }
+            }
+            149 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 57,
+                }
+            }
+            150 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 57,
+                }
+            }
+            151 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 58,
+                }
+            }
+            152 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 58,
+                }
+            }
+            153 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 59,
+                }
+            }
+            154 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 59,
+                }
+            }
+            155 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 60,
+                }
+            }
+            156 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 60,
+                }
+            }
+            157 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 61,
+                }
+            }
+            158 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 61,
+                }
+            }
+            159 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 62,
+                }
+            }
+            160 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 62,
+                }
+            }
+            161 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 3,
+                    nonterminal_produced: 63,
+                }
+            }
+            162 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 63,
+                }
+            }
+            163 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 64,
+                }
+            }
+            164 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 64,
+                }
+            }
+            165 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 4,
+                    nonterminal_produced: 64,
+                }
+            }
+            166 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 65,
+                }
+            }
+            167 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 65,
+                }
+            }
+            168 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 66,
+                }
+            }
+            169 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 66,
+                }
+            }
+            170 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 2,
+                    nonterminal_produced: 67,
+                }
+            }
+            171 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 67,
+                }
+            }
+            172 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 68,
+                }
+            }
+            173 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 69,
+                }
+            }
+            174 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 70,
+                }
+            }
+            175 => __state_machine::SimulatedReduce::Accept,
+            176 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 72,
+                }
+            }
+            177 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 0,
+                    nonterminal_produced: 72,
+                }
+            }
+            _ => panic!("invalid reduction index {}", __reduce_index)
+        }
+    }
+    pub struct ProgramParser {
+        _priv: (),
+    }
+
+    impl ProgramParser {
+        pub fn new() -> ProgramParser {
+            ProgramParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            'err,
+            __TOKEN: __ToTriple<'err, >,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+            __tokens0: __TOKENS,
+        ) -> Result<Vec<Stmt>, __lalrpop_util::ParseError<Position, Token, LexicalError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            __state_machine::Parser::drive(
+                __StateMachine {
+                    errors,
+                    __phantom: core::marker::PhantomData::<(&())>,
+                },
+                __tokens,
+            )
+        }
+    }
+    fn __accepts<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __error_state: i16,
+        __states: & [i16],
+        __opt_integer: Option<usize>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> bool
+    {
+        let mut __states = __states.to_vec();
+        __states.push(__error_state);
+        loop {
+            let mut __states_len = __states.len();
+            let __top = __states[__states_len - 1];
+            let __action = match __opt_integer {
+                None => __EOF_ACTION[__top as usize],
+                Some(__integer) => __action(__top, __integer),
+            };
+            if __action == 0 { return false; }
+            if __action > 0 { return true; }
+            let (__to_pop, __nt) = match __simulate_reduce(-(__action + 1), core::marker::PhantomData::<(&())>) {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop, nonterminal_produced
+                } => (states_to_pop, nonterminal_produced),
+                __state_machine::SimulatedReduce::Accept => return true,
+            };
+            __states_len -= __to_pop;
+            __states.truncate(__states_len);
+            let __top = __states[__states_len - 1];
+            let __next_state = __goto(__top, __nt);
+            __states.push(__next_state);
+        }
+    }
+    pub(crate) fn __reduce<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __action: i16,
+        __lookahead_start: Option<&Position>,
+        __states: &mut alloc::vec::Vec<i16>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> Option<Result<Vec<Stmt>,__lalrpop_util::ParseError<Position, Token, LexicalError>>>
+    {
+        let (__pop_states, __nonterminal) = match __action {
+            0 => {
+                __reduce0(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            1 => {
+                __reduce1(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            2 => {
+                __reduce2(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            3 => {
+                __reduce3(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            4 => {
+                __reduce4(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            5 => {
+                __reduce5(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            6 => {
+                __reduce6(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            7 => {
+                __reduce7(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            8 => {
+                __reduce8(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            9 => {
+                __reduce9(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            10 => {
+                __reduce10(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            11 => {
+                __reduce11(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            12 => {
+                __reduce12(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            13 => {
+                __reduce13(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            14 => {
+                __reduce14(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            15 => {
+                __reduce15(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            16 => {
+                __reduce16(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            17 => {
+                __reduce17(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            18 => {
+                __reduce18(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            19 => {
+                __reduce19(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            20 => {
+                __reduce20(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            21 => {
+                __reduce21(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            22 => {
+                __reduce22(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            23 => {
+                __reduce23(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            24 => {
+                __reduce24(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            25 => {
+                __reduce25(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            26 => {
+                __reduce26(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            27 => {
+                __reduce27(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            28 => {
+                __reduce28(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            29 => {
+                __reduce29(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            30 => {
+                __reduce30(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            31 => {
+                __reduce31(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            32 => {
+                __reduce32(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            33 => {
+                __reduce33(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            34 => {
+                __reduce34(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            35 => {
+                __reduce35(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            36 => {
+                __reduce36(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            37 => {
+                __reduce37(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            38 => {
+                __reduce38(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            39 => {
+                __reduce39(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            40 => {
+                __reduce40(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            41 => {
+                __reduce41(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            42 => {
+                __reduce42(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            43 => {
+                __reduce43(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            44 => {
+                __reduce44(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            45 => {
+                __reduce45(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            46 => {
+                __reduce46(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            47 => {
+                __reduce47(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            48 => {
+                __reduce48(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            49 => {
+                __reduce49(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            50 => {
+                __reduce50(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            51 => {
+                __reduce51(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            52 => {
+                __reduce52(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            53 => {
+                __reduce53(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            54 => {
+                __reduce54(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            55 => {
+                __reduce55(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            56 => {
+                __reduce56(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            57 => {
+                __reduce57(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            58 => {
+                __reduce58(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            59 => {
+                __reduce59(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            60 => {
+                __reduce60(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            61 => {
+                __reduce61(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            62 => {
+                __reduce62(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            63 => {
+                __reduce63(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            64 => {
+                __reduce64(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            65 => {
+                __reduce65(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            66 => {
+                __reduce66(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            67 => {
+                __reduce67(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            68 => {
+                __reduce68(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            69 => {
+                __reduce69(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            70 => {
+                __reduce70(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            71 => {
+                __reduce71(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            72 => {
+                __reduce72(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            73 => {
+                __reduce73(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            74 => {
+                __reduce74(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            75 => {
+                __reduce75(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            76 => {
+                __reduce76(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            77 => {
+                __reduce77(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            78 => {
+                __reduce78(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            79 => {
+                __reduce79(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            80 => {
+                __reduce80(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            81 => {
+                __reduce81(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            82 => {
+                __reduce82(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            83 => {
+                __reduce83(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            84 => {
+                __reduce84(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            85 => {
+                __reduce85(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            86 => {
+                __reduce86(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            87 => {
+                __reduce87(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            88 => {
+                __reduce88(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            89 => {
+                __reduce89(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            90 => {
+                __reduce90(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            91 => {
+                __reduce91(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            92 => {
+                __reduce92(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            93 => {
+                __reduce93(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            94 => {
+                __reduce94(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            95 => {
+                __reduce95(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            96 => {
+                __reduce96(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            97 => {
+                __reduce97(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            98 => {
+                __reduce98(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            99 => {
+                __reduce99(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            100 => {
+                __reduce100(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            101 => {
+                __reduce101(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            102 => {
+                __reduce102(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            103 => {
+                __reduce103(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            104 => {
+                __reduce104(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            105 => {
+                __reduce105(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            106 => {
+                __reduce106(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            107 => {
+                __reduce107(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            108 => {
+                __reduce108(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            109 => {
+                __reduce109(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            110 => {
+                __reduce110(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            111 => {
+                __reduce111(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            112 => {
+                __reduce112(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            113 => {
+                __reduce113(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            114 => {
+                __reduce114(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            115 => {
+                __reduce115(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            116 => {
+                __reduce116(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            117 => {
+                __reduce117(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            118 => {
+                __reduce118(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            119 => {
+                __reduce119(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            120 => {
+                __reduce120(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            121 => {
+                __reduce121(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            122 => {
+                __reduce122(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            123 => {
+                __reduce123(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            124 => {
+                __reduce124(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            125 => {
+                __reduce125(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            126 => {
+                __reduce126(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            127 => {
+                __reduce127(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            128 => {
+                __reduce128(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            129 => {
+                __reduce129(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            130 => {
+                __reduce130(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            131 => {
+                __reduce131(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            132 => {
+                __reduce132(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            133 => {
+                __reduce133(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            134 => {
+                __reduce134(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            135 => {
+                __reduce135(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            136 => {
+                __reduce136(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            137 => {
+                __reduce137(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            138 => {
+                __reduce138(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            139 => {
+                __reduce139(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            140 => {
+                __reduce140(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            141 => {
+                __reduce141(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            142 => {
+                __reduce142(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            143 => {
+                __reduce143(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            144 => {
+                __reduce144(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            145 => {
+                __reduce145(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            146 => {
+                __reduce146(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            147 => {
+                __reduce147(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            148 => {
+                __reduce148(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            149 => {
+                __reduce149(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            150 => {
+                __reduce150(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            151 => {
+                __reduce151(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            152 => {
+                __reduce152(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            153 => {
+                __reduce153(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            154 => {
+                __reduce154(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            155 => {
+                __reduce155(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            156 => {
+                __reduce156(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            157 => {
+                __reduce157(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            158 => {
+                __reduce158(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            159 => {
+                __reduce159(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            160 => {
+                __reduce160(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            161 => {
+                __reduce161(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            162 => {
+                __reduce162(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            163 => {
+                __reduce163(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            164 => {
+                __reduce164(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            165 => {
+                __reduce165(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            166 => {
+                __reduce166(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            167 => {
+                __reduce167(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            168 => {
+                __reduce168(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            169 => {
+                __reduce169(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            170 => {
+                __reduce170(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            171 => {
+                __reduce171(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            172 => {
+                __reduce172(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            173 => {
+                __reduce173(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            174 => {
+                __reduce174(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            175 => {
+                // __Program = Program => ActionFn(0);
+                let __sym0 = __pop_Variant10(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(errors, __sym0);
+                return Some(Ok(__nt));
+            }
+            176 => {
+                __reduce176(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            177 => {
+                __reduce177(errors, __lookahead_start, __symbols, core::marker::PhantomData::<(&())>)
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        let __state = *__states.last().unwrap();
+        let __next_state = __goto(__state, __nonterminal);
+        __states.push(__next_state);
+        None
+    }
+    #[inline(never)]
+    fn __symbol_type_mismatch() -> ! {
+        panic!("symbol type mismatch")
+    }
+    fn __pop_Variant17<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, (Expr, Position), Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant17(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant12<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, (Expr, Vec<Stmt>, Location), Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant12(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant16<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, (Symbol, Position, Vec<(Expr, Position)>), Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant16(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant22<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, (Vec<Expr>, Vec<Stmt>, Location), Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant22(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant14<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, (Vec<Stmt>, Location), Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant14(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant9<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, BinaryOperator, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant9(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant5<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Expr, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant5(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant19<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Location, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant19(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant20<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Option<Stmt>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant20(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant8<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Position, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant8(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant24<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Stmt, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant24(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant1<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Symbol, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant1(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant0<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Token, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant0(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant25<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, UnaryOperator, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant25(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<Expr>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant11(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant10<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, Vec<Stmt>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant10(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant3<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, __lalrpop_util::ErrorRecovery<Position, Token, LexicalError>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant3(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant18<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, alloc::vec::Vec<(Expr, Position)>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant18(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant13<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant13(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant23<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant23(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant7<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, alloc::vec::Vec<Expr>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant7(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant21<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, alloc::vec::Vec<Option<Stmt>>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant21(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant15<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, core::option::Option<(Vec<Stmt>, Location)>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant15(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant6<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, core::option::Option<Expr>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant6(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant4<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, core::option::Option<Symbol>, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant4(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    fn __pop_Variant2<
+    >(
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>
+    ) -> (Position, u32, Position)
+     {
+        match __symbols.pop() {
+            Some((__l, __Symbol::Variant2(__v), __r)) => (__l, __v, __r),
+            _ => __symbol_type_mismatch()
+        }
+    }
+    pub(crate) fn __reduce0<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // (":" <built>) = ":", built => ActionFn(122);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action122::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant1(__nt), __end));
+        (2, 0)
+    }
+    pub(crate) fn __reduce1<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // (":" <built>)? = ":", built => ActionFn(142);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action142::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (2, 1)
+    }
+    pub(crate) fn __reduce2<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // (":" <built>)? =  => ActionFn(121);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action121::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 1)
+    }
+    pub(crate) fn __reduce3<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("=" <Expr>) = "=", Expr => ActionFn(119);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action119::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 2)
+    }
+    pub(crate) fn __reduce4<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("=" <Expr>)? = "=", Expr => ActionFn(145);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action145::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (2, 3)
+    }
+    pub(crate) fn __reduce5<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ("=" <Expr>)? =  => ActionFn(118);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action118::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 3)
+    }
+    pub(crate) fn __reduce6<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Literal> ",") = Literal, "," => ActionFn(106);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action106::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 4)
+    }
+    pub(crate) fn __reduce7<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Literal> ",")* =  => ActionFn(104);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action104::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (0, 5)
+    }
+    pub(crate) fn __reduce8<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Literal> ",")* = (<Literal> ",")+ => ActionFn(105);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action105::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 5)
+    }
+    pub(crate) fn __reduce9<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Literal> ",")+ = Literal, "," => ActionFn(148);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action148::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 6)
+    }
+    pub(crate) fn __reduce10<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // (<Literal> ",")+ = (<Literal> ",")+, Literal, "," => ActionFn(149);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action149::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 6)
+    }
+    pub(crate) fn __reduce11<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // @L =  => ActionFn(123);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action123::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 7)
+    }
+    pub(crate) fn __reduce12<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // @R =  => ActionFn(85);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action85::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant8(__nt), __end));
+        (0, 8)
+    }
+    pub(crate) fn __reduce13<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // AddOp = "+" => ActionFn(52);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action52::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 9)
+    }
+    pub(crate) fn __reduce14<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // AddOp = "-" => ActionFn(53);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action53::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 9)
+    }
+    pub(crate) fn __reduce15<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // AndExpr = Tier<AndOp, BoolExpr> => ActionFn(34);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action34::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 10)
+    }
+    pub(crate) fn __reduce16<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // AndOp = "&" => ActionFn(43);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action43::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 11)
+    }
+    pub(crate) fn __reduce17<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Arg = Expr, "," => ActionFn(63);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action63::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 12)
+    }
+    pub(crate) fn __reduce18<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Arg* =  => ActionFn(83);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action83::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (0, 13)
+    }
+    pub(crate) fn __reduce19<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Arg* = Arg+ => ActionFn(84);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action84::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 13)
+    }
+    pub(crate) fn __reduce20<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Arg+ = Expr, "," => ActionFn(178);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action178::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 14)
+    }
+    pub(crate) fn __reduce21<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Arg+ = Arg+, Expr, "," => ActionFn(179);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action179::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (3, 14)
+    }
+    pub(crate) fn __reduce22<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ArithmeticExpr = Tier<AddOp, Factor> => ActionFn(37);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action37::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 15)
+    }
+    pub(crate) fn __reduce23<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Body =  => ActionFn(222);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action222::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 16)
+    }
+    pub(crate) fn __reduce24<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Body = RecoverStmt+ => ActionFn(223);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action223::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 16)
+    }
+    pub(crate) fn __reduce25<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // BoolExpr = Tier<BoolOp, ShiftExpr> => ActionFn(35);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action35::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 17)
+    }
+    pub(crate) fn __reduce26<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // BoolOp = ">" => ActionFn(44);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action44::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce27<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // BoolOp = ">=" => ActionFn(45);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action45::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce28<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // BoolOp = "<" => ActionFn(46);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action46::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce29<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // BoolOp = "<=" => ActionFn(47);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action47::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce30<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // BoolOp = "==" => ActionFn(48);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action48::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce31<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // BoolOp = "!=" => ActionFn(49);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action49::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 18)
+    }
+    pub(crate) fn __reduce32<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallArgs = "(", Expr, ")" => ActionFn(184);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action184::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 19)
+    }
+    pub(crate) fn __reduce33<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallArgs = "(", ")" => ActionFn(185);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action185::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (2, 19)
+    }
+    pub(crate) fn __reduce34<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallArgs = "(", Arg+, Expr, ")" => ActionFn(186);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action186::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (4, 19)
+    }
+    pub(crate) fn __reduce35<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallArgs = "(", Arg+, ")" => ActionFn(187);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action187::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant11(__nt), __end));
+        (3, 19)
+    }
+    pub(crate) fn __reduce36<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallExpr = built, "(", Expr, ")" => ActionFn(192);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action192::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 20)
+    }
+    pub(crate) fn __reduce37<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallExpr = built, "(", ")" => ActionFn(193);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action193::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 20)
+    }
+    pub(crate) fn __reduce38<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallExpr = built, "(", Arg+, Expr, ")" => ActionFn(194);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action194::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (5, 20)
+    }
+    pub(crate) fn __reduce39<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallExpr = built, "(", Arg+, ")" => ActionFn(195);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action195::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 20)
+    }
+    pub(crate) fn __reduce40<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // CallExpr = Term => ActionFn(61);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action61::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 20)
+    }
+    pub(crate) fn __reduce41<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ElseIfStmt = Loc, "혹은", Expr, "{", Body, "}" => ActionFn(25);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant10(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action25::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 21)
+    }
+    pub(crate) fn __reduce42<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ElseIfStmt* =  => ActionFn(111);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action111::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (0, 22)
+    }
+    pub(crate) fn __reduce43<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ElseIfStmt* = ElseIfStmt+ => ActionFn(112);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action112::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 22)
+    }
+    pub(crate) fn __reduce44<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ElseIfStmt+ = ElseIfStmt => ActionFn(134);
+        let __sym0 = __pop_Variant12(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action134::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (1, 23)
+    }
+    pub(crate) fn __reduce45<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ElseIfStmt+ = ElseIfStmt+, ElseIfStmt => ActionFn(135);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant12(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action135::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant13(__nt), __end));
+        (2, 23)
+    }
+    pub(crate) fn __reduce46<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ElseStmt = Loc, "그외", "{", Body, "}" => ActionFn(27);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant10(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action27::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant14(__nt), __end));
+        (5, 24)
+    }
+    pub(crate) fn __reduce47<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ElseStmt? = ElseStmt => ActionFn(109);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action109::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (1, 25)
+    }
+    pub(crate) fn __reduce48<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ElseStmt? =  => ActionFn(110);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action110::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant15(__nt), __end));
+        (0, 25)
+    }
+    pub(crate) fn __reduce49<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr = Expr, "?", LogicalOrExpr, ":", LogicalOrExpr => ActionFn(28);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant5(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action28::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (5, 26)
+    }
+    pub(crate) fn __reduce50<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr = LogicalOrExpr => ActionFn(29);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action29::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 26)
+    }
+    pub(crate) fn __reduce51<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr* =  => ActionFn(113);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action113::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (0, 27)
+    }
+    pub(crate) fn __reduce52<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr* = Expr+ => ActionFn(114);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action114::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 27)
+    }
+    pub(crate) fn __reduce53<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr+ = Expr => ActionFn(132);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action132::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (1, 28)
+    }
+    pub(crate) fn __reduce54<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr+ = Expr+, Expr => ActionFn(133);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action133::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant7(__nt), __end));
+        (2, 28)
+    }
+    pub(crate) fn __reduce55<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr? = Expr => ActionFn(81);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action81::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (1, 29)
+    }
+    pub(crate) fn __reduce56<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Expr? =  => ActionFn(82);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action82::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant6(__nt), __end));
+        (0, 29)
+    }
+    pub(crate) fn __reduce57<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Factor = Tier<MulOp, UnaryExpr> => ActionFn(38);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action38::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 30)
+    }
+    pub(crate) fn __reduce58<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Index = var, IndexArg+ => ActionFn(166);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant18(__symbols);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action166::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant16(__nt), __end));
+        (2, 31)
+    }
+    pub(crate) fn __reduce59<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IndexArg = "INDEX", Expr, "]" => ActionFn(167);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action167::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant17(__nt), __end));
+        (3, 32)
+    }
+    pub(crate) fn __reduce60<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IndexArg* =  => ActionFn(77);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action77::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (0, 33)
+    }
+    pub(crate) fn __reduce61<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IndexArg* = IndexArg+ => ActionFn(78);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action78::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 33)
+    }
+    pub(crate) fn __reduce62<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IndexArg+ = IndexArg => ActionFn(79);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action79::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (1, 34)
+    }
+    pub(crate) fn __reduce63<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // IndexArg+ = IndexArg+, IndexArg => ActionFn(80);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant17(__symbols);
+        let __sym0 = __pop_Variant18(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action80::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant18(__nt), __end));
+        (2, 34)
+    }
+    pub(crate) fn __reduce64<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Literal = number => ActionFn(168);
+        let __sym0 = __pop_Variant2(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action168::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 35)
+    }
+    pub(crate) fn __reduce65<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Literal = string => ActionFn(169);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action169::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 35)
+    }
+    pub(crate) fn __reduce66<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Literal = "참" => ActionFn(170);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action170::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 35)
+    }
+    pub(crate) fn __reduce67<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Literal = "거짓" => ActionFn(171);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action171::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 35)
+    }
+    pub(crate) fn __reduce68<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Literal = "없음" => ActionFn(172);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action172::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 35)
+    }
+    pub(crate) fn __reduce69<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Loc =  => ActionFn(158);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action158::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant19(__nt), __end));
+        (0, 36)
+    }
+    pub(crate) fn __reduce70<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalAndExpr = Tier<LogicalAndOp, OrExpr> => ActionFn(31);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 37)
+    }
+    pub(crate) fn __reduce71<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalAndOp = "&&" => ActionFn(40);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action40::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 38)
+    }
+    pub(crate) fn __reduce72<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalOrExpr = Tier<LogicalOrOp, LogicalAndExpr> => ActionFn(30);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 39)
+    }
+    pub(crate) fn __reduce73<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // LogicalOrOp = "||" => ActionFn(39);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action39::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 40)
+    }
+    pub(crate) fn __reduce74<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // MulOp = "*" => ActionFn(54);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action54::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 41)
+    }
+    pub(crate) fn __reduce75<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // MulOp = "/" => ActionFn(55);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action55::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 41)
+    }
+    pub(crate) fn __reduce76<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // MulOp = "%" => ActionFn(56);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action56::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 41)
+    }
+    pub(crate) fn __reduce77<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // OrExpr = Tier<OrOp, XorExpr> => ActionFn(32);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 42)
+    }
+    pub(crate) fn __reduce78<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // OrOp = "|" => ActionFn(41);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action41::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 43)
+    }
+    pub(crate) fn __reduce79<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Program =  => ActionFn(228);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action228::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (0, 44)
+    }
+    pub(crate) fn __reduce80<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Program = TopStmt+ => ActionFn(229);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action229::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant10(__nt), __end));
+        (1, 44)
+    }
+    pub(crate) fn __reduce81<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RecoverStmt = Stmt => ActionFn(6);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 45)
+    }
+    pub(crate) fn __reduce82<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RecoverStmt = error, ";" => ActionFn(7);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action7::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (2, 45)
+    }
+    pub(crate) fn __reduce83<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RecoverStmt* =  => ActionFn(124);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action124::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (0, 46)
+    }
+    pub(crate) fn __reduce84<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RecoverStmt* = RecoverStmt+ => ActionFn(125);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action125::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 46)
+    }
+    pub(crate) fn __reduce85<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RecoverStmt+ = RecoverStmt => ActionFn(130);
+        let __sym0 = __pop_Variant20(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action130::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 47)
+    }
+    pub(crate) fn __reduce86<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // RecoverStmt+ = RecoverStmt+, RecoverStmt => ActionFn(131);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action131::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (2, 47)
+    }
+    pub(crate) fn __reduce87<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SelectArm = Loc, Literal, "{", Body, "}" => ActionFn(150);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant10(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action150::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (5, 48)
+    }
+    pub(crate) fn __reduce88<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SelectArm = Loc, (<Literal> ",")+, Literal, "{", Body, "}" => ActionFn(151);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant10(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action151::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant22(__nt), __end));
+        (6, 48)
+    }
+    pub(crate) fn __reduce89<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SelectArm* =  => ActionFn(107);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action107::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (0, 49)
+    }
+    pub(crate) fn __reduce90<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SelectArm* = SelectArm+ => ActionFn(108);
+        let __sym0 = __pop_Variant23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action108::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 49)
+    }
+    pub(crate) fn __reduce91<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SelectArm+ = SelectArm => ActionFn(136);
+        let __sym0 = __pop_Variant22(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action136::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (1, 50)
+    }
+    pub(crate) fn __reduce92<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // SelectArm+ = SelectArm+, SelectArm => ActionFn(137);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant22(__symbols);
+        let __sym0 = __pop_Variant23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action137::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant23(__nt), __end));
+        (2, 50)
+    }
+    pub(crate) fn __reduce93<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ShiftExpr = Tier<ShiftOp, ArithmeticExpr> => ActionFn(36);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action36::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 51)
+    }
+    pub(crate) fn __reduce94<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ShiftOp = "<<" => ActionFn(50);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action50::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 52)
+    }
+    pub(crate) fn __reduce95<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // ShiftOp = ">>" => ActionFn(51);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action51::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 52)
+    }
+    pub(crate) fn __reduce96<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "종료", ";" => ActionFn(9);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action9::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (3, 53)
+    }
+    pub(crate) fn __reduce97<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "시작", built, "(", Expr, ")", ";" => ActionFn(196);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant5(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action196::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (7, 53)
+    }
+    pub(crate) fn __reduce98<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "시작", built, "(", ")", ";" => ActionFn(197);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action197::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (6, 53)
+    }
+    pub(crate) fn __reduce99<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "시작", built, "(", Arg+, Expr, ")", ";" => ActionFn(198);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action198::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (8, 53)
+    }
+    pub(crate) fn __reduce100<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "시작", built, "(", Arg+, ")", ";" => ActionFn(199);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant7(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action199::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (7, 53)
+    }
+    pub(crate) fn __reduce101<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "기다리기", ";" => ActionFn(11);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action11::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (3, 53)
+    }
+    pub(crate) fn __reduce102<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, var, ":", built, "=", Expr, ";" => ActionFn(143);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant5(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant1(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action143::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (7, 53)
+    }
+    pub(crate) fn __reduce103<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, var, "=", Expr, ";" => ActionFn(144);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant1(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action144::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (5, 53)
+    }
+    pub(crate) fn __reduce104<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, Index, "=", Expr, ";" => ActionFn(13);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant16(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action13::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (5, 53)
+    }
+    pub(crate) fn __reduce105<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "변수", var, "=", Expr, ";" => ActionFn(146);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant5(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action146::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (6, 53)
+    }
+    pub(crate) fn __reduce106<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "변수", var, ";" => ActionFn(147);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action147::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (4, 53)
+    }
+    pub(crate) fn __reduce107<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINT", channel, ";" => ActionFn(230);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action230::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (4, 53)
+    }
+    pub(crate) fn __reduce108<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINT", ";" => ActionFn(231);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action231::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (3, 53)
+    }
+    pub(crate) fn __reduce109<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINT", channel, Expr+, ";" => ActionFn(232);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action232::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (5, 53)
+    }
+    pub(crate) fn __reduce110<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINT", Expr+, ";" => ActionFn(233);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action233::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (4, 53)
+    }
+    pub(crate) fn __reduce111<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINTL", channel, ";" => ActionFn(234);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action234::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (4, 53)
+    }
+    pub(crate) fn __reduce112<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINTL", ";" => ActionFn(235);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action235::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (3, 53)
+    }
+    pub(crate) fn __reduce113<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINTL", channel, Expr+, ";" => ActionFn(236);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action236::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (5, 53)
+    }
+    pub(crate) fn __reduce114<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINTL", Expr+, ";" => ActionFn(237);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action237::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (4, 53)
+    }
+    pub(crate) fn __reduce115<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINTW", channel, ";" => ActionFn(238);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action238::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (4, 53)
+    }
+    pub(crate) fn __reduce116<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINTW", ";" => ActionFn(239);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action239::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (3, 53)
+    }
+    pub(crate) fn __reduce117<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINTW", channel, Expr+, ";" => ActionFn(240);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action240::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (5, 53)
+    }
+    pub(crate) fn __reduce118<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "PRINTW", Expr+, ";" => ActionFn(241);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action241::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (4, 53)
+    }
+    pub(crate) fn __reduce119<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "만약", Expr, "{", Body, "}", ElseStmt => ActionFn(202);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant14(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant10(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action202::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (7, 53)
+    }
+    pub(crate) fn __reduce120<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "만약", Expr, "{", Body, "}" => ActionFn(203);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant10(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action203::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (6, 53)
+    }
+    pub(crate) fn __reduce121<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "만약", Expr, "{", Body, "}", ElseIfStmt+, ElseStmt => ActionFn(204);
+        assert!(__symbols.len() >= 8);
+        let __sym7 = __pop_Variant14(__symbols);
+        let __sym6 = __pop_Variant13(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant10(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action204::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (8, 53)
+    }
+    pub(crate) fn __reduce122<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "만약", Expr, "{", Body, "}", ElseIfStmt+ => ActionFn(205);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant13(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant10(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action205::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (7, 53)
+    }
+    pub(crate) fn __reduce123<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "반복", Expr, "{", Body, "}" => ActionFn(19);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant10(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action19::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (6, 53)
+    }
+    pub(crate) fn __reduce124<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "타이머", Expr, "{", Body, "}" => ActionFn(20);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant10(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action20::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (6, 53)
+    }
+    pub(crate) fn __reduce125<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "각각", var, Expr, "{", Body, "}" => ActionFn(21);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant10(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant5(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action21::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (7, 53)
+    }
+    pub(crate) fn __reduce126<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "선택", Expr, "{", ElseStmt, "}" => ActionFn(224);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant14(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action224::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (6, 53)
+    }
+    pub(crate) fn __reduce127<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "선택", Expr, "{", SelectArm+, ElseStmt, "}" => ActionFn(225);
+        assert!(__symbols.len() >= 7);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant14(__symbols);
+        let __sym4 = __pop_Variant23(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action225::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (7, 53)
+    }
+    pub(crate) fn __reduce128<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "선택", Expr, "{", "}" => ActionFn(226);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action226::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (5, 53)
+    }
+    pub(crate) fn __reduce129<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, "선택", Expr, "{", SelectArm+, "}" => ActionFn(227);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant23(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action227::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (6, 53)
+    }
+    pub(crate) fn __reduce130<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Loc, Expr, ";" => ActionFn(23);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action23::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (3, 53)
+    }
+    pub(crate) fn __reduce131<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Stmt = Stmt, ";" => ActionFn(24);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant24(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action24::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant24(__nt), __end));
+        (2, 53)
+    }
+    pub(crate) fn __reduce132<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = Literal => ActionFn(71);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action71::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce133<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = var => ActionFn(173);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action173::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce134<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = Index => ActionFn(160);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action160::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 54)
+    }
+    pub(crate) fn __reduce135<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "[", Expr, "]" => ActionFn(214);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action214::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 54)
+    }
+    pub(crate) fn __reduce136<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "[", Expr, "]", IndexArg+ => ActionFn(215);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action215::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 54)
+    }
+    pub(crate) fn __reduce137<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "[", "]" => ActionFn(216);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action216::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 54)
+    }
+    pub(crate) fn __reduce138<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "[", "]", IndexArg+ => ActionFn(217);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant18(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action217::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 54)
+    }
+    pub(crate) fn __reduce139<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "[", Arg+, Expr, "]" => ActionFn(218);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action218::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 54)
+    }
+    pub(crate) fn __reduce140<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "[", Arg+, Expr, "]", IndexArg+ => ActionFn(219);
+        assert!(__symbols.len() >= 5);
+        let __sym4 = __pop_Variant18(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action219::<>(errors, __sym0, __sym1, __sym2, __sym3, __sym4);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (5, 54)
+    }
+    pub(crate) fn __reduce141<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "[", Arg+, "]" => ActionFn(220);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action220::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 54)
+    }
+    pub(crate) fn __reduce142<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "[", Arg+, "]", IndexArg+ => ActionFn(221);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant18(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant7(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action221::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 54)
+    }
+    pub(crate) fn __reduce143<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "(", Expr, ")" => ActionFn(175);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action175::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 54)
+    }
+    pub(crate) fn __reduce144<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Term = "자원", "(", string, ")" => ActionFn(176);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action176::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (4, 54)
+    }
+    pub(crate) fn __reduce145<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<AddOp, Factor> = Tier<AddOp, Factor>, AddOp, Factor => ActionFn(88);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action88::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 55)
+    }
+    pub(crate) fn __reduce146<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<AddOp, Factor> = Factor => ActionFn(89);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action89::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 55)
+    }
+    pub(crate) fn __reduce147<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<AndOp, BoolExpr> = Tier<AndOp, BoolExpr>, AndOp, BoolExpr => ActionFn(94);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action94::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 56)
+    }
+    pub(crate) fn __reduce148<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<AndOp, BoolExpr> = BoolExpr => ActionFn(95);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action95::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 56)
+    }
+    pub(crate) fn __reduce149<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<BoolOp, ShiftExpr> = Tier<BoolOp, ShiftExpr>, BoolOp, ShiftExpr => ActionFn(92);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action92::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 57)
+    }
+    pub(crate) fn __reduce150<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<BoolOp, ShiftExpr> = ShiftExpr => ActionFn(93);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action93::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 57)
+    }
+    pub(crate) fn __reduce151<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<LogicalAndOp, OrExpr> = Tier<LogicalAndOp, OrExpr>, LogicalAndOp, OrExpr => ActionFn(100);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action100::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 58)
+    }
+    pub(crate) fn __reduce152<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<LogicalAndOp, OrExpr> = OrExpr => ActionFn(101);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action101::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 58)
+    }
+    pub(crate) fn __reduce153<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<LogicalOrOp, LogicalAndExpr> = Tier<LogicalOrOp, LogicalAndExpr>, LogicalOrOp, LogicalAndExpr => ActionFn(102);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action102::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 59)
+    }
+    pub(crate) fn __reduce154<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<LogicalOrOp, LogicalAndExpr> = LogicalAndExpr => ActionFn(103);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action103::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 59)
+    }
+    pub(crate) fn __reduce155<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<MulOp, UnaryExpr> = Tier<MulOp, UnaryExpr>, MulOp, UnaryExpr => ActionFn(86);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action86::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 60)
+    }
+    pub(crate) fn __reduce156<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<MulOp, UnaryExpr> = UnaryExpr => ActionFn(87);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action87::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 60)
+    }
+    pub(crate) fn __reduce157<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<OrOp, XorExpr> = Tier<OrOp, XorExpr>, OrOp, XorExpr => ActionFn(98);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action98::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 61)
+    }
+    pub(crate) fn __reduce158<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<OrOp, XorExpr> = XorExpr => ActionFn(99);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action99::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 61)
+    }
+    pub(crate) fn __reduce159<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<ShiftOp, ArithmeticExpr> = Tier<ShiftOp, ArithmeticExpr>, ShiftOp, ArithmeticExpr => ActionFn(90);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action90::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 62)
+    }
+    pub(crate) fn __reduce160<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<ShiftOp, ArithmeticExpr> = ArithmeticExpr => ActionFn(91);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action91::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 62)
+    }
+    pub(crate) fn __reduce161<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<XorOp, AndExpr> = Tier<XorOp, AndExpr>, XorOp, AndExpr => ActionFn(96);
+        assert!(__symbols.len() >= 3);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant9(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action96::<>(errors, __sym0, __sym1, __sym2);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (3, 63)
+    }
+    pub(crate) fn __reduce162<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // Tier<XorOp, AndExpr> = AndExpr => ActionFn(97);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action97::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 63)
+    }
+    pub(crate) fn __reduce163<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // TopStmt = RecoverStmt => ActionFn(2);
+        let __sym0 = __pop_Variant20(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (1, 64)
+    }
+    pub(crate) fn __reduce164<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // TopStmt = Loc, "장면", built, ";" => ActionFn(3);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action3::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (4, 64)
+    }
+    pub(crate) fn __reduce165<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // TopStmt = Loc, "포함", string, ";" => ActionFn(4);
+        assert!(__symbols.len() >= 4);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant1(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant19(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action4::<>(errors, __sym0, __sym1, __sym2, __sym3);
+        __symbols.push((__start, __Symbol::Variant20(__nt), __end));
+        (4, 64)
+    }
+    pub(crate) fn __reduce166<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // TopStmt* =  => ActionFn(126);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action126::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (0, 65)
+    }
+    pub(crate) fn __reduce167<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // TopStmt* = TopStmt+ => ActionFn(127);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action127::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 65)
+    }
+    pub(crate) fn __reduce168<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // TopStmt+ = TopStmt => ActionFn(128);
+        let __sym0 = __pop_Variant20(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action128::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (1, 66)
+    }
+    pub(crate) fn __reduce169<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // TopStmt+ = TopStmt+, TopStmt => ActionFn(129);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant21(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action129::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant21(__nt), __end));
+        (2, 66)
+    }
+    pub(crate) fn __reduce170<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryExpr = UnaryOp, CallExpr => ActionFn(177);
+        assert!(__symbols.len() >= 2);
+        let __sym1 = __pop_Variant5(__symbols);
+        let __sym0 = __pop_Variant25(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action177::<>(errors, __sym0, __sym1);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (2, 67)
+    }
+    pub(crate) fn __reduce171<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryExpr = CallExpr => ActionFn(58);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action58::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 67)
+    }
+    pub(crate) fn __reduce172<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // UnaryOp = "!" => ActionFn(59);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action59::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant25(__nt), __end));
+        (1, 68)
+    }
+    pub(crate) fn __reduce173<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // XorExpr = Tier<XorOp, AndExpr> => ActionFn(33);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant5(__nt), __end));
+        (1, 69)
+    }
+    pub(crate) fn __reduce174<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // XorOp = "^" => ActionFn(42);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant9(__nt), __end));
+        (1, 70)
+    }
+    pub(crate) fn __reduce176<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // channel? = channel => ActionFn(115);
+        let __sym0 = __pop_Variant1(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action115::<>(errors, __sym0);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (1, 72)
+    }
+    pub(crate) fn __reduce177<
+        'err,
+    >(
+        errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+        __lookahead_start: Option<&Position>,
+        __symbols: &mut alloc::vec::Vec<(Position,__Symbol<>,Position)>,
+        _: core::marker::PhantomData<(&'err ())>,
+    ) -> (usize, usize)
+    {
+        // channel? =  => ActionFn(116);
+        let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
+        let __end = __start.clone();
+        let __nt = super::__action116::<>(errors, &__start, &__end);
+        __symbols.push((__start, __Symbol::Variant4(__nt), __end));
+        (0, 72)
+    }
+}
+pub use self::__parse__Program::ProgramParser;
+
+#[allow(unused_variables)]
+fn __action0<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Vec<Stmt>, Position),
+) -> Vec<Stmt>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action1<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, stmts, _): (Position, alloc::vec::Vec<Option<Stmt>>, Position),
+) -> Vec<Stmt>
+{
+    stmts.into_iter().flatten().collect()
+}
+
+#[allow(unused_variables)]
+fn __action2<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Option<Stmt>, Position),
+) -> Option<Stmt>
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action3<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, name, _): (Position, Symbol, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Option<Stmt>
+{
+    Some(Stmt::Label { name, location })
+}
+
+#[allow(unused_variables)]
+fn __action4<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, path, _): (Position, Symbol, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Option<Stmt>
+{
+    Some(Stmt::Include { path, location })
+}
+
+#[allow(unused_variables)]
+fn __action5<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, stmts, _): (Position, alloc::vec::Vec<Option<Stmt>>, Position),
+) -> Vec<Stmt>
+{
+    stmts.into_iter().flatten().collect()
+}
+
+#[allow(unused_variables)]
+fn __action6<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Stmt, Position),
+) -> Option<Stmt>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, error, _): (Position, __lalrpop_util::ErrorRecovery<Position, Token, LexicalError>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Option<Stmt>
+{
+    {
+        errors.push(error);
+        None
+    }
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Position, Position),
+) -> Location
+{
+    __0.into()
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Exit { location }
+}
+
+#[allow(unused_variables)]
+fn __action10<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, name, _): (Position, Symbol, Position),
+    (_, args, _): (Position, Vec<Expr>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Spawn { name, args, location }
+}
+
+#[allow(unused_variables)]
+fn __action11<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Join { location }
+}
+
+#[allow(unused_variables)]
+fn __action12<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, var, _): (Position, Symbol, Position),
+    (_, ty, _): (Position, core::option::Option<Symbol>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, value, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Assign { var, ty, value, location }
+}
+
+#[allow(unused_variables)]
+fn __action13<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, target, _): (Position, (Symbol, Position, Vec<(Expr, Position)>), Position),
+    (_, _, _): (Position, Token, Position),
+    (_, value, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    {
+        let (var, _, indices) = target;
+        let indices = indices.into_iter().map(|(index, _)| index).collect();
+        Stmt::SetIndex { var, indices, value, location }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action14<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, var, _): (Position, Symbol, Position),
+    (_, value, _): (Position, core::option::Option<Expr>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Declare { var, value, location }
+}
+
+#[allow(unused_variables)]
+fn __action15<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, channel, _): (Position, core::option::Option<Symbol>, Position),
+    (_, values, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Print { values, channel, newline: false, wait: false, location }
+}
+
+#[allow(unused_variables)]
+fn __action16<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, channel, _): (Position, core::option::Option<Symbol>, Position),
+    (_, values, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Print { values, channel, newline: true, wait: false, location }
+}
+
+#[allow(unused_variables)]
+fn __action17<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, channel, _): (Position, core::option::Option<Symbol>, Position),
+    (_, values, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Print { values, channel, newline: true, wait: true, location }
+}
+
+#[allow(unused_variables)]
+fn __action18<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, cond, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, body, _): (Position, Vec<Stmt>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, elseifstmts, _): (Position, alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>, Position),
+    (_, elsestmt, _): (Position, core::option::Option<(Vec<Stmt>, Location)>, Position),
+) -> Stmt
+{
+    {
+        let mut arms = elseifstmts;
+        arms.insert(0, (cond, body, location));
+        let other = elsestmt.unwrap_or_default();
+        Stmt::If {
+            arms,
+            other: other.0,
+            other_location: other.1,
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, cond, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, body, _): (Position, Vec<Stmt>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::While { cond, body, location }
+}
+
+#[allow(unused_variables)]
+fn __action20<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, delay, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, body, _): (Position, Vec<Stmt>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Timer { delay, body, location }
+}
+
+#[allow(unused_variables)]
+fn __action21<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, var, _): (Position, Symbol, Position),
+    (_, iter, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, body, _): (Position, Vec<Stmt>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::ForEach { var, iter, body, location }
+}
+
+#[allow(unused_variables)]
+fn __action22<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, value, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, arms, _): (Position, alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>, Position),
+    (_, elsestmt, _): (Position, core::option::Option<(Vec<Stmt>, Location)>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    {
+        let other = elsestmt.unwrap_or_default();
+        Stmt::Select {
+            value,
+            arms,
+            other: other.0,
+            other_location: other.1,
+            location,
+        }
+    }
+}
+
+#[allow(unused_variables)]
+fn __action23<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, expr, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    Stmt::Expression { expr, location }
+}
+
+#[allow(unused_variables)]
+fn __action24<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Stmt, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Stmt
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action25<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, cond, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, body, _): (Position, Vec<Stmt>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> (Expr, Vec<Stmt>, Location)
+{
+    (cond, body, location)
+}
+
+#[allow(unused_variables)]
+fn __action26<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, values, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, last, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, body, _): (Position, Vec<Stmt>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> (Vec<Expr>, Vec<Stmt>, Location)
+{
+    {
+        let mut values = values;
+        values.push(last);
+        (values, body, location)
+    }
+}
+
+#[allow(unused_variables)]
+fn __action27<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, location, _): (Position, Location, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, body, _): (Position, Vec<Stmt>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> (Vec<Stmt>, Location)
+{
+    (body, location)
+}
+
+#[allow(unused_variables)]
+fn __action28<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, mhs, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.ternary_op(mhs, rhs, TernaryOperator::Conditional)
+}
+
+#[allow(unused_variables)]
+fn __action29<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action30<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action31<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action32<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action33<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action34<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action35<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action36<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action37<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action38<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action39<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::LogicalOr
+}
+
+#[allow(unused_variables)]
+fn __action40<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::LogicalAnd
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Or
+}
+
+#[allow(unused_variables)]
+fn __action42<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Xor
+}
+
+#[allow(unused_variables)]
+fn __action43<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::And
+}
+
+#[allow(unused_variables)]
+fn __action44<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Greater
+}
+
+#[allow(unused_variables)]
+fn __action45<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::GreaterOrEqual
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Less
+}
+
+#[allow(unused_variables)]
+fn __action47<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::LessOrEqual
+}
+
+#[allow(unused_variables)]
+fn __action48<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Equal
+}
+
+#[allow(unused_variables)]
+fn __action49<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::NotEqual
+}
+
+#[allow(unused_variables)]
+fn __action50<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Shl
+}
+
+#[allow(unused_variables)]
+fn __action51<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Shr
+}
+
+#[allow(unused_variables)]
+fn __action52<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Add
+}
+
+#[allow(unused_variables)]
+fn __action53<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Sub
+}
+
+#[allow(unused_variables)]
+fn __action54<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Mul
+}
+
+#[allow(unused_variables)]
+fn __action55<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Div
+}
+
+#[allow(unused_variables)]
+fn __action56<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> BinaryOperator
+{
+    BinaryOperator::Rem
+}
+
+#[allow(unused_variables)]
+fn __action57<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, op, _): (Position, UnaryOperator, Position),
+    (_, expr, _): (Position, Expr, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    expr.unary_op(op, l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action59<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Token, Position),
+) -> UnaryOperator
+{
+    UnaryOperator::Not
+}
+
+#[allow(unused_variables)]
+fn __action60<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, name, _): (Position, Symbol, Position),
+    (_, args, _): (Position, Vec<Expr>, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::BuiltinFunc { name, args }, l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action61<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action62<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, _, _): (Position, Token, Position),
+    (_, args, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, last, _): (Position, core::option::Option<Expr>, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Vec<Expr>
+{
+    {
+        let mut args = args;
+        if let Some(last) = last {
+            args.push(last);
+        }
+        args
+    }
+}
+
+#[allow(unused_variables)]
+fn __action63<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action64<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, value, _): (Position, u32, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::Number(value), l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action65<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, value, _): (Position, Symbol, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::String(value), l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action66<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::Bool(true), l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action67<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::Bool(false), l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action68<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::Null, l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action69<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, name, _): (Position, Symbol, Position),
+    (_, end, _): (Position, Position, Position),
+    (_, indices, _): (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> (Symbol, Position, Vec<(Expr, Position)>)
+{
+    (name, end, indices)
+}
+
+#[allow(unused_variables)]
+fn __action70<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, _, _): (Position, Token, Position),
+    (_, index, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, r, _): (Position, Position, Position),
+) -> (Expr, Position)
+{
+    (index, r)
+}
+
+#[allow(unused_variables)]
+fn __action71<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action72<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, name, _): (Position, Symbol, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::Variable(name), l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action73<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, target, _): (Position, (Symbol, Position, Vec<(Expr, Position)>), Position),
+) -> Expr
+{
+    {
+        let (name, end, indices) = target;
+        indices.into_iter().fold(
+            Expr::new(ExprKind::Variable(name), l.to(end)),
+            |value, (index, r)| value.index(index, l.to(r)),
+        )
+    }
+}
+
+#[allow(unused_variables)]
+fn __action74<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, items, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, last, _): (Position, core::option::Option<Expr>, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, r, _): (Position, Position, Position),
+    (_, indices, _): (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    {
+        let mut items = items;
+        items.extend(last);
+        indices.into_iter().fold(
+            Expr::new(ExprKind::List(items), l.to(r)),
+            |value, (index, r)| value.index(index, l.to(r)),
+        )
+    }
+}
+
+#[allow(unused_variables)]
+fn __action75<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, value, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::Nop(Box::new(value)), l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action76<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, l, _): (Position, Position, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, path, _): (Position, Symbol, Position),
+    (_, _, _): (Position, Token, Position),
+    (_, r, _): (Position, Position, Position),
+) -> Expr
+{
+    Expr::new(ExprKind::Asset(path), l.to(r))
+}
+
+#[allow(unused_variables)]
+fn __action77<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> alloc::vec::Vec<(Expr, Position)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action78<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> alloc::vec::Vec<(Expr, Position)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action79<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, (Expr, Position), Position),
+) -> alloc::vec::Vec<(Expr, Position)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action80<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+    (_, e, _): (Position, (Expr, Position), Position),
+) -> alloc::vec::Vec<(Expr, Position)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action81<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> core::option::Option<Expr>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action82<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> core::option::Option<Expr>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action83<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> alloc::vec::Vec<Expr>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action84<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Expr>, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action85<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Position
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action86<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action87<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action88<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action89<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action90<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action91<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action92<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action93<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action94<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action95<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action96<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action97<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action98<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action99<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action100<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action101<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action102<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, lhs, _): (Position, Expr, Position),
+    (_, op, _): (Position, BinaryOperator, Position),
+    (_, rhs, _): (Position, Expr, Position),
+) -> Expr
+{
+    lhs.binary_op(rhs, op)
+}
+
+#[allow(unused_variables)]
+fn __action103<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action104<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> alloc::vec::Vec<Expr>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action105<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Expr>, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action106<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+    (_, _, _): (Position, Token, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action107<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action108<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>, Position),
+) -> alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action109<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, (Vec<Stmt>, Location), Position),
+) -> core::option::Option<(Vec<Stmt>, Location)>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action110<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> core::option::Option<(Vec<Stmt>, Location)>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action111<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action112<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>, Position),
+) -> alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action113<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> alloc::vec::Vec<Expr>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action114<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Expr>, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action115<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Symbol, Position),
+) -> core::option::Option<Symbol>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action116<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> core::option::Option<Symbol>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action117<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> core::option::Option<Expr>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action118<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> core::option::Option<Expr>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action119<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, _, _): (Position, Token, Position),
+    (_, __0, _): (Position, Expr, Position),
+) -> Expr
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action120<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Symbol, Position),
+) -> core::option::Option<Symbol>
+{
+    Some(__0)
+}
+
+#[allow(unused_variables)]
+fn __action121<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> core::option::Option<Symbol>
+{
+    None
+}
+
+#[allow(unused_variables)]
+fn __action122<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, _, _): (Position, Token, Position),
+    (_, __0, _): (Position, Symbol, Position),
+) -> Symbol
+{
+    __0
+}
+
+#[allow(unused_variables)]
+fn __action123<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Position
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action124<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> alloc::vec::Vec<Option<Stmt>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action125<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Option<Stmt>>, Position),
+) -> alloc::vec::Vec<Option<Stmt>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action126<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> alloc::vec::Vec<Option<Stmt>>
+{
+    alloc::vec![]
+}
+
+#[allow(unused_variables)]
+fn __action127<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Option<Stmt>>, Position),
+) -> alloc::vec::Vec<Option<Stmt>>
+{
+    v
+}
+
+#[allow(unused_variables)]
+fn __action128<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Option<Stmt>, Position),
+) -> alloc::vec::Vec<Option<Stmt>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action129<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Option<Stmt>>, Position),
+    (_, e, _): (Position, Option<Stmt>, Position),
+) -> alloc::vec::Vec<Option<Stmt>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action130<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Option<Stmt>, Position),
+) -> alloc::vec::Vec<Option<Stmt>>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action131<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Option<Stmt>>, Position),
+    (_, e, _): (Position, Option<Stmt>, Position),
+) -> alloc::vec::Vec<Option<Stmt>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action132<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action133<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, e, _): (Position, Expr, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action134<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, (Expr, Vec<Stmt>, Location), Position),
+) -> alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action135<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>, Position),
+    (_, e, _): (Position, (Expr, Vec<Stmt>, Location), Position),
+) -> alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action136<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, (Vec<Expr>, Vec<Stmt>, Location), Position),
+) -> alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action137<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>, Position),
+    (_, e, _): (Position, (Vec<Expr>, Vec<Stmt>, Location), Position),
+) -> alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action138<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action139<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, e, _): (Position, Expr, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action140<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, __0, _): (Position, Expr, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    alloc::vec![__0]
+}
+
+#[allow(unused_variables)]
+fn __action141<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    (_, v, _): (Position, alloc::vec::Vec<Expr>, Position),
+    (_, e, _): (Position, Expr, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+#[allow(unused_variables)]
+fn __action142<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Symbol, Position),
+) -> core::option::Option<Symbol>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action122(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action120(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action143<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Symbol, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Symbol, Position),
+    __4: (Position, Token, Position),
+    __5: (Position, Expr, Position),
+    __6: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action142(
+        errors,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action144<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Symbol, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Expr, Position),
+    __4: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action121(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action145<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Expr, Position),
+) -> core::option::Option<Expr>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action119(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action117(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action146<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Expr, Position),
+    __5: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action145(
+        errors,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action147<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action118(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action14(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action148<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Expr, Position),
+    __1: (Position, Token, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action106(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action138(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action149<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, alloc::vec::Vec<Expr>, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action106(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action139(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action150<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Vec<Stmt>, Position),
+    __4: (Position, Token, Position),
+) -> (Vec<Expr>, Vec<Stmt>, Location)
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action104(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action26(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action151<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Vec<Stmt>, Position),
+    __5: (Position, Token, Position),
+) -> (Vec<Expr>, Vec<Stmt>, Location)
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action105(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action26(
+        errors,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action152<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, Vec<Expr>, Position),
+    __2: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action60(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action153<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, u32, Position),
+    __1: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action64(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action154<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action65(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action155<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action66(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action156<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action67(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action157<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action158<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Location
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action8(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action159<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action72(
+        errors,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action160<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, (Symbol, Position, Vec<(Expr, Position)>), Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action73(
+        errors,
+        __temp0,
+        __0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action161<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, core::option::Option<Expr>, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Position, Position),
+    __5: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action74(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action162<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action75(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action163<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action76(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action164<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, UnaryOperator, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Position, Position),
+) -> Expr
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action123(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        errors,
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action165<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, Vec<Expr>, Position),
+) -> Expr
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action152(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action166<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> (Symbol, Position, Vec<(Expr, Position)>)
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action69(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action167<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+) -> (Expr, Position)
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action70(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action168<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, u32, Position),
+) -> Expr
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action153(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action169<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+) -> Expr
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action154(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action170<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action155(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action171<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action156(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action172<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action157(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action173<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+) -> Expr
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action159(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action174<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, core::option::Option<Expr>, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action161(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action175<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action162(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action176<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action163(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action177<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, UnaryOperator, Position),
+    __1: (Position, Expr, Position),
+) -> Expr
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action85(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action164(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action178<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Expr, Position),
+    __1: (Position, Token, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action63(
+        errors,
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action140(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action179<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, alloc::vec::Vec<Expr>, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+) -> alloc::vec::Vec<Expr>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action63(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action141(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action180<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, core::option::Option<Expr>, Position),
+    __2: (Position, Token, Position),
+) -> Vec<Expr>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action83(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action62(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action181<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, core::option::Option<Expr>, Position),
+    __3: (Position, Token, Position),
+) -> Vec<Expr>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action84(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action62(
+        errors,
+        __0,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action182<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, core::option::Option<Expr>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action83(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action174(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action183<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, core::option::Option<Expr>, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action84(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action174(
+        errors,
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action184<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+) -> Vec<Expr>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action81(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action180(
+        errors,
+        __0,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action185<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Token, Position),
+) -> Vec<Expr>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action82(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action180(
+        errors,
+        __0,
+        __temp0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action186<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+) -> Vec<Expr>
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action81(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action181(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action187<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Token, Position),
+) -> Vec<Expr>
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action82(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action181(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action188<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action81(
+        errors,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action182(
+        errors,
+        __0,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action189<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action82(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action182(
+        errors,
+        __0,
+        __temp0,
+        __1,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action190<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action81(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action183(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action191<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action82(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action183(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action192<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action184(
+        errors,
+        __1,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action165(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action193<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action185(
+        errors,
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action165(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action194<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, alloc::vec::Vec<Expr>, Position),
+    __3: (Position, Expr, Position),
+    __4: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action186(
+        errors,
+        __1,
+        __2,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action165(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action195<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Symbol, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, alloc::vec::Vec<Expr>, Position),
+    __3: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action187(
+        errors,
+        __1,
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action165(
+        errors,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action196<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Expr, Position),
+    __5: (Position, Token, Position),
+    __6: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action184(
+        errors,
+        __3,
+        __4,
+        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action197<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Token, Position),
+    __5: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action185(
+        errors,
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action198<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<Expr>, Position),
+    __5: (Position, Expr, Position),
+    __6: (Position, Token, Position),
+    __7: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action186(
+        errors,
+        __3,
+        __4,
+        __5,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action199<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<Expr>, Position),
+    __5: (Position, Token, Position),
+    __6: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action187(
+        errors,
+        __3,
+        __4,
+        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action10(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action200<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Vec<Stmt>, Position),
+    __5: (Position, Token, Position),
+    __6: (Position, core::option::Option<(Vec<Stmt>, Location)>, Position),
+) -> Stmt
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __6.0.clone();
+    let __temp0 = __action111(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action201<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Vec<Stmt>, Position),
+    __5: (Position, Token, Position),
+    __6: (Position, alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>, Position),
+    __7: (Position, core::option::Option<(Vec<Stmt>, Location)>, Position),
+) -> Stmt
+{
+    let __start0 = __6.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action112(
+        errors,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+        __7,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action202<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Vec<Stmt>, Position),
+    __5: (Position, Token, Position),
+    __6: (Position, (Vec<Stmt>, Location), Position),
+) -> Stmt
+{
+    let __start0 = __6.0.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action109(
+        errors,
+        __6,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action200(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action203<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Vec<Stmt>, Position),
+    __5: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __5.2.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action110(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action200(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action204<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Vec<Stmt>, Position),
+    __5: (Position, Token, Position),
+    __6: (Position, alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>, Position),
+    __7: (Position, (Vec<Stmt>, Location), Position),
+) -> Stmt
+{
+    let __start0 = __7.0.clone();
+    let __end0 = __7.2.clone();
+    let __temp0 = __action109(
+        errors,
+        __7,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action201(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action205<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Vec<Stmt>, Position),
+    __5: (Position, Token, Position),
+    __6: (Position, alloc::vec::Vec<(Expr, Vec<Stmt>, Location)>, Position),
+) -> Stmt
+{
+    let __start0 = __6.2.clone();
+    let __end0 = __6.2.clone();
+    let __temp0 = __action110(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action201(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action206<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>, Position),
+    __5: (Position, (Vec<Stmt>, Location), Position),
+    __6: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __5.0.clone();
+    let __end0 = __5.2.clone();
+    let __temp0 = __action109(
+        errors,
+        __5,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action207<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>, Position),
+    __5: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __5.0.clone();
+    let __temp0 = __action110(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action22(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __temp0,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action208<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, core::option::Option<Symbol>, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action113(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action209<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, core::option::Option<Symbol>, Position),
+    __3: (Position, alloc::vec::Vec<Expr>, Position),
+    __4: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action114(
+        errors,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action15(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action210<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, core::option::Option<Symbol>, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action113(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action211<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, core::option::Option<Symbol>, Position),
+    __3: (Position, alloc::vec::Vec<Expr>, Position),
+    __4: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action114(
+        errors,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action16(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action212<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, core::option::Option<Symbol>, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action113(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action213<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, core::option::Option<Symbol>, Position),
+    __3: (Position, alloc::vec::Vec<Expr>, Position),
+    __4: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action114(
+        errors,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action214<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action77(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action188(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action215<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Expr, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action78(
+        errors,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action188(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action216<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action77(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action189(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action217<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action78(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action189(
+        errors,
+        __0,
+        __1,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action218<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action77(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action190(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action219<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action78(
+        errors,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action190(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action220<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Token, Position),
+) -> Expr
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action77(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action191(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action221<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Token, Position),
+    __1: (Position, alloc::vec::Vec<Expr>, Position),
+    __2: (Position, Token, Position),
+    __3: (Position, alloc::vec::Vec<(Expr, Position)>, Position),
+) -> Expr
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action78(
+        errors,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action191(
+        errors,
+        __0,
+        __1,
+        __2,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action222<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Vec<Stmt>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action124(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action223<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, alloc::vec::Vec<Option<Stmt>>, Position),
+) -> Vec<Stmt>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action125(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action5(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action224<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, (Vec<Stmt>, Location), Position),
+    __5: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action107(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action206(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action225<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>, Position),
+    __5: (Position, (Vec<Stmt>, Location), Position),
+    __6: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action108(
+        errors,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action206(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action226<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action107(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action207(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action227<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Expr, Position),
+    __3: (Position, Token, Position),
+    __4: (Position, alloc::vec::Vec<(Vec<Expr>, Vec<Stmt>, Location)>, Position),
+    __5: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __4.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action108(
+        errors,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action207(
+        errors,
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __5,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action228<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __lookbehind: &Position,
+    __lookahead: &Position,
+) -> Vec<Stmt>
+{
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action126(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action229<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, alloc::vec::Vec<Option<Stmt>>, Position),
+) -> Vec<Stmt>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action127(
+        errors,
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        errors,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action230<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action208(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action231<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action116(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action208(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action232<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, alloc::vec::Vec<Expr>, Position),
+    __4: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action209(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action233<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, alloc::vec::Vec<Expr>, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action116(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action209(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action234<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action210(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action235<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action116(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action210(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action236<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, alloc::vec::Vec<Expr>, Position),
+    __4: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action211(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action237<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, alloc::vec::Vec<Expr>, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action116(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action211(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action238<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action212(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action239<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action116(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action212(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action240<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, Symbol, Position),
+    __3: (Position, alloc::vec::Vec<Expr>, Position),
+    __4: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action115(
+        errors,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action241<
+    'err,
+>(
+    errors: &'err mut Vec<ErrorRecovery<Position, Token, LexicalError>>,
+    __0: (Position, Location, Position),
+    __1: (Position, Token, Position),
+    __2: (Position, alloc::vec::Vec<Expr>, Position),
+    __3: (Position, Token, Position),
+) -> Stmt
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action116(
+        errors,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        errors,
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
+}
+
+pub trait __ToTriple<'err, >
+{
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, LexicalError>>;
+}
+
+impl<'err, > __ToTriple<'err, > for (Position, Token, Position)
+{
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, LexicalError>> {
+        Ok(value)
+    }
+}
+impl<'err, > __ToTriple<'err, > for Result<(Position, Token, Position), LexicalError>
+{
+    fn to_triple(value: Self) -> Result<(Position,Token,Position), __lalrpop_util::ParseError<Position, Token, LexicalError>> {
+        match value {
+            Ok(v) => Ok(v),
+            Err(error) => Err(__lalrpop_util::ParseError::User { error }),
+        }
+    }
+}
@@ -0,0 +1,161 @@
+use crate::interner::Symbol;
+use crate::location::Location;
+use crate::snapshot::Iteration;
+use crate::value::Value;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::mem::size_of;
+
+/// Choice made by player
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Choice {
+    pub location: Location,
+    pub index: u32,
+}
+
+/// Log of choices in order they are made
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BranchLog {
+    choices: Vec<Choice>,
+}
+
+impl BranchLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn push(&mut self, choice: Choice) {
+        self.choices.push(choice);
+    }
+
+    #[inline]
+    pub fn choices(&self) -> &[Choice] {
+        &self.choices
+    }
+
+    /// Keep only first `len` choices, useful for jumping back to previous choice
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        self.choices.truncate(len);
+    }
+
+    /// Bytes allocated for choices
+    pub fn memory_usage(&self) -> usize {
+        self.choices.capacity() * size_of::<Choice>()
+    }
+}
+
+/// State at the start of print statement with changes made after it
+struct Checkpoint {
+    cursor: usize,
+    stack: Vec<Value>,
+    iterations: Vec<Iteration>,
+    branch_len: usize,
+    /// Previous values of variables in written order
+    writes: Vec<(Symbol, Option<Value>)>,
+}
+
+/// Journal of reversible changes grouped by print statements
+pub(crate) struct Journal {
+    checkpoints: VecDeque<Checkpoint>,
+    capacity: usize,
+}
+
+/// State which should be restored by rewinding
+pub(crate) struct Rewound {
+    pub cursor: usize,
+    pub stack: Vec<Value>,
+    pub iterations: Vec<Iteration>,
+    pub branch_len: usize,
+}
+
+impl Journal {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            checkpoints: VecDeque::with_capacity(capacity.min(64)),
+            capacity,
+        }
+    }
+
+    pub fn checkpoint(
+        &mut self,
+        cursor: usize,
+        stack: &[Value],
+        iterations: &[Iteration],
+        branch_len: usize,
+    ) {
+        if self.checkpoints.len() == self.capacity {
+            self.checkpoints.pop_front();
+        }
+
+        self.checkpoints.push_back(Checkpoint {
+            cursor,
+            stack: stack.to_vec(),
+            iterations: iterations.to_vec(),
+            branch_len,
+            writes: Vec::new(),
+        });
+    }
+
+    /// Bytes allocated for checkpoints with values saved in them
+    pub fn memory_usage(&self) -> usize {
+        self.checkpoints.capacity() * size_of::<Checkpoint>()
+            + self
+                .checkpoints
+                .iter()
+                .map(|checkpoint| {
+                    checkpoint.stack.capacity() * size_of::<Value>()
+                        + checkpoint.stack.iter().map(Value::heap_size).sum::<usize>()
+                        + checkpoint.iterations.capacity() * size_of::<Iteration>()
+                        + checkpoint
+                            .iterations
+                            .iter()
+                            .map(|iteration| iteration.value.heap_size())
+                            .sum::<usize>()
+                        + checkpoint.writes.capacity() * size_of::<(Symbol, Option<Value>)>()
+                        + checkpoint
+                            .writes
+                            .iter()
+                            .filter_map(|(_, prev)| prev.as_ref())
+                            .map(Value::heap_size)
+                            .sum::<usize>()
+                })
+                .sum::<usize>()
+    }
+
+    pub fn record_write(&mut self, var: Symbol, prev: Option<Value>) {
+        if let Some(checkpoint) = self.checkpoints.back_mut() {
+            checkpoint.writes.push((var, prev));
+        }
+    }
+
+    /// Undo last `count` print statements, `undo` is called with previous value of variables
+    pub fn rewind(
+        &mut self,
+        count: usize,
+        mut undo: impl FnMut(Symbol, Option<Value>),
+    ) -> Option<Rewound> {
+        let mut ret = None;
+
+        for _ in 0..count {
+            let checkpoint = match self.checkpoints.pop_back() {
+                Some(checkpoint) => checkpoint,
+                None => break,
+            };
+
+            for (var, prev) in checkpoint.writes.into_iter().rev() {
+                undo(var, prev);
+            }
+
+            ret = Some(Rewound {
+                cursor: checkpoint.cursor,
+                stack: checkpoint.stack,
+                iterations: checkpoint.iterations,
+                branch_len: checkpoint.branch_len,
+            });
+        }
+
+        ret
+    }
+}
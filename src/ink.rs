@@ -0,0 +1,383 @@
+//! Convert subset of [Ink](https://github.com/inkle/ink) stories into kes source
+//!
+//! Supported subset
+//! - text lines, `<>` glue and inline `{변수}`, `{조건: 참|거짓}`
+//! - `VAR`, `CONST` and `~` assignments including `+=`, `-=`, `++`, `--`
+//! - `~ 함수(인자)` calls which become builtin calls
+//! - multiline `{ 조건: ... - 조건: ... - else: ... }` conditionals
+//! - `-> END` and `-> DONE`
+//!
+//! Knots, stitches, choices, gathers and other diverts are reported as [`ImportError::Unsupported`]
+//!
+//! ## Examples
+//! ```rust
+//! let source = kes::ink::to_kes_source("VAR gold = 10\nYou have {gold} gold.").unwrap();
+//! assert_eq!(source, "$gold = 10;\n@! 'You have ' $gold ' gold.';\n");
+//! ```
+
+use crate::error::ImportError;
+use crate::lexer::escape_str;
+use crate::location::Location;
+use std::fmt::Write;
+
+fn is_ident_start(c: char) -> bool {
+    c == '_' || c.is_alphabetic()
+}
+
+fn is_ident_char(c: char) -> bool {
+    c == '_' || c.is_alphanumeric()
+}
+
+/// Quote text as kes string
+fn quote(text: &str) -> String {
+    format!("'{}'", escape_str(text))
+}
+
+/// Convert ink expression into kes expression
+fn expression(expr: &str, location: Location) -> Result<String, ImportError> {
+    let invalid = || ImportError::InvalidExpression(expr.trim().into(), location);
+    let mut out = String::new();
+    let mut chars = expr.char_indices().peekable();
+
+    // `(`, `!` or builtin name was written just before
+    let mut glued = true;
+    let push = |out: &mut String, token: &str, glued: &mut bool| {
+        let attach = token == ")" || token == ",";
+        if !*glued && !attach {
+            out.push(' ');
+        }
+        out.push_str(token);
+        *glued = token == "(" || token == "!";
+    };
+
+    while let Some((start, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {}
+            '0'..='9' => {
+                let mut end = start + 1;
+                while let Some(&(idx, c)) = chars.peek() {
+                    if c.is_ascii_digit() {
+                        chars.next();
+                        end = idx + 1;
+                    } else if c == '.' {
+                        // kes only has integers
+                        return Err(invalid());
+                    } else {
+                        break;
+                    }
+                }
+                push(&mut out, &expr[start..end], &mut glued);
+            }
+            c if is_ident_start(c) => {
+                let mut end = start + c.len_utf8();
+                while let Some(&(idx, c)) = chars.peek() {
+                    if is_ident_char(c) {
+                        chars.next();
+                        end = idx + c.len_utf8();
+                    } else {
+                        break;
+                    }
+                }
+                let ident = &expr[start..end];
+                match ident {
+                    "and" => push(&mut out, "&", &mut glued),
+                    "or" => push(&mut out, "|", &mut glued),
+                    "not" => push(&mut out, "!", &mut glued),
+                    "mod" => push(&mut out, "%", &mut glued),
+                    "true" => push(&mut out, "참", &mut glued),
+                    "false" => push(&mut out, "거짓", &mut glued),
+                    _ if expr[end..].trim_start().starts_with('(') => {
+                        push(&mut out, ident, &mut glued);
+                        glued = true;
+                    }
+                    _ => push(&mut out, &format!("${}", ident), &mut glued),
+                }
+            }
+            '"' => {
+                let end = expr[start + 1..].find('"').ok_or_else(invalid)? + start + 1;
+                push(&mut out, &quote(&expr[start + 1..end]), &mut glued);
+                while chars.peek().map_or(false, |&(idx, _)| idx <= end) {
+                    chars.next();
+                }
+            }
+            '&' | '|' | '=' | '!' | '<' | '>' => {
+                let next = chars.peek().map(|&(_, c)| c);
+                // second item is whether next char is part of operator
+                let (token, long) = match (c, next) {
+                    ('&', Some('&')) => ("&", true),
+                    ('|', Some('|')) => ("|", true),
+                    ('=', Some('=')) => ("==", true),
+                    ('!', Some('=')) => ("!=", true),
+                    ('<', Some('=')) => ("<=", true),
+                    ('>', Some('=')) => (">=", true),
+                    ('!', _) => ("!", false),
+                    ('<', _) => ("<", false),
+                    ('>', _) => (">", false),
+                    _ => return Err(invalid()),
+                };
+                if long {
+                    chars.next();
+                }
+                push(&mut out, token, &mut glued);
+            }
+            '+' | '-' | '*' | '/' | '%' | '(' | ')' | ',' => {
+                push(&mut out, &c.to_string(), &mut glued)
+            }
+            _ => return Err(invalid()),
+        }
+    }
+
+    if out.is_empty() {
+        Err(invalid())
+    } else {
+        Ok(out)
+    }
+}
+
+/// Values of print statement from text with inline `{}`
+fn text_values(text: &str, location: Location) -> Result<Vec<String>, ImportError> {
+    let mut values = Vec::new();
+    let mut rest = text;
+
+    while let Some(open) = rest.find('{') {
+        if open > 0 {
+            values.push(quote(&rest[..open]));
+        }
+        let close = rest[open..]
+            .find('}')
+            .ok_or_else(|| ImportError::InvalidExpression(rest[open..].into(), location))?
+            + open;
+        let inner = &rest[open + 1..close];
+
+        let value = match inner.split_once(':') {
+            Some((cond, branches)) => {
+                let (then, other) = branches.split_once('|').unwrap_or((branches, ""));
+                format!(
+                    "({} ? {} : {})",
+                    expression(cond, location)?,
+                    quote(then.trim()),
+                    quote(other.trim())
+                )
+            }
+            None if inner.contains('|') => {
+                return Err(ImportError::Unsupported("시퀀스", location))
+            }
+            None => expression(inner, location)?,
+        };
+        values.push(value);
+        rest = &rest[close + 1..];
+    }
+
+    if !rest.is_empty() {
+        values.push(quote(rest));
+    }
+
+    Ok(values)
+}
+
+/// `~` line without `~`
+fn logic(line: &str, location: Location) -> Result<String, ImportError> {
+    let line = line.trim();
+
+    for (suffix, op) in [("++", "+"), ("--", "-")] {
+        if let Some(var) = line.strip_suffix(suffix) {
+            let var = var.trim();
+            return Ok(format!("${} = ${} {} 1;", var, var, op));
+        }
+    }
+
+    for (assign, op) in [("+=", "+"), ("-=", "-")] {
+        if let Some((var, value)) = line.split_once(assign) {
+            let var = var.trim();
+            return Ok(format!(
+                "${} = ${} {} {};",
+                var,
+                var,
+                op,
+                expression(value, location)?
+            ));
+        }
+    }
+
+    match line.split_once('=') {
+        Some((var, value)) if !value.starts_with('=') && var.trim().chars().all(is_ident_char) => {
+            Ok(format!(
+                "${} = {};",
+                var.trim(),
+                expression(value, location)?
+            ))
+        }
+        _ => Ok(format!("{};", expression(line, location)?)),
+    }
+}
+
+/// Convert ink story into kes source, one statement per line
+pub fn to_kes_source(ink: &str) -> Result<String, ImportError> {
+    let mut out = String::new();
+    // locations of open multiline conditionals
+    let mut conditionals = Vec::new();
+
+    for (idx, line) in ink.lines().enumerate() {
+        let location = Location::new(idx + 1);
+        let line = line.split("//").next().unwrap_or_default();
+        // tags
+        let line = line.split('#').next().unwrap_or_default().trim();
+        let indent = "    ".repeat(conditionals.len());
+
+        let stmt = if line.is_empty() {
+            continue;
+        } else if line.starts_with("==") {
+            return Err(ImportError::Unsupported("노트", location));
+        } else if line.starts_with('=') {
+            return Err(ImportError::Unsupported("스티치", location));
+        } else if line.starts_with('*') || line.starts_with('+') {
+            return Err(ImportError::Unsupported("선택지", location));
+        } else if let Some(target) = line.strip_prefix("->") {
+            match target.trim() {
+                "END" | "DONE" => "종료;".into(),
+                _ => return Err(ImportError::Unsupported("다이버트", location)),
+            }
+        } else if let Some(decl) = line
+            .strip_prefix("VAR ")
+            .or_else(|| line.strip_prefix("CONST "))
+        {
+            logic(decl, location)?
+        } else if let Some(stmt) = line.strip_prefix('~') {
+            logic(stmt, location)?
+        } else if let Some(arm) = line.strip_prefix('-') {
+            if conditionals.is_empty() {
+                return Err(ImportError::Unsupported("게더", location));
+            }
+            let cond = arm
+                .trim()
+                .strip_suffix(':')
+                .ok_or_else(|| ImportError::InvalidExpression(arm.trim().into(), location))?;
+            let indent = "    ".repeat(conditionals.len() - 1);
+            if cond.trim() == "else" {
+                writeln!(out, "{}}} 그외 {{", indent).unwrap();
+            } else {
+                writeln!(out, "{}}} 혹은 {} {{", indent, expression(cond, location)?).unwrap();
+            }
+            continue;
+        } else if line == "}" {
+            if conditionals.pop().is_none() {
+                return Err(ImportError::InvalidExpression(line.into(), location));
+            }
+            writeln!(out, "{}}}", "    ".repeat(conditionals.len())).unwrap();
+            continue;
+        } else if let Some(cond) = line
+            .strip_prefix('{')
+            .and_then(|cond| cond.strip_suffix(':'))
+            .filter(|cond| !cond.contains('}'))
+        {
+            writeln!(out, "{}만약 {} {{", indent, expression(cond, location)?).unwrap();
+            conditionals.push(location);
+            continue;
+        } else {
+            let text = line.strip_prefix("<>").unwrap_or(line);
+            let (text, print) = match text.strip_suffix("<>") {
+                Some(text) => (text, "@"),
+                None => (text, "@!"),
+            };
+            let mut stmt = print.to_string();
+            for value in text_values(text, location)? {
+                stmt.push(' ');
+                stmt.push_str(&value);
+            }
+            stmt.push(';');
+            stmt
+        };
+
+        writeln!(out, "{}{}", indent, stmt).unwrap();
+    }
+
+    match conditionals.pop() {
+        Some(location) => Err(ImportError::UnclosedConditional(location)),
+        None => Ok(out),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_kes_source;
+    use crate::error::ImportError;
+    use crate::location::Location;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn story() {
+        let ink = r#"// 상점
+VAR gold = 10
+VAR has_sword = false
+
+Welcome to the shop. #intro
+~ gold -= 3
+~ has_sword = true
+~ play_sound("buy")
+{ has_sword and gold > 5:
+    You bought a sword. <>
+    You have {gold} gold left.
+- gold > 0:
+    You are almost broke.
+- else:
+    You can't buy anything.
+}
+{has_sword: Armed|Unarmed}
+-> END
+"#;
+        assert_eq!(
+            to_kes_source(ink).unwrap(),
+            "$gold = 10;
+$has_sword = 거짓;
+@! 'Welcome to the shop.';
+$gold = $gold - 3;
+$has_sword = 참;
+play_sound('buy');
+만약 $has_sword & $gold > 5 {
+    @ 'You bought a sword. ';
+    @! 'You have ' $gold ' gold left.';
+} 혹은 $gold > 0 {
+    @! 'You are almost broke.';
+} 그외 {
+    @! 'You can\\'t buy anything.';
+}
+@! ($has_sword ? 'Armed' : 'Unarmed');
+종료;
+"
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        assert_eq!(
+            to_kes_source("Hello\n* Choice").unwrap_err(),
+            ImportError::Unsupported("선택지", Location::new(2))
+        );
+        assert_eq!(
+            to_kes_source("=== shop ===").unwrap_err(),
+            ImportError::Unsupported("노트", Location::new(1))
+        );
+        assert_eq!(
+            to_kes_source("~ x = 1.5").unwrap_err(),
+            ImportError::InvalidExpression("1.5".into(), Location::new(1))
+        );
+        assert_eq!(
+            to_kes_source("{ x:\nyes").unwrap_err(),
+            ImportError::UnclosedConditional(Location::new(1))
+        );
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn run_converted() {
+        use crate::builtin::RecordBuiltin;
+        use crate::context::Context;
+        use crate::program::Program;
+
+        let source = to_kes_source("VAR n = 2\n~ n++\nCount: {n}\n{ n == 3:\nthree\n}").unwrap();
+        let program = Program::from_source(&source).unwrap();
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "Count: 3@#three@#");
+    }
+}
@@ -5,7 +5,9 @@ use crate::{
 };
 use serde::{Deserialize, Serialize};
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+/// New instructions can be added in minor versions
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
 pub enum Instruction {
     Nop,
     Exit,
@@ -16,16 +18,72 @@ pub enum Instruction {
     LoadVar(Symbol),
     StoreVar(Symbol),
     CallBuiltin(Symbol),
-    Print { newline: bool, wait: bool },
+    SpawnBuiltin(Symbol),
+    Join,
+    Print {
+        newline: bool,
+        wait: bool,
+    },
     BinaryOperator(BinaryOperator),
     UnaryOperator(UnaryOperator),
     TernaryOperator(TernaryOperator),
     Goto(u32),
     GotoIfNot(u32),
+    /// Register timer starting next instruction and jump to end of timer body
+    Timer(u32),
+    /// Return from timer body
+    EndTimer,
+    /// Pop value and jump by `len + 1` `Goto`s after this instruction,
+    /// `min + n` uses `n`th `Goto` and other values use last one
+    JumpTable {
+        min: u32,
+        len: u16,
+    },
+    /// Pop N values and push list of them in order
+    BuildList(u32),
+    /// Pop index and list, push element at index
+    LoadIndex,
+    /// Pop value and `depth` indices, set element of list in variable in place
+    StoreIndex {
+        var: Symbol,
+        depth: u32,
+    },
+    /// Pop value and start `각각` loop over its elements
+    IterStart,
+    /// Push next element of innermost `각각` loop,
+    /// loop is finished and jumps to target when there is no element
+    IterNext(u32),
+    /// Pop N values and push result of folding them with `+` from left, string is allocated once
+    ConcatN(u8),
+    LoadBool(bool),
+    LoadNull,
+    /// `Print` to channel like `@#로그`
+    PrintChannel {
+        channel: Symbol,
+        newline: bool,
+        wait: bool,
+    },
+    /// Push path of `자원('...')` as string, unlike `LoadStr` it is never translated
+    LoadAsset(Symbol),
+}
+
+impl Instruction {
+    /// Move jump target by `offset`, used when instructions are appended after other program
+    pub(crate) fn shift_target(&mut self, offset: u32) {
+        match self {
+            Instruction::Goto(pos)
+            | Instruction::GotoIfNot(pos)
+            | Instruction::IterNext(pos)
+            | Instruction::Timer(pos) => {
+                *pos += offset;
+            }
+            _ => {}
+        }
+    }
 }
 
 /// Contains location from source
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct InstructionWithDebug {
     pub inst: Instruction,
     pub location: Location,
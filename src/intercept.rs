@@ -0,0 +1,40 @@
+use crate::instruction::Instruction;
+use crate::location::Location;
+use crate::value::Value;
+
+/// What `Context` does with instruction after `Interceptor::before`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Action {
+    /// Run instruction as usual
+    Continue,
+    /// Move to next instruction without running it
+    ///
+    /// Skipped `CallBuiltin` should pop its arguments and push return value itself
+    Skip,
+    /// Stop script with `RuntimeError::Aborted`
+    Abort,
+}
+
+/// Instruction about to be run
+pub struct Intercepted<'a> {
+    pub instruction: Instruction,
+    pub location: Location,
+    /// Name of builtin for `CallBuiltin` and `SpawnBuiltin`
+    pub builtin: Option<&'a str>,
+    /// Value stack, arguments of builtin call are at the end
+    pub stack: &'a mut Vec<Value>,
+}
+
+/// Hook called before every instruction
+///
+/// Hosts running user mods can veto builtin calls or rewrite their arguments
+/// in one place instead of wrapping every builtin
+pub trait Interceptor: Send + Sync {
+    fn before(&mut self, inst: Intercepted<'_>) -> Action;
+}
+
+impl<F: FnMut(Intercepted<'_>) -> Action + Send + Sync> Interceptor for F {
+    fn before(&mut self, inst: Intercepted<'_>) -> Action {
+        self(inst)
+    }
+}
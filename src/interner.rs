@@ -2,6 +2,11 @@ use serde::{Deserialize, Serialize};
 use std::num::NonZeroU32;
 use string_interner::StringInterner;
 
+/// Handle of interned string
+///
+/// Interner only appends strings and is serialized in symbol order,
+/// so `Symbol` resolves to same string after serialize/deserialize round trip of `Program`
+/// and after interner grows by `Program::extend_from_source` or `Program::replace_strings`
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct Symbol(NonZeroU32);
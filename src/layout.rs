@@ -0,0 +1,178 @@
+use crate::builtin::{Builtin, Handle};
+use crate::context::Context;
+use crate::value::Value;
+use async_trait::async_trait;
+use unicode_width::UnicodeWidthChar;
+
+/// `Builtin` wrapper which delivers printed text as whole lines wrapped to console width
+///
+/// Width is counted with unicode width so Korean full-width characters take 2 columns
+pub struct WrapBuiltin<B: Builtin> {
+    inner: B,
+    width: usize,
+    line: String,
+    line_width: usize,
+}
+
+impl<B: Builtin> WrapBuiltin<B> {
+    pub fn new(inner: B, width: usize) -> Self {
+        Self {
+            inner,
+            width: width.max(1),
+            line: String::new(),
+            line_width: 0,
+        }
+    }
+
+    #[inline]
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Deliver remaining text without newline
+    pub fn flush_line(&mut self) {
+        if !self.line.is_empty() {
+            self.inner.print(std::mem::take(&mut self.line).into());
+            self.line_width = 0;
+        }
+    }
+
+    pub fn into_inner(mut self) -> B {
+        self.flush_line();
+        self.inner
+    }
+
+    fn emit_line(&mut self, line: String) {
+        self.inner.print(line.into());
+        self.inner.new_line();
+    }
+
+    fn wrap(&mut self) {
+        // break at last space if there is
+        let line = std::mem::take(&mut self.line);
+        match line.rfind(' ') {
+            Some(pos) if pos != 0 => {
+                let rest = line[pos + 1..].to_string();
+                let mut line = line;
+                line.truncate(pos);
+                self.emit_line(line);
+                self.line_width = rest.chars().filter_map(|c| c.width()).sum();
+                self.line = rest;
+            }
+            _ => {
+                self.emit_line(line);
+                self.line_width = 0;
+            }
+        }
+    }
+
+    fn push_str(&mut self, text: &str) {
+        for ch in text.chars() {
+            if ch == '\n' {
+                let line = std::mem::take(&mut self.line);
+                self.emit_line(line);
+                self.line_width = 0;
+                continue;
+            }
+
+            let width = ch.width().unwrap_or(0);
+
+            if self.line_width + width > self.width {
+                if ch == ' ' {
+                    let line = std::mem::take(&mut self.line);
+                    self.emit_line(line);
+                    self.line_width = 0;
+                    continue;
+                }
+                self.wrap();
+            }
+
+            self.line.push(ch);
+            self.line_width += width;
+        }
+    }
+}
+
+#[async_trait]
+impl<B: Builtin> Builtin for WrapBuiltin<B> {
+    #[inline]
+    async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value {
+        self.inner.run(name, ctx).await
+    }
+    #[inline]
+    fn resolve(&mut self, name: &str) -> Option<Handle> {
+        self.inner.resolve(name)
+    }
+    #[inline]
+    async fn call(&mut self, handle: Handle, name: &str, ctx: &mut Context<'_>) -> Value {
+        self.inner.call(handle, name, ctx).await
+    }
+    #[inline]
+    fn load(&mut self, name: &str) -> Option<Value> {
+        self.inner.load(name)
+    }
+    fn print(&mut self, v: Value) {
+        match v {
+            Value::Str(s) => self.push_str(&s),
+            v => self.push_str(&v.to_string()),
+        }
+    }
+    fn new_line(&mut self) {
+        let line = std::mem::take(&mut self.line);
+        self.emit_line(line);
+        self.line_width = 0;
+    }
+    async fn wait(&mut self) {
+        self.flush_line();
+        self.inner.wait().await;
+    }
+    #[inline]
+    async fn spawn(&mut self, name: &str, ctx: &mut Context<'_>) {
+        self.inner.spawn(name, ctx).await;
+    }
+    #[inline]
+    async fn join(&mut self) {
+        self.inner.join().await;
+    }
+    #[inline]
+    async fn flush(&mut self) {
+        self.inner.flush().await;
+    }
+    async fn auto_wait(&mut self) {
+        self.flush_line();
+        self.inner.auto_wait().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WrapBuiltin;
+    use crate::builtin::RecordBuiltin;
+    use crate::context::Context;
+    use crate::program::Program;
+    use pretty_assertions::assert_eq;
+
+    fn test_impl(code: &str, width: usize) -> String {
+        let program = Program::from_source(code).unwrap();
+        let mut builtin = WrapBuiltin::new(RecordBuiltin::new(), width);
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        builtin.into_inner().text().to_string()
+    }
+
+    #[test]
+    fn word_wrap() {
+        assert_eq!(test_impl("@@'hello world foo';", 11), "hello world@foo@");
+        assert_eq!(test_impl("@'abc' 'def'; @@'gh';", 4), "abcd@efgh@");
+    }
+
+    #[test]
+    fn full_width() {
+        assert_eq!(test_impl("@@'가나다라마';", 5), "가나@다라@마@");
+    }
+
+    #[test]
+    fn flush_on_wait() {
+        assert_eq!(test_impl("@'가나'; @!'다';", 10), "가나다@#");
+        assert_eq!(test_impl("@'가나';", 10), "가나");
+    }
+}
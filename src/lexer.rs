@@ -1,18 +1,45 @@
 use crate::error::{LexicalError, LexicalResult as Result};
 use crate::interner::Interner;
-use crate::location::Location;
+use crate::location::{Location, Span};
 use crate::operator::{BinaryOperator, TernaryOperator, UnaryOperator};
 use crate::token::Token;
+use std::borrow::Cow;
 use std::collections::BTreeMap;
+use unicode_normalization::{is_nfc_quick, IsNormalized, UnicodeNormalization};
+use unicode_xid::UnicodeXID;
+
+/// Position of token which parser uses, statements only keep `location`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Position {
+    pub location: Location,
+    /// Byte offset from start of source
+    pub offset: usize,
+}
+
+impl Position {
+    /// Span between two positions
+    pub fn to(self, end: Self) -> Span {
+        Span::new(self.offset, end.offset)
+    }
+}
 
-pub type Spanned = (Location, Token, Location);
+impl From<Position> for Location {
+    fn from(position: Position) -> Self {
+        position.location
+    }
+}
+
+pub type Spanned = (Position, Token, Position);
 
 fn is_ident_char(c: char) -> bool {
-    match c {
-        '_' | '0'..='9' | 'a'..='z' | 'A'..='Z' | 'ㄱ'..='ㅎ' | 'ㅏ'..='ㅣ' | '가'..='힣' => {
-            true
-        }
-        _ => false,
+    c == '_' || UnicodeXID::is_xid_continue(c)
+}
+
+/// Decomposed hangul(common on macOS) should be same symbol as composed one
+fn normalize_ident(ident: &str) -> Cow<'_, str> {
+    match is_nfc_quick(ident.chars()) {
+        IsNormalized::Yes => Cow::Borrowed(ident),
+        _ => Cow::Owned(ident.nfc().collect()),
     }
 }
 
@@ -20,6 +47,85 @@ fn is_not_ident_char(c: char) -> bool {
     !is_ident_char(c)
 }
 
+/// `{` comes before `;` in `text`, so keyword is followed by expression and block
+///
+/// Expressions can't have `{` or `;` outside of strings and comments
+fn starts_block(text: &str) -> bool {
+    let mut rest = text;
+    while let Some(pos) = rest.find(['{', ';', '#', '\'']) {
+        let after = &rest[pos + 1..];
+        rest = match rest.as_bytes()[pos] {
+            b'{' => return true,
+            b';' => return false,
+            b'#' => after.find('\n').map_or("", |end| &after[end..]),
+            _ => match after.strip_prefix("''") {
+                Some(raw) => raw.find("'''").map_or("", |end| &raw[end + 3..]),
+                None => {
+                    let mut chars = after.char_indices();
+                    let mut end = after.len();
+                    while let Some((idx, ch)) = chars.next() {
+                        match ch {
+                            '\\' => {
+                                chars.next();
+                            }
+                            '\'' => {
+                                end = idx + 1;
+                                break;
+                            }
+                            _ => {}
+                        }
+                    }
+                    &after[end..]
+                }
+            },
+        };
+    }
+
+    false
+}
+
+/// `text` starts with name, like `숲` of `장면 숲;`
+fn starts_ident(text: &str) -> bool {
+    text.starts_with(|c: char| c == '_' || UnicodeXID::is_xid_start(c))
+}
+
+/// Read `{AC00}` part of `\u{AC00}`
+fn read_unicode_escape(chars: &mut std::str::CharIndices) -> Option<char> {
+    if chars.next()?.1 != '{' {
+        return None;
+    }
+
+    let mut code = 0u32;
+    for len in 0..=6 {
+        match chars.next()?.1 {
+            '}' if len != 0 => return std::char::from_u32(code),
+            ch => code = code * 16 + ch.to_digit(16)?,
+        }
+    }
+
+    None
+}
+
+/// Escape text so it can be written back inside `'` as same string
+#[cfg(any(feature = "formatter", feature = "runtime", feature = "ink"))]
+pub(crate) fn escape_str(text: &str) -> Cow<'_, str> {
+    if !text.contains(&['\'', '\\', '\n', '\t'][..]) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len() + 2);
+    for ch in text.chars() {
+        match ch {
+            '\'' => out.push_str("\\'"),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            ch => out.push(ch),
+        }
+    }
+    Cow::Owned(out)
+}
+
 pub trait CommentHandler<'s> {
     fn add_comment(&mut self, location: Location, comment: &'s str);
 }
@@ -51,18 +157,45 @@ impl<'a, 's> CommentHandler<'s> for &'a mut StoreComment<'s> {
 
 pub struct Lexer<'s, 'i, C: CommentHandler<'s>> {
     text: &'s str,
+    source_len: usize,
     interner: &'i mut Interner,
     line: usize,
     comment_handler: C,
+    /// Previous token can be indexed and no whitespace follows it
+    index_allowed: bool,
+    /// Previous token is print, `#` right after it starts channel instead of comment
+    channel_allowed: bool,
+    /// Next token starts statement
+    stmt_allowed: bool,
 }
 
 impl<'s, 'i, C: CommentHandler<'s>> Lexer<'s, 'i, C> {
     pub fn new(text: &'s str, interner: &'i mut Interner, comment_handler: C) -> Self {
+        let start = Position {
+            location: Location::new(1),
+            offset: 0,
+        };
+        Self::new_at(text, interner, comment_handler, start)
+    }
+
+    /// Lexer for `text` which is at `start` of larger source
+    ///
+    /// Positions of tokens are same as lexing whole source
+    pub fn new_at(
+        text: &'s str,
+        interner: &'i mut Interner,
+        comment_handler: C,
+        start: Position,
+    ) -> Self {
         Self {
             text,
+            source_len: start.offset + text.len(),
             interner,
-            line: 1,
+            line: start.location.line,
             comment_handler,
+            index_allowed: false,
+            channel_allowed: false,
+            stmt_allowed: true,
         }
     }
 
@@ -71,6 +204,14 @@ impl<'s, 'i, C: CommentHandler<'s>> Lexer<'s, 'i, C> {
         Location::new(self.line)
     }
 
+    #[inline]
+    fn current_position(&self) -> Position {
+        Position {
+            location: self.location(),
+            offset: self.source_len - self.text.len(),
+        }
+    }
+
     fn skip_ws(&mut self) {
         let mut bytes = self.text.as_bytes().iter();
         while let Some(b) = bytes.next() {
@@ -129,6 +270,46 @@ impl<'s, 'i, C: CommentHandler<'s>> Lexer<'s, 'i, C> {
         }
     }
 
+    /// Parse integer literal, `_` can be used as digit separator like `1_000_000`
+    fn parse_number(&self, ident: &str) -> Result<u32> {
+        let mut num = 0u32;
+        let mut overflow = false;
+        let mut prev_separator = false;
+
+        for b in ident.bytes() {
+            match b {
+                b'0'..=b'9' => {
+                    prev_separator = false;
+                    match num
+                        .checked_mul(10)
+                        .and_then(|n| n.checked_add(u32::from(b - b'0')))
+                    {
+                        Some(n) => num = n,
+                        None => overflow = true,
+                    }
+                }
+                b'_' if !prev_separator => prev_separator = true,
+                b'_' => return Err(self.make_code_err("숫자 구분자 `_`는 연속될수 없습니다")),
+                _ => {
+                    return Err(
+                        self.make_code_err("변수가 아닌 식별자는 숫자부터 시작할수 없습니다")
+                    )
+                }
+            }
+        }
+
+        if prev_separator {
+            Err(self.make_code_err("숫자는 구분자 `_`로 끝날수 없습니다"))
+        } else if overflow {
+            Err(LexicalError::IntegerOverflow(
+                ident.to_string(),
+                self.location(),
+            ))
+        } else {
+            Ok(num)
+        }
+    }
+
     fn try_match_pop_byte(&mut self, match_byte: u8) -> bool {
         match self.text.as_bytes().get(0) {
             Some(b) if *b == match_byte => {
@@ -140,11 +321,59 @@ impl<'s, 'i, C: CommentHandler<'s>> Lexer<'s, 'i, C> {
         }
     }
 
-    fn read_str(&mut self) -> Result<&'s str> {
-        let pos = memchr::memchr(b'\'', self.text.as_bytes())
-            .ok_or(self.make_code_err("String quote is not paired"))?;
+    /// Read string literal after opening `'`, only literals with escape allocate
+    fn read_str(&mut self) -> Result<Cow<'s, str>> {
+        let text = self.text;
+        let pos = memchr::memchr2(b'\'', b'\\', text.as_bytes())
+            .ok_or_else(|| self.make_code_err("String quote is not paired"))?;
+
+        if text.as_bytes()[pos] == b'\'' {
+            self.text = unsafe { text.get_unchecked(pos + 1..) };
+            return Ok(Cow::Borrowed(unsafe { text.get_unchecked(..pos) }));
+        }
+
+        let mut lit = String::from(unsafe { text.get_unchecked(..pos) });
+        let mut chars = unsafe { text.get_unchecked(pos..) }.char_indices();
+
+        while let Some((idx, ch)) = chars.next() {
+            match ch {
+                '\'' => {
+                    self.text = unsafe { text.get_unchecked(pos + idx + 1..) };
+                    return Ok(Cow::Owned(lit));
+                }
+                '\\' => match chars.next() {
+                    Some((_, '\'')) => lit.push('\''),
+                    Some((_, '\\')) => lit.push('\\'),
+                    Some((_, 'n')) => lit.push('\n'),
+                    Some((_, 't')) => lit.push('\t'),
+                    Some((_, 'u')) if chars.as_str().starts_with('{') => {
+                        lit.push(read_unicode_escape(&mut chars).ok_or_else(|| {
+                            self.make_code_err("잘못된 유니코드 이스케이프입니다")
+                        })?)
+                    }
+                    // other `\` is kept as written, `'C:\path'` was valid before escapes
+                    Some((_, ch)) => {
+                        lit.push('\\');
+                        lit.push(ch);
+                    }
+                    None => break,
+                },
+                ch => lit.push(ch),
+            }
+        }
+
+        Err(self.make_code_err("String quote is not paired"))
+    }
+
+    /// Read `'''` string literal after opening quotes, text is kept as written
+    /// with line breaks and escapes like `\n` are not processed
+    fn read_raw_str(&mut self) -> Result<&'s str> {
+        let pos = self
+            .text
+            .find("'''")
+            .ok_or_else(|| self.make_code_err("String quote is not paired"))?;
         let lit = unsafe { self.text.get_unchecked(..pos) };
-        self.text = unsafe { self.text.get_unchecked(pos + 1..) };
+        self.text = unsafe { self.text.get_unchecked(pos + 3..) };
         Ok(lit)
     }
 
@@ -157,24 +386,104 @@ impl<'s, 'i, C: CommentHandler<'s>> Lexer<'s, 'i, C> {
         }
     }
 
+    /// Strip `keyword` only when it isn't prefix of identifier like `만약에`
+    ///
+    /// Digit is still allowed right after keyword so `만약1` keeps working
+    fn try_strip_keyword(&mut self, keyword: &str) -> bool {
+        match self.text.strip_prefix(keyword) {
+            Some(rest) if !rest.starts_with(|c: char| is_ident_char(c) && !c.is_ascii_digit()) => {
+                self.text = rest;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// `keyword` is at start and `rest` after it and whitespace passes `check`
+    ///
+    /// Keywords which were builtin names are read as keyword only in their own syntax
+    fn followed_by(&self, keyword: &str, check: impl FnOnce(&str) -> bool) -> bool {
+        self.text
+            .strip_prefix(keyword)
+            .map_or(false, |rest| check(rest.trim_start()))
+    }
+
+    /// Strip literal `keyword` unless `(` right after it calls builtin of same name
+    ///
+    /// Space before `(` keeps literal like `@참 (1)`, same as `[` after variable
+    fn try_strip_literal(&mut self, keyword: &str) -> bool {
+        match self.text.strip_prefix(keyword) {
+            Some(rest) if rest.starts_with('(') => false,
+            _ => self.try_strip_keyword(keyword),
+        }
+    }
+
     fn try_read_keyword(&mut self) -> Result<Option<Token>> {
-        if self.try_strip_prefix("만약") {
+        if self.try_strip_keyword("만약") {
             Ok(Some(Token::If))
-        } else if self.try_strip_prefix("혹은") {
+        } else if self.try_strip_keyword("혹은") {
             Ok(Some(Token::ElseIf))
-        } else if self.try_strip_prefix("그외") {
+        } else if self.try_strip_keyword("그외") {
             Ok(Some(Token::Else))
-        } else if self.try_strip_prefix("종료") {
+        } else if self.try_strip_keyword("종료") {
             Ok(Some(Token::Exit))
-        } else if self.try_strip_prefix("반복") {
+        } else if self.try_strip_keyword("반복") {
             Ok(Some(Token::While))
+        } else if self.stmt_allowed
+            && self.followed_by("시작", starts_ident)
+            && self.try_strip_keyword("시작")
+        {
+            Ok(Some(Token::Spawn))
+        } else if self.stmt_allowed
+            && self.followed_by("기다리기", |rest| rest.starts_with(';'))
+            && self.try_strip_keyword("기다리기")
+        {
+            Ok(Some(Token::Join))
+        } else if self.try_strip_keyword("타이머") {
+            Ok(Some(Token::Timer))
+        } else if self.stmt_allowed
+            && self.followed_by("선택", starts_block)
+            && self.try_strip_keyword("선택")
+        {
+            Ok(Some(Token::Select))
+        } else if self.try_strip_keyword("각각") {
+            Ok(Some(Token::ForEach))
+        } else if self.stmt_allowed
+            && self.followed_by("장면", starts_ident)
+            && self.try_strip_keyword("장면")
+        {
+            Ok(Some(Token::Label))
+        } else if self.followed_by("변수", |rest| rest.starts_with('$'))
+            && self.try_strip_keyword("변수")
+        {
+            Ok(Some(Token::Declare))
+        } else if self.followed_by("자원", |rest| {
+            rest.strip_prefix('(')
+                .map_or(false, |path| path.trim_start().starts_with('\''))
+        }) && self.try_strip_keyword("자원")
+        {
+            Ok(Some(Token::Asset))
+        } else if self.stmt_allowed
+            && self.followed_by("포함", |rest| rest.starts_with('\''))
+            && self.try_strip_keyword("포함")
+        {
+            Ok(Some(Token::Include))
+        } else if self.try_strip_literal("참") {
+            Ok(Some(Token::BoolLit(true)))
+        } else if self.try_strip_literal("거짓") {
+            Ok(Some(Token::BoolLit(false)))
+        } else if self.try_strip_literal("없음") {
+            Ok(Some(Token::NullLit))
         } else {
             Ok(None)
         }
     }
 
     fn try_read_unary_operator(&mut self) -> Option<UnaryOperator> {
-        if self.try_match_pop_byte(b'!') {
+        // `!=` is binary operator
+        if self.text.starts_with("!=") {
+            None
+        } else if self.try_match_pop_byte(b'!') {
             Some(UnaryOperator::Not)
         } else {
             None
@@ -192,12 +501,20 @@ impl<'s, 'i, C: CommentHandler<'s>> Lexer<'s, 'i, C> {
             Some(BinaryOperator::Div)
         } else if self.try_match_pop_byte(b'%') {
             Some(BinaryOperator::Rem)
+        } else if self.try_strip_prefix("&&") {
+            Some(BinaryOperator::LogicalAnd)
+        } else if self.try_strip_prefix("||") {
+            Some(BinaryOperator::LogicalOr)
         } else if self.try_match_pop_byte(b'&') {
             Some(BinaryOperator::And)
         } else if self.try_match_pop_byte(b'|') {
             Some(BinaryOperator::Or)
         } else if self.try_match_pop_byte(b'^') {
             Some(BinaryOperator::Xor)
+        } else if self.try_strip_prefix("<<") {
+            Some(BinaryOperator::Shl)
+        } else if self.try_strip_prefix(">>") {
+            Some(BinaryOperator::Shr)
         } else if self.try_match_pop_byte(b'>') {
             if self.try_match_pop_byte(b'=') {
                 Some(BinaryOperator::GreaterOrEqual)
@@ -252,20 +569,37 @@ impl<'s, 'i, C: CommentHandler<'s>> Lexer<'s, 'i, C> {
 
         if let Some(ident) = self.try_read_ident() {
             if let b'0'..=b'9' = ident.as_bytes()[0] {
-                return ident.parse().map(Token::IntLit).map_err(|_| {
-                    self.make_code_err("변수가 아닌 식별자는 숫자부터 시작할수 없습니다")
-                });
+                return self.parse_number(ident).map(Token::IntLit);
             } else {
-                return Ok(Token::Builtin(self.interner.get_or_intern(ident)));
+                return Ok(Token::Builtin(
+                    self.interner.get_or_intern(normalize_ident(ident)),
+                ));
             }
         }
 
-        if self.try_match_pop_byte(b'\'') {
-            self.read_str()
-                .map(|s| Token::StrLit(self.interner.get_or_intern(s)))
+        if self.channel_allowed && self.try_match_pop_byte(b'#') {
+            let name = self
+                .try_read_ident()
+                .ok_or_else(|| self.make_code_err("채널 이름이 필요합니다"))?;
+            Ok(Token::Channel(
+                self.interner.get_or_intern(normalize_ident(name)),
+            ))
+        } else if self.text.starts_with('\'') {
+            let text = self.text;
+            let lit = if self.try_strip_prefix("'''") {
+                Cow::Borrowed(self.read_raw_str()?)
+            } else {
+                self.try_match_pop_byte(b'\'');
+                self.read_str()?
+            };
+            let consumed = &text.as_bytes()[..text.len() - self.text.len()];
+            self.line += memchr::memchr_iter(b'\n', consumed).count();
+            Ok(Token::StrLit(self.interner.get_or_intern(lit)))
         } else if self.try_match_pop_byte(b'$') {
             let ident = self.read_ident();
-            Ok(Token::Variable(self.interner.get_or_intern(ident)))
+            Ok(Token::Variable(
+                self.interner.get_or_intern(normalize_ident(ident)),
+            ))
         } else if self.try_match_pop_byte(b'{') {
             Ok(Token::OpenBrace)
         } else if self.try_match_pop_byte(b'}') {
@@ -274,6 +608,14 @@ impl<'s, 'i, C: CommentHandler<'s>> Lexer<'s, 'i, C> {
             Ok(Token::OpenParan)
         } else if self.try_match_pop_byte(b')') {
             Ok(Token::CloseParan)
+        } else if self.try_match_pop_byte(b'[') {
+            if self.index_allowed {
+                Ok(Token::OpenIndex)
+            } else {
+                Ok(Token::OpenBracket)
+            }
+        } else if self.try_match_pop_byte(b']') {
+            Ok(Token::CloseBracket)
         } else if self.try_match_pop_byte(b'@') {
             if self.try_match_pop_byte(b'@') {
                 Ok(Token::PrintLine)
@@ -296,14 +638,30 @@ impl<'s, 'i, C: CommentHandler<'s>> Iterator for Lexer<'s, 'i, C> {
     type Item = Result<Spanned>;
 
     fn next(&mut self) -> Option<Result<Spanned>> {
-        self.skip_ws();
+        let len = self.text.len();
+        if !(self.channel_allowed && self.text.starts_with('#')) {
+            self.skip_ws();
+        }
+        if self.text.len() != len {
+            // `@$목록 [0]` prints list literal after variable
+            self.index_allowed = false;
+        }
 
         if self.text.is_empty() {
             None
         } else {
-            let start = self.location();
+            let start = self.current_position();
             let token = self.read_next();
-            let end = self.location();
+            let end = self.current_position();
+            self.index_allowed = matches!(token, Ok(Token::Variable(_)) | Ok(Token::CloseBracket));
+            self.channel_allowed = matches!(
+                token,
+                Ok(Token::Print) | Ok(Token::PrintLine) | Ok(Token::PrintWait)
+            );
+            self.stmt_allowed = matches!(
+                token,
+                Ok(Token::SemiColon) | Ok(Token::OpenBrace) | Ok(Token::CloseBrace)
+            );
 
             let triple = token.map(|token| (start, token, end));
 
@@ -343,6 +701,142 @@ fn lex_test() {
 
     let one = interner.get_or_intern("1");
 
+    let keyword_prefix = interner.get_or_intern("만약에");
+
+    ts = Lexer::new("만약에 만약{ 종료;", &mut interner, IgnoreComment);
+    assert_eq!(next!(), Token::Builtin(keyword_prefix));
+    assert_eq!(next!(), Token::If);
+    assert_eq!(next!(), Token::OpenBrace);
+    assert_eq!(next!(), Token::Exit);
+    assert_eq!(next!(), Token::SemiColon);
+
+    let declare = interner.get_or_intern("변수");
+
+    ts = Lexer::new("변수(); 변수 $1;", &mut interner, IgnoreComment);
+    assert_eq!(next!(), Token::Builtin(declare));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::Declare);
+    assert_eq!(next!(), Token::Variable(one));
+
+    let select = interner.get_or_intern("선택");
+
+    ts = Lexer::new(
+        "선택(); 만약 선택() {} 선택 $1 {",
+        &mut interner,
+        IgnoreComment,
+    );
+    assert_eq!(next!(), Token::Builtin(select));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::If);
+    assert_eq!(next!(), Token::Builtin(select));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::OpenBrace);
+    assert_eq!(next!(), Token::CloseBrace);
+    assert_eq!(next!(), Token::Select);
+    assert_eq!(next!(), Token::Variable(one));
+
+    let spawn = interner.get_or_intern("시작");
+    let join = interner.get_or_intern("기다리기");
+    let load = interner.get_or_intern("로딩");
+
+    ts = Lexer::new(
+        "시작(); 시작 로딩(); 기다리기(시작()); 기다리기;",
+        &mut interner,
+        IgnoreComment,
+    );
+    assert_eq!(next!(), Token::Builtin(spawn));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::Spawn);
+    assert_eq!(next!(), Token::Builtin(load));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::Builtin(join));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::Builtin(spawn));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::Join);
+    assert_eq!(next!(), Token::SemiColon);
+
+    let label = interner.get_or_intern("장면");
+    let forest = interner.get_or_intern("숲");
+
+    ts = Lexer::new(
+        "장면('숲'); 장면 숲; @장면 숲",
+        &mut interner,
+        IgnoreComment,
+    );
+    assert_eq!(next!(), Token::Builtin(label));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::StrLit(forest));
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::Label);
+    assert_eq!(next!(), Token::Builtin(forest));
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::Print);
+    assert_eq!(next!(), Token::Builtin(label));
+
+    let include = interner.get_or_intern("포함");
+    let path = interner.get_or_intern("a.kes");
+
+    ts = Lexer::new("포함('a.kes'); 포함 'a.kes';", &mut interner, IgnoreComment);
+    assert_eq!(next!(), Token::Builtin(include));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::StrLit(path));
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::Include);
+    assert_eq!(next!(), Token::StrLit(path));
+
+    let asset = interner.get_or_intern("자원");
+
+    ts = Lexer::new("자원(1); 자원 ( 'a.kes');", &mut interner, IgnoreComment);
+    assert_eq!(next!(), Token::Builtin(asset));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::IntLit(1));
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::SemiColon);
+    assert_eq!(next!(), Token::Asset);
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::StrLit(path));
+
+    let and = interner.get_or_intern("그리고");
+
+    ts = Lexer::new("그리고() && 1", &mut interner, IgnoreComment);
+    assert_eq!(next!(), Token::Builtin(and));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::BinaryOp(BinaryOperator::LogicalAnd));
+
+    let truth = interner.get_or_intern("참");
+
+    ts = Lexer::new("참() 참 (거짓);", &mut interner, IgnoreComment);
+    assert_eq!(next!(), Token::Builtin(truth));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::BoolLit(true));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::BoolLit(false));
+
+    let null = interner.get_or_intern("없음");
+
+    ts = Lexer::new("없음() 없음", &mut interner, IgnoreComment);
+    assert_eq!(next!(), Token::Builtin(null));
+    assert_eq!(next!(), Token::OpenParan);
+    assert_eq!(next!(), Token::CloseParan);
+    assert_eq!(next!(), Token::NullLit);
+
     ts = Lexer::new("$1 = 1 + 2", &mut interner, IgnoreComment);
     assert_eq!(next!(), Token::Variable(one));
     assert_eq!(next!(), Token::Assign);
@@ -351,3 +845,166 @@ fn lex_test() {
     assert_eq!(next!(), Token::IntLit(2));
     assert!(ts.text.is_empty());
 }
+
+#[test]
+fn normalize_test() {
+    use pretty_assertions::assert_eq;
+    let mut interner = Interner::new();
+    let composed = interner.get_or_intern("이름");
+
+    // `이름` typed as conjoining jamo
+    let decomposed = "\u{110B}\u{1175}\u{1105}\u{1173}\u{11B7}";
+    let source = format!("${} {}", decomposed, decomposed);
+    let mut ts = Lexer::new(&source, &mut interner, IgnoreComment);
+
+    assert_eq!(ts.next().unwrap().unwrap().1, Token::Variable(composed));
+    assert_eq!(ts.next().unwrap().unwrap().1, Token::Builtin(composed));
+
+    let ts = Lexer::new("名前 変数", &mut interner, IgnoreComment);
+    assert_eq!(ts.count(), 2);
+}
+
+#[test]
+fn number_test() {
+    use pretty_assertions::assert_eq;
+    let mut interner = Interner::new();
+
+    let mut lex = |text| {
+        Lexer::new(text, &mut interner, IgnoreComment)
+            .next()
+            .unwrap()
+            .map(|(_, token, _)| token)
+    };
+
+    assert_eq!(lex("1_000_000").unwrap(), Token::IntLit(1_000_000));
+    assert_eq!(lex("4294967295").unwrap(), Token::IntLit(u32::MAX));
+    assert!(matches!(
+        lex("4294967296"),
+        Err(LexicalError::IntegerOverflow(num, _)) if num == "4294967296"
+    ));
+    assert!(matches!(lex("1__0"), Err(LexicalError::InvalidCode(..))));
+    assert!(matches!(lex("10_"), Err(LexicalError::InvalidCode(..))));
+    assert!(matches!(lex("1abc"), Err(LexicalError::InvalidCode(..))));
+}
+
+#[test]
+fn escape_test() {
+    use pretty_assertions::assert_eq;
+    let mut interner = Interner::new();
+
+    let mut lex = |text| {
+        let token = Lexer::new(text, &mut interner, IgnoreComment)
+            .next()
+            .unwrap();
+        token.map(|(_, token, _)| match token {
+            Token::StrLit(sym) => interner.resolve(sym).unwrap().to_string(),
+            _ => unreachable!(),
+        })
+    };
+
+    assert_eq!(lex(r"'It\'s'").unwrap(), "It's");
+    assert_eq!(lex(r"'\\n'").unwrap(), "\\n");
+    assert_eq!(lex(r"'a\nb\tc'").unwrap(), "a\nb\tc");
+    assert_eq!(lex(r"'\u{AC00}\u{1F600}'").unwrap(), "가😀");
+    assert_eq!(lex(r"'\o/ C:\users'").unwrap(), "\\o/ C:\\users");
+    assert!(matches!(
+        lex(r"'\u{D800}'"),
+        Err(LexicalError::InvalidCode(..))
+    ));
+    assert!(matches!(lex(r"'\u{}'"), Err(LexicalError::InvalidCode(..))));
+    assert!(matches!(lex(r"'abc\'"), Err(LexicalError::InvalidCode(..))));
+}
+
+#[test]
+fn raw_str_test() {
+    use pretty_assertions::assert_eq;
+    let mut interner = Interner::new();
+    let raw = interner.get_or_intern("첫줄\n'둘째줄' \\n\n");
+    let text = interner.get_or_intern("가\n나");
+
+    let tokens = Lexer::new(
+        "@'''첫줄\n'둘째줄' \\n\n''';\n@'가\n나';\n$1",
+        &mut interner,
+        IgnoreComment,
+    )
+    .map(|token| {
+        let (start, token, _) = token.unwrap();
+        (start.location.line, token)
+    })
+    .collect::<Vec<_>>();
+    assert_eq!(tokens[1], (1, Token::StrLit(raw)));
+    assert_eq!(tokens[2], (3, Token::SemiColon));
+    assert_eq!(tokens[4], (4, Token::StrLit(text)));
+    assert_eq!(tokens[5], (5, Token::SemiColon));
+    assert_eq!(tokens[6].0, 6);
+
+    let mut lex = Lexer::new("'''abc''", &mut interner, IgnoreComment);
+    assert!(matches!(
+        lex.next(),
+        Some(Err(LexicalError::InvalidCode(..)))
+    ));
+}
+
+#[test]
+fn channel_test() {
+    use pretty_assertions::assert_eq;
+    let mut interner = Interner::new();
+    let log = interner.get_or_intern("로그");
+    let a = interner.get_or_intern("a");
+
+    let tokens = Lexer::new(
+        "@#로그 'a'; @@#로그 @ #주석\n;",
+        &mut interner,
+        IgnoreComment,
+    )
+    .map(|token| token.unwrap().1)
+    .collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        [
+            Token::Print,
+            Token::Channel(log),
+            Token::StrLit(a),
+            Token::SemiColon,
+            Token::PrintLine,
+            Token::Channel(log),
+            Token::Print,
+            Token::SemiColon,
+        ]
+    );
+
+    let mut lex = Lexer::new("@# 'a'", &mut interner, IgnoreComment);
+    lex.next();
+    assert!(matches!(
+        lex.next(),
+        Some(Err(LexicalError::InvalidCode(..)))
+    ));
+}
+
+#[test]
+fn bracket_test() {
+    use pretty_assertions::assert_eq;
+    let mut interner = Interner::new();
+    let var = interner.get_or_intern("목록");
+
+    let tokens = Lexer::new("@$목록[0][1] [2];", &mut interner, IgnoreComment)
+        .map(|token| token.unwrap().1)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tokens,
+        [
+            Token::Print,
+            Token::Variable(var),
+            Token::OpenIndex,
+            Token::IntLit(0),
+            Token::CloseBracket,
+            Token::OpenIndex,
+            Token::IntLit(1),
+            Token::CloseBracket,
+            Token::OpenBracket,
+            Token::IntLit(2),
+            Token::CloseBracket,
+            Token::SemiColon,
+        ]
+    );
+}
@@ -1,9 +1,7 @@
 //! kes implementation in Rust
 //! ## Examples
 //! ```rust
-//! use kes::builtin::RecordBuiltin;
-//! use kes::context::Context;
-//! use kes::program::Program;
+//! use kes::prelude::{Context, Program, RecordBuiltin};
 //! use futures_executor::block_on;
 //! let source = "$1 = 1 + 2; @$1;";
 //! let program = Program::from_source(source).unwrap();
@@ -12,27 +10,131 @@
 //! block_on(ctx.run(&mut builtin)).unwrap();
 //! assert_eq!(builtin.text(), "3");
 //! ```
+//!
+//! ## Stability
+//! [`prelude`] is the supported API for embedders. [`bytecode`], [`location`], `tiny` and [`value`]
+//! follow semver too, other modules are hidden from docs and may change between minor versions
 
+#[cfg(feature = "grammar-codegen")]
 use lalrpop_util::lalrpop_mod;
 
+#[cfg(feature = "compiler")]
+#[doc(hidden)]
+pub mod analysis;
+#[cfg(feature = "parser")]
+#[doc(hidden)]
 pub mod ast;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod bench;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
 pub mod builtin;
+#[cfg(feature = "compiler")]
+pub mod bytecode;
+#[cfg(feature = "compiler")]
+#[doc(hidden)]
+pub mod compat;
+#[cfg(feature = "compiler")]
 mod compiler;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
 pub mod context;
+#[cfg(feature = "parser")]
+#[doc(hidden)]
 pub mod error;
+#[cfg(feature = "formatter")]
+#[doc(hidden)]
 pub mod formatter;
+#[cfg(feature = "grammar-codegen")]
 lalrpop_mod!(
     #[allow(unused)]
     grammar
 );
+#[cfg(all(feature = "pregenerated-grammar", not(feature = "grammar-codegen")))]
+#[allow(unused, clippy::all)]
+#[rustfmt::skip]
+#[path = "grammar_generated.rs"]
+mod grammar;
+#[cfg(all(
+    feature = "parser",
+    not(any(feature = "grammar-codegen", feature = "pregenerated-grammar"))
+))]
+compile_error!("either `grammar-codegen` or `pregenerated-grammar` feature is required");
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod history;
+#[cfg(feature = "ink")]
+#[doc(hidden)]
+pub mod ink;
+#[cfg(feature = "compiler")]
 mod instruction;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod intercept;
+#[cfg(feature = "parser")]
+#[doc(hidden)]
 pub mod interner;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod layout;
+#[cfg(feature = "parser")]
 mod lexer;
+#[cfg(feature = "compiler")]
+#[doc(hidden)]
+pub mod loader;
+#[cfg(feature = "parser")]
 pub mod location;
+#[cfg(feature = "parser")]
 mod operator;
+#[cfg(feature = "parser")]
+#[doc(hidden)]
 pub mod parser;
+#[cfg(feature = "parser")]
+pub mod prelude;
+#[cfg(feature = "compiler")]
+#[doc(hidden)]
 pub mod program;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod sandbox;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod session;
+#[cfg(feature = "compiler")]
+#[doc(hidden)]
+pub mod signature;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod snapshot;
+#[cfg(feature = "parser")]
+#[doc(hidden)]
+pub mod source;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod stdlib;
+#[cfg(feature = "compiler")]
+mod suggest;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod table;
+#[cfg(feature = "tiny-runtime")]
+pub mod tiny;
+#[cfg(feature = "parser")]
 mod token;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod trace;
+#[cfg(feature = "runtime")]
+#[doc(hidden)]
+pub mod transcript;
+#[cfg(feature = "typewriter")]
+#[doc(hidden)]
+pub mod typewriter;
+#[cfg(any(feature = "compiler", feature = "tiny-runtime"))]
 pub mod value;
 
+#[cfg(feature = "runtime")]
 pub use async_trait::async_trait;
+#[cfg(feature = "compiler")]
+pub use prelude::compile_source;
@@ -0,0 +1,162 @@
+//! Files included by `포함 'other.kes';`
+//!
+//! [`Program::from_entry`](crate::program::Program::from_entry) replaces each `포함` with
+//! statements of included file, paths are relative to root of [`Loader`] not to including file
+
+use crate::ast::Stmt;
+use crate::error::{LexicalError, ParseError};
+use crate::interner::Interner;
+use crate::location::Location;
+use crate::parser::parse;
+use crate::source::Source;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/// Source of files for `포함`, host decides which files script can include
+pub trait Loader {
+    /// Text of file, `None` when file doesn't exist or isn't allowed
+    fn load(&mut self, path: &str) -> Option<String>;
+}
+
+/// `Loader` which keeps files in memory
+#[derive(Clone, Debug, Default)]
+pub struct MemoryLoader(BTreeMap<String, String>);
+
+impl MemoryLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(mut self, path: impl Into<String>, text: impl Into<String>) -> Self {
+        self.0.insert(path.into(), text.into());
+        self
+    }
+}
+
+impl Loader for MemoryLoader {
+    fn load(&mut self, path: &str) -> Option<String> {
+        self.0.get(path).cloned()
+    }
+}
+
+/// `Loader` which reads files under `root`, paths escaping `root` are rejected
+#[derive(Clone, Debug)]
+pub struct DirLoader {
+    root: PathBuf,
+}
+
+impl DirLoader {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl Loader for DirLoader {
+    fn load(&mut self, path: &str) -> Option<String> {
+        let path = Path::new(path);
+        if !path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return None;
+        }
+
+        std::fs::read_to_string(self.root.join(path)).ok()
+    }
+}
+
+/// Statements of every file with file each statement came from
+pub(crate) struct Included {
+    pub stmts: Vec<Stmt>,
+    /// Paths of entry file and included files, index is file id
+    pub files: Vec<String>,
+    /// `(index of first statement, file id)`, sorted by statement
+    pub regions: Vec<(usize, u32)>,
+    /// `포함` statement which included each file first, default for entry file
+    pub includes: Vec<Location>,
+}
+
+impl Included {
+    pub fn new(entry: &str) -> Self {
+        Self {
+            stmts: Vec::new(),
+            files: vec![entry.into()],
+            regions: Vec::new(),
+            includes: vec![Location::default()],
+        }
+    }
+
+    fn enter(&mut self, file: u32) {
+        let start = self.stmts.len();
+        match self.regions.last_mut().filter(|region| region.0 == start) {
+            Some(region) => region.1 = file,
+            None => self.regions.push((start, file)),
+        }
+    }
+}
+
+/// Error of included file `path`, reported at `포함` statement of including file
+pub(crate) fn in_include(path: &str, location: Location, error: ParseError) -> ParseError {
+    ParseError::User {
+        error: LexicalError::InInclude(path.into(), Box::new(error), location),
+    }
+}
+
+/// Append statements of `program` to `out`, `포함` is replaced with statements of included file
+///
+/// `file` is id of file of `program`, `stack` has paths of files being included,
+/// file including one of them is a cycle
+pub(crate) fn resolve_includes(
+    program: Vec<Stmt>,
+    file: u32,
+    interner: &mut Interner,
+    loader: &mut dyn Loader,
+    stack: &mut Vec<String>,
+    out: &mut Included,
+) -> Result<(), ParseError> {
+    out.enter(file);
+
+    for stmt in program {
+        let (path, location) = match stmt {
+            Stmt::Include { path, location } => (path, location),
+            stmt => {
+                out.stmts.push(stmt);
+                continue;
+            }
+        };
+        let path = interner.resolve(path).unwrap_or_default().to_string();
+
+        if stack.contains(&path) {
+            return Err(ParseError::User {
+                error: LexicalError::IncludeCycle(path, location),
+            });
+        }
+
+        let text = match loader.load(&path) {
+            Some(text) => text,
+            None => {
+                return Err(ParseError::User {
+                    error: LexicalError::IncludeNotFound(path, location),
+                })
+            }
+        };
+        let included = parse(Source::new(&text).as_str(), interner)
+            .map_err(|error| in_include(&path, location, error))?;
+        let id = match out.files.iter().position(|file| *file == path) {
+            Some(id) => id as u32,
+            None => {
+                out.files.push(path.clone());
+                out.includes.push(location);
+                out.files.len() as u32 - 1
+            }
+        };
+
+        stack.push(path);
+        let result = resolve_includes(included, id, interner, loader, stack, out);
+        let path = stack.pop().unwrap_or_default();
+        result.map_err(|error| in_include(&path, location, error))?;
+        out.enter(file);
+    }
+
+    Ok(())
+}
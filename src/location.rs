@@ -1,7 +1,9 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Ord, PartialOrd, Serialize, Deserialize)]
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
 pub struct Location {
     pub line: usize,
 }
@@ -21,3 +23,23 @@ impl fmt::Display for Location {
         write!(f, "L{}", self.line)
     }
 }
+
+/// Byte range of source text
+#[derive(
+    Copy, Clone, Debug, Default, PartialEq, Eq, Ord, PartialOrd, Hash, Serialize, Deserialize,
+)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    /// Span from start of `self` to end of `other`
+    pub fn to(self, other: Self) -> Self {
+        Self::new(self.start, other.end)
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum UnaryOperator {
     /// !
     Not,
@@ -12,9 +12,14 @@ impl UnaryOperator {
             UnaryOperator::Not => "!",
         }
     }
+
+    /// Unary operators bind tighter than every binary operator
+    pub fn precedence(self) -> u8 {
+        10
+    }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum BinaryOperator {
     /// +
     Add,
@@ -32,6 +37,14 @@ pub enum BinaryOperator {
     Or,
     /// ^
     Xor,
+    /// `&&`, right side is evaluated only when left side is true
+    LogicalAnd,
+    /// `||`, right side is evaluated only when left side is false
+    LogicalOr,
+    /// `<<`, shifting by 32 or more gives 0
+    Shl,
+    /// `>>`, shifting by 32 or more gives 0
+    Shr,
 
     /// ==
     Equal,
@@ -58,6 +71,10 @@ impl BinaryOperator {
             BinaryOperator::And => "&",
             BinaryOperator::Or => "|",
             BinaryOperator::Xor => "^",
+            BinaryOperator::LogicalAnd => "&&",
+            BinaryOperator::LogicalOr => "||",
+            BinaryOperator::Shl => "<<",
+            BinaryOperator::Shr => ">>",
 
             BinaryOperator::Equal => "==",
             BinaryOperator::NotEqual => "!=",
@@ -67,9 +84,31 @@ impl BinaryOperator {
             BinaryOperator::LessOrEqual => "<=",
         }
     }
+
+    /// Binding power of operator, higher binds tighter
+    ///
+    /// Every binary operator is left associative, this table must match tiers in grammar
+    pub fn precedence(self) -> u8 {
+        match self {
+            BinaryOperator::LogicalOr => 1,
+            BinaryOperator::LogicalAnd => 2,
+            BinaryOperator::Or => 3,
+            BinaryOperator::Xor => 4,
+            BinaryOperator::And => 5,
+            BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+            | BinaryOperator::Greater
+            | BinaryOperator::GreaterOrEqual
+            | BinaryOperator::Less
+            | BinaryOperator::LessOrEqual => 6,
+            BinaryOperator::Shl | BinaryOperator::Shr => 7,
+            BinaryOperator::Add | BinaryOperator::Sub => 8,
+            BinaryOperator::Mul | BinaryOperator::Div | BinaryOperator::Rem => 9,
+        }
+    }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum TernaryOperator {
     /// ? :
     Conditional,
@@ -87,4 +126,9 @@ impl TernaryOperator {
             TernaryOperator::Conditional => ":",
         }
     }
+
+    /// Ternary operators bind looser than every binary operator
+    pub fn precedence(self) -> u8 {
+        0
+    }
 }
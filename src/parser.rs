@@ -1,13 +1,223 @@
-use crate::error::ParseError;
+use crate::error::{LexicalError, ParseError};
 use crate::interner::Interner;
-use crate::lexer::{IgnoreComment, Lexer, StoreComment};
+use crate::lexer::{IgnoreComment, Lexer, Spanned, StoreComment};
+use crate::location::Span;
+use crate::token::Token;
 use crate::{ast::Stmt, location::Location};
-use std::collections::BTreeMap;
+use lalrpop_util::ErrorRecovery;
+use std::collections::{BTreeMap, HashSet};
+
+pub use crate::lexer::Position;
+
+type GrammarError = lalrpop_util::ParseError<Position, Token, LexicalError>;
+
+/// Errors only report line of position
+fn to_parse_error(error: GrammarError) -> ParseError {
+    error.map_location(Location::from)
+}
+
+/// First error is reported even if parser recovered from it
+fn first_error(
+    result: Result<Vec<Stmt>, GrammarError>,
+    errors: Vec<ErrorRecovery<Position, Token, LexicalError>>,
+) -> Result<Vec<Stmt>, ParseError> {
+    match errors.into_iter().next() {
+        Some(recovery) => Err(to_parse_error(recovery.error)),
+        None => result.map_err(to_parse_error),
+    }
+}
+
+/// Every `장면` name must be unique, grammar already keeps them at top level
+pub fn duplicate_label(program: &[Stmt], interner: &Interner) -> Option<ParseError> {
+    let mut names = HashSet::new();
+
+    program.iter().find_map(|stmt| match stmt {
+        Stmt::Label { name, location } if !names.insert(*name) => Some(ParseError::User {
+            error: LexicalError::DuplicateLabel(
+                interner.resolve(*name).unwrap_or_default().into(),
+                *location,
+            ),
+        }),
+        _ => None,
+    })
+}
 
 /// Parse program from source
 pub fn parse(s: &str, interner: &mut Interner) -> Result<Vec<Stmt>, ParseError> {
+    let mut errors = Vec::new();
     let lexer = Lexer::new(s, interner, IgnoreComment);
-    crate::grammar::ProgramParser::new().parse(lexer)
+    let result = crate::grammar::ProgramParser::new().parse(&mut errors, lexer);
+    let program = first_error(result, errors)?;
+
+    match duplicate_label(&program, interner) {
+        Some(err) => Err(err),
+        None => Ok(program),
+    }
+}
+
+/// Parse program from source, broken statements are skipped until next `;`
+///
+/// Returns every error with statements which are parsed successfully
+pub fn parse_recovering(s: &str, interner: &mut Interner) -> (Vec<Stmt>, Vec<ParseError>) {
+    let start = Position {
+        location: Location::new(1),
+        offset: 0,
+    };
+    let (program, mut errors) = parse_recovering_at(s, interner, start);
+    errors.extend(duplicate_label(&program, interner));
+    (program, errors)
+}
+
+/// Parse top-level statements which are at `start` of larger source like `parse_recovering`
+///
+/// Locations and spans are same as parsing whole source, labels are not checked
+/// since same name can be in other part of source, see `duplicate_label`
+pub fn parse_recovering_at(
+    s: &str,
+    interner: &mut Interner,
+    start: Position,
+) -> (Vec<Stmt>, Vec<ParseError>) {
+    let mut errors = Vec::new();
+    let lexer = Lexer::new_at(s, interner, IgnoreComment, start);
+    let result = crate::grammar::ProgramParser::new().parse(&mut errors, lexer);
+    let mut errors = errors
+        .into_iter()
+        .map(|recovery| to_parse_error(recovery.error))
+        .collect::<Vec<_>>();
+
+    match result {
+        Ok(program) => (program, errors),
+        Err(err) => {
+            errors.push(to_parse_error(err));
+            (Vec::new(), errors)
+        }
+    }
+}
+
+/// Run only lexer over source, returns number of tokens
+///
+/// Mainly for measuring lexer separately from parser
+pub fn count_tokens(s: &str, interner: &mut Interner) -> Result<usize, LexicalError> {
+    let mut count = 0;
+    for token in Lexer::new(s, interner, IgnoreComment) {
+        token?;
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Source range of statement
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StmtSpan {
+    /// From first token to `;` or last `}`
+    pub span: Span,
+    /// Every `{ ... }` of statement in source order, `만약` and `선택` have one per arm
+    pub blocks: Vec<BlockSpan>,
+}
+
+/// Source range of block with statements in it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BlockSpan {
+    /// Including braces
+    pub span: Span,
+    pub stmts: Vec<StmtSpan>,
+}
+
+type Tokens = std::iter::Peekable<std::vec::IntoIter<Spanned>>;
+
+/// Blocks of arms until `}` of `선택`, values and `그외` between them are skipped
+fn select_arm_spans(tokens: &mut Tokens) -> Vec<BlockSpan> {
+    let mut blocks = Vec::new();
+
+    while let Some(&(start, token, _)) = tokens.peek() {
+        if token == Token::CloseBrace {
+            break;
+        }
+
+        tokens.next();
+
+        if token == Token::OpenBrace {
+            let stmts = body_spans(tokens);
+            let end = match tokens.next() {
+                Some((_, _, close_end)) => close_end,
+                None => break,
+            };
+            blocks.push(BlockSpan {
+                span: start.to(end),
+                stmts,
+            });
+        }
+    }
+
+    blocks
+}
+
+fn body_spans(tokens: &mut Tokens) -> Vec<StmtSpan> {
+    let mut stmts = Vec::new();
+
+    while let Some(&(start, first, _)) = tokens.peek() {
+        if first == Token::CloseBrace {
+            break;
+        }
+
+        let mut end = start;
+        let mut blocks = Vec::new();
+
+        while let Some((token_start, token, token_end)) = tokens.next() {
+            end = token_end;
+
+            match token {
+                Token::SemiColon => break,
+                Token::OpenBrace if first == Token::Select => {
+                    blocks = select_arm_spans(tokens);
+                    if let Some((_, _, close_end)) = tokens.next() {
+                        end = close_end;
+                    }
+                    break;
+                }
+                Token::OpenBrace => {
+                    let stmts = body_spans(tokens);
+                    if let Some((_, _, close_end)) = tokens.next() {
+                        end = close_end;
+                    }
+                    blocks.push(BlockSpan {
+                        span: token_start.to(end),
+                        stmts,
+                    });
+
+                    // `혹은` and `그외` continue same statement
+                    if !matches!(
+                        tokens.peek(),
+                        Some((_, Token::ElseIf, _)) | Some((_, Token::Else, _))
+                    ) {
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        // extra `;` belongs to previous statement
+        while let Some(&(_, Token::SemiColon, semi_end)) = tokens.peek() {
+            end = semi_end;
+            tokens.next();
+        }
+
+        stmts.push(StmtSpan {
+            span: start.to(end),
+            blocks,
+        });
+    }
+
+    stmts
+}
+
+/// Find range of every statement with lexer only
+///
+/// Result matches statements of `parse` when source has no parse error
+pub fn statement_spans(s: &str, interner: &mut Interner) -> Result<Vec<StmtSpan>, LexicalError> {
+    let tokens = Lexer::new(s, interner, IgnoreComment).collect::<Result<Vec<_>, _>>()?;
+    Ok(body_spans(&mut tokens.into_iter().peekable()))
 }
 
 /// Parse program from source with comments
@@ -15,24 +225,46 @@ pub fn parse_with_comments<'s>(
     s: &'s str,
     interner: &mut Interner,
 ) -> Result<(Vec<Stmt>, BTreeMap<Location, &'s str>), ParseError> {
+    let mut errors = Vec::new();
     let mut comment_handler = StoreComment::new();
     let lexer = Lexer::new(s, interner, &mut comment_handler);
-    crate::grammar::ProgramParser::new()
-        .parse(lexer)
-        .map(|program| (program, comment_handler.into_comments()))
+    let result = crate::grammar::ProgramParser::new().parse(&mut errors, lexer);
+    first_error(result, errors).map(|program| (program, comment_handler.into_comments()))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::parse;
+    #[cfg(feature = "grammar-codegen")]
+    #[test]
+    fn pregenerated_grammar_is_fresh() {
+        assert!(
+            include_str!(concat!(env!("OUT_DIR"), "/grammar.rs"))
+                == include_str!("grammar_generated.rs"),
+            "`src/grammar_generated.rs` is outdated, run `KES_UPDATE_GRAMMAR=1 cargo build`"
+        );
+    }
+
+    use super::{
+        count_tokens, parse, parse_recovering, parse_recovering_at, statement_spans, BlockSpan,
+        StmtSpan,
+    };
     use crate::{
-        ast::{Expr, Stmt},
+        ast::{Expr, ExprKind, Stmt},
         interner::Interner,
-        location::Location,
-        operator::BinaryOperator,
+        lexer::Position,
+        location::{Location, Span},
+        operator::{BinaryOperator, UnaryOperator},
     };
     use pretty_assertions::assert_eq;
 
+    fn expr(kind: ExprKind, start: usize, end: usize) -> Expr {
+        Expr::new(kind, Span::new(start, end))
+    }
+
+    fn num(num: u32, start: usize) -> Expr {
+        expr(ExprKind::Number(num), start, start + num.to_string().len())
+    }
+
     macro_rules! make_test {
         ($name:ident, $code:expr, [$(($sym:ident, $text:expr),)*], $program:expr) => {
             #[test]
@@ -61,15 +293,23 @@ mod tests {
         [(one, "1"),],
         [Stmt::Assign {
             var: one,
-            value: Expr::BinaryOp {
-                lhs: Box::new(Expr::Number(1)),
-                rhs: Box::new(Expr::BinaryOp {
-                    lhs: Box::new(Expr::Number(2)),
-                    rhs: Box::new(Expr::Number(3)),
-                    op: BinaryOperator::Mul,
-                }),
-                op: BinaryOperator::Add,
-            },
+            ty: None,
+            value: num(1, 5).binary_op(
+                num(2, 9).binary_op(num(3, 13), BinaryOperator::Mul),
+                BinaryOperator::Add
+            ),
+            location: Location::new(1),
+        }]
+    );
+
+    make_test!(
+        typed,
+        "$체력: 수 = 100;",
+        [(hp, "체력"), (int, "수"),],
+        [Stmt::Assign {
+            var: hp,
+            ty: Some(int),
+            value: num(100, 15),
             location: Location::new(1),
         }]
     );
@@ -79,7 +319,8 @@ mod tests {
         "@@'123' 123;",
         [(text, "123"),],
         [Stmt::Print {
-            values: vec![Expr::String(text), Expr::Number(123)],
+            values: vec![expr(ExprKind::String(text), 2, 7), num(123, 8)],
+            channel: None,
             newline: true,
             wait: false,
             location: Location::new(1),
@@ -97,33 +338,218 @@ mod tests {
         [
             Stmt::Assign {
                 var: one,
-                value: Expr::Number(1),
+                ty: None,
+                value: num(1, 18),
                 location: Location::new(2),
             },
             Stmt::Assign {
                 var: two,
-                value: Expr::Number(2),
+                ty: None,
+                value: num(2, 38),
                 location: Location::new(3),
             },
             Stmt::Assign {
                 var: three,
-                value: Expr::BinaryOp {
-                    lhs: Box::new(Expr::Variable(one)),
-                    rhs: Box::new(Expr::Variable(two)),
-                    op: BinaryOperator::Add,
-                },
+                ty: None,
+                value: expr(ExprKind::Variable(one), 58, 60)
+                    .binary_op(expr(ExprKind::Variable(two), 63, 65), BinaryOperator::Add),
                 location: Location::new(4),
             },
         ]
     );
 
+    make_test!(
+        trailing_comma,
+        "함수(1, 2,);",
+        [(func, "함수"),],
+        [Stmt::Expression {
+            expr: expr(
+                ExprKind::BuiltinFunc {
+                    name: func,
+                    args: vec![num(1, 7), num(2, 10)],
+                },
+                0,
+                13
+            ),
+            location: Location::new(1),
+        }]
+    );
+
+    make_test!(
+        operand_span,
+        "2 + '2';",
+        [(two, "2"),],
+        [Stmt::Expression {
+            expr: num(2, 0).binary_op(expr(ExprKind::String(two), 4, 7), BinaryOperator::Add),
+            location: Location::new(1),
+        }]
+    );
+
+    make_test!(
+        unary_paren_span,
+        "!(1);",
+        [],
+        [Stmt::Expression {
+            expr: expr(ExprKind::Nop(Box::new(num(1, 2))), 1, 4)
+                .unary_op(UnaryOperator::Not, Span::new(0, 4)),
+            location: Location::new(1),
+        }]
+    );
+
+    make_test!(
+        empty_print,
+        "@; @@;",
+        [],
+        [
+            Stmt::Print {
+                values: vec![],
+                channel: None,
+                newline: false,
+                wait: false,
+                location: Location::new(1),
+            },
+            Stmt::Print {
+                values: vec![],
+                channel: None,
+                newline: true,
+                wait: false,
+                location: Location::new(1),
+            },
+        ]
+    );
+
+    #[test]
+    fn precedence_table() {
+        use crate::operator::BinaryOperator::*;
+
+        let ops = [
+            Add,
+            Sub,
+            Div,
+            Mul,
+            Rem,
+            And,
+            Or,
+            Xor,
+            LogicalAnd,
+            LogicalOr,
+            Shl,
+            Shr,
+            Equal,
+            NotEqual,
+            Less,
+            LessOrEqual,
+            Greater,
+            GreaterOrEqual,
+        ];
+
+        for &first in ops.iter() {
+            for &second in ops.iter() {
+                let mut interner = Interner::new();
+                let code = format!("1 {} 2 {} 3;", first.name(), second.name());
+                let program = parse(&code, &mut interner).unwrap();
+
+                let two = 3 + first.name().len();
+                let three = two + 3 + second.name().len();
+
+                let expected = if second.precedence() > first.precedence() {
+                    num(1, 0).binary_op(num(2, two).binary_op(num(3, three), second), first)
+                } else {
+                    num(1, 0)
+                        .binary_op(num(2, two), first)
+                        .binary_op(num(3, three), second)
+                };
+
+                assert_eq!(
+                    program,
+                    [Stmt::Expression {
+                        expr: expected,
+                        location: Location::new(1),
+                    }],
+                    "{}",
+                    code
+                );
+            }
+        }
+    }
+
     make_test!(
         compare,
         "1 > 2;",
         [],
         [Stmt::Expression {
-            expr: Expr::Number(1).binary_op(Expr::Number(2), BinaryOperator::Greater),
+            expr: num(1, 0).binary_op(num(2, 4), BinaryOperator::Greater),
             location: Location::new(1),
         }]
     );
+
+    #[test]
+    fn token_count() {
+        let mut interner = Interner::new();
+        assert_eq!(
+            count_tokens("$1 = 2; # 주석\n@'가';", &mut interner).unwrap(),
+            7
+        );
+        assert!(count_tokens("'닫히지 않음", &mut interner).is_err());
+    }
+
+    #[test]
+    fn statement_span() {
+        let mut interner = Interner::new();
+        let stmt = |start, end, blocks| StmtSpan {
+            span: Span::new(start, end),
+            blocks,
+        };
+        let block = |start, end, stmts| BlockSpan {
+            span: Span::new(start, end),
+            stmts,
+        };
+
+        assert_eq!(
+            statement_spans("$1 = 2;;\n만약 1 { @1; } 그외 { }\n@2;", &mut interner).unwrap(),
+            [
+                stmt(0, 8, vec![]),
+                stmt(
+                    9,
+                    36,
+                    vec![
+                        block(18, 25, vec![stmt(20, 23, vec![])]),
+                        block(33, 36, vec![])
+                    ]
+                ),
+                stmt(37, 40, vec![]),
+            ]
+        );
+
+        assert_eq!(
+            statement_spans("선택 1 { 1 { @1; } 그외 { } }", &mut interner).unwrap(),
+            [stmt(
+                0,
+                33,
+                vec![
+                    block(13, 20, vec![stmt(15, 18, vec![])]),
+                    block(28, 31, vec![])
+                ]
+            )]
+        );
+    }
+
+    #[test]
+    fn parse_part() {
+        let code = "@1;\n# 주석\n만약 $가 { @2; }\n장면 가;\n";
+        let start = code.find("만약").unwrap();
+        let mut interner = Interner::new();
+        let (whole, _) = parse_recovering(code, &mut interner);
+        let (part, errors) = parse_recovering_at(
+            &code[start..],
+            &mut interner,
+            Position {
+                location: Location::new(3),
+                offset: start,
+            },
+        );
+
+        assert!(errors.is_empty());
+        assert_eq!(part, &whole[1..]);
+    }
 }
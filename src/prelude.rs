@@ -0,0 +1,42 @@
+//! Supported API for embedding kes
+//!
+//! Items in this module follow semver, other modules change more often
+//! while internals are reworked and are hidden from docs. Prefer importing from here
+//!
+//! ```rust
+//! use kes::prelude::*;
+//! use futures_executor::block_on;
+//! let program = compile_source("$1 = 1 + 2; @$1;").unwrap();
+//! let mut builtin = RecordBuiltin::new();
+//! block_on(Context::new(&program).run(&mut builtin)).unwrap();
+//! assert_eq!(builtin.text(), "3");
+//! ```
+
+#[cfg(feature = "runtime")]
+pub use crate::builtin::{Builtin, RecordBuiltin};
+#[cfg(feature = "runtime")]
+pub use crate::context::{Context, ContextBuilder, WaitControl, WaitPolicy};
+pub use crate::error::{LexicalError, ParseError};
+#[cfg(feature = "runtime")]
+pub use crate::error::{RuntimeError, RuntimeResult};
+#[cfg(feature = "formatter")]
+pub use crate::formatter::format_code;
+pub use crate::location::Location;
+pub use crate::parser::parse;
+#[cfg(feature = "compiler")]
+pub use crate::program::{CompileOptions, Program};
+#[cfg(feature = "runtime")]
+pub use crate::snapshot::Snapshot;
+pub use crate::source::Source;
+#[cfg(feature = "runtime")]
+pub use crate::stdlib::StdBuiltin;
+#[cfg(feature = "compiler")]
+pub use crate::value::Value;
+#[cfg(feature = "runtime")]
+pub use async_trait::async_trait;
+
+/// Compile source with default options, same as `Program::from_source`
+#[cfg(feature = "compiler")]
+pub fn compile_source(source: &str) -> Result<Program, ParseError> {
+    Program::from_source(source)
+}
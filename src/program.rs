@@ -1,30 +1,630 @@
-use crate::ast::Stmt;
-use crate::compiler::Compiler;
-use crate::error::ParseError;
-use crate::instruction::InstructionWithDebug;
+use crate::analysis::{
+    extend_variable_types, undeclared_variables, unreachable_code, use_before_assign,
+    variable_type_warnings, variable_types,
+};
+use crate::ast::{IntoKesAst, Stmt};
+use crate::compat::{Feature, Requirement, BUILTINS_KEY, ENGINE_KEY};
+use crate::compiler::{Compiled, Compiler, Label};
+use crate::error::{CompileWarning, LexicalError, ParseError, ProgramFormatError};
+use crate::instruction::{Instruction, InstructionWithDebug};
 use crate::interner::{Interner, Symbol};
-use crate::parser::parse;
-use serde::{Deserialize, Serialize};
+use crate::loader::{in_include, resolve_includes, Included, Loader};
+use crate::location::Location;
+use crate::parser::{duplicate_label, parse, parse_recovering};
+use crate::signature::{ParamType, Signatures};
+use crate::source::{Metadata, Source};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{BuildHasher, Hash, Hasher};
+#[cfg(feature = "runtime")]
+use string_interner::symbol::Symbol as _;
 
+/// Options for compiling `Program`
+#[derive(Clone, Copy, Default)]
+pub struct CompileOptions<'a> {
+    /// Builtin signatures used for checking calls
+    pub signatures: Option<&'a Signatures>,
+    /// Reject variables read without assignment or `변수` declaration before
+    pub strict: bool,
+    /// Reject `자원('...')` paths which are not in manifest
+    pub assets: Option<&'a dyn AssetManifest>,
+}
+
+/// Assets shipped with game, provided by host's content pipeline
+pub trait AssetManifest {
+    fn contains(&self, path: &str) -> bool;
+}
+
+impl<S: BuildHasher> AssetManifest for HashSet<String, S> {
+    fn contains(&self, path: &str) -> bool {
+        HashSet::contains(self, path)
+    }
+}
+
+impl AssetManifest for BTreeSet<String> {
+    fn contains(&self, path: &str) -> bool {
+        BTreeSet::contains(self, path)
+    }
+}
+
+/// Builtin name which runs other script, see `Session`
+pub const RUN_SCRIPT: &str = "실행";
+
+/// Builtins called by program and scenes run by `실행`
+///
+/// Every `장면` is node of graph, code before first `장면` is node with empty name
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Default)]
+pub struct CallGraph {
+    /// Builtin name with lines which call it
+    pub builtins: BTreeMap<String, Vec<Location>>,
+    /// Node of each scene by name
+    pub scenes: BTreeMap<String, SceneNode>,
+}
+
+/// Code from `장면` to next `장면`
+#[derive(Serialize, Deserialize, Eq, PartialEq, Debug, Default)]
+pub struct SceneNode {
+    /// Builtins called in scene
+    pub builtins: BTreeSet<String>,
+    /// Edge to script of each `실행('이름')` with line of call, name must be string literal
+    pub runs: Vec<(String, Location)>,
+}
+
+/// String literal in program for localization
+#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]
+pub struct StringEntry {
+    /// Stable id derived from text
+    pub id: String,
+    pub text: String,
+    pub location: Location,
+}
+
+/// Make stable id of string literal
+///
+/// It only depends on text so moving lines doesn't break translations
+pub fn string_id(text: &str) -> String {
+    let mut hasher = StableHasher::new();
+    hasher.write(text.as_bytes());
+    format!("{:016x}", hasher.finish())
+}
+
+/// FNV-1a hasher which gives same result on every platform
+struct StableHasher(u64);
+
+impl StableHasher {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for b in bytes {
+            self.0 = (self.0 ^ *b as u64).wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+
+    fn write_isize(&mut self, n: isize) {
+        self.write_u64(n as u64);
+    }
+}
+
+/// First unknown or conflicting type of `$이름: 수 = 값;`
+fn check_types(program: &[Stmt], interner: &Interner) -> Result<(), ParseError> {
+    match variable_types(program, interner).1.into_iter().next() {
+        Some(error) => Err(ParseError::User { error }),
+        None => Ok(()),
+    }
+}
+
+/// `포함` left in program, only `Program::from_entry` has loader for them
+fn unresolved_includes<'a>(
+    program: &'a [Stmt],
+    interner: &'a Interner,
+) -> impl Iterator<Item = LexicalError> + 'a {
+    program.iter().filter_map(move |stmt| match stmt {
+        Stmt::Include { path, location } => Some(LexicalError::IncludeNotFound(
+            interner.resolve(*path).unwrap_or_default().into(),
+            *location,
+        )),
+        _ => None,
+    })
+}
+
+fn check_includes(program: &[Stmt], interner: &Interner) -> Result<(), ParseError> {
+    match unresolved_includes(program, interner).next() {
+        Some(error) => Err(ParseError::User { error }),
+        None => Ok(()),
+    }
+}
+
+/// Lines of files in `Included` moved apart so every line of program is unique
+///
+/// Statements of included files keep lines of their own file, passes over whole program
+/// only report lines so they can't tell file of warning otherwise
+struct FileLines {
+    /// `(lines moved, file id)` of each region, sorted
+    regions: Vec<(usize, u32)>,
+}
+
+impl FileLines {
+    fn new(ast: &mut [Stmt], regions: &[(usize, u32)]) -> Self {
+        let mut moved = 0;
+        let mut lines = Vec::with_capacity(regions.len());
+
+        for (idx, &(start, file)) in regions.iter().enumerate() {
+            let end = regions.get(idx + 1).map_or(ast.len(), |next| next.0);
+            if start == end {
+                continue;
+            }
+            lines.push((moved, file));
+            moved = ast[start..end]
+                .iter_mut()
+                .fold(moved, |last, stmt| last.max(stmt.shift_lines(moved)));
+        }
+
+        Self { regions: lines }
+    }
+
+    /// File id and line in that file of moved `location`
+    fn local(&self, location: Location) -> (u32, Location) {
+        match self
+            .regions
+            .iter()
+            .rev()
+            .find(|(moved, _)| *moved < location.line)
+        {
+            Some(&(moved, file)) => (file, Location::new(location.line - moved)),
+            None => (0, location),
+        }
+    }
+
+    fn warning(&self, warning: CompileWarning) -> CompileWarning {
+        let (file, location) = self.local(warning.location());
+        let warning = warning.map_location(|_| location);
+        if file == 0 {
+            warning
+        } else {
+            CompileWarning::InFile(file, Box::new(warning))
+        }
+    }
+
+    /// Error of included file is reported at `포함` like errors of `Program::from_entry`
+    fn error(&self, error: LexicalError, included: &Included) -> ParseError {
+        let (file, location) = error
+            .location()
+            .map_or((0, Location::default()), |location| self.local(location));
+        let error = ParseError::User {
+            error: error.map_location(|_| location),
+        };
+        if file == 0 {
+            error
+        } else {
+            let file = file as usize;
+            in_include(&included.files[file], included.includes[file], error)
+        }
+    }
+}
+
+/// Current version of serialized `Program` format
+///
+/// Fields are read by position, so any change of fields needs new version
+pub const PROGRAM_FORMAT_VERSION: u32 = 1;
+
+/// Start of serialized `Program`, programs made before version 1 start with interner
+const PROGRAM_MAGIC: u32 = u32::from_le_bytes(*b"KES\0");
+
+/// Header of serialized `Program`, deserializing fails when it is not current format
+#[derive(Eq, PartialEq, Debug)]
+struct FormatHeader;
+
+impl Serialize for FormatHeader {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (PROGRAM_MAGIC, PROGRAM_FORMAT_VERSION).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FormatHeader {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match <(u32, u32)>::deserialize(deserializer) {
+            Ok((PROGRAM_MAGIC, PROGRAM_FORMAT_VERSION)) => Ok(FormatHeader),
+            Ok((PROGRAM_MAGIC, version)) => Err(D::Error::custom(
+                ProgramFormatError::UnsupportedVersion(version),
+            )),
+            _ => Err(D::Error::custom(ProgramFormatError::Unknown)),
+        }
+    }
+}
+
+/// Compiled script
+///
+/// Serialized bytes only depend on source, interner is serialized in symbol order
+/// so compiled program can be hashed for caching.
+/// Bytes start with [`PROGRAM_FORMAT_VERSION`], programs of other versions must be compiled again
 #[derive(Serialize, Deserialize, Eq, PartialEq, Debug)]
 pub struct Program {
+    format: FormatHeader,
     interner: Interner,
     instructions: Vec<InstructionWithDebug>,
+    /// Position of first instruction of each statement, sorted
+    statements: Vec<u32>,
+    /// `장면` in source order
+    labels: Vec<Label>,
+    /// Types declared by `$이름: 수 = 값;`
+    variable_types: BTreeMap<String, ParamType>,
+    /// Front matter of source
+    metadata: Metadata,
+    complete: bool,
+    /// Entry file and included files of `from_entry`, index is file id
+    files: Vec<String>,
+    /// `(position of first instruction, file id)` sorted, empty for single source
+    file_starts: Vec<(u32, u32)>,
+}
+
+/// Every problem found while compiling source
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    pub errors: Vec<ParseError>,
+    pub warnings: Vec<CompileWarning>,
 }
 
 impl Program {
-    pub fn from_ast(program: &[Stmt], interner: Interner) -> Self {
+    /// Compile statements from parser or other front-end implementing [`IntoKesAst`]
+    pub fn from_ast<'a>(program: impl IntoKesAst<'a>, mut interner: Interner) -> Self {
+        let program = program.into_kes_ast(&mut interner);
+        let compiled = Compiler::new().compile_program(&program);
+        Self::from_compiled(compiled, &program, interner)
+    }
+
+    fn from_compiled(compiled: Compiled, program: &[Stmt], interner: Interner) -> Self {
+        // front-ends are trusted, unknown types are left out
+        let (variable_types, _) = variable_types(program, &interner);
+
+        Self {
+            format: FormatHeader,
+            instructions: compiled.instructions,
+            statements: compiled.statements,
+            labels: compiled.labels,
+            variable_types,
+            interner,
+            metadata: Metadata::new(),
+            complete: true,
+            files: Vec::new(),
+            file_starts: compiled.files,
+        }
+    }
+
+    /// Instructions must be checked by `bytecode::verify` before running
+    pub(crate) fn from_instructions(
+        interner: Interner,
+        instructions: Vec<InstructionWithDebug>,
+        statements: Vec<u32>,
+    ) -> Self {
         Self {
+            format: FormatHeader,
             interner,
-            instructions: Compiler::new().compile(program),
+            instructions,
+            statements,
+            labels: Vec::new(),
+            variable_types: BTreeMap::new(),
+            metadata: Metadata::new(),
+            complete: true,
+            files: Vec::new(),
+            file_starts: Vec::new(),
         }
     }
 
+    pub fn from_ast_with_options(
+        program: &[Stmt],
+        interner: Interner,
+        options: &CompileOptions,
+    ) -> (Self, Vec<CompileWarning>) {
+        let compiler = match options.signatures {
+            Some(signatures) => Compiler::with_signatures(signatures, &interner),
+            None => Compiler::new(),
+        };
+        let compiled = compiler.compile_program(program);
+        let (variable_types, _) = variable_types(program, &interner);
+        let mut warnings = compiled.warnings;
+        warnings.extend(use_before_assign(program, &interner));
+        warnings.extend(unreachable_code(program, &interner));
+        warnings.extend(variable_type_warnings(program, &interner, &variable_types));
+        warnings.sort_by_key(CompileWarning::location);
+
+        (
+            Self {
+                format: FormatHeader,
+                interner,
+                instructions: compiled.instructions,
+                statements: compiled.statements,
+                labels: compiled.labels,
+                variable_types,
+                metadata: Metadata::new(),
+                complete: true,
+                files: Vec::new(),
+                file_starts: Vec::new(),
+            },
+            warnings,
+        )
+    }
+
     pub fn from_source(source: &str) -> Result<Self, ParseError> {
         let mut interner = Interner::new();
-        let ast = parse(source, &mut interner)?;
+        let source = Source::new(source);
+        let ast = parse(source.as_str(), &mut interner)?;
+        check_includes(&ast, &interner)?;
+        check_types(&ast, &interner)?;
+        let mut program = Self::from_ast(&ast, interner);
+        program.metadata = source.metadata().clone();
+
+        Ok(program)
+    }
+
+    /// Compile file `path` of `loader`, `포함` is replaced with statements of included file
+    ///
+    /// Metadata comes from front matter of entry file,
+    /// locations of included statements are lines in their own file, see [`Program::file`].
+    /// Errors in included file are [`LexicalError::InInclude`] at `포함` statement
+    pub fn from_entry(path: &str, loader: &mut dyn Loader) -> Result<Self, ParseError> {
+        let (included, interner, metadata) = Self::load_entry(path, loader)?;
+        let compiled = Compiler::new().compile_files(&included.stmts, &included.regions);
+        let mut program = Self::from_compiled(compiled, &included.stmts, interner);
+        program.files = included.files;
+        program.metadata = metadata;
+
+        Ok(program)
+    }
+
+    /// `from_entry` with checks of `from_source_with_options`
+    ///
+    /// Warnings of included files are `CompileWarning::InFile` with index of `files`
+    pub fn from_entry_with_options(
+        path: &str,
+        loader: &mut dyn Loader,
+        options: &CompileOptions,
+    ) -> Result<(Self, Vec<CompileWarning>), ParseError> {
+        let (mut included, interner, metadata) = Self::load_entry(path, loader)?;
+        let lines = FileLines::new(&mut included.stmts, &included.regions);
+        let ast = &included.stmts;
+
+        if options.strict {
+            if let Some(error) = undeclared_variables(ast, &interner).into_iter().next() {
+                return Err(lines.error(error, &included));
+            }
+        }
+
+        let compiler = match options.signatures {
+            Some(signatures) => Compiler::with_signatures(signatures, &interner),
+            None => Compiler::new(),
+        };
+        let mut compiled = compiler.compile_files(ast, &included.regions);
+        let (variable_types, _) = variable_types(ast, &interner);
+        let mut warnings = std::mem::take(&mut compiled.warnings);
+        warnings.extend(use_before_assign(ast, &interner));
+        warnings.extend(unreachable_code(ast, &interner));
+        warnings.extend(variable_type_warnings(ast, &interner, &variable_types));
+        warnings.sort_by_key(CompileWarning::location);
+
+        let mut program = Self::from_compiled(compiled, ast, interner);
+        if let Some(error) = program.missing_assets(options.assets).next() {
+            return Err(lines.error(error, &included));
+        }
+        for inst in program.instructions.iter_mut() {
+            inst.location = lines.local(inst.location).1;
+        }
+        for label in program.labels.iter_mut() {
+            label.location = lines.local(label.location).1;
+        }
+        program.files = included.files;
+        program.metadata = metadata;
+
+        let warnings = warnings
+            .into_iter()
+            .map(|warning| lines.warning(warning))
+            .collect();
+        Ok((program, warnings))
+    }
+
+    /// Parse `path` and files included by it, statements are checked like `from_source`
+    fn load_entry(
+        path: &str,
+        loader: &mut dyn Loader,
+    ) -> Result<(Included, Interner, Metadata), ParseError> {
+        let text = loader.load(path).ok_or_else(|| ParseError::User {
+            error: LexicalError::IncludeNotFound(path.into(), Location::default()),
+        })?;
+        let mut interner = Interner::new();
+        let source = Source::new(&text);
+        let ast = parse(source.as_str(), &mut interner)?;
+        let mut included = Included::new(path);
+        resolve_includes(
+            ast,
+            0,
+            &mut interner,
+            loader,
+            &mut vec![path.into()],
+            &mut included,
+        )?;
+        if let Some(error) = duplicate_label(&included.stmts, &interner) {
+            return Err(error);
+        }
+        check_types(&included.stmts, &interner)?;
 
-        Ok(Self::from_ast(&ast, interner))
+        Ok((included, interner, source.metadata().clone()))
+    }
+
+    pub fn from_source_with_options(
+        source: &str,
+        options: &CompileOptions,
+    ) -> Result<(Self, Vec<CompileWarning>), ParseError> {
+        let mut interner = Interner::new();
+        let source = Source::new(source);
+        let ast = parse(source.as_str(), &mut interner)?;
+        check_includes(&ast, &interner)?;
+        check_types(&ast, &interner)?;
+
+        if options.strict {
+            if let Some(error) = undeclared_variables(&ast, &interner).into_iter().next() {
+                return Err(ParseError::User { error });
+            }
+        }
+
+        let (mut program, warnings) = Self::from_ast_with_options(&ast, interner, options);
+        program.metadata = source.metadata().clone();
+
+        if let Some(error) = program.missing_assets(options.assets).next() {
+            return Err(ParseError::User { error });
+        }
+
+        Ok((program, warnings))
+    }
+
+    /// Compile every statement which can be parsed, broken statements are left out
+    ///
+    /// Diagnostics has all parse errors and warnings of parsed statements
+    pub fn try_from_sources(source: &str, options: &CompileOptions) -> (Self, Diagnostics) {
+        let mut interner = Interner::new();
+        let source = Source::new(source);
+        let (ast, errors) = parse_recovering(source.as_str(), &mut interner);
+        let (mut program, diagnostics) = Self::try_from_parsed(&ast, errors, interner, options);
+        program.metadata = source.metadata().clone();
+
+        (program, diagnostics)
+    }
+
+    /// `try_from_sources` with statements which are already parsed
+    ///
+    /// `errors` are parse errors of `ast`, for editors which keep `ast` between edits.
+    /// Metadata of front matter is not set
+    pub fn try_from_parsed(
+        ast: &[Stmt],
+        mut errors: Vec<ParseError>,
+        interner: Interner,
+        options: &CompileOptions,
+    ) -> (Self, Diagnostics) {
+        errors.extend(
+            unresolved_includes(ast, &interner)
+                .chain(variable_types(ast, &interner).1)
+                .map(|error| ParseError::User { error }),
+        );
+        let complete = errors.is_empty();
+        if options.strict {
+            errors.extend(
+                undeclared_variables(ast, &interner)
+                    .into_iter()
+                    .map(|error| ParseError::User { error }),
+            );
+        }
+        let (mut program, warnings) = Self::from_ast_with_options(ast, interner, options);
+        program.complete = complete;
+        errors.extend(
+            program
+                .missing_assets(options.assets)
+                .map(|error| ParseError::User { error }),
+        );
+
+        (program, Diagnostics { errors, warnings })
+    }
+
+    /// Compile `source` and append it to end of program
+    ///
+    /// Existing symbols are reused so `Context` can continue with `Context::resume`
+    /// from snapshot taken at end of previous program
+    pub fn extend_from_source(&mut self, source: &str) -> Result<(), ParseError> {
+        let ast = parse(Source::new(source).as_str(), &mut self.interner)?;
+        check_includes(&ast, &self.interner)?;
+        let mut variable_types = self.variable_types.clone();
+        if let Some(error) = extend_variable_types(&mut variable_types, &ast, &self.interner)
+            .into_iter()
+            .next()
+        {
+            return Err(ParseError::User { error });
+        }
+        let offset = self.instructions.len() as u32;
+        let compiled = Compiler::new().compile_program(&ast);
+
+        if let Some(label) = compiled
+            .labels
+            .iter()
+            .find(|label| self.labels.iter().any(|prev| prev.name == label.name))
+        {
+            return Err(ParseError::User {
+                error: LexicalError::DuplicateLabel(
+                    self.resolve(label.name).unwrap_or_default().into(),
+                    label.location,
+                ),
+            });
+        }
+
+        self.instructions
+            .extend(compiled.instructions.into_iter().map(|mut inst| {
+                inst.inst.shift_target(offset);
+                inst
+            }));
+        self.statements
+            .extend(compiled.statements.into_iter().map(|pos| pos + offset));
+        self.labels
+            .extend(compiled.labels.into_iter().map(|label| Label {
+                pos: label.pos + offset,
+                ..label
+            }));
+        self.variable_types = variable_types;
+        if !self.file_starts.is_empty() {
+            // appended source has no path, it is counted as entry file
+            self.file_starts.push((offset, 0));
+        }
+
+        Ok(())
+    }
+
+    /// Hash of compiled program, same on every platform
+    ///
+    /// Saves can use it for detecting that script is changed
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+        for (_, text) in &self.interner {
+            text.hash(&mut hasher);
+        }
+        self.instructions.hash(&mut hasher);
+        // programs without types keep hash of older versions
+        if !self.variable_types.is_empty() {
+            self.variable_types.hash(&mut hasher);
+        }
+        self.complete.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// `key: value` pairs of front matter block, read without running program
+    #[inline]
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// `false` when some statements are left out by parse errors
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.complete
     }
 
     #[inline]
@@ -32,17 +632,418 @@ impl Program {
         &self.instructions
     }
 
+    /// Whether instruction at `pos` is first instruction of statement
+    #[inline]
+    pub fn is_statement_start(&self, pos: usize) -> bool {
+        self.statements.binary_search(&(pos as u32)).is_ok()
+    }
+
+    /// Entry file and included files of [`Program::from_entry`], index is file id
+    pub fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    /// File of instruction at `pos`, `None` unless program is from [`Program::from_entry`]
+    ///
+    /// Lines of instruction locations are lines in this file
+    pub fn file(&self, pos: usize) -> Option<&str> {
+        self.files
+            .get(self.file_id(pos) as usize)
+            .map(String::as_str)
+    }
+
+    /// Index of `file(pos)` in `files`, `0` is entry file
+    pub(crate) fn file_id(&self, pos: usize) -> u32 {
+        let idx = self
+            .file_starts
+            .partition_point(|&(start, _)| start as usize <= pos);
+        idx.checked_sub(1).map_or(0, |idx| self.file_starts[idx].1)
+    }
+
+    /// Name and location of every `장면` in source order
+    pub fn entry_points(&self) -> impl Iterator<Item = (&str, Location)> + '_ {
+        self.labels
+            .iter()
+            .map(move |label| (self.resolve(label.name).unwrap_or_default(), label.location))
+    }
+
+    /// Type declared by `$name: 수 = 값;`, assigning other type fails at runtime
+    pub fn variable_type(&self, name: &str) -> Option<ParamType> {
+        self.variable_types.get(name).copied()
+    }
+
+    /// Every variable with declared type, sorted by name
+    pub fn variable_types(&self) -> impl Iterator<Item = (&str, ParamType)> + '_ {
+        self.variable_types
+            .iter()
+            .map(|(name, ty)| (name.as_str(), *ty))
+    }
+
+    /// Position of first instruction after `장면 name;`
+    pub fn entry_point(&self, name: &str) -> Option<usize> {
+        let name = self.symbol(name)?;
+        self.labels
+            .iter()
+            .find(|label| label.name == name)
+            .map(|label| label.pos as usize)
+    }
+
     #[inline]
     pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
         self.interner.resolve(symbol)
     }
+
+    #[inline]
+    pub fn symbol(&self, name: &str) -> Option<Symbol> {
+        self.interner.get(name)
+    }
+
+    /// Whether symbol is name of `$_` temporary, indexed by symbol
+    #[cfg(feature = "runtime")]
+    pub(crate) fn temporaries(&self) -> Vec<bool> {
+        let mut temporaries = vec![false; self.interner.len()];
+        for (symbol, name) in &self.interner {
+            temporaries[symbol.to_usize()] = name.starts_with('_');
+        }
+        temporaries
+    }
+
+    /// Paths of `자원('...')` with their locations in program order
+    pub fn assets(&self) -> impl Iterator<Item = (&str, Location)> + '_ {
+        self.instructions
+            .iter()
+            .filter_map(move |inst| match inst.inst {
+                Instruction::LoadAsset(path) => {
+                    Some((self.resolve(path).unwrap_or_default(), inst.location))
+                }
+                _ => None,
+            })
+    }
+
+    /// Assets which are not in `manifest`, nothing when there is no manifest
+    fn missing_assets<'s>(
+        &'s self,
+        manifest: Option<&'s dyn AssetManifest>,
+    ) -> impl Iterator<Item = LexicalError> + 's {
+        self.assets()
+            .filter(move |(path, _)| manifest.map_or(false, |manifest| !manifest.contains(path)))
+            .map(|(path, location)| LexicalError::MissingAsset(path.into(), location))
+    }
+
+    /// List every string literal with its location
+    pub fn extract_strings(&self) -> Vec<StringEntry> {
+        self.instructions
+            .iter()
+            .filter_map(|inst| match inst.inst {
+                Instruction::LoadStr(sym) => {
+                    let text = self.resolve(sym).unwrap_or_default();
+                    Some(StringEntry {
+                        id: string_id(text),
+                        text: text.into(),
+                        location: inst.location,
+                    })
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Replace string literals with translations keyed by [`string_id`]
+    pub fn replace_strings(&mut self, translations: &HashMap<String, String>) {
+        for inst in self.instructions.iter_mut() {
+            if let Instruction::LoadStr(sym) = &mut inst.inst {
+                let id = string_id(self.interner.resolve(*sym).unwrap_or_default());
+                if let Some(text) = translations.get(&id) {
+                    *sym = self.interner.get_or_intern(text);
+                }
+            }
+        }
+    }
+
+    /// Requirements from metadata and instructions, see [`crate::compat`]
+    pub fn requires(&self) -> BTreeSet<Requirement> {
+        let metadata = self.metadata();
+        let mut requirements = BTreeSet::new();
+
+        if let Some(constraint) = metadata.get(ENGINE_KEY) {
+            requirements.insert(Requirement::Engine(constraint.clone()));
+        }
+
+        if let Some(builtins) = metadata.get(BUILTINS_KEY) {
+            requirements.extend(
+                builtins
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|name| !name.is_empty())
+                    .map(|name| Requirement::Builtin(name.into())),
+            );
+        }
+
+        for inst in self.instructions() {
+            let requirement = match inst.inst {
+                Instruction::CallBuiltin(name) => {
+                    Requirement::Builtin(self.resolve(name).unwrap_or_default().into())
+                }
+                Instruction::SpawnBuiltin(name) => {
+                    requirements.insert(Requirement::Feature(Feature::Spawn));
+                    Requirement::Builtin(self.resolve(name).unwrap_or_default().into())
+                }
+                Instruction::Timer(_) => Requirement::Feature(Feature::Timer),
+                Instruction::BuildList(_)
+                | Instruction::LoadIndex
+                | Instruction::StoreIndex { .. } => Requirement::Feature(Feature::List),
+                _ => continue,
+            };
+            requirements.insert(requirement);
+        }
+
+        requirements
+    }
+
+    pub fn call_graph(&self) -> CallGraph {
+        let mut graph = CallGraph::default();
+        graph.scenes.insert(String::new(), SceneNode::default());
+        for (name, _) in self.entry_points() {
+            graph.scenes.insert(name.into(), SceneNode::default());
+        }
+
+        let mut labels = self.labels.iter().peekable();
+        let mut scene = "";
+
+        for (pos, inst) in self.instructions.iter().enumerate() {
+            while let Some(label) = labels.next_if(|label| label.pos as usize <= pos) {
+                scene = self.resolve(label.name).unwrap_or_default();
+            }
+
+            if let Instruction::CallBuiltin(name) | Instruction::SpawnBuiltin(name) = inst.inst {
+                let name = self.resolve(name).unwrap_or_default();
+                let lines = graph.builtins.entry(name.into()).or_insert_with(Vec::new);
+
+                if lines.last() != Some(&inst.location) {
+                    lines.push(inst.location);
+                }
+
+                let node = graph.scenes.get_mut(scene).unwrap();
+                node.builtins.insert(name.into());
+
+                let target = match pos.checked_sub(1).map(|prev| &self.instructions[prev].inst) {
+                    Some(Instruction::LoadStr(target)) if name == RUN_SCRIPT => target,
+                    _ => continue,
+                };
+                let target = self.resolve(*target).unwrap_or_default();
+                node.runs.push((target.into(), inst.location));
+            }
+        }
+
+        graph
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::Program;
+    #[cfg(feature = "runtime")]
+    use crate::builtin::RecordBuiltin;
+    #[cfg(feature = "runtime")]
+    use crate::context::Context;
+    use crate::error::ParseError;
+    use crate::location::Location;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn call_graph() {
+        let program = Program::from_source("ㅇ(ㄴ(1), ㄴ(2));\n@ㄴ(3);").unwrap();
+        let graph = program.call_graph();
+        assert_eq!(
+            serde_json::to_string(&graph).unwrap(),
+            r#"{"builtins":{"ㄴ":[{"line":1},{"line":2}],"ㅇ":[{"line":1}]},"scenes":{"":{"builtins":["ㄴ","ㅇ"],"runs":[]}}}"#
+        );
+    }
+
+    #[test]
+    fn call_graph_scenes() {
+        let program = Program::from_source(
+            "실행('처음');\n장면 상점;\n@ㄴ(1);\n실행('창고');\n장면 창고;\n실행($1);",
+        )
+        .unwrap();
+        let graph = program.call_graph();
+
+        assert_eq!(graph.scenes.len(), 3);
+        assert_eq!(
+            graph.scenes[""].runs,
+            [("처음".to_string(), Location::new(1))]
+        );
+        assert_eq!(
+            graph.scenes["상점"].builtins.iter().collect::<Vec<_>>(),
+            ["ㄴ", "실행"]
+        );
+        assert_eq!(
+            graph.scenes["상점"].runs,
+            [("창고".to_string(), Location::new(4))]
+        );
+        assert!(graph.scenes["창고"].runs.is_empty());
+        assert_eq!(graph.builtins["실행"].len(), 3);
+    }
+
+    #[test]
+    fn partial_compile() {
+        use super::CompileOptions;
+        use crate::error::CompileWarning;
+
+        let (program, diagnostics) = Program::try_from_sources(
+            "$1 = 1;\n$2 = + ;\n@$3;\n만약 1 { @ 2 +; }",
+            &CompileOptions::default(),
+        );
+
+        assert!(!program.is_complete());
+        assert_eq!(diagnostics.errors.len(), 2);
+        assert_eq!(
+            diagnostics.warnings,
+            [CompileWarning::ConstantCondition(true, Location::new(4))]
+        );
+
+        let (program, diagnostics) = Program::try_from_sources("@1;", &CompileOptions::default());
+        assert!(program.is_complete());
+        assert!(diagnostics.errors.is_empty());
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn extend_from_source() {
+        let mut program = Program::from_source("$1 = 2;").unwrap();
+        let symbol = program.symbol("1");
+
+        let snapshot = {
+            let mut ctx = Context::new(&program);
+            let mut builtin = RecordBuiltin::new();
+            futures_executor::block_on(async { while ctx.step(&mut builtin).await.unwrap() {} });
+            ctx.snapshot()
+        };
+
+        program
+            .extend_from_source("반복 $1 > 0 { @$1; $1 = $1 - 1; }")
+            .unwrap();
+        assert_eq!(program.symbol("1"), symbol);
+
+        let ctx = Context::resume(&program, snapshot);
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "21");
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn external_ast() {
+        use crate::ast::{Expr, ExprKind, IntoKesAst, Stmt};
+        use crate::interner::Interner;
+        use crate::location::Span;
+        use std::borrow::Cow;
+
+        /// Each line is printed and waits for input
+        struct Dialogue(&'static [&'static str]);
+
+        impl IntoKesAst<'static> for Dialogue {
+            fn into_kes_ast(self, interner: &mut Interner) -> Cow<'static, [Stmt]> {
+                self.0
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, line)| Stmt::Print {
+                        values: vec![Expr::new(
+                            ExprKind::String(interner.get_or_intern(line)),
+                            Span::new(0, 0),
+                        )],
+                        channel: None,
+                        newline: true,
+                        wait: true,
+                        location: Location::new(idx + 1),
+                    })
+                    .collect()
+            }
+        }
+
+        let program = Program::from_ast(Dialogue(&["안녕", "잘가"]), Interner::new());
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "안녕@#잘가@#");
+    }
+
+    #[test]
+    fn strict() {
+        use super::CompileOptions;
+        use crate::error::LexicalError;
+
+        let options = CompileOptions {
+            strict: true,
+            ..CompileOptions::default()
+        };
+
+        let err = Program::from_source_with_options("$체력 = 10;\n@$체럭;", &options).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "L2에서 선언되지 않은 `$체럭` 변수가 사용됐습니다 (혹시 `$체력`?)"
+        );
+
+        let source = "변수 $이름;\n변수 $체력 = 10;\n만약 $체력 { $공격 = 1; }\n@$이름 $공격;";
+        assert!(Program::from_source_with_options(source, &options).is_ok());
+
+        let (program, diagnostics) = Program::try_from_sources("@$1 $1 $2;", &options);
+        assert!(program.is_complete());
+        assert!(matches!(
+            diagnostics.errors.as_slice(),
+            [
+                ParseError::User {
+                    error: LexicalError::UndeclaredVariable(..)
+                },
+                ParseError::User {
+                    error: LexicalError::UndeclaredVariable(..)
+                }
+            ]
+        ));
+    }
+
+    #[test]
+    fn windows_source() {
+        let program = Program::from_source("\u{FEFF}@'안녕';\r\n@'잘가';\r\n").unwrap();
+        let strings = program.extract_strings();
+
+        assert_eq!(strings[0].location, Location::new(1));
+        assert_eq!(strings[1].text, "잘가");
+        assert_eq!(strings[1].location, Location::new(2));
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn replace_strings() {
+        let mut program = Program::from_source("@'안녕';\n@@'안녕' '잘가';").unwrap();
+        let strings = program.extract_strings();
+        assert_eq!(strings.len(), 3);
+        assert_eq!(strings[0].id, strings[1].id);
+        assert_eq!(strings[2].location.line, 2);
+
+        let mut translations = std::collections::HashMap::new();
+        translations.insert(strings[0].id.clone(), "Hello".to_string());
+        program.replace_strings(&translations);
+
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "HelloHello잘가@");
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn metadata() {
+        let program =
+            Program::from_source("---\n제목: 상점\n빌트인: 소리, 표\n---\n@'어서오세요';\n@@ 1 2;")
+                .unwrap();
+        assert_eq!(program.metadata()["제목"], "상점");
+        assert_eq!(program.metadata()["빌트인"], "소리, 표");
+        assert_eq!(program.extract_strings()[0].location, Location::new(5));
+
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "어서오세요12@");
+    }
+
     #[test]
     fn test_serde() {
         let prev = Program::from_source("만약 1 { ㅇ(1+2*3, 4); } 그외 { 123; }").unwrap();
@@ -50,4 +1051,301 @@ mod tests {
         let cur = bincode::deserialize::<Program>(&bytes).unwrap();
         assert_eq!(prev, cur);
     }
+
+    #[test]
+    fn format_version() {
+        use super::PROGRAM_FORMAT_VERSION;
+        use crate::error::ProgramFormatError;
+
+        let program = Program::from_source("@1;").unwrap();
+        let bytes = bincode::serialize(&program).unwrap();
+
+        // programs before version 1 start with interner
+        let err = bincode::deserialize::<Program>(&bytes[8..]).unwrap_err();
+        assert_eq!(err.to_string(), ProgramFormatError::Unknown.to_string());
+
+        let mut future = bytes;
+        future[4..8].copy_from_slice(&(PROGRAM_FORMAT_VERSION + 1).to_le_bytes());
+        let err = bincode::deserialize::<Program>(&future).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            ProgramFormatError::UnsupportedVersion(PROGRAM_FORMAT_VERSION + 1).to_string()
+        );
+    }
+
+    #[test]
+    fn symbol_stable_after_serde() {
+        let program = Program::from_source("$이름 = '철수';\n@$이름 ㄱ();").unwrap();
+        let names = ["이름", "철수", "ㄱ"];
+        let symbols = names.map(|name| program.symbol(name).unwrap());
+
+        let bytes = bincode::serialize(&program).unwrap();
+        let mut program = bincode::deserialize::<Program>(&bytes).unwrap();
+        program
+            .extend_from_source("$나이 = 10; @'영희' $이름 ㄴ();")
+            .unwrap();
+
+        for (name, symbol) in names.iter().zip(symbols) {
+            assert_eq!(program.resolve(symbol), Some(*name));
+            assert_eq!(program.symbol(name), Some(symbol));
+        }
+    }
+
+    #[test]
+    fn entry_points() {
+        use crate::error::LexicalError;
+
+        let mut program = Program::from_source("@1;\n종료;\n장면 숲;\n@2;\n장면 끝;").unwrap();
+        assert_eq!(
+            program.entry_points().collect::<Vec<_>>(),
+            [("숲", Location::new(3)), ("끝", Location::new(5))]
+        );
+        assert_eq!(program.entry_point("숲"), Some(3));
+        assert_eq!(
+            program.entry_point("끝"),
+            Some(program.instructions().len())
+        );
+        assert_eq!(program.entry_point("성"), None);
+
+        assert!(Program::from_source("만약 1 { 장면 숲; }").is_err());
+        assert!(matches!(
+            Program::from_source("장면 숲;\n장면 숲;"),
+            Err(ParseError::User {
+                error: LexicalError::DuplicateLabel(name, location)
+            }) if name == "숲" && location == Location::new(2)
+        ));
+        assert!(program.extend_from_source("장면 숲;").is_err());
+
+        program.extend_from_source("장면 성;\n@3;").unwrap();
+        assert_eq!(program.entry_point("성"), Some(5));
+    }
+
+    #[test]
+    fn variable_types() {
+        use crate::error::LexicalError;
+        use crate::signature::ParamType;
+
+        let mut program = Program::from_source("$체력: 수 = 1;").unwrap();
+        assert_eq!(program.variable_type("체력"), Some(ParamType::Int));
+        assert_eq!(program.variable_type("이름"), None);
+
+        assert!(matches!(
+            Program::from_source("$1 = 1;\n$1: 숫자 = 1;"),
+            Err(ParseError::User {
+                error: LexicalError::UnknownType(ty, location)
+            }) if ty == "숫자" && location == Location::new(2)
+        ));
+        assert!(matches!(
+            program.extend_from_source("$체력: 문자열 = '';"),
+            Err(ParseError::User {
+                error: LexicalError::TypeConflict(..)
+            })
+        ));
+
+        program.extend_from_source("$이름: 문자열 = '';").unwrap();
+        assert_eq!(
+            program.variable_types().collect::<Vec<_>>(),
+            [("이름", ParamType::Str), ("체력", ParamType::Int)]
+        );
+    }
+
+    #[test]
+    fn serialize_stable() {
+        let code =
+            "$이름 = '철수';\n만약 $이름 == '철수' { @@'안녕' $이름; }\n반복 ㄱ() { ㄴ(1, '2'); }";
+        let compile = || {
+            let mut program = Program::from_source(code).unwrap();
+            let translations = program
+                .extract_strings()
+                .into_iter()
+                .map(|entry| (entry.id, entry.text + "!"))
+                .collect();
+            program.replace_strings(&translations);
+            bincode::serialize(&program).unwrap()
+        };
+
+        let bytes = compile();
+        assert_eq!(bytes, compile());
+
+        let program = bincode::deserialize::<Program>(&bytes).unwrap();
+        assert_eq!(bincode::serialize(&program).unwrap(), bytes);
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn include() {
+        use crate::error::LexicalError;
+        use crate::loader::MemoryLoader;
+
+        let mut loader = MemoryLoader::new()
+            .insert(
+                "main.kes",
+                "---\n제목: 시작\n---\n@1;\n포함 'a.kes';\n@$가;",
+            )
+            .insert("a.kes", "포함 'b/c.kes';\n@2;")
+            .insert("b/c.kes", "$가 = 3;")
+            .insert("cycle.kes", "포함 'a.kes';\n포함 'cycle.kes';");
+        let program = Program::from_entry("main.kes", &mut loader).unwrap();
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "123");
+        assert_eq!(program.metadata()["제목"], "시작");
+        assert_eq!(program.files(), ["main.kes", "a.kes", "b/c.kes"]);
+        let files = (0..program.instructions().len())
+            .map(|pos| {
+                (
+                    program.file(pos).unwrap(),
+                    program.instructions()[pos].location.line,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(files.first(), Some(&("main.kes", 4)));
+        assert!(files.contains(&("b/c.kes", 1)));
+        assert!(files.contains(&("a.kes", 2)));
+        assert_eq!(files.last(), Some(&("main.kes", 6)));
+
+        let mut broken = MemoryLoader::new()
+            .insert("main.kes", "@1;\n포함 'a.kes';")
+            .insert("a.kes", "포함 'b.kes';")
+            .insert("b.kes", "@1");
+        assert!(matches!(
+            Program::from_entry("main.kes", &mut broken),
+            Err(ParseError::User {
+                error: LexicalError::InInclude(path, error, location),
+            }) if path == "a.kes" && location == Location::new(2) && matches!(
+                *error,
+                ParseError::User { error: LexicalError::InInclude(ref path, ..) } if path == "b.kes"
+            )
+        ));
+
+        assert!(matches!(
+            Program::from_entry("cycle.kes", &mut loader),
+            Err(ParseError::User {
+                error: LexicalError::IncludeCycle(path, location),
+            }) if path == "cycle.kes" && location == Location::new(2)
+        ));
+        assert!(matches!(
+            Program::from_entry("없음.kes", &mut loader),
+            Err(ParseError::User {
+                error: LexicalError::IncludeNotFound(..),
+            })
+        ));
+        // only `from_entry` has loader
+        assert!(matches!(
+            Program::from_source("포함 'a.kes';"),
+            Err(ParseError::User {
+                error: LexicalError::IncludeNotFound(path, _),
+            }) if path == "a.kes"
+        ));
+    }
+
+    #[test]
+    fn include_with_options() {
+        use super::CompileOptions;
+        use crate::error::{CompileWarning, LexicalError};
+        use crate::loader::MemoryLoader;
+        use crate::signature::{ParamType, Signature, Signatures};
+
+        let mut signatures = Signatures::new();
+        signatures.insert("함수", Signature::new([ParamType::Int]));
+        let options = CompileOptions {
+            signatures: Some(&signatures),
+            ..CompileOptions::default()
+        };
+        let mut loader = MemoryLoader::new()
+            .insert("main.kes", "@$가;\n포함 'a.kes';\n종료;\n@1;")
+            .insert("a.kes", "$가 = 1;\n함수(1, 2);\n장면 가게;");
+        let (program, warnings) =
+            Program::from_entry_with_options("main.kes", &mut loader, &options).unwrap();
+
+        assert_eq!(
+            warnings,
+            [
+                CompileWarning::UseBeforeAssign("가".into(), Location::new(1)),
+                CompileWarning::InFile(
+                    1,
+                    Box::new(CompileWarning::ArgumentCount(
+                        "함수".into(),
+                        1,
+                        2,
+                        Location::new(2)
+                    ))
+                ),
+                CompileWarning::UnreachableCode(Location::new(4)),
+            ]
+        );
+        assert_eq!(warnings[1].file(), 1);
+        assert_eq!(
+            program.entry_points().collect::<Vec<_>>(),
+            [("가게", Location::new(3))]
+        );
+        let lines = program
+            .instructions()
+            .iter()
+            .map(|inst| inst.location.line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines.first(), Some(&1));
+        assert!(lines.iter().all(|line| *line <= 4));
+
+        let options = CompileOptions {
+            strict: true,
+            ..CompileOptions::default()
+        };
+        let mut loader = MemoryLoader::new()
+            .insert("main.kes", "$가 = 1;\n\n포함 'a.kes';")
+            .insert("a.kes", "@$가;\n@$나;");
+        assert!(matches!(
+            Program::from_entry_with_options("main.kes", &mut loader, &options),
+            Err(ParseError::User {
+                error: LexicalError::InInclude(path, error, location),
+            }) if path == "a.kes" && location == Location::new(3) && matches!(
+                *error,
+                ParseError::User {
+                    error: LexicalError::UndeclaredVariable(ref name, _, Location { line: 2 })
+                } if name == "나"
+            )
+        ));
+    }
+
+    #[cfg(feature = "runtime")]
+    #[test]
+    fn assets() {
+        use super::CompileOptions;
+        use crate::error::LexicalError;
+        use std::collections::BTreeSet;
+
+        let code = "@자원('voice/001.ogg') '안녕';\n효과음(자원('se/문.ogg'));";
+        let program = Program::from_source(code).unwrap();
+        assert_eq!(
+            program.assets().collect::<Vec<_>>(),
+            [
+                ("voice/001.ogg", Location::new(1)),
+                ("se/문.ogg", Location::new(2))
+            ]
+        );
+        assert_eq!(program.extract_strings().len(), 1);
+
+        let mut builtin = RecordBuiltin::new();
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "voice/001.ogg안녕효과음");
+
+        let manifest = ["voice/001.ogg".to_string()]
+            .iter()
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        let options = CompileOptions {
+            assets: Some(&manifest),
+            ..CompileOptions::default()
+        };
+        assert!(matches!(
+            Program::from_source_with_options(code, &options),
+            Err(ParseError::User {
+                error: LexicalError::MissingAsset(path, location)
+            }) if path == "se/문.ogg" && location == Location::new(2)
+        ));
+
+        let (program, diagnostics) = Program::try_from_sources(code, &options);
+        assert!(program.is_complete());
+        assert_eq!(diagnostics.errors.len(), 1);
+    }
 }
@@ -0,0 +1,23 @@
+use std::collections::BTreeSet;
+
+/// Builtins which script is allowed to call
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sandbox {
+    builtins: BTreeSet<String>,
+}
+
+impl Sandbox {
+    /// Sandbox which doesn't allow any builtin
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn allow(mut self, name: impl Into<String>) -> Self {
+        self.builtins.insert(name.into());
+        self
+    }
+
+    pub fn is_allowed(&self, name: &str) -> bool {
+        self.builtins.contains(name)
+    }
+}
@@ -0,0 +1,357 @@
+//! Run game made of many small scripts
+//!
+//! [`Session`] owns named programs and variables shared between them.
+//! `실행('이름')` suspends current script, runs other script to the end and resumes
+//!
+//! ## Examples
+//! ```rust
+//! use kes::builtin::RecordBuiltin;
+//! use kes::session::Session;
+//!
+//! let mut session = Session::new();
+//! session.add_source("메인", "$금화 = 10; 실행('상점'); @@$금화;").unwrap();
+//! session.add_source("상점", "$금화 = $금화 - 3;").unwrap();
+//!
+//! let mut builtin = RecordBuiltin::new();
+//! futures_executor::block_on(session.run("메인", &mut builtin)).unwrap();
+//! assert_eq!(builtin.text(), "7@");
+//! ```
+
+use crate::builtin::{Builtin, Handle};
+use crate::context::Context;
+use crate::error::{Frame, ParseError, RuntimeError, RuntimeResult};
+use crate::program::Program;
+use crate::value::Value;
+use async_trait::async_trait;
+use std::collections::BTreeMap;
+
+pub use crate::program::RUN_SCRIPT;
+
+/// Default limit of nested `실행`
+pub const MAX_DEPTH: usize = 64;
+
+/// Named programs with variables shared between them
+pub struct Session {
+    programs: BTreeMap<String, Program>,
+    variables: BTreeMap<String, Value>,
+    max_depth: usize,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self {
+            programs: BTreeMap::new(),
+            variables: BTreeMap::new(),
+            max_depth: MAX_DEPTH,
+        }
+    }
+}
+
+impl Session {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit of nested `실행`, script exceeding it fails with runtime error
+    pub fn set_max_depth(&mut self, depth: usize) {
+        self.max_depth = depth;
+    }
+
+    /// Add program, returns previous program with same name
+    pub fn insert(&mut self, name: impl Into<String>, program: Program) -> Option<Program> {
+        self.programs.insert(name.into(), program)
+    }
+
+    pub fn add_source(&mut self, name: impl Into<String>, source: &str) -> Result<(), ParseError> {
+        self.insert(name, Program::from_source(source)?);
+        Ok(())
+    }
+
+    pub fn program(&self, name: &str) -> Option<&Program> {
+        self.programs.get(name)
+    }
+
+    pub fn programs(&self) -> impl Iterator<Item = (&str, &Program)> + '_ {
+        self.programs
+            .iter()
+            .map(|(name, program)| (name.as_str(), program))
+    }
+
+    /// Shared variables, updated when script ends or calls `실행`
+    pub fn variables(&self) -> &BTreeMap<String, Value> {
+        &self.variables
+    }
+
+    pub fn variable(&self, name: &str) -> Option<&Value> {
+        self.variables.get(name)
+    }
+
+    pub fn set_variable(&mut self, name: impl Into<String>, value: impl Into<Value>) {
+        self.variables.insert(name.into(), value.into());
+    }
+
+    /// Run program `name` to the end, `실행` in it runs other programs of session
+    ///
+    /// Error in nested script is [`RuntimeError::Traced`] with every script in call stack
+    pub async fn run<B: Builtin>(&mut self, name: &str, mut builtin: B) -> RuntimeResult<()> {
+        let (script, program) =
+            self.programs
+                .get_key_value(name)
+                .ok_or(RuntimeError::ExecutionError(
+                    "스크립트를 찾을수 없습니다",
+                    0,
+                ))?;
+        let mut builtin = SessionBuiltin {
+            programs: &self.programs,
+            variables: &mut self.variables,
+            inner: &mut builtin,
+            script,
+            depth: 0,
+            max_depth: self.max_depth,
+            error: None,
+        };
+
+        builtin.run_program(program).await
+    }
+}
+
+/// Builtin which handles `실행` and passes others to `inner`
+struct SessionBuiltin<'s, B: Builtin> {
+    programs: &'s BTreeMap<String, Program>,
+    variables: &'s mut BTreeMap<String, Value>,
+    inner: &'s mut B,
+    /// Name of running script
+    script: &'s str,
+    depth: usize,
+    max_depth: usize,
+    /// Error of nested script, caller stops with it after current step
+    error: Option<RuntimeError>,
+}
+
+impl<'s, B: Builtin> SessionBuiltin<'s, B> {
+    async fn run_program(&mut self, program: &Program) -> RuntimeResult<()> {
+        let mut ctx = Context::new(program);
+        for (name, value) in self.variables.iter() {
+            ctx.set_variable(name, value.clone());
+        }
+
+        loop {
+            let script = self.script;
+            let running = ctx.step(&mut *self).await.map_err(|err| {
+                let line = err.line();
+                err.with_frame(Frame {
+                    script: script.into(),
+                    line,
+                })
+            })?;
+            if let Some(err) = self.error.take() {
+                return Err(err);
+            }
+            if !running {
+                break;
+            }
+        }
+
+        self.export(&mut ctx);
+        Ok(())
+    }
+
+    fn export(&mut self, ctx: &mut Context<'_>) {
+        self.variables.extend(
+            ctx.variables_snapshot()
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+    }
+
+    async fn run_script(&mut self, ctx: &mut Context<'_>) -> RuntimeResult<()> {
+        let line = ctx.location().line;
+        let name: String = ctx.pop_into_ret()?;
+        let caller = self.script;
+        let frame = || Frame {
+            script: caller.into(),
+            line,
+        };
+        let programs = self.programs;
+        let (script, program) = programs.get_key_value(&name).ok_or_else(|| {
+            RuntimeError::ExecutionError("스크립트를 찾을수 없습니다", line).with_frame(frame())
+        })?;
+
+        if self.depth >= self.max_depth {
+            return Err(RuntimeError::StackOverflow(line).with_frame(frame()));
+        }
+
+        self.export(ctx);
+
+        let mut nested = SessionBuiltin {
+            programs,
+            variables: &mut *self.variables,
+            inner: &mut *self.inner,
+            script,
+            depth: self.depth + 1,
+            max_depth: self.max_depth,
+            error: None,
+        };
+        let result = nested.run_program(program).await;
+        result.map_err(|err| err.with_frame(frame()))?;
+
+        for (name, value) in self.variables.iter() {
+            ctx.set_variable(name, value.clone());
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<'s, B: Builtin> Builtin for SessionBuiltin<'s, B> {
+    async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value {
+        if name != RUN_SCRIPT {
+            return self.inner.run(name, ctx).await;
+        }
+
+        if let Err(err) = self.run_script(ctx).await {
+            self.error = Some(err);
+        }
+        Value::Int(0)
+    }
+    fn resolve(&mut self, name: &str) -> Option<Handle> {
+        if name == RUN_SCRIPT {
+            None
+        } else {
+            self.inner.resolve(name)
+        }
+    }
+    #[inline]
+    async fn call(&mut self, handle: Handle, name: &str, ctx: &mut Context<'_>) -> Value {
+        self.inner.call(handle, name, ctx).await
+    }
+    #[inline]
+    fn load(&mut self, name: &str) -> Option<Value> {
+        self.inner.load(name)
+    }
+    #[inline]
+    fn print(&mut self, v: Value) {
+        self.inner.print(v);
+    }
+    #[inline]
+    fn print_group(&mut self, values: Vec<Value>) {
+        self.inner.print_group(values);
+    }
+    #[inline]
+    fn print_channel(&mut self, channel: &str, values: Vec<Value>) {
+        self.inner.print_channel(channel, values);
+    }
+    #[inline]
+    fn new_line(&mut self) {
+        self.inner.new_line();
+    }
+    #[inline]
+    async fn flush(&mut self) {
+        self.inner.flush().await;
+    }
+    #[inline]
+    async fn wait(&mut self) {
+        self.inner.wait().await;
+    }
+    #[inline]
+    async fn spawn(&mut self, name: &str, ctx: &mut Context<'_>) {
+        self.inner.spawn(name, ctx).await;
+    }
+    #[inline]
+    async fn join(&mut self) {
+        self.inner.join().await;
+    }
+    #[inline]
+    async fn auto_wait(&mut self) {
+        self.inner.auto_wait().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Session;
+    use crate::builtin::RecordBuiltin;
+    use crate::error::{Frame, RuntimeError};
+    use crate::value::Value;
+    use futures_executor::block_on;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn nested_scripts() {
+        let mut session = Session::new();
+        session
+            .add_source(
+                "메인",
+                "$방문 = 0;\n@@'시작';\n실행('마을');\n@@'끝' $방문 $마을;",
+            )
+            .unwrap();
+        session
+            .add_source("마을", "$방문 = $방문 + 1; $마을 = '평화'; 실행('상점');")
+            .unwrap();
+        session
+            .add_source("상점", "@@'상점' $방문; $방문 = $방문 + 1;")
+            .unwrap();
+
+        let mut builtin = RecordBuiltin::new();
+        block_on(session.run("메인", &mut builtin)).unwrap();
+        assert_eq!(builtin.text(), "시작@상점1@끝2평화@");
+        assert_eq!(session.variable("방문"), Some(&Value::Int(2)));
+    }
+
+    #[test]
+    fn errors() {
+        let mut session = Session::new();
+        session
+            .add_source("메인", "@@1;\n실행('없음');\n@@2;")
+            .unwrap();
+        session.add_source("재귀", "실행('재귀');").unwrap();
+        session.set_max_depth(8);
+
+        let mut builtin = RecordBuiltin::new();
+        let err = block_on(session.run("메인", &mut builtin)).unwrap_err();
+        assert!(matches!(err.root(), RuntimeError::ExecutionError(_, 2)));
+        assert_eq!(builtin.text(), "1@");
+
+        let err = block_on(session.run("재귀", RecordBuiltin::new())).unwrap_err();
+        assert!(matches!(err.root(), RuntimeError::StackOverflow(1)));
+        assert_eq!(err.frames().len(), 9);
+    }
+
+    #[test]
+    fn trace() {
+        let mut session = Session::new();
+        session.add_source("메인", "@@1;\n\n실행('마을');").unwrap();
+        session
+            .add_source("마을", "$0 = 1;\n실행('상점');")
+            .unwrap();
+        session.add_source("상점", "1 - '1';").unwrap();
+
+        let err = block_on(session.run("메인", RecordBuiltin::new())).unwrap_err();
+        assert_eq!(err.line(), 1);
+        assert_eq!(
+            err.frames(),
+            [
+                Frame {
+                    script: "상점".into(),
+                    line: 1
+                },
+                Frame {
+                    script: "마을".into(),
+                    line: 2
+                },
+                Frame {
+                    script: "메인".into(),
+                    line: 3
+                },
+            ]
+        );
+        assert_eq!(
+            err.to_string(),
+            format!(
+                "{}\n    상점 1번째 줄\n    마을 2번째 줄\n    메인 3번째 줄",
+                err.root()
+            )
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use crate::value::Value;
+use ahash::AHashMap;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+/// Type of builtin parameter or variable declared with `$이름: 수 = 값;`
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ParamType {
+    #[default]
+    Any,
+    Int,
+    Str,
+    Bool,
+    List,
+}
+
+impl ParamType {
+    pub fn name(self) -> &'static str {
+        match self {
+            ParamType::Any => "any",
+            ParamType::Int => "int",
+            ParamType::Str => "str",
+            ParamType::Bool => "bool",
+            ParamType::List => "list",
+        }
+    }
+
+    /// Type written in script, `수`, `문자열`, `참거짓` or `목록`
+    pub fn from_keyword(name: &str) -> Option<Self> {
+        match name {
+            "수" => Some(ParamType::Int),
+            "문자열" => Some(ParamType::Str),
+            "참거짓" => Some(ParamType::Bool),
+            "목록" => Some(ParamType::List),
+            _ => None,
+        }
+    }
+
+    pub fn accepts(self, value: &Value) -> bool {
+        matches!(
+            (self, value),
+            (ParamType::Any, _)
+                | (ParamType::Int, Value::Int(..))
+                | (ParamType::Str, Value::Str(..))
+                | (ParamType::Bool, Value::Bool(..))
+                | (ParamType::List, Value::List(..))
+        )
+    }
+}
+
+/// Declaration of builtin function
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Signature {
+    params: Vec<ParamType>,
+    ret: ParamType,
+}
+
+impl Signature {
+    pub fn new(params: impl Into<Vec<ParamType>>) -> Self {
+        Self {
+            params: params.into(),
+            ret: ParamType::Any,
+        }
+    }
+
+    /// Declare type of returned value, checked by `Context` when return checking is enabled
+    pub fn returns(mut self, ret: ParamType) -> Self {
+        self.ret = ret;
+        self
+    }
+
+    #[inline]
+    pub fn ret(&self) -> ParamType {
+        self.ret
+    }
+
+    #[inline]
+    pub fn params(&self) -> &[ParamType] {
+        &self.params
+    }
+
+    #[inline]
+    pub fn arity(&self) -> usize {
+        self.params.len()
+    }
+
+    /// Human readable form like `함수(int, str)` or `함수(int) -> str` for signatureHelp and hover
+    pub fn label(&self, name: &str) -> String {
+        let mut out = String::with_capacity(name.len() + self.params.len() * 5 + 2);
+        out.push_str(name);
+        out.push('(');
+        for (idx, param) in self.params.iter().enumerate() {
+            if idx != 0 {
+                out.push_str(", ");
+            }
+            write!(out, "{}", param.name()).unwrap();
+        }
+        out.push(')');
+        if self.ret != ParamType::Any {
+            write!(out, " -> {}", self.ret.name()).unwrap();
+        }
+        out
+    }
+}
+
+/// Builtin signature registry
+///
+/// One declaration is shared by compiler warnings, editor tooling and runtime argument checking
+#[derive(Clone, Debug, Default)]
+pub struct Signatures {
+    signatures: AHashMap<String, Signature>,
+}
+
+impl Signatures {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, name: impl Into<String>, signature: Signature) -> &mut Self {
+        self.signatures.insert(name.into(), signature);
+        self
+    }
+
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&Signature> {
+        self.signatures.get(name)
+    }
+
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.signatures.contains_key(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Signature)> {
+        self.signatures
+            .iter()
+            .map(|(name, signature)| (name.as_str(), signature))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ParamType, Signature, Signatures};
+    use crate::value::Value;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn label() {
+        let sig = Signature::new([ParamType::Int, ParamType::Str]);
+        assert_eq!(sig.label("함수"), "함수(int, str)");
+        assert_eq!(Signature::default().label("함수"), "함수()");
+        assert_eq!(
+            Signature::new([ParamType::Int])
+                .returns(ParamType::Str)
+                .label("쉼표"),
+            "쉼표(int) -> str"
+        );
+    }
+
+    #[test]
+    fn accepts() {
+        assert!(ParamType::Any.accepts(&Value::Int(1)));
+        assert!(ParamType::Str.accepts(&Value::from("1")));
+        assert!(!ParamType::Int.accepts(&Value::from("1")));
+        assert!(ParamType::List.accepts(&Value::from(vec![Value::Int(1)])));
+    }
+
+    #[test]
+    fn keyword() {
+        assert_eq!(ParamType::from_keyword("수"), Some(ParamType::Int));
+        assert_eq!(ParamType::from_keyword("목록"), Some(ParamType::List));
+        assert_eq!(ParamType::from_keyword("숫자"), None);
+    }
+
+    #[test]
+    fn registry() {
+        let mut signatures = Signatures::new();
+        signatures.insert("함수", Signature::new([ParamType::Int]));
+        assert!(signatures.contains("함수"));
+        assert_eq!(signatures.get("함수").unwrap().arity(), 1);
+        assert!(signatures.get("없는함수").is_none());
+    }
+}
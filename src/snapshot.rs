@@ -0,0 +1,169 @@
+use crate::error::SaveError;
+use crate::history::BranchLog;
+use crate::location::Location;
+use crate::program::Program;
+use crate::value::Value;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Exported state of `Context`
+///
+/// Variables are stored with names so they don't depend on symbol of program
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub cursor: usize,
+    pub stack: Vec<Value>,
+    pub variables: BTreeMap<String, Value>,
+    pub branch_log: BranchLog,
+    /// Running `각각` loops from outermost
+    #[serde(default)]
+    pub iterations: Vec<Iteration>,
+    #[serde(default)]
+    pub runtime: RuntimeState,
+}
+
+/// State of runtime subsystems, `Context` keeps them in one place
+///
+/// Field added here is saved and restored with `Snapshot` without other changes
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeState {
+    /// State of `Context::random`, `0` keeps seed of restoring context
+    pub rng: u64,
+    /// Lines of print statements which are already executed
+    pub seen: BTreeSet<Location>,
+    /// `타이머` blocks waiting for their deadline
+    pub timers: Vec<PendingTimer>,
+    /// Positions to return after timer bodies which are running, innermost is last
+    pub timer_returns: Vec<usize>,
+}
+
+impl RuntimeState {
+    /// Deadlines of timers become time left from `now`
+    pub(crate) fn export(&self, now: u64) -> Self {
+        let mut state = self.clone();
+        for timer in state.timers.iter_mut() {
+            timer.deadline = timer.deadline.saturating_sub(now);
+        }
+        state
+    }
+
+    /// Time left of timers is counted from `now` again
+    pub(crate) fn import(mut self, now: u64) -> Self {
+        for timer in self.timers.iter_mut() {
+            timer.deadline += now;
+        }
+        self
+    }
+}
+
+/// Timer block waiting for its deadline
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PendingTimer {
+    /// Clock time to run body, time left from snapshot in `Snapshot`
+    pub deadline: u64,
+    /// Position of first instruction of body
+    pub start: usize,
+}
+
+/// Position of running `각각` loop
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Iteration {
+    /// Elements are read from value in place, it is never copied per element
+    pub value: Value,
+    /// Position of next element, see `Value::element_at`
+    pub pos: usize,
+}
+
+/// Current version of `SaveData` format
+///
+/// Version 2 moved `seen` into `RuntimeState` with RNG and timers
+pub const SAVE_FORMAT_VERSION: u32 = 2;
+
+/// `Snapshot` with information of program and format which made it
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SaveData {
+    pub version: u32,
+    /// `Program::content_hash` of program which made the snapshot
+    pub program_hash: u64,
+    pub snapshot: Snapshot,
+}
+
+impl SaveData {
+    pub fn new(program: &Program, snapshot: Snapshot) -> Self {
+        Self {
+            version: SAVE_FORMAT_VERSION,
+            program_hash: program.content_hash(),
+            snapshot,
+        }
+    }
+
+    /// `true` when save is made by same format and `program`
+    pub fn is_compatible(&self, program: &Program) -> bool {
+        self.version == SAVE_FORMAT_VERSION && self.program_hash == program.content_hash()
+    }
+
+    /// Take snapshot which can be restored on `program`
+    ///
+    /// `hook` is called with old save when it is made by other program or older format,
+    /// it can fix snapshot(e.g. move cursor) and returns `false` to reject save
+    pub fn migrate(
+        mut self,
+        program: &Program,
+        hook: impl FnOnce(&mut SaveData) -> bool,
+    ) -> Result<Snapshot, SaveError> {
+        if self.version > SAVE_FORMAT_VERSION {
+            return Err(SaveError::UnsupportedVersion(self.version));
+        }
+
+        if !self.is_compatible(program) && !hook(&mut self) {
+            return Err(SaveError::Incompatible);
+        }
+
+        Ok(self.snapshot)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SaveData, SAVE_FORMAT_VERSION};
+    use crate::error::SaveError;
+    use crate::program::Program;
+    use crate::snapshot::Snapshot;
+
+    #[test]
+    fn migrate() {
+        let old = Program::from_source("$1 = 1;\n@$1;").unwrap();
+        let new = Program::from_source("$1 = 2;\n@$1;").unwrap();
+        let snapshot = Snapshot {
+            cursor: 2,
+            ..Snapshot::default()
+        };
+
+        let save = SaveData::new(&old, snapshot.clone());
+        assert!(save.is_compatible(&old));
+        assert!(!save.is_compatible(&new));
+
+        let migrated = save.clone().migrate(&old, |_| unreachable!());
+        assert_eq!(migrated, Ok(snapshot));
+
+        let rejected = save.clone().migrate(&new, |_| false);
+        assert_eq!(rejected, Err(SaveError::Incompatible));
+
+        let migrated = save
+            .migrate(&new, |save| {
+                save.snapshot.cursor = 0;
+                true
+            })
+            .unwrap();
+        assert_eq!(migrated.cursor, 0);
+
+        let future = SaveData {
+            version: SAVE_FORMAT_VERSION + 1,
+            ..SaveData::new(&new, Snapshot::default())
+        };
+        assert_eq!(
+            future.migrate(&new, |_| true),
+            Err(SaveError::UnsupportedVersion(SAVE_FORMAT_VERSION + 1))
+        );
+    }
+}
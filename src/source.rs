@@ -0,0 +1,195 @@
+use crate::error::SourceError;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// `key: value` pairs of front matter block
+pub type Metadata = BTreeMap<String, String>;
+
+/// Script source normalized for lexing
+///
+/// UTF-8 BOM is stripped and CRLF is converted to LF
+///
+/// Front matter block at top of script is parsed into [`Metadata`]
+/// and replaced with spaces so lines of code don't move
+/// ```text
+/// ---
+/// 제목: 상점
+/// 엔진: 0.16
+/// ---
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Source<'s> {
+    text: Cow<'s, str>,
+    metadata: Metadata,
+    /// Front matter block as written, without last newline
+    header: String,
+}
+
+const HEADER_FENCE: &str = "---";
+
+/// Parse front matter at start of `text`, returns metadata and length of block
+///
+/// Block without closing fence or with line other than `key: value`, `#` comment
+/// or empty line is not front matter and left to parser
+fn front_matter(text: &str) -> Option<(Metadata, usize)> {
+    let mut lines = text.split_inclusive('\n');
+    let first = lines.next()?;
+    if first.trim_end() != HEADER_FENCE {
+        return None;
+    }
+
+    let mut metadata = Metadata::new();
+    let mut len = first.len();
+
+    for line in lines {
+        let content = line.trim();
+        if content == HEADER_FENCE {
+            return Some((metadata, len + line.trim_end_matches('\n').len()));
+        }
+        len += line.len();
+
+        if content.is_empty() || content.starts_with('#') {
+            continue;
+        }
+
+        let (key, value) = content.split_once(':')?;
+        let key = key.trim();
+        if key.is_empty() {
+            return None;
+        }
+        metadata.insert(key.into(), value.trim().into());
+    }
+
+    None
+}
+
+impl<'s> Source<'s> {
+    pub fn new(text: &'s str) -> Self {
+        let text = text.strip_prefix('\u{FEFF}').unwrap_or(text);
+
+        let text = if text.contains("\r\n") {
+            Cow::Owned(text.replace("\r\n", "\n"))
+        } else {
+            Cow::Borrowed(text)
+        };
+
+        match front_matter(&text) {
+            Some((metadata, len)) => {
+                let mut text = text.into_owned();
+                let header = text[..len].to_string();
+                // keep newlines and byte offsets for locations
+                let blank: String = header
+                    .bytes()
+                    .map(|b| if b == b'\n' { '\n' } else { ' ' })
+                    .collect();
+                text.replace_range(..len, &blank);
+
+                Self {
+                    text: Cow::Owned(text),
+                    metadata,
+                    header,
+                }
+            }
+            None => Self {
+                text,
+                metadata: Metadata::new(),
+                header: String::new(),
+            },
+        }
+    }
+
+    pub fn from_bytes(bytes: &'s [u8]) -> Result<Self, SourceError> {
+        std::str::from_utf8(bytes)
+            .map(Self::new)
+            .map_err(|err| SourceError::InvalidUtf8(err.valid_up_to()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    pub fn into_string(self) -> String {
+        self.text.into_owned()
+    }
+
+    #[inline]
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    /// Front matter block as written, empty when there isn't
+    #[inline]
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+}
+
+impl Source<'static> {
+    pub fn read(path: impl AsRef<Path>) -> Result<Self, SourceError> {
+        let bytes = std::fs::read(path)?;
+        let Source {
+            text,
+            metadata,
+            header,
+        } = Source::from_bytes(&bytes)?;
+
+        Ok(Self {
+            text: Cow::Owned(text.into_owned()),
+            metadata,
+            header,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+    use crate::error::SourceError;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn strip_bom() {
+        assert_eq!(Source::new("\u{FEFF}@1;").as_str(), "@1;");
+        assert_eq!(
+            Source::from_bytes(b"\xEF\xBB\xBF@1;").unwrap().as_str(),
+            "@1;"
+        );
+    }
+
+    #[test]
+    fn normalize_crlf() {
+        assert_eq!(Source::new("@1;\r\n@2;\r\n").as_str(), "@1;\n@2;\n");
+    }
+
+    #[test]
+    fn front_matter() {
+        let source = Source::new("---\r\n제목: 상점\r\n# 주석\r\n엔진: >=0.16\r\n---\r\n@1;");
+        assert_eq!(
+            source.as_str(),
+            "   \n              \n        \n              \n   \n@1;"
+        );
+        assert_eq!(
+            source.header(),
+            "---\n제목: 상점\n# 주석\n엔진: >=0.16\n---"
+        );
+        assert_eq!(source.metadata()["제목"], "상점");
+        assert_eq!(source.metadata()["엔진"], ">=0.16");
+        assert_eq!(source.metadata().len(), 2);
+
+        // not front matter
+        for code in ["---\n제목: 상점\n@1;", "---\n@1;\n---\n", "@1;\n---\n---\n"] {
+            let source = Source::new(code);
+            assert_eq!(source.as_str(), code);
+            assert!(source.metadata().is_empty());
+        }
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        assert!(matches!(
+            Source::from_bytes(b"@'\xED\x95\x9C\xFF';"),
+            Err(SourceError::InvalidUtf8(5))
+        ));
+    }
+}
@@ -0,0 +1,440 @@
+use crate::builtin::{Builtin, Handle};
+use crate::context::Context;
+use crate::signature::{ParamType, Signature, Signatures};
+use crate::table::{Table, TableSource};
+use crate::value::Value;
+use async_trait::async_trait;
+use std::collections::{BTreeMap, HashMap};
+
+/// Persistent key-value storage for `저장` and `불러오기`, host decides backing store
+pub trait Storage: Send {
+    fn get(&mut self, key: &str) -> Option<Value>;
+    fn set(&mut self, key: &str, value: Value);
+    fn delete(&mut self, key: &str);
+}
+
+/// `Storage` which keeps values in memory
+#[derive(Clone, Debug, Default)]
+pub struct MemoryStorage(BTreeMap<String, Value>);
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn values(&self) -> &BTreeMap<String, Value> {
+        &self.0
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn get(&mut self, key: &str) -> Option<Value> {
+        self.0.get(key).cloned()
+    }
+
+    fn set(&mut self, key: &str, value: Value) {
+        self.0.insert(key.into(), value);
+    }
+
+    fn delete(&mut self, key: &str) {
+        self.0.remove(key);
+    }
+}
+
+/// `Builtin` wrapper providing standard builtins, other names are passed to inner builtin
+///
+/// Arguments of wrong type are treated as default value, use `StdBuiltin::signatures` to check them
+pub struct StdBuiltin<B: Builtin> {
+    inner: B,
+    storage: Option<Box<dyn Storage>>,
+    tables: Option<Box<dyn TableSource>>,
+    /// Parsed tables by path, `None` when file can't be read or parsed
+    loaded: HashMap<String, Option<Table>>,
+}
+
+impl<B: Builtin> StdBuiltin<B> {
+    pub fn new(inner: B) -> Self {
+        Self {
+            inner,
+            storage: None,
+            tables: None,
+            loaded: HashMap::new(),
+        }
+    }
+
+    /// Enable `저장` and `불러오기` with `storage`
+    pub fn with_storage(mut self, storage: impl Storage + 'static) -> Self {
+        self.storage = Some(Box::new(storage));
+        self
+    }
+
+    /// Enable `표` and `표크기` with files from `source`
+    ///
+    /// Missing or broken tables give `없음` and size `0`, use `Table::from_file_text` to check them before running
+    pub fn with_tables(mut self, source: impl TableSource + 'static) -> Self {
+        self.tables = Some(Box::new(source));
+        self
+    }
+
+    fn table(&mut self, path: String) -> Option<&Table> {
+        let tables = self.tables.as_mut()?;
+        self.loaded
+            .entry(path)
+            .or_insert_with_key(|path| {
+                let text = tables.read(path)?;
+                Table::from_file_text(path, &text).ok()
+            })
+            .as_ref()
+    }
+
+    #[inline]
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    #[inline]
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+
+    /// Signatures of standard builtins
+    pub fn signatures() -> Signatures {
+        let mut signatures = Signatures::new();
+        signatures
+            .insert(
+                "쉼표",
+                Signature::new([ParamType::Int]).returns(ParamType::Str),
+            )
+            .insert(
+                "한글수",
+                Signature::new([ParamType::Int]).returns(ParamType::Str),
+            )
+            .insert("저장", Signature::new([ParamType::Str, ParamType::Any]))
+            .insert("불러오기", Signature::new([ParamType::Str]))
+            .insert(
+                "표",
+                Signature::new([ParamType::Str, ParamType::Any, ParamType::Str]),
+            )
+            .insert(
+                "표크기",
+                Signature::new([ParamType::Str]).returns(ParamType::Int),
+            )
+            .insert("설정", Signature::new([ParamType::Str]))
+            .insert("알림", Signature::new([ParamType::Str, ParamType::Any]))
+            .insert("추적", Signature::new([ParamType::Any]));
+        signatures
+    }
+}
+
+/// Format number with digit grouping like `1,234,567`
+pub fn group_digits(num: u32) -> String {
+    let digits = num.to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (idx, ch) in digits.chars().enumerate() {
+        if idx != 0 && (digits.len() - idx) % 3 == 0 {
+            out.push(',');
+        }
+        out.push(ch);
+    }
+
+    out
+}
+
+/// Format number in Korean, native numerals under 100 and Sino-Korean numerals for others
+pub fn korean_number(num: u32) -> String {
+    const ONES: [&str; 10] = [
+        "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+    ];
+    const TENS: [&str; 10] = [
+        "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+    ];
+
+    if num == 0 {
+        return "영".into();
+    }
+
+    if num < 100 {
+        let mut out = String::from(TENS[num as usize / 10]);
+        out.push_str(ONES[num as usize % 10]);
+        return out;
+    }
+
+    sino_korean_number(num)
+}
+
+fn sino_korean_number(mut num: u32) -> String {
+    const DIGITS: [&str; 10] = ["", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
+    const UNITS: [&str; 4] = ["", "십", "백", "천"];
+    const GROUPS: [&str; 3] = ["", "만", "억"];
+
+    let mut groups = Vec::with_capacity(3);
+
+    while num != 0 {
+        groups.push(num % 10000);
+        num /= 10000;
+    }
+
+    let mut out = String::new();
+
+    for (group_idx, group) in groups.iter().enumerate().rev() {
+        if *group == 0 {
+            continue;
+        }
+
+        for unit in (0..4).rev() {
+            let digit = (*group / 10u32.pow(unit as u32) % 10) as usize;
+            if digit == 0 {
+                continue;
+            }
+            // 일 is omitted before 십, 백, 천 and single 만
+            if digit != 1 || (unit == 0 && (group_idx != 1 || *group != 1)) {
+                out.push_str(DIGITS[digit]);
+            }
+            out.push_str(UNITS[unit]);
+        }
+
+        out.push_str(GROUPS[group_idx]);
+    }
+
+    out
+}
+
+#[async_trait]
+impl<B: Builtin> Builtin for StdBuiltin<B> {
+    async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value {
+        match name {
+            "쉼표" => group_digits(ctx.pop_into_ret().unwrap_or_default()).into(),
+            "한글수" => korean_number(ctx.pop_into_ret().unwrap_or_default()).into(),
+            "알림" => {
+                let value = ctx.pop_ret().unwrap_or(Value::Int(0));
+                let name: String = ctx.pop_into_ret().unwrap_or_default();
+                ctx.post_message(name, value);
+                Value::Null
+            }
+            "설정" => {
+                let key: String = ctx.pop_into_ret().unwrap_or_default();
+                ctx.config(&key).cloned().unwrap_or(Value::Null)
+            }
+            "추적" => {
+                let tracing = ctx.pop_ret().is_ok_and(|v| v.into_bool());
+                ctx.set_tracing(tracing);
+                Value::Null
+            }
+            "저장" if self.storage.is_some() => {
+                let value = ctx.pop_ret().unwrap_or(Value::Int(0));
+                let key: String = ctx.pop_into_ret().unwrap_or_default();
+                self.storage.as_mut().unwrap().set(&key, value);
+                Value::Null
+            }
+            "불러오기" if self.storage.is_some() => {
+                let key: String = ctx.pop_into_ret().unwrap_or_default();
+                self.storage
+                    .as_mut()
+                    .unwrap()
+                    .get(&key)
+                    .unwrap_or(Value::Null)
+            }
+            "표" if self.tables.is_some() => {
+                let column: String = ctx.pop_into_ret().unwrap_or_default();
+                let key = ctx.pop_ret().unwrap_or(Value::Int(0));
+                let path: String = ctx.pop_into_ret().unwrap_or_default();
+                self.table(path)
+                    .and_then(|table| table.get(&key, &column))
+                    .cloned()
+                    .unwrap_or(Value::Null)
+            }
+            "표크기" if self.tables.is_some() => {
+                let path: String = ctx.pop_into_ret().unwrap_or_default();
+                Value::Int(self.table(path).map_or(0, |table| table.len() as u32))
+            }
+            _ => self.inner.run(name, ctx).await,
+        }
+    }
+    /// Standard builtins are matched by name, others are resolved by inner builtin
+    fn resolve(&mut self, name: &str) -> Option<Handle> {
+        if Self::signatures().contains(name) {
+            None
+        } else {
+            self.inner.resolve(name)
+        }
+    }
+    #[inline]
+    async fn call(&mut self, handle: Handle, name: &str, ctx: &mut Context<'_>) -> Value {
+        self.inner.call(handle, name, ctx).await
+    }
+    #[inline]
+    fn load(&mut self, name: &str) -> Option<Value> {
+        self.inner.load(name)
+    }
+    #[inline]
+    fn print(&mut self, v: Value) {
+        self.inner.print(v);
+    }
+    #[inline]
+    fn print_group(&mut self, values: Vec<Value>) {
+        self.inner.print_group(values);
+    }
+    #[inline]
+    fn print_channel(&mut self, channel: &str, values: Vec<Value>) {
+        self.inner.print_channel(channel, values);
+    }
+    #[inline]
+    fn new_line(&mut self) {
+        self.inner.new_line();
+    }
+    #[inline]
+    async fn flush(&mut self) {
+        self.inner.flush().await;
+    }
+    #[inline]
+    async fn wait(&mut self) {
+        self.inner.wait().await;
+    }
+    #[inline]
+    async fn auto_wait(&mut self) {
+        self.inner.auto_wait().await;
+    }
+    #[inline]
+    async fn spawn(&mut self, name: &str, ctx: &mut Context<'_>) {
+        self.inner.spawn(name, ctx).await;
+    }
+    #[inline]
+    async fn join(&mut self) {
+        self.inner.join().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{group_digits, korean_number, MemoryStorage, StdBuiltin};
+    use crate::builtin::RecordBuiltin;
+    use crate::context::Context;
+    use crate::program::Program;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn grouping() {
+        assert_eq!(group_digits(0), "0");
+        assert_eq!(group_digits(999), "999");
+        assert_eq!(group_digits(1000), "1,000");
+        assert_eq!(group_digits(1234567), "1,234,567");
+    }
+
+    #[test]
+    fn korean() {
+        assert_eq!(korean_number(0), "영");
+        assert_eq!(korean_number(3), "셋");
+        assert_eq!(korean_number(10), "열");
+        assert_eq!(korean_number(42), "마흔둘");
+        assert_eq!(korean_number(100), "백");
+        assert_eq!(korean_number(111), "백십일");
+        assert_eq!(korean_number(10000), "만");
+        assert_eq!(korean_number(12345), "만이천삼백사십오");
+        assert_eq!(korean_number(210001), "이십일만일");
+        assert_eq!(korean_number(100000000), "일억");
+    }
+
+    #[test]
+    fn builtin() {
+        let program = Program::from_source("@쉼표(1234567) 한글수(3) 기타();").unwrap();
+        let mut builtin = StdBuiltin::new(RecordBuiltin::new());
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.inner().text(), "기타1,234,567셋0");
+    }
+
+    #[test]
+    fn message() {
+        use crate::context::Message;
+        use crate::location::Location;
+        use crate::value::Value;
+
+        struct Drain(StdBuiltin<RecordBuiltin>, Vec<Message>);
+
+        #[crate::async_trait]
+        impl crate::builtin::Builtin for Drain {
+            async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value {
+                let ret = self.0.run(name, ctx).await;
+                self.1.extend(ctx.take_messages());
+                ret
+            }
+            fn print(&mut self, _v: Value) {}
+            fn new_line(&mut self) {}
+            async fn wait(&mut self) {}
+        }
+
+        let program = Program::from_source("알림('흔들기', 3);\n알림('소리', 'bgm');").unwrap();
+        let mut builtin = Drain(StdBuiltin::new(RecordBuiltin::new()), Vec::new());
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(
+            builtin.1,
+            [
+                Message {
+                    name: "흔들기".into(),
+                    value: Value::Int(3),
+                    location: Location::new(1),
+                },
+                Message {
+                    name: "소리".into(),
+                    value: Value::from("bgm"),
+                    location: Location::new(2),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn storage() {
+        let program =
+            Program::from_source("저장('플래그', 1 + 2); @불러오기('플래그') 불러오기('없음');")
+                .unwrap();
+        let mut builtin = StdBuiltin::new(RecordBuiltin::new()).with_storage(MemoryStorage::new());
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.inner().text(), "3없음");
+    }
+
+    #[test]
+    fn config() {
+        let program =
+            Program::from_source("만약 설정('언어') == 'ko' { @설정('난이도'); } @설정('없음');")
+                .unwrap();
+        let mut builtin = StdBuiltin::new(RecordBuiltin::new());
+        let ctx = Context::builder(&program)
+            .config("언어", "ko")
+            .config("난이도", 2)
+            .build();
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        assert_eq!(builtin.inner().text(), "2없음");
+    }
+
+    #[test]
+    fn tables() {
+        use crate::table::MemoryTables;
+
+        let program = Program::from_source(
+            "@표('items.csv', '검', '가격') 표크기('items.csv') 표('items.csv', '활', '가격') 표('없음.csv', '검', '가격');",
+        )
+        .unwrap();
+        let tables = MemoryTables::new().insert("items.csv", "이름,가격\n검,100\n방패,50");
+        let mut builtin = StdBuiltin::new(RecordBuiltin::new()).with_tables(tables);
+        futures_executor::block_on(Context::new(&program).run(&mut builtin)).unwrap();
+        assert_eq!(builtin.inner().text(), "1002없음없음");
+    }
+
+    #[test]
+    fn tracing() {
+        let program = Program::from_source("추적(0);\n@1;\n추적(1);\n@'가';").unwrap();
+        let mut builtin = StdBuiltin::new(RecordBuiltin::new());
+        let mut out = Vec::new();
+        let mut ctx = Context::new(&program);
+        ctx.set_trace_writer(&mut out);
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "line L1\ncall 추적(0) L1\nreturn 추적 = 없음 L3\nline L3\nline L4\nprint '가' L4\n"
+        );
+        assert_eq!(builtin.inner().text(), "1가");
+    }
+}
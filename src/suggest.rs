@@ -0,0 +1,56 @@
+/// Edit distance between `a` and `b` counted by chars
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut cur = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+
+    prev[b.len()]
+}
+
+/// Find most similar name with `name` in `candidates` for "혹시 ...?" suggestions
+///
+/// Names which are too different are ignored, ties are broken by name order
+pub(crate) fn closest<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let limit = (name.chars().count() / 3).max(1);
+
+    candidates
+        .into_iter()
+        .filter(|candidate| *candidate != name)
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{closest, edit_distance};
+
+    #[test]
+    fn distance() {
+        assert_eq!(edit_distance("체력", "체럭"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn closest_name() {
+        let names = ["체력", "최대체력", "기력"];
+        assert_eq!(closest("체럭", names.iter().copied()), Some("체력"));
+        assert_eq!(closest("최대체럭", names.iter().copied()), Some("최대체력"));
+        assert_eq!(closest("마나", names.iter().copied()), None);
+        assert_eq!(closest("체력", ["체력"].iter().copied()), None);
+    }
+}
@@ -0,0 +1,269 @@
+use crate::error::TableError;
+use crate::value::Value;
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+/// Source of table files for `표`, host decides which files script can read
+pub trait TableSource: Send {
+    /// Text of table file, `None` when file doesn't exist or isn't allowed
+    fn read(&mut self, path: &str) -> Option<String>;
+}
+
+/// `TableSource` which keeps files in memory
+#[derive(Clone, Debug, Default)]
+pub struct MemoryTables(BTreeMap<String, String>);
+
+impl MemoryTables {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(mut self, path: impl Into<String>, text: impl Into<String>) -> Self {
+        self.0.insert(path.into(), text.into());
+        self
+    }
+}
+
+impl TableSource for MemoryTables {
+    fn read(&mut self, path: &str) -> Option<String> {
+        self.0.get(path).cloned()
+    }
+}
+
+/// `TableSource` which reads files under `root`, paths escaping `root` are rejected
+#[derive(Clone, Debug)]
+pub struct DirTables {
+    root: PathBuf,
+}
+
+impl DirTables {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+}
+
+impl TableSource for DirTables {
+    fn read(&mut self, path: &str) -> Option<String> {
+        let path = Path::new(path);
+        if !path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+        {
+            return None;
+        }
+
+        std::fs::read_to_string(self.root.join(path)).ok()
+    }
+}
+
+/// Rows of table keyed by value of first column
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Table {
+    rows: BTreeMap<Value, BTreeMap<String, Value>>,
+}
+
+/// Numbers become `Value::Int`, others `Value::Str`
+fn cell(text: &str) -> Value {
+    match text.parse::<u32>() {
+        Ok(num) => Value::Int(num),
+        Err(_) => text.into(),
+    }
+}
+
+/// Fields of CSV record starting at `line`, quoted fields can contain newlines
+fn csv_record(
+    lines: &mut std::iter::Peekable<std::str::Lines<'_>>,
+    line: &mut usize,
+) -> Result<Vec<String>, TableError> {
+    let start = *line;
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut text = lines.next().unwrap_or_default().chars().peekable();
+
+    loop {
+        match text.next() {
+            Some('"') if quoted => {
+                if text.peek() == Some(&'"') {
+                    text.next();
+                    field.push('"');
+                } else {
+                    quoted = false;
+                }
+            }
+            Some('"') if field.is_empty() => quoted = true,
+            Some(',') if !quoted => fields.push(std::mem::take(&mut field)),
+            Some(c) => field.push(c),
+            None if quoted => match lines.next() {
+                Some(next) => {
+                    *line += 1;
+                    field.push('\n');
+                    text = next.chars().peekable();
+                }
+                None => return Err(TableError::Csv("닫히지 않은 따옴표", start)),
+            },
+            None => break,
+        }
+    }
+
+    fields.push(field);
+    *line += 1;
+    Ok(fields)
+}
+
+impl Table {
+    /// First row is header, first column is key of row
+    pub fn from_csv(text: &str) -> Result<Self, TableError> {
+        let mut lines = text.lines().peekable();
+        let mut line = 1;
+        let header = csv_record(&mut lines, &mut line)?;
+        let mut rows = BTreeMap::new();
+
+        while lines.peek().is_some() {
+            let start = line;
+            let record = csv_record(&mut lines, &mut line)?;
+            if record.len() == 1 && record[0].is_empty() {
+                continue;
+            }
+            if record.len() != header.len() {
+                return Err(TableError::Csv("열 개수가 헤더와 다름", start));
+            }
+
+            let key = cell(&record[0]);
+            let cells = header
+                .iter()
+                .cloned()
+                .zip(record.iter().map(|text| cell(text)))
+                .collect();
+            rows.insert(key, cells);
+        }
+
+        Ok(Self { rows })
+    }
+
+    /// Object of row objects keyed by row key, or array of row objects keyed by index
+    #[cfg(feature = "json-tables")]
+    pub fn from_json(text: &str) -> Result<Self, TableError> {
+        use serde_json::Value as Json;
+
+        fn json_value(json: Json) -> Value {
+            match json {
+                Json::Bool(b) => b.into(),
+                Json::Number(num) => match num.as_u64() {
+                    Some(num) if num <= u32::MAX as u64 => Value::Int(num as u32),
+                    _ => num.to_string().into(),
+                },
+                Json::String(text) => text.into(),
+                Json::Null => Value::Null,
+                other => other.to_string().into(),
+            }
+        }
+
+        fn json_row(json: Json) -> Result<BTreeMap<String, Value>, TableError> {
+            match json {
+                Json::Object(cells) => Ok(cells
+                    .into_iter()
+                    .map(|(column, value)| (column, json_value(value)))
+                    .collect()),
+                _ => Err(TableError::Json("행은 객체여야 합니다".into())),
+            }
+        }
+
+        let rows =
+            match serde_json::from_str(text).map_err(|err| TableError::Json(err.to_string()))? {
+                Json::Object(rows) => rows
+                    .into_iter()
+                    .map(|(key, row)| Ok((cell(&key), json_row(row)?)))
+                    .collect::<Result<_, _>>()?,
+                Json::Array(rows) => rows
+                    .into_iter()
+                    .enumerate()
+                    .map(|(idx, row)| Ok((Value::Int(idx as u32), json_row(row)?)))
+                    .collect::<Result<_, _>>()?,
+                _ => return Err(TableError::Json("표는 객체나 배열이어야 합니다".into())),
+            };
+
+        Ok(Self { rows })
+    }
+
+    /// Parse by extension of `path`
+    pub fn from_file_text(path: &str, text: &str) -> Result<Self, TableError> {
+        match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some("csv") => Self::from_csv(text),
+            #[cfg(feature = "json-tables")]
+            Some("json") => Self::from_json(text),
+            _ => Err(TableError::UnsupportedFormat(path.into())),
+        }
+    }
+
+    pub fn get(&self, key: &Value, column: &str) -> Option<&Value> {
+        self.rows.get(key)?.get(column)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DirTables, Table, TableSource};
+    use crate::error::TableError;
+    use crate::value::Value;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn csv() {
+        let table = Table::from_csv(
+            "이름,가격,설명\n검,100,\"날카로운, \"\"전설의\"\" 검\"\n방패,50,\"여러\n줄\"\n",
+        )
+        .unwrap();
+        assert_eq!(table.len(), 2);
+        assert_eq!(
+            table.get(&Value::from("검"), "가격"),
+            Some(&Value::Int(100))
+        );
+        assert_eq!(
+            table.get(&Value::from("검"), "설명"),
+            Some(&Value::from("날카로운, \"전설의\" 검"))
+        );
+        assert_eq!(
+            table.get(&Value::from("방패"), "설명"),
+            Some(&Value::from("여러\n줄"))
+        );
+        assert_eq!(table.get(&Value::from("활"), "가격"), None);
+
+        assert_eq!(
+            Table::from_csv("a,b\n1,2\n3\n").unwrap_err(),
+            TableError::Csv("열 개수가 헤더와 다름", 3)
+        );
+    }
+
+    #[cfg(feature = "json-tables")]
+    #[test]
+    fn json() {
+        let table = Table::from_json(r#"{"검": {"가격": 100, "희귀": true}}"#).unwrap();
+        assert_eq!(
+            table.get(&Value::from("검"), "희귀"),
+            Some(&Value::Bool(true))
+        );
+
+        let table = Table::from_json(r#"[{"이름": "검"}, {"이름": "방패"}]"#).unwrap();
+        assert_eq!(
+            table.get(&Value::Int(1), "이름"),
+            Some(&Value::from("방패"))
+        );
+    }
+
+    #[test]
+    fn dir_rejects_escape() {
+        let mut tables = DirTables::new(env!("CARGO_MANIFEST_DIR"));
+        assert!(tables.read("Cargo.toml").is_some());
+        assert_eq!(tables.read("../Cargo.toml"), None);
+        assert_eq!(tables.read("/etc/passwd"), None);
+    }
+}
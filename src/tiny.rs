@@ -0,0 +1,853 @@
+//! Small synchronous runtime for embedding in size-constrained platforms
+//!
+//! With `default-features = false, features = ["tiny-runtime"]` only this module and `Value` are built,
+//! parser, formatter, serde and async-trait are left out.
+//! Programs are compiled by full build and shipped as image made by [`encode`]
+//!
+//! Builtins are called synchronously, so timers, `SpawnBuiltin`, `Join` and channel prints are rejected by [`encode`].
+//! Translator, tracer, journal and snapshots are not supported
+//!
+//! ## Examples
+//! ```rust
+//! # #[cfg(feature = "compiler")] {
+//! use kes::program::Program;
+//! use kes::tiny::{encode, Host, Image, Vm};
+//! use kes::value::Value;
+//!
+//! struct Print(String);
+//!
+//! impl Host for Print {
+//!     fn call(&mut self, _name: &str, _vm: &mut Vm<'_>) -> Value {
+//!         Value::Null
+//!     }
+//!     fn print(&mut self, value: Value) {
+//!         self.0 += &value.to_string();
+//!     }
+//!     fn new_line(&mut self) {}
+//!     fn wait(&mut self) {}
+//! }
+//!
+//! let bytes = encode(&Program::from_source("$1 = 1 + 2; @$1;").unwrap()).unwrap();
+//! let image = Image::decode(&bytes).unwrap();
+//! let mut host = Print(String::new());
+//! Vm::new(&image).run(&mut host).unwrap();
+//! assert_eq!(host.0, "3");
+//! # }
+//! ```
+
+use crate::value::Value;
+use std::convert::TryInto;
+use std::fmt::Write;
+use thiserror::Error;
+
+const MAGIC: &[u8; 4] = b"KEST";
+const VERSION: u8 = 1;
+
+#[derive(Clone, Debug, Error, Eq, PartialEq)]
+pub enum Error {
+    #[error("잘못된 바이트코드입니다: {0}")]
+    Invalid(&'static str),
+    #[error("`{0}` 명령어는 tiny-runtime에서 지원하지 않습니다")]
+    Unsupported(&'static str),
+    #[error("{1}번째 줄 실행중 에러발생 {0}")]
+    Execution(&'static str, usize),
+    #[error("{1}번째 줄 실행중 잘못된 `{0}` 타입이 들어왔습니다")]
+    Type(&'static str, usize),
+    #[error("{1}번째 줄 실행중 `${0}` 변수를 찾을수 없습니다")]
+    UndefinedVariable(String, usize),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Instruction of image, binary operators have their own opcodes
+///
+/// Symbols are indices of `Image::symbols` and targets are indices of `Image::ops`
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Op {
+    Nop,
+    Exit,
+    Pop,
+    Duplicate,
+    LoadInt(u32),
+    LoadStr(u32),
+    LoadVar(u32),
+    StoreVar(u32),
+    CallBuiltin(u32),
+    Print { newline: bool, wait: bool },
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    And,
+    Or,
+    Xor,
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Not,
+    Conditional,
+    Goto(u32),
+    GotoIfNot(u32),
+    JumpTable { min: u32, len: u32 },
+    BuildList(u32),
+    LoadIndex,
+    StoreIndex { var: u32, depth: u32 },
+    IterStart,
+    IterNext(u32),
+    ConcatN(u32),
+    LoadBool(bool),
+    LoadNull,
+    Shl,
+    Shr,
+}
+
+impl Op {
+    /// Opcode and operands, opcodes are only appended so old images stay readable
+    fn encode(self) -> (u8, [u32; 2]) {
+        match self {
+            Op::Nop => (0, [0, 0]),
+            Op::Exit => (1, [0, 0]),
+            Op::Pop => (2, [0, 0]),
+            Op::Duplicate => (3, [0, 0]),
+            Op::LoadInt(num) => (4, [num, 0]),
+            Op::LoadStr(sym) => (5, [sym, 0]),
+            Op::LoadVar(sym) => (6, [sym, 0]),
+            Op::StoreVar(sym) => (7, [sym, 0]),
+            Op::CallBuiltin(sym) => (8, [sym, 0]),
+            Op::Print { newline, wait } => (9, [newline as u32, wait as u32]),
+            Op::Add => (10, [0, 0]),
+            Op::Sub => (11, [0, 0]),
+            Op::Mul => (12, [0, 0]),
+            Op::Div => (13, [0, 0]),
+            Op::Rem => (14, [0, 0]),
+            Op::And => (15, [0, 0]),
+            Op::Or => (16, [0, 0]),
+            Op::Xor => (17, [0, 0]),
+            Op::Equal => (18, [0, 0]),
+            Op::NotEqual => (19, [0, 0]),
+            Op::Less => (20, [0, 0]),
+            Op::LessOrEqual => (21, [0, 0]),
+            Op::Greater => (22, [0, 0]),
+            Op::GreaterOrEqual => (23, [0, 0]),
+            Op::Not => (24, [0, 0]),
+            Op::Conditional => (25, [0, 0]),
+            Op::Goto(pos) => (26, [pos, 0]),
+            Op::GotoIfNot(pos) => (27, [pos, 0]),
+            Op::JumpTable { min, len } => (28, [min, len]),
+            Op::BuildList(count) => (29, [count, 0]),
+            Op::LoadIndex => (30, [0, 0]),
+            Op::StoreIndex { var, depth } => (31, [var, depth]),
+            Op::IterStart => (32, [0, 0]),
+            Op::IterNext(end) => (33, [end, 0]),
+            Op::ConcatN(count) => (34, [count, 0]),
+            Op::LoadBool(value) => (35, [value as u32, 0]),
+            Op::LoadNull => (36, [0, 0]),
+            Op::Shl => (37, [0, 0]),
+            Op::Shr => (38, [0, 0]),
+        }
+    }
+
+    fn decode(code: u8, [a, b]: [u32; 2]) -> Option<Self> {
+        Some(match code {
+            0 => Op::Nop,
+            1 => Op::Exit,
+            2 => Op::Pop,
+            3 => Op::Duplicate,
+            4 => Op::LoadInt(a),
+            5 => Op::LoadStr(a),
+            6 => Op::LoadVar(a),
+            7 => Op::StoreVar(a),
+            8 => Op::CallBuiltin(a),
+            9 => Op::Print {
+                newline: a != 0,
+                wait: b != 0,
+            },
+            10 => Op::Add,
+            11 => Op::Sub,
+            12 => Op::Mul,
+            13 => Op::Div,
+            14 => Op::Rem,
+            15 => Op::And,
+            16 => Op::Or,
+            17 => Op::Xor,
+            18 => Op::Equal,
+            19 => Op::NotEqual,
+            20 => Op::Less,
+            21 => Op::LessOrEqual,
+            22 => Op::Greater,
+            23 => Op::GreaterOrEqual,
+            24 => Op::Not,
+            25 => Op::Conditional,
+            26 => Op::Goto(a),
+            27 => Op::GotoIfNot(a),
+            28 => Op::JumpTable { min: a, len: b },
+            29 => Op::BuildList(a),
+            30 => Op::LoadIndex,
+            31 => Op::StoreIndex { var: a, depth: b },
+            32 => Op::IterStart,
+            33 => Op::IterNext(a),
+            34 => Op::ConcatN(a),
+            35 => Op::LoadBool(a != 0),
+            36 => Op::LoadNull,
+            37 => Op::Shl,
+            38 => Op::Shr,
+            _ => return None,
+        })
+    }
+
+    fn symbol(self) -> Option<u32> {
+        match self {
+            Op::LoadStr(sym)
+            | Op::LoadVar(sym)
+            | Op::StoreVar(sym)
+            | Op::CallBuiltin(sym)
+            | Op::StoreIndex { var: sym, .. } => Some(sym),
+            _ => None,
+        }
+    }
+
+    fn target(self) -> Option<u32> {
+        match self {
+            Op::Goto(pos) | Op::GotoIfNot(pos) | Op::IterNext(pos) => Some(pos),
+            _ => None,
+        }
+    }
+}
+
+/// Encode compiled program as image for `Image::decode`
+///
+/// Layout is `KEST`, version byte, symbols as length prefixed UTF-8
+/// and instructions as line, opcode and two operands, every number is little endian `u32`
+#[cfg(feature = "compiler")]
+pub fn encode(program: &crate::program::Program) -> Result<Vec<u8>> {
+    use crate::instruction::Instruction;
+    use crate::operator::{BinaryOperator, TernaryOperator, UnaryOperator};
+    use std::collections::HashMap;
+
+    crate::bytecode::verify(program).map_err(|_| Error::Invalid("검증되지 않은 프로그램입니다"))?;
+
+    let mut symbols = Vec::new();
+    let mut indices = HashMap::new();
+    let mut symbol = |sym| -> Result<u32> {
+        let name = program
+            .resolve(sym)
+            .ok_or(Error::Invalid("없는 심볼입니다"))?;
+        Ok(*indices.entry(name).or_insert_with(|| {
+            symbols.push(name);
+            symbols.len() as u32 - 1
+        }))
+    };
+
+    let mut ops = Vec::with_capacity(program.instructions().len());
+    for inst in program.instructions() {
+        let op = match inst.inst {
+            Instruction::Nop => Op::Nop,
+            Instruction::Exit => Op::Exit,
+            Instruction::Pop => Op::Pop,
+            Instruction::Duplicate => Op::Duplicate,
+            Instruction::LoadInt(num) => Op::LoadInt(num),
+            Instruction::LoadStr(sym) | Instruction::LoadAsset(sym) => Op::LoadStr(symbol(sym)?),
+            Instruction::LoadVar(sym) => Op::LoadVar(symbol(sym)?),
+            Instruction::StoreVar(sym) => Op::StoreVar(symbol(sym)?),
+            Instruction::CallBuiltin(sym) => Op::CallBuiltin(symbol(sym)?),
+            Instruction::SpawnBuiltin(..) => return Err(Error::Unsupported("SpawnBuiltin")),
+            Instruction::Join => return Err(Error::Unsupported("Join")),
+            Instruction::Print { newline, wait } => Op::Print { newline, wait },
+            Instruction::PrintChannel { .. } => return Err(Error::Unsupported("PrintChannel")),
+            Instruction::BinaryOperator(op) => match op {
+                BinaryOperator::Add => Op::Add,
+                BinaryOperator::Sub => Op::Sub,
+                BinaryOperator::Mul => Op::Mul,
+                BinaryOperator::Div => Op::Div,
+                BinaryOperator::Rem => Op::Rem,
+                BinaryOperator::And | BinaryOperator::LogicalAnd => Op::And,
+                BinaryOperator::Or | BinaryOperator::LogicalOr => Op::Or,
+                BinaryOperator::Xor => Op::Xor,
+                BinaryOperator::Shl => Op::Shl,
+                BinaryOperator::Shr => Op::Shr,
+                BinaryOperator::Equal => Op::Equal,
+                BinaryOperator::NotEqual => Op::NotEqual,
+                BinaryOperator::Less => Op::Less,
+                BinaryOperator::LessOrEqual => Op::LessOrEqual,
+                BinaryOperator::Greater => Op::Greater,
+                BinaryOperator::GreaterOrEqual => Op::GreaterOrEqual,
+            },
+            Instruction::UnaryOperator(UnaryOperator::Not) => Op::Not,
+            Instruction::TernaryOperator(TernaryOperator::Conditional) => Op::Conditional,
+            Instruction::Goto(pos) => Op::Goto(pos),
+            Instruction::GotoIfNot(pos) => Op::GotoIfNot(pos),
+            Instruction::Timer(..) => return Err(Error::Unsupported("Timer")),
+            Instruction::EndTimer => return Err(Error::Unsupported("EndTimer")),
+            Instruction::JumpTable { min, len } => Op::JumpTable {
+                min,
+                len: len as u32,
+            },
+            Instruction::BuildList(count) => Op::BuildList(count),
+            Instruction::LoadIndex => Op::LoadIndex,
+            Instruction::StoreIndex { var, depth } => Op::StoreIndex {
+                var: symbol(var)?,
+                depth,
+            },
+            Instruction::IterStart => Op::IterStart,
+            Instruction::IterNext(end) => Op::IterNext(end),
+            Instruction::ConcatN(count) => Op::ConcatN(count as u32),
+            Instruction::LoadBool(value) => Op::LoadBool(value),
+            Instruction::LoadNull => Op::LoadNull,
+        };
+        ops.push((op, inst.location.line as u32));
+    }
+
+    let mut out = Vec::with_capacity(16 + ops.len() * 13);
+    out.extend_from_slice(MAGIC);
+    out.push(VERSION);
+    out.extend_from_slice(&(symbols.len() as u32).to_le_bytes());
+    for name in symbols {
+        out.extend_from_slice(&(name.len() as u32).to_le_bytes());
+        out.extend_from_slice(name.as_bytes());
+    }
+    out.extend_from_slice(&(ops.len() as u32).to_le_bytes());
+    for (op, line) in ops {
+        let (code, operands) = op.encode();
+        out.extend_from_slice(&line.to_le_bytes());
+        out.push(code);
+        for operand in operands.iter() {
+            out.extend_from_slice(&operand.to_le_bytes());
+        }
+    }
+
+    Ok(out)
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(Error::Invalid("이미지가 잘렸습니다"));
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+}
+
+/// Decoded program, checked once so `Vm` never reads out of range
+#[derive(Clone, Debug)]
+pub struct Image {
+    symbols: Box<[Box<str>]>,
+    /// `$_이름` variables cleared after every print
+    temporaries: Box<[u32]>,
+    /// Instruction with its line
+    ops: Box<[(Op, u32)]>,
+}
+
+impl Image {
+    pub fn decode(bytes: &[u8]) -> Result<Self> {
+        let mut reader = Reader(bytes);
+
+        if reader.bytes(4)? != MAGIC {
+            return Err(Error::Invalid("kes 이미지가 아닙니다"));
+        }
+        if reader.u8()? != VERSION {
+            return Err(Error::Invalid("지원하지 않는 버전입니다"));
+        }
+
+        let symbol_count = reader.u32()?;
+        let mut symbols = Vec::new();
+        for _ in 0..symbol_count {
+            let len = reader.u32()? as usize;
+            let name = std::str::from_utf8(reader.bytes(len)?)
+                .map_err(|_| Error::Invalid("심볼이 UTF-8이 아닙니다"))?;
+            symbols.push(Box::from(name));
+        }
+
+        let op_count = reader.u32()?;
+        let mut ops = Vec::new();
+        for _ in 0..op_count {
+            let line = reader.u32()?;
+            let code = reader.u8()?;
+            let operands = [reader.u32()?, reader.u32()?];
+            let op = Op::decode(code, operands).ok_or(Error::Invalid("알수없는 명령어입니다"))?;
+            ops.push((op, line));
+        }
+
+        if !reader.0.is_empty() {
+            return Err(Error::Invalid("이미지 뒤에 남은 데이터가 있습니다"));
+        }
+
+        for (pos, (op, _)) in ops.iter().enumerate() {
+            if op.symbol().map_or(false, |sym| sym >= symbol_count) {
+                return Err(Error::Invalid("없는 심볼을 사용합니다"));
+            }
+            if op.target().map_or(false, |target| target > op_count) {
+                return Err(Error::Invalid("범위를 벗어난 명령어로 이동합니다"));
+            }
+            if let Op::JumpTable { len, .. } = op {
+                let end = (*len as usize)
+                    .checked_add(pos + 2)
+                    .ok_or(Error::Invalid("점프 테이블이 너무 큽니다"))?;
+                if !ops.get(pos + 1..end).map_or(false, |table| {
+                    table.iter().all(|(op, _)| matches!(op, Op::Goto(..)))
+                }) {
+                    return Err(Error::Invalid("점프 테이블 뒤에 `Goto`가 부족합니다"));
+                }
+            }
+        }
+
+        let temporaries = symbols
+            .iter()
+            .enumerate()
+            .filter(|(_, name)| name.starts_with('_'))
+            .map(|(idx, _)| idx as u32)
+            .collect();
+
+        Ok(Self {
+            symbols: symbols.into(),
+            temporaries,
+            ops: ops.into(),
+        })
+    }
+
+    fn symbol(&self, name: &str) -> Option<usize> {
+        self.symbols.iter().position(|symbol| &**symbol == name)
+    }
+}
+
+/// Functions and output of host, synchronous version of `Builtin`
+pub trait Host {
+    /// Run builtin, arguments are at end of stack and builtin pops them like `Context`
+    fn call(&mut self, name: &str, vm: &mut Vm<'_>) -> Value;
+    fn print(&mut self, value: Value);
+    fn new_line(&mut self);
+    fn wait(&mut self);
+    /// Value of variable which is never assigned, like `Builtin::load`
+    fn load(&mut self, _name: &str) -> Option<Value> {
+        None
+    }
+}
+
+/// Running state of `Image`
+pub struct Vm<'i> {
+    image: &'i Image,
+    stack: Vec<Value>,
+    /// Indexed by symbol
+    variables: Vec<Option<Value>>,
+    /// Running `각각` loops with position of next element, innermost is last
+    iterations: Vec<(Value, usize)>,
+    cursor: usize,
+}
+
+impl<'i> Vm<'i> {
+    pub fn new(image: &'i Image) -> Self {
+        Self {
+            image,
+            stack: Vec::new(),
+            variables: vec![None; image.symbols.len()],
+            iterations: Vec::new(),
+            cursor: 0,
+        }
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<Value> {
+        self.stack.pop()
+    }
+
+    #[inline]
+    pub fn stack(&self) -> &[Value] {
+        &self.stack
+    }
+
+    pub fn variable(&self, name: &str) -> Option<&Value> {
+        self.variables[self.image.symbol(name)?].as_ref()
+    }
+
+    /// Set variable, returns `false` when image doesn't use it
+    pub fn set_variable(&mut self, name: &str, value: impl Into<Value>) -> bool {
+        match self.image.symbol(name) {
+            Some(idx) => {
+                self.variables[idx] = Some(value.into());
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.image.ops.len()
+    }
+
+    /// Line of next instruction, `0` when finished
+    pub fn line(&self) -> usize {
+        self.image
+            .ops
+            .get(self.cursor)
+            .map_or(0, |(_, line)| *line as usize)
+    }
+
+    pub fn run(&mut self, host: &mut impl Host) -> Result<()> {
+        while self.step(host)? {}
+        Ok(())
+    }
+
+    /// Run single instruction, returns `false` when image is finished
+    pub fn step(&mut self, host: &mut impl Host) -> Result<bool> {
+        let (op, line) = match self.image.ops.get(self.cursor) {
+            Some(&(op, line)) => (op, line as usize),
+            None => return Ok(false),
+        };
+        let image = self.image;
+        let exec_err = |msg| Error::Execution(msg, line);
+        let type_err = |value: &Value| Error::Type(value.type_name(), line);
+
+        macro_rules! pop {
+            () => {
+                self.stack.pop().ok_or(exec_err("인자가 부족합니다"))?
+            };
+        }
+
+        macro_rules! pop_int {
+            () => {
+                match pop!() {
+                    Value::Int(num) => num,
+                    other => return Err(type_err(&other)),
+                }
+            };
+        }
+
+        macro_rules! arith {
+            ($op:ident) => {{
+                let rhs = pop_int!();
+                let lhs = pop_int!();
+                Value::Int(lhs.$op(rhs))
+            }};
+        }
+
+        macro_rules! checked_arith {
+            ($op:ident) => {{
+                let rhs = pop_int!();
+                let lhs = pop_int!();
+                Value::Int(lhs.$op(rhs).ok_or(exec_err("0으로 나눌수 없습니다"))?)
+            }};
+        }
+
+        macro_rules! shift {
+            ($op:ident) => {{
+                let rhs = pop_int!();
+                let lhs = pop_int!();
+                Value::Int(lhs.$op(rhs).unwrap_or(0))
+            }};
+        }
+
+        macro_rules! logic {
+            ($op:tt) => {{
+                let rhs = pop!().into_bool();
+                let lhs = pop!().into_bool();
+                Value::Bool(lhs $op rhs)
+            }};
+        }
+
+        macro_rules! ord {
+            ($($ordering:ident)|+) => {{
+                let rhs = pop!();
+                let lhs = pop!();
+                let ordering = lhs.compare(&rhs).ok_or_else(|| type_err(&rhs))?;
+                Value::Bool(matches!(ordering, $(std::cmp::Ordering::$ordering)|+))
+            }};
+        }
+
+        let value = match op {
+            Op::Nop => None,
+            Op::Exit => {
+                self.cursor = image.ops.len();
+                return Ok(true);
+            }
+            Op::Pop => {
+                self.stack.pop();
+                None
+            }
+            Op::Duplicate => Some(
+                self.stack
+                    .last()
+                    .ok_or(exec_err("인자가 없습니다"))?
+                    .clone(),
+            ),
+            Op::LoadInt(num) => Some(Value::Int(num)),
+            Op::LoadStr(sym) => Some(Value::from(&*image.symbols[sym as usize])),
+            Op::LoadBool(value) => Some(Value::Bool(value)),
+            Op::LoadNull => Some(Value::Null),
+            Op::LoadVar(sym) => {
+                let name = &*image.symbols[sym as usize];
+                match self.variables[sym as usize].clone() {
+                    Some(value) => Some(value),
+                    None => Some(
+                        host.load(name)
+                            .ok_or_else(|| Error::UndefinedVariable(name.into(), line))?,
+                    ),
+                }
+            }
+            Op::StoreVar(sym) => {
+                self.variables[sym as usize] = Some(pop!());
+                None
+            }
+            Op::CallBuiltin(sym) => Some(host.call(&image.symbols[sym as usize], self)),
+            Op::Print { newline, wait } => {
+                for value in self.stack.drain(..) {
+                    host.print(value);
+                }
+                if newline || wait {
+                    host.new_line();
+                }
+                for &sym in image.temporaries.iter() {
+                    self.variables[sym as usize] = None;
+                }
+                if wait {
+                    host.wait();
+                }
+                None
+            }
+            Op::Add => {
+                let rhs = pop!();
+                let lhs = pop!();
+                Some(add(lhs, rhs).map_err(|rhs| type_err(&rhs))?)
+            }
+            Op::ConcatN(count) => {
+                let start = self
+                    .stack
+                    .len()
+                    .checked_sub(count as usize)
+                    .ok_or(exec_err("인자가 부족합니다"))?;
+                let mut values = self.stack.split_off(start).into_iter();
+                let first = values.next().ok_or(exec_err("인자가 부족합니다"))?;
+                Some(values.try_fold(first, add).map_err(|rhs| type_err(&rhs))?)
+            }
+            Op::Sub => Some(arith!(wrapping_sub)),
+            Op::Mul => Some(arith!(wrapping_mul)),
+            Op::Div => Some(checked_arith!(checked_div)),
+            Op::Rem => Some(checked_arith!(checked_rem)),
+            Op::And => Some(logic!(&)),
+            Op::Or => Some(logic!(|)),
+            Op::Xor => Some(logic!(^)),
+            Op::Shl => Some(shift!(checked_shl)),
+            Op::Shr => Some(shift!(checked_shr)),
+            Op::Equal => {
+                let rhs = pop!();
+                Some(Value::Bool(pop!() == rhs))
+            }
+            Op::NotEqual => {
+                let rhs = pop!();
+                Some(Value::Bool(pop!() != rhs))
+            }
+            Op::Less => Some(ord!(Less)),
+            Op::LessOrEqual => Some(ord!(Less | Equal)),
+            Op::Greater => Some(ord!(Greater)),
+            Op::GreaterOrEqual => Some(ord!(Greater | Equal)),
+            Op::Not => Some(Value::Bool(!pop!().into_bool())),
+            Op::Conditional => {
+                let rhs = pop!();
+                let lhs = pop!();
+                Some(if pop!().into_bool() { lhs } else { rhs })
+            }
+            Op::Goto(pos) => {
+                self.cursor = pos as usize;
+                return Ok(true);
+            }
+            Op::GotoIfNot(pos) => {
+                if !pop!().into_bool() {
+                    self.cursor = pos as usize;
+                    return Ok(true);
+                }
+                None
+            }
+            Op::JumpTable { min, len } => {
+                let idx = match pop!() {
+                    Value::Int(num) if num.wrapping_sub(min) < len => num - min,
+                    _ => len,
+                };
+                // checked by `Image::decode`
+                if let (Op::Goto(pos), _) = image.ops[self.cursor + 1 + idx as usize] {
+                    self.cursor = pos as usize;
+                }
+                return Ok(true);
+            }
+            Op::BuildList(count) => {
+                let start = self
+                    .stack
+                    .len()
+                    .checked_sub(count as usize)
+                    .ok_or(exec_err("인자가 부족합니다"))?;
+                Some(Value::from(self.stack.split_off(start)))
+            }
+            Op::LoadIndex => {
+                let index = pop_int!() as usize;
+                match pop!() {
+                    Value::List(mut list) if index < list.len() => Some(list.swap_remove(index)),
+                    Value::List(..) => return Err(exec_err("목록의 범위를 벗어났습니다")),
+                    other => return Err(type_err(&other)),
+                }
+            }
+            Op::StoreIndex { var, depth } => {
+                let value = pop!();
+                let start = self
+                    .stack
+                    .len()
+                    .checked_sub(depth as usize)
+                    .ok_or(exec_err("인자가 부족합니다"))?;
+                let indices = self.stack.split_off(start);
+                let mut target = self.variables[var as usize].as_mut().ok_or_else(|| {
+                    Error::UndefinedVariable(image.symbols[var as usize].to_string(), line)
+                })?;
+                for index in indices {
+                    let index = match index {
+                        Value::Int(index) => index as usize,
+                        other => return Err(type_err(&other)),
+                    };
+                    target = match target {
+                        Value::List(list) => list
+                            .get_mut(index)
+                            .ok_or(exec_err("목록의 범위를 벗어났습니다"))?,
+                        other => return Err(type_err(&*other)),
+                    };
+                }
+                *target = value;
+                None
+            }
+            Op::IterStart => {
+                let value = pop!();
+                if !value.is_iterable() {
+                    return Err(type_err(&value));
+                }
+                self.iterations.push((value, 0));
+                None
+            }
+            Op::IterNext(end) => {
+                let (value, pos) = self
+                    .iterations
+                    .last_mut()
+                    .ok_or(exec_err("각각 밖에서 다음 항목을 읽었습니다"))?;
+                match value.element_at(*pos) {
+                    Some((element, next)) => {
+                        *pos = next;
+                        Some(element)
+                    }
+                    None => {
+                        self.iterations.pop();
+                        self.cursor = end as usize;
+                        return Ok(true);
+                    }
+                }
+            }
+        };
+
+        if let Some(value) = value {
+            self.stack.push(value);
+        }
+        self.cursor += 1;
+
+        Ok(true)
+    }
+}
+
+/// `+` of `Context`, returns rhs when types can't be added
+fn add(lhs: Value, rhs: Value) -> std::result::Result<Value, Value> {
+    Ok(match (lhs, rhs) {
+        (Value::Int(l), Value::Int(r)) => Value::Int(l.wrapping_add(r)),
+        (Value::Str(l), r) => {
+            let mut l = *l;
+            write!(l, "{}", r).unwrap();
+            l.into()
+        }
+        (l, Value::Str(r)) => format!("{}{}", l, r).into(),
+        (Value::List(l), Value::List(r)) => {
+            let mut l = *l;
+            l.extend(*r);
+            l.into()
+        }
+        (_, r) => return Err(r),
+    })
+}
+
+#[cfg(all(test, feature = "compiler"))]
+mod tests {
+    use super::{encode, Error, Host, Image, Vm};
+    use crate::program::Program;
+    use crate::value::Value;
+    use pretty_assertions::assert_eq;
+
+    /// Records like `RecordBuiltin`, builtins pop their argument and return it doubled
+    struct Record(String);
+
+    impl Host for Record {
+        fn call(&mut self, name: &str, vm: &mut Vm<'_>) -> Value {
+            self.0.push_str(name);
+            match vm.pop() {
+                Some(Value::Int(num)) => Value::Int(num * 2),
+                _ => Value::Null,
+            }
+        }
+        fn print(&mut self, value: Value) {
+            self.0 += &value.to_string();
+        }
+        fn new_line(&mut self) {
+            self.0.push('@');
+        }
+        fn wait(&mut self) {
+            self.0.push('#');
+        }
+    }
+
+    fn run(code: &str) -> Result<String, Error> {
+        let image = Image::decode(&encode(&Program::from_source(code).unwrap())?)?;
+        let mut host = Record(String::new());
+        Vm::new(&image).run(&mut host)?;
+        Ok(host.0)
+    }
+
+    #[test]
+    fn run_program() {
+        assert_eq!(
+            run("$1 = 0; 반복 $1 < 3 { $1 = $1 + 1; @$1; } @@'끝' 두배(4);").unwrap(),
+            "123두배끝8@"
+        );
+        assert_eq!(
+            run("$목록 = [1, 2]; $목록[1] = '가'; 각각 $값 $목록 { @$값; } @!$목록;").unwrap(),
+            "1가[1, 가]@#"
+        );
+        assert_eq!(run("@1 == 1 & 2 > 3 ? 'a' : 'b';").unwrap(), "b");
+        assert_eq!(run("@1 << 4 >> 2 ' ' (1 >> 40);").unwrap(), "4 0");
+        assert!(matches!(
+            run("@$없는변수;"),
+            Err(Error::UndefinedVariable(..))
+        ));
+        assert!(matches!(run("@1 / 0;"), Err(Error::Execution(..))));
+    }
+
+    #[test]
+    fn unsupported() {
+        let program = Program::from_source("타이머 10 { @1; }").unwrap();
+        assert_eq!(encode(&program), Err(Error::Unsupported("Timer")));
+    }
+
+    #[test]
+    fn invalid_image() {
+        let mut bytes = encode(&Program::from_source("@1;").unwrap()).unwrap();
+        assert!(Image::decode(&bytes[..bytes.len() - 1]).is_err());
+        bytes[0] = b'X';
+        assert_eq!(
+            Image::decode(&bytes).unwrap_err(),
+            Error::Invalid("kes 이미지가 아닙니다")
+        );
+    }
+}
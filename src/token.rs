@@ -1,5 +1,7 @@
+use crate::error::Language;
 use crate::interner::Symbol;
 use crate::operator::{BinaryOperator, TernaryOperator, UnaryOperator};
+use std::fmt;
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Token {
@@ -13,6 +15,28 @@ pub enum Token {
     Exit,
     /// 반복
     While,
+    /// 시작
+    Spawn,
+    /// 기다리기
+    Join,
+    /// 타이머
+    Timer,
+    /// 선택
+    Select,
+    /// 각각
+    ForEach,
+    /// 장면
+    Label,
+    /// 변수
+    Declare,
+    /// 자원
+    Asset,
+    /// 포함
+    Include,
+    /// 참, 거짓
+    BoolLit(bool),
+    /// 없음
+    NullLit,
     /// ''
     StrLit(Symbol),
     /// 123
@@ -39,6 +63,12 @@ pub enum Token {
     OpenParan,
     /// )
     CloseParan,
+    /// [
+    OpenBracket,
+    /// `[` right after variable or `]`, starts index like `$목록[0]`
+    OpenIndex,
+    /// ]
+    CloseBracket,
 
     /// @
     Print,
@@ -46,6 +76,8 @@ pub enum Token {
     PrintWait,
     /// @@
     PrintLine,
+    /// `#이름` right after print token like `@#로그`
+    Channel(Symbol),
 
     /// ;
     SemiColon,
@@ -56,3 +88,143 @@ pub enum Token {
     /// =
     Assign,
 }
+
+const PUNCTS: &[&str] = &[
+    "{", "}", "(", ")", "[", "]", "?", ":", "=", ";", ",", "!", "+", "-", "*", "/", "%", "&", "|",
+    "^", "&&", "||", ">", ">=", "<", "<=", "!=", "==", "<<", ">>",
+];
+
+/// Kind of token without its value
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TokenKind {
+    /// 만약, 반복, ...
+    Keyword(&'static str),
+    /// Operators and punctuations like `(`, `;`, `@!`
+    Punct(&'static str),
+    /// ''
+    StrLit,
+    /// 123
+    IntLit,
+    /// ABC
+    Builtin,
+    /// $ABC
+    Variable,
+    /// #ABC
+    Channel,
+}
+
+impl TokenKind {
+    /// Convert terminal name of grammar which lalrpop reports as expected
+    pub(crate) fn from_terminal(terminal: &str) -> Option<Self> {
+        let kind = match terminal.trim_matches('"') {
+            "built" => TokenKind::Builtin,
+            "number" => TokenKind::IntLit,
+            "string" => TokenKind::StrLit,
+            "var" => TokenKind::Variable,
+            "channel" => TokenKind::Channel,
+            "만약" => TokenKind::Keyword("만약"),
+            "혹은" => TokenKind::Keyword("혹은"),
+            "그외" => TokenKind::Keyword("그외"),
+            "종료" => TokenKind::Keyword("종료"),
+            "반복" => TokenKind::Keyword("반복"),
+            "시작" => TokenKind::Keyword("시작"),
+            "기다리기" => TokenKind::Keyword("기다리기"),
+            "타이머" => TokenKind::Keyword("타이머"),
+            "선택" => TokenKind::Keyword("선택"),
+            "각각" => TokenKind::Keyword("각각"),
+            "장면" => TokenKind::Keyword("장면"),
+            "변수" => TokenKind::Keyword("변수"),
+            "자원" => TokenKind::Keyword("자원"),
+            "포함" => TokenKind::Keyword("포함"),
+            "참" => TokenKind::Keyword("참"),
+            "거짓" => TokenKind::Keyword("거짓"),
+            "없음" => TokenKind::Keyword("없음"),
+            "PRINT" => TokenKind::Punct("@"),
+            "PRINTL" => TokenKind::Punct("@@"),
+            "PRINTW" => TokenKind::Punct("@!"),
+            "INDEX" => TokenKind::Punct("["),
+            other => TokenKind::Punct(PUNCTS.iter().copied().find(|punct| *punct == other)?),
+        };
+
+        Some(kind)
+    }
+
+    /// Source text of token, `None` for literals and identifiers
+    pub fn text(self) -> Option<&'static str> {
+        match self {
+            TokenKind::Keyword(text) | TokenKind::Punct(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Human readable name of token kind used in diagnostics
+    pub fn describe(self, language: Language) -> String {
+        match self {
+            TokenKind::Keyword(text) | TokenKind::Punct(text) => format!("`{}`", text),
+            TokenKind::StrLit => language.pick("문자열", "string").into(),
+            TokenKind::IntLit => language.pick("숫자", "number").into(),
+            TokenKind::Builtin => language.pick("함수 이름", "function name").into(),
+            TokenKind::Variable => language.pick("변수", "variable").into(),
+            TokenKind::Channel => language.pick("채널", "channel").into(),
+        }
+    }
+}
+
+impl Token {
+    pub fn kind(self) -> TokenKind {
+        match self {
+            Token::If => TokenKind::Keyword("만약"),
+            Token::ElseIf => TokenKind::Keyword("혹은"),
+            Token::Else => TokenKind::Keyword("그외"),
+            Token::Exit => TokenKind::Keyword("종료"),
+            Token::While => TokenKind::Keyword("반복"),
+            Token::Spawn => TokenKind::Keyword("시작"),
+            Token::Join => TokenKind::Keyword("기다리기"),
+            Token::Timer => TokenKind::Keyword("타이머"),
+            Token::Select => TokenKind::Keyword("선택"),
+            Token::ForEach => TokenKind::Keyword("각각"),
+            Token::Label => TokenKind::Keyword("장면"),
+            Token::Declare => TokenKind::Keyword("변수"),
+            Token::Asset => TokenKind::Keyword("자원"),
+            Token::Include => TokenKind::Keyword("포함"),
+            Token::BoolLit(true) => TokenKind::Keyword("참"),
+            Token::BoolLit(false) => TokenKind::Keyword("거짓"),
+            Token::NullLit => TokenKind::Keyword("없음"),
+            Token::StrLit(_) => TokenKind::StrLit,
+            Token::IntLit(_) => TokenKind::IntLit,
+            Token::Builtin(_) => TokenKind::Builtin,
+            Token::Variable(_) => TokenKind::Variable,
+            Token::Channel(_) => TokenKind::Channel,
+            Token::UnaryOp(op) => TokenKind::Punct(op.name()),
+            Token::BinaryOp(op) => TokenKind::Punct(op.name()),
+            Token::TernaryOp(_, true) => TokenKind::Punct("?"),
+            Token::TernaryOp(_, false) => TokenKind::Punct(":"),
+            Token::OpenBrace => TokenKind::Punct("{"),
+            Token::CloseBrace => TokenKind::Punct("}"),
+            Token::OpenParan => TokenKind::Punct("("),
+            Token::CloseParan => TokenKind::Punct(")"),
+            Token::OpenBracket | Token::OpenIndex => TokenKind::Punct("["),
+            Token::CloseBracket => TokenKind::Punct("]"),
+            Token::Print => TokenKind::Punct("@"),
+            Token::PrintWait => TokenKind::Punct("@!"),
+            Token::PrintLine => TokenKind::Punct("@@"),
+            Token::SemiColon => TokenKind::Punct(";"),
+            Token::Comma => TokenKind::Punct(","),
+            Token::Assign => TokenKind::Punct("="),
+        }
+    }
+
+    /// Human readable name of token used in diagnostics
+    pub fn describe(self, language: Language) -> String {
+        match self {
+            Token::IntLit(num) => format!("{} `{}`", language.pick("숫자", "number"), num),
+            _ => self.kind().describe(language),
+        }
+    }
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.describe(Language::Korean))
+    }
+}
@@ -0,0 +1,128 @@
+use crate::lexer::escape_str;
+use crate::location::Location;
+use crate::value::Value;
+use std::io::Write;
+
+/// Event reported to `Tracer` while running
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TraceEvent<'a> {
+    /// Execution moved to another file of [`Program::from_entry`](crate::program::Program::from_entry),
+    /// following lines are lines in this file
+    File(&'a str),
+    /// Execution moved to another line
+    Line(Location),
+    /// Statement is about to be run, fired again when loop condition is checked
+    Statement(Location),
+    /// Builtin is about to be called
+    ///
+    /// Values are arguments when signature of builtin is set by `Context::set_signatures`,
+    /// otherwise every value pushed by current statement like `Context::stack`
+    Call(&'a str, &'a [Value], Location),
+    /// Builtin returned value
+    Return(&'a str, &'a Value, Location),
+    /// Values are about to be printed
+    Print(&'a [Value], Location),
+}
+
+/// Observer of running script
+pub trait Tracer: Send + Sync {
+    fn trace(&mut self, event: TraceEvent<'_>);
+}
+
+impl<F: FnMut(TraceEvent<'_>) + Send + Sync> Tracer for F {
+    fn trace(&mut self, event: TraceEvent<'_>) {
+        self(event)
+    }
+}
+
+/// `Tracer` writes one event per line
+///
+/// ```text
+/// line L2
+/// call 함수(1, '가') L2
+/// return 함수 = 0 L2
+/// print '안녕' L3
+/// ```
+///
+/// Write errors are ignored so tracing never stops script
+pub struct TraceWriter<W> {
+    out: W,
+}
+
+impl<W: Write> TraceWriter<W> {
+    pub fn new(out: W) -> Self {
+        Self { out }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+/// Value written like source literal, `'가'` for string
+pub fn literal(value: &Value) -> String {
+    match value {
+        Value::Int(num) => num.to_string(),
+        Value::Str(text) => format!("'{}'", escape_str(text)),
+        Value::List(list) => format!("[{}]", literals(list)),
+        Value::Bool(..) | Value::Null => value.to_string(),
+    }
+}
+
+pub(crate) fn literals(values: &[Value]) -> String {
+    values.iter().map(literal).collect::<Vec<_>>().join(", ")
+}
+
+impl<W: Write + Send + Sync> Tracer for TraceWriter<W> {
+    fn trace(&mut self, event: TraceEvent<'_>) {
+        let _ = match event {
+            TraceEvent::File(path) => writeln!(self.out, "file {}", path),
+            TraceEvent::Line(location) => writeln!(self.out, "line {}", location),
+            // lines are enough for text traces
+            TraceEvent::Statement(..) => Ok(()),
+            TraceEvent::Call(name, args, location) => {
+                writeln!(self.out, "call {}({}) {}", name, literals(args), location)
+            }
+            TraceEvent::Return(name, value, location) => {
+                writeln!(
+                    self.out,
+                    "return {} = {} {}",
+                    name,
+                    literal(value),
+                    location
+                )
+            }
+            TraceEvent::Print(values, location) => {
+                writeln!(self.out, "print {} {}", literals(values), location)
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TraceEvent, TraceWriter, Tracer};
+    use crate::location::Location;
+    use crate::value::Value;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn writer_format() {
+        let mut writer = TraceWriter::new(Vec::new());
+        let location = Location::new(2);
+        writer.trace(TraceEvent::File("a.kes"));
+        writer.trace(TraceEvent::Line(location));
+        writer.trace(TraceEvent::Call(
+            "함수",
+            &[Value::Int(1), Value::from("it's \"가\"\n")],
+            location,
+        ));
+        writer.trace(TraceEvent::Return("함수", &Value::Int(0), location));
+        writer.trace(TraceEvent::Print(&[Value::from("안녕")], location));
+
+        assert_eq!(
+            String::from_utf8(writer.into_inner()).unwrap(),
+            "file a.kes\nline L2\ncall 함수(1, 'it\\'s \"가\"\\n') L2\nreturn 함수 = 0 L2\nprint '안녕' L2\n"
+        );
+    }
+}
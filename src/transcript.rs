@@ -0,0 +1,329 @@
+//! Record output of script for scenario tests
+//!
+//! [`TranscriptBuiltin`] records what script printed and called with lines,
+//! choice builtins get answers from scripted input list.
+//! Transcript is compared with expected text by [`assert_transcript`]
+//!
+//! ## Examples
+//! ```rust
+//! use kes::builtin::RecordBuiltin;
+//! use kes::context::Context;
+//! use kes::program::Program;
+//! use kes::transcript::{assert_transcript, TranscriptBuiltin};
+//!
+//! let program = Program::from_source("@@'어디로?';\n만약 선택('숲', '성') == 1 { @!'성 엔딩'; }").unwrap();
+//! let mut builtin = TranscriptBuiltin::new(RecordBuiltin::new(), [1]).choice_builtin("선택");
+//! let ctx = Context::builder(&program).tracer(builtin.tracer()).build();
+//! futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+//!
+//! assert_transcript(
+//!     builtin.transcript(),
+//!     "print '어디로?' L1\nnewline\nchoice 1 L2\nprint '성 엔딩' L2\nnewline\nwait\n",
+//! );
+//! ```
+
+use crate::builtin::Builtin;
+use crate::context::Context;
+use crate::location::Location;
+use crate::trace::{literal, literals, TraceEvent, Tracer};
+use crate::value::Value;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+/// One output of script
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TranscriptEvent {
+    /// Values of print statement joined into text
+    Print {
+        text: String,
+        location: Location,
+    },
+    NewLine,
+    Wait,
+    /// Builtin call with arguments
+    Call {
+        name: String,
+        args: Vec<Value>,
+        location: Location,
+    },
+    /// Answer of choice builtin
+    Choice {
+        index: u32,
+        location: Location,
+    },
+}
+
+impl fmt::Display for TranscriptEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TranscriptEvent::Print { text, location } => write!(
+                f,
+                "print {} {}",
+                literal(&Value::from(text.as_str())),
+                location
+            ),
+            TranscriptEvent::NewLine => f.write_str("newline"),
+            TranscriptEvent::Wait => f.write_str("wait"),
+            TranscriptEvent::Call {
+                name,
+                args,
+                location,
+            } => write!(f, "call {}({}) {}", name, literals(args), location),
+            TranscriptEvent::Choice { index, location } => {
+                write!(f, "choice {} {}", index, location)
+            }
+        }
+    }
+}
+
+/// Recorded events in order, `Display` writes one event per line
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Transcript {
+    pub events: Vec<TranscriptEvent>,
+}
+
+impl fmt::Display for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for event in self.events.iter() {
+            writeln!(f, "{}", event)?;
+        }
+        Ok(())
+    }
+}
+
+/// Panic with first different line when `actual` doesn't match `expected` text
+pub fn assert_transcript(actual: &Transcript, expected: &str) {
+    let actual = actual.to_string();
+    if actual == expected {
+        return;
+    }
+
+    let mut actual_lines = actual.lines();
+    let mut expected_lines = expected.lines();
+    let mut line = 1;
+
+    loop {
+        match (actual_lines.next(), expected_lines.next()) {
+            (Some(left), Some(right)) if left == right => line += 1,
+            (left, right) => panic!(
+                "transcript differs at line {}\n  actual: {}\nexpected: {}\n\nactual transcript:\n{}",
+                line,
+                left.unwrap_or("<end>"),
+                right.unwrap_or("<end>"),
+                actual
+            ),
+        }
+    }
+}
+
+/// `Builtin` wrapper which records `Transcript`
+///
+/// Lines of print statements come from [`TranscriptBuiltin::tracer`] which should be set to context
+pub struct TranscriptBuiltin<B: Builtin> {
+    inner: B,
+    transcript: Transcript,
+    choices: VecDeque<u32>,
+    choice_builtins: BTreeSet<String>,
+    /// Location of last print statement reported by tracer
+    print_location: Arc<Mutex<Location>>,
+}
+
+impl<B: Builtin> TranscriptBuiltin<B> {
+    /// `choices` are answers of choice builtins in order, `0` is used after they run out
+    pub fn new(inner: B, choices: impl IntoIterator<Item = u32>) -> Self {
+        Self {
+            inner,
+            transcript: Transcript::default(),
+            choices: choices.into_iter().collect(),
+            choice_builtins: BTreeSet::new(),
+            print_location: Arc::default(),
+        }
+    }
+
+    /// Builtin `name` returns next scripted choice instead of calling inner builtin
+    ///
+    /// Every value on stack is taken as option, so call it alone like `만약 선택('가', '나') == 0`
+    pub fn choice_builtin(mut self, name: impl Into<String>) -> Self {
+        self.choice_builtins.insert(name.into());
+        self
+    }
+
+    /// Tracer which tells locations of print statements
+    pub fn tracer(&self) -> impl Tracer {
+        let print_location = self.print_location.clone();
+        move |event: TraceEvent<'_>| {
+            if let TraceEvent::Print(_, location) = event {
+                *print_location.lock().unwrap() = location;
+            }
+        }
+    }
+
+    #[inline]
+    pub fn transcript(&self) -> &Transcript {
+        &self.transcript
+    }
+
+    /// Scripted choices which are not used yet
+    pub fn remaining_choices(&self) -> impl Iterator<Item = u32> + '_ {
+        self.choices.iter().copied()
+    }
+
+    #[inline]
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    pub fn into_inner(self) -> (B, Transcript) {
+        (self.inner, self.transcript)
+    }
+
+    fn push_print(&mut self, text: String) {
+        let location = *self.print_location.lock().unwrap();
+        self.transcript
+            .events
+            .push(TranscriptEvent::Print { text, location });
+    }
+}
+
+#[async_trait]
+impl<B: Builtin> Builtin for TranscriptBuiltin<B> {
+    async fn run(&mut self, name: &str, ctx: &mut Context<'_>) -> Value {
+        let location = ctx.location();
+
+        if self.choice_builtins.contains(name) {
+            let index = match ctx.replayed_choice() {
+                Some(index) => index,
+                None => {
+                    let index = self.choices.pop_front().unwrap_or(0);
+                    ctx.record_choice(index);
+                    index
+                }
+            };
+            // every argument is option of choice
+            while ctx.pop_ret().is_ok() {}
+            self.transcript
+                .events
+                .push(TranscriptEvent::Choice { index, location });
+            return Value::Int(index);
+        }
+
+        self.transcript.events.push(TranscriptEvent::Call {
+            name: name.into(),
+            args: ctx.args().to_vec(),
+            location,
+        });
+        self.inner.run(name, ctx).await
+    }
+    #[inline]
+    fn load(&mut self, name: &str) -> Option<Value> {
+        self.inner.load(name)
+    }
+    fn print(&mut self, v: Value) {
+        self.push_print(v.to_string());
+        self.inner.print(v);
+    }
+    fn print_group(&mut self, values: Vec<Value>) {
+        self.push_print(values.iter().map(ToString::to_string).collect());
+        self.inner.print_group(values);
+    }
+    fn print_channel(&mut self, channel: &str, values: Vec<Value>) {
+        self.push_print(values.iter().map(ToString::to_string).collect());
+        self.inner.print_channel(channel, values);
+    }
+    fn new_line(&mut self) {
+        self.transcript.events.push(TranscriptEvent::NewLine);
+        self.inner.new_line();
+    }
+    #[inline]
+    async fn flush(&mut self) {
+        self.inner.flush().await;
+    }
+    async fn wait(&mut self) {
+        self.transcript.events.push(TranscriptEvent::Wait);
+        self.inner.wait().await;
+    }
+    #[inline]
+    async fn spawn(&mut self, name: &str, ctx: &mut Context<'_>) {
+        self.inner.spawn(name, ctx).await;
+    }
+    #[inline]
+    async fn join(&mut self) {
+        self.inner.join().await;
+    }
+    async fn auto_wait(&mut self) {
+        self.transcript.events.push(TranscriptEvent::Wait);
+        self.inner.auto_wait().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{assert_transcript, Transcript, TranscriptBuiltin};
+    use crate::builtin::RecordBuiltin;
+    use crate::context::Context;
+    use crate::program::Program;
+    use pretty_assertions::assert_eq;
+
+    const STORY: &str = "@@'갈림길';
+만약 선택('왼쪽', '오른쪽') == 0 {
+    소리('바람');
+    만약 선택('계속', '돌아가기') == 0 { @!'엔딩 1'; } 그외 { @!'엔딩 2'; }
+} 그외 {
+    @!'엔딩 3';
+}";
+
+    fn play(choices: &[u32]) -> TranscriptBuiltin<RecordBuiltin> {
+        let program = Program::from_source(STORY).unwrap();
+        let mut builtin = TranscriptBuiltin::new(RecordBuiltin::new(), choices.iter().copied())
+            .choice_builtin("선택");
+        let ctx = Context::builder(&program).tracer(builtin.tracer()).build();
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        builtin
+    }
+
+    #[test]
+    fn scenario() {
+        let builtin = play(&[0, 1]);
+        assert_eq!(builtin.remaining_choices().count(), 0);
+        assert_transcript(
+            builtin.transcript(),
+            "print '갈림길' L1
+newline
+choice 0 L2
+call 소리('바람') L3
+choice 1 L4
+print '엔딩 2' L4
+newline
+wait
+",
+        );
+        assert_eq!(builtin.inner().text(), "갈림길@소리엔딩 2@#");
+
+        assert_transcript(
+            play(&[1]).transcript(),
+            "print '갈림길' L1\nnewline\nchoice 1 L2\nprint '엔딩 3' L6\nnewline\nwait\n",
+        );
+    }
+
+    #[test]
+    fn serde() {
+        let transcript = play(&[0, 0]).into_inner().1;
+        let json = serde_json::to_string(&transcript).unwrap();
+        assert_eq!(
+            serde_json::from_str::<Transcript>(&json).unwrap(),
+            transcript
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "transcript differs at line 3")]
+    fn mismatch() {
+        assert_transcript(
+            play(&[1]).transcript(),
+            "print '갈림길' L1\nnewline\nchoice 0 L2\n",
+        );
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Host-provided pacing for typewriter style output
+///
+/// When set on `Context`, printed strings are delivered to `Builtin::print` one grapheme at a time
+/// and `delay` is awaited between them
+#[async_trait]
+pub trait Pacer: Send + Sync {
+    /// Wait before next chunk
+    async fn delay(&mut self);
+    /// Print remaining chunks of current print statement at once
+    fn is_skipped(&self) -> bool {
+        false
+    }
+}
+
+/// Split text into chunks which are printed at once
+pub fn chunks(text: &str) -> impl Iterator<Item = &str> {
+    text.graphemes(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{chunks, Pacer};
+    use crate::builtin::RecordBuiltin;
+    use crate::context::Context;
+    use crate::program::Program;
+    use async_trait::async_trait;
+    use pretty_assertions::assert_eq;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct CountPacer {
+        count: Arc<AtomicUsize>,
+        skip_after: usize,
+    }
+
+    #[async_trait]
+    impl Pacer for CountPacer {
+        async fn delay(&mut self) {
+            self.count.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn is_skipped(&self) -> bool {
+            self.count.load(Ordering::Relaxed) >= self.skip_after
+        }
+    }
+
+    #[test]
+    fn grapheme_chunks() {
+        assert_eq!(
+            chunks("가나e\u{301}").collect::<Vec<_>>(),
+            ["가", "나", "e\u{301}"]
+        );
+    }
+
+    fn test_impl(skip_after: usize) -> (String, usize) {
+        let program = Program::from_source("@@'가나다' 12;").unwrap();
+        let count = Arc::new(AtomicUsize::new(0));
+        let mut builtin = RecordBuiltin::new();
+        let mut ctx = Context::new(&program);
+        ctx.set_pacer(CountPacer {
+            count: count.clone(),
+            skip_after,
+        });
+        futures_executor::block_on(ctx.run(&mut builtin)).unwrap();
+        (builtin.text().to_string(), count.load(Ordering::Relaxed))
+    }
+
+    #[test]
+    fn pacing() {
+        assert_eq!(test_impl(usize::MAX), ("가나다12@".into(), 4));
+    }
+
+    #[test]
+    fn skip() {
+        assert_eq!(test_impl(1), ("가나다12@".into(), 1));
+    }
+}
@@ -1,13 +1,45 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 
+/// Value of script
+///
+/// Strings and lists are behind thin boxes so `Value` is 16 bytes and ints are stored inline,
+/// stack pushes and pops only move two words
+///
+/// ## Comparison
+/// - `==` and `!=` compare structure, values of different types are not equal so `1 != 참`
+/// - `<`, `>`, `<=` and `>=` need ints or strings of same type, see [`Value::compare`]
+/// - derived `Ord` follows order of variants, it is for sorted keys only
+///
+/// Comparisons, `!`, `&`, `|` and `^` produce `Bool`, arithmetic on it is type error
+///
+/// ## Conditions
+/// `만약`, `반복`, `? :` and logical operators accept every value, see [`Value::into_bool`]
+///
+/// Values own everything in them, `clone` is deep and never shares state with original
+///
+/// New types can be added in minor versions, matches outside of kes need wildcard arm
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum Value {
     Int(u32),
-    Str(String),
+    Str(Box<String>),
+    /// `[1, '가']`, elements are changed in place but length is fixed until `+` makes new list
+    List(Box<Vec<Value>>),
+    /// `참` or `거짓`, printed as same words
+    Bool(bool),
+    /// `없음`, result of builtin which has nothing to return
+    Null,
 }
 
+const _: () = assert!(std::mem::size_of::<Value>() <= 16);
+
 impl Value {
+    /// Value as condition, `거짓`, `없음`, `0`, `''` and `[]` are false and others are true
     #[inline]
     pub fn into_bool(&self) -> bool {
         self.into()
@@ -17,6 +49,56 @@ impl Value {
         match self {
             Value::Int(..) => "int",
             Value::Str(..) => "str",
+            Value::List(..) => "list",
+            Value::Bool(..) => "bool",
+            Value::Null => "null",
+        }
+    }
+
+    /// Bytes allocated by value outside of itself, elements of list are counted recursively
+    pub fn heap_size(&self) -> usize {
+        match self {
+            Value::Int(..) | Value::Bool(..) | Value::Null => 0,
+            Value::Str(str) => std::mem::size_of::<String>() + str.capacity(),
+            Value::List(list) => {
+                std::mem::size_of::<Vec<Value>>()
+                    + list.capacity() * std::mem::size_of::<Value>()
+                    + list.iter().map(Value::heap_size).sum::<usize>()
+            }
+        }
+    }
+
+    /// Whether `각각` can iterate elements of value
+    pub fn is_iterable(&self) -> bool {
+        matches!(self, Value::Str(..) | Value::List(..))
+    }
+
+    /// Element at `pos` with position of next element, `None` after last element
+    ///
+    /// Elements of string are characters at byte offsets, list positions are indices
+    /// and int, bool and null have no element
+    pub fn element_at(&self, pos: usize) -> Option<(Value, usize)> {
+        match self {
+            Value::Int(..) | Value::Bool(..) | Value::Null => None,
+            Value::Str(str) => {
+                let ch = str.get(pos..)?.chars().next()?;
+                Some((
+                    Value::from(&*ch.encode_utf8(&mut [0; 4])),
+                    pos + ch.len_utf8(),
+                ))
+            }
+            Value::List(list) => Some((list.get(pos)?.clone(), pos + 1)),
+        }
+    }
+
+    /// Order of values with same type, `None` when ordering them has no meaning
+    ///
+    /// Ints are ordered by number, strings by bytes, bools and lists are never ordered
+    pub fn compare(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Int(l), Value::Int(r)) => Some(l.cmp(r)),
+            (Value::Str(l), Value::Str(r)) => Some(l.cmp(r)),
+            _ => None,
         }
     }
 }
@@ -27,6 +109,19 @@ impl Display for Value {
         match self {
             Value::Int(num) => num.fmt(formatter),
             Value::Str(str) => formatter.write_str(str),
+            Value::List(list) => {
+                formatter.write_str("[")?;
+                for (idx, value) in list.iter().enumerate() {
+                    if idx != 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    value.fmt(formatter)?;
+                }
+                formatter.write_str("]")
+            }
+            Value::Bool(true) => formatter.write_str("참"),
+            Value::Bool(false) => formatter.write_str("거짓"),
+            Value::Null => formatter.write_str("없음"),
         }
     }
 }
@@ -34,11 +129,7 @@ impl Display for Value {
 impl From<bool> for Value {
     #[inline]
     fn from(b: bool) -> Self {
-        if b {
-            Value::Int(1)
-        } else {
-            Value::Int(0)
-        }
+        Value::Bool(b)
     }
 }
 
@@ -48,6 +139,9 @@ impl<'a> From<Value> for bool {
         match v {
             Value::Int(i) => i != 0,
             Value::Str(s) => !s.is_empty(),
+            Value::List(list) => !list.is_empty(),
+            Value::Bool(b) => b,
+            Value::Null => false,
         }
     }
 }
@@ -58,6 +152,9 @@ impl<'a> From<&'a Value> for bool {
         match v {
             Value::Int(i) => *i != 0,
             Value::Str(s) => !s.is_empty(),
+            Value::List(list) => !list.is_empty(),
+            Value::Bool(b) => *b,
+            Value::Null => false,
         }
     }
 }
@@ -72,14 +169,21 @@ impl From<u32> for Value {
 impl From<String> for Value {
     #[inline]
     fn from(s: String) -> Self {
-        Value::Str(s)
+        Value::Str(Box::new(s))
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    #[inline]
+    fn from(list: Vec<Value>) -> Self {
+        Value::List(Box::new(list))
     }
 }
 
 impl<'a> From<&'a str> for Value {
     #[inline]
     fn from(s: &'a str) -> Self {
-        Value::Str(s.to_string())
+        Value::Str(Box::new(s.into()))
     }
 }
 
@@ -117,8 +221,107 @@ impl TryFrom<Value> for String {
     #[inline]
     fn try_from(v: Value) -> Result<Self, Self::Error> {
         match v {
-            Value::Str(s) => Ok(s),
+            Value::Str(s) => Ok(*s),
+            _ => Err(ValueConvertError(v.type_name())),
+        }
+    }
+}
+
+impl TryFrom<Value> for Vec<Value> {
+    type Error = ValueConvertError;
+
+    #[inline]
+    fn try_from(v: Value) -> Result<Self, Self::Error> {
+        match v {
+            Value::List(list) => Ok(*list),
             _ => Err(ValueConvertError(v.type_name())),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Value;
+    use pretty_assertions::assert_eq;
+    use std::cmp::Ordering;
+
+    #[test]
+    fn elements() {
+        let value = Value::from("가a나");
+        let mut pos = 0;
+        let mut elements = Vec::new();
+        while let Some((element, next)) = value.element_at(pos) {
+            elements.push(element);
+            pos = next;
+        }
+        assert_eq!(
+            elements,
+            [Value::from("가"), Value::from("a"), Value::from("나")]
+        );
+        assert_eq!(Value::Int(3).element_at(0), None);
+        assert_eq!(
+            Value::from(vec![Value::Int(1)]).element_at(0),
+            Some((Value::Int(1), 1))
+        );
+    }
+
+    #[test]
+    fn heap_size() {
+        assert_eq!(Value::Int(3).heap_size(), 0);
+        assert_eq!(
+            Value::from("가a").heap_size(),
+            std::mem::size_of::<String>() + 4
+        );
+        assert_eq!(
+            Value::from(vec![Value::from("abc"), Value::Null]).heap_size(),
+            std::mem::size_of::<Vec<Value>>()
+                + 2 * std::mem::size_of::<Value>()
+                + std::mem::size_of::<String>()
+                + 3
+        );
+    }
+
+    #[test]
+    fn compare() {
+        assert_eq!(Value::Int(2).compare(&Value::Int(10)), Some(Ordering::Less));
+        assert_eq!(
+            Value::from("나").compare(&Value::from("가")),
+            Some(Ordering::Greater)
+        );
+        assert_eq!(Value::Int(1).compare(&Value::from("1")), None);
+        assert_ne!(Value::Int(1), Value::from("1"));
+        assert_eq!(Value::from("가"), Value::from(String::from("가")));
+
+        let list = Value::from(vec![Value::Int(1), Value::from(vec![Value::from("가")])]);
+        assert_eq!(list, list.clone());
+        assert_ne!(list, Value::from(vec![Value::Int(1)]));
+        assert_eq!(list.compare(&list), None);
+        assert_eq!(list.to_string(), "[1, [가]]");
+    }
+
+    #[test]
+    fn bool() {
+        assert_eq!(Value::from(true).to_string(), "참");
+        assert_eq!(Value::from(false).type_name(), "bool");
+        assert_ne!(Value::from(true), Value::Int(1));
+        assert_eq!(Value::from(true).compare(&Value::from(false)), None);
+
+        let conditions = [
+            Value::from(false),
+            Value::Int(0),
+            Value::from(""),
+            Value::from(Vec::new()),
+            Value::Null,
+        ];
+        assert!(conditions.iter().all(|value| !value.into_bool()));
+        assert!(Value::Int(2).into_bool() && Value::from(true).into_bool());
+    }
+
+    #[test]
+    fn null() {
+        assert_eq!(Value::Null.to_string(), "없음");
+        assert_eq!(Value::Null.type_name(), "null");
+        assert_ne!(Value::Null, Value::Int(0));
+        assert_eq!(Value::Null.element_at(0), None);
+    }
+}
@@ -0,0 +1,77 @@
+//! Golden tests over `tests/corpus`
+//!
+//! Every `<name>.kes` has `<name>.formatted.kes` which is expected output of formatter,
+//! and `<name>.trace` which is `RecordBuiltin` text of running it.
+//! Run with `KES_BLESS=1` to rewrite expected files after intended formatter change.
+
+use futures_executor::block_on;
+use kes::builtin::RecordBuiltin;
+use kes::context::Context;
+use kes::formatter::format_code_to_string;
+use kes::program::Program;
+use pretty_assertions::assert_eq;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn corpus_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/corpus")
+}
+
+fn sources() -> Vec<PathBuf> {
+    let mut sources = fs::read_dir(corpus_dir())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            let name = path.file_name().unwrap().to_str().unwrap();
+            name.ends_with(".kes") && !name.ends_with(".formatted.kes")
+        })
+        .collect::<Vec<_>>();
+    sources.sort();
+    sources
+}
+
+fn trace(code: &str) -> String {
+    let program = Program::from_source(code).unwrap();
+    let mut builtin = RecordBuiltin::new();
+    block_on(Context::new(&program).run(&mut builtin)).unwrap();
+    builtin.text().into()
+}
+
+/// Compare with golden file, or overwrite it when blessing
+fn check_golden(path: &Path, actual: &str) {
+    if std::env::var_os("KES_BLESS").is_some() {
+        fs::write(path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("{} 를 읽을 수 없습니다: {}", path.display(), err));
+    assert_eq!(expected, actual, "{}", path.display());
+}
+
+#[test]
+fn corpus() {
+    let sources = sources();
+    assert!(!sources.is_empty());
+
+    for source in sources {
+        let code = fs::read_to_string(&source).unwrap();
+        let formatted = format_code_to_string(&code).unwrap();
+
+        check_golden(&source.with_extension("formatted.kes"), &formatted);
+
+        // formatting is stable
+        assert_eq!(
+            format_code_to_string(&formatted).unwrap(),
+            formatted,
+            "{}",
+            source.display()
+        );
+
+        // formatting doesn't change behavior
+        let original = trace(&code);
+        assert_eq!(original, trace(&formatted), "{}", source.display());
+
+        check_golden(&source.with_extension("trace"), &format!("{}\n", original));
+    }
+}